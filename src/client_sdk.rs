@@ -33,6 +33,13 @@ SOFTWARE.
 //! - Pool ID calculation
 //! - Error handling and validation
 //! - Type-safe pool configuration
+//! - Pool configuration snapshots (`GetPoolConfig`) for backup and replication
+//! - Typed `Swap`, `Deposit` and `Withdraw` instruction builders with the exact account order,
+//!   as `PoolClient` methods or as the free functions `build_swap_instruction`,
+//!   `build_deposit_instruction` and `build_withdraw_instruction`
+//! - Decimal-aware ratio conversion (`normalize_ratio`) from human ratios to base units
+//! - End-to-end pool setup (`setup_pool`): new mints, `InitializePool` and seed liquidity
+//!   as ready-to-sign instruction batches
 //!
 //! ## Quick Start
 //! 
@@ -74,12 +81,27 @@ SOFTWARE.
 //! # }
 //! ```
 
-use solana_program::pubkey::Pubkey;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction, system_program, sysvar,
+};
+use spl_associated_token_account::{get_associated_token_address, instruction::create_associated_token_account};
 
 use crate::{
-    constants::{POOL_STATE_SEED_PREFIX, TOKEN_A_VAULT_SEED_PREFIX, TOKEN_B_VAULT_SEED_PREFIX},
+    constants::{
+        LP_TOKEN_A_MINT_SEED_PREFIX, LP_TOKEN_B_MINT_SEED_PREFIX, MAIN_TREASURY_SEED_PREFIX,
+        POOL_STATE_SEED_PREFIX, SYSTEM_STATE_SEED_PREFIX, TOKEN_A_VAULT_SEED_PREFIX,
+        TOKEN_B_VAULT_SEED_PREFIX,
+    },
+    types::PoolInstruction,
 };
 
+pub use crate::types::PortablePoolConfig;
+
 /// Errors that can occur when using the pool client
 #[derive(Debug)]
 pub enum PoolClientError {
@@ -87,6 +109,10 @@ pub enum PoolClientError {
     InvalidRatio,
     /// Invalid deposit token (must be either multiple or base token)
     InvalidDepositToken,
+    /// Token mint is neither of the pool's tokens
+    InvalidTokenMint,
+    /// Ratio does not fit in a u64 once scaled to base units
+    RatioOverflow,
     /// Feature not yet implemented
     NotImplemented,
     /// Error during instruction serialization
@@ -104,6 +130,8 @@ impl std::fmt::Display for PoolClientError {
         match self {
             PoolClientError::InvalidRatio => write!(f, "Invalid ratio: must be greater than 0"),
             PoolClientError::InvalidDepositToken => write!(f, "Invalid deposit token: must be either multiple or base token"),
+            PoolClientError::InvalidTokenMint => write!(f, "Invalid token mint: must be one of the pool's tokens"),
+            PoolClientError::RatioOverflow => write!(f, "Ratio overflow: scaled ratio does not fit in a u64"),
             PoolClientError::NotImplemented => write!(f, "Feature not yet implemented"),
             PoolClientError::SerializationError => write!(f, "Failed to serialize instruction data"),
         }
@@ -196,6 +224,10 @@ pub struct PoolAddresses {
     pub token_b_vault: Pubkey,
     /// Token B vault bump seed
     pub token_b_vault_bump: u8,
+    /// LP Token A mint address
+    pub lp_token_a_mint: Pubkey,
+    /// LP Token B mint address
+    pub lp_token_b_mint: Pubkey,
 }
 
 /// High-level client for interacting with Fixed Ratio Trading Pools
@@ -273,6 +305,16 @@ impl PoolClient {
             &self.program_id,
         );
         
+        // Derive LP mint PDAs
+        let (lp_token_a_mint, _) = Pubkey::find_program_address(
+            &[LP_TOKEN_A_MINT_SEED_PREFIX, pool_state.as_ref()],
+            &self.program_id,
+        );
+        let (lp_token_b_mint, _) = Pubkey::find_program_address(
+            &[LP_TOKEN_B_MINT_SEED_PREFIX, pool_state.as_ref()],
+            &self.program_id,
+        );
+        
         PoolAddresses {
             pool_state,
             pool_authority_bump,
@@ -284,6 +326,8 @@ impl PoolClient {
             token_a_vault_bump,
             token_b_vault,
             token_b_vault_bump,
+            lp_token_a_mint,
+            lp_token_b_mint,
         }
     }
     
@@ -321,8 +365,481 @@ impl PoolClient {
         addresses.pool_state  // The pool state PDA serves as the unique pool ID
     }
 
+    /// Decodes the return data of a `GetPoolConfig` call into a pool snapshot.
+    /// 
+    /// # Arguments
+    /// * `return_data` - Return data from simulating `GetPoolConfig`
+    /// 
+    /// # Returns
+    /// * `Result<PortablePoolConfig, PoolClientError>` - The snapshot or a serialization error
+    pub fn decode_pool_config(&self, return_data: &[u8]) -> Result<PortablePoolConfig, PoolClientError> {
+        Ok(PortablePoolConfig::try_from_slice(return_data)?)
+    }
+
+    /// Builds the creation config (mints and ratio) of a snapshotted pool.
+    /// 
+    /// Passing it to `derive_pool_addresses` gives the recreated pool's addresses
+    /// under this client's program ID.
+    /// 
+    /// # Arguments
+    /// * `snapshot` - Pool snapshot from `decode_pool_config`
+    /// 
+    /// # Returns
+    /// * `Result<PoolConfig, PoolClientError>` - The pool configuration or an error
+    pub fn pool_config_from_snapshot(&self, snapshot: &PortablePoolConfig) -> Result<PoolConfig, PoolClientError> {
+        PoolConfig::new(
+            snapshot.token_a_mint,
+            snapshot.token_b_mint,
+            snapshot.ratio_a_numerator,
+            snapshot.ratio_b_denominator,
+        )
+    }
+
+    /// Builds the instruction that recreates a snapshotted pool.
+    /// 
+    /// Carries over the ratio, creation flags and swap fee. The liquidity fee,
+    /// amount limits, LP cap, reserve floors, minimum reserve, swap mode and
+    /// owner-only delegation are set afterwards with their admin instructions.
+    /// 
+    /// # Arguments
+    /// * `snapshot` - Pool snapshot from `decode_pool_config`
+    /// 
+    /// # Returns
+    /// * `PoolInstruction` - `InitializePoolWithSwapFee` for the snapshot
+    pub fn recreate_pool_instruction(&self, snapshot: &PortablePoolConfig) -> PoolInstruction {
+        PoolInstruction::InitializePoolWithSwapFee {
+            ratio_a_numerator: snapshot.ratio_a_numerator,
+            ratio_b_denominator: snapshot.ratio_b_denominator,
+            flags: snapshot.flags,
+            initial_swap_contract_fee: Some(snapshot.swap_contract_fee),
+        }
+    }
+
+    /// Builds a `Swap` instruction in the exact account order `process_swap_execute` expects.
+    /// 
+    /// The user's input and output accounts are their associated token accounts for the
+    /// mint being sold and the other pool token. The Main Treasury PDA is always appended,
+    /// so the instruction keeps working when a protocol swap SOL fee is set.
+    /// 
+    /// # Arguments
+    /// * `pool` - Pool addresses from `derive_pool_addresses`
+    /// * `user` - Swapping wallet (signer, pays the SOL fees)
+    /// * `input_mint` - Mint being sold (Token A or Token B)
+    /// * `amount_in` - Amount of the input token to swap
+    /// * `expected_amount_out` - Ratio-derived output; fixed-ratio swaps fail unless it matches exactly
+    /// 
+    /// # Returns
+    /// * `Result<Instruction, PoolClientError>` - The instruction (12 accounts) or an error
+    /// 
+    /// # Errors
+    /// * `InvalidTokenMint` - If `input_mint` is not one of the pool's tokens
+    pub fn build_swap_instruction(
+        &self,
+        pool: &PoolAddresses,
+        user: &Pubkey,
+        input_mint: &Pubkey,
+        amount_in: u64,
+        expected_amount_out: u64,
+    ) -> Result<Instruction, PoolClientError> {
+        let output_mint = self.other_pool_mint(pool, input_mint)?;
+        let data = PoolInstruction::Swap {
+            input_token_mint: *input_mint,
+            amount_in,
+            expected_amount_out,
+            pool_id: pool.pool_state,
+        }.try_to_vec()?;
+        
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*user, true),                                               // 0: User Authority Signer
+                AccountMeta::new_readonly(system_program::id(), false),                      // 1: System Program
+                AccountMeta::new_readonly(self.system_state_address(), false),              // 2: System State PDA
+                AccountMeta::new(pool.pool_state, false),                                    // 3: Pool State PDA
+                AccountMeta::new_readonly(spl_token::id(), false),                           // 4: SPL Token Program
+                AccountMeta::new(pool.token_a_vault, false),                                 // 5: Token A Vault PDA
+                AccountMeta::new(pool.token_b_vault, false),                                 // 6: Token B Vault PDA
+                AccountMeta::new(get_associated_token_address(user, input_mint), false),     // 7: User Input Token Account
+                AccountMeta::new(get_associated_token_address(user, &output_mint), false),   // 8: User Output Token Account
+                AccountMeta::new_readonly(*input_mint, false),                               // 9: Input Token Mint
+                AccountMeta::new_readonly(output_mint, false),                               // 10: Output Token Mint
+                AccountMeta::new(self.main_treasury_address(), false),                       // 11: Main Treasury PDA
+            ],
+            data,
+        })
+    }
+
+    /// Builds a `Deposit` instruction in the exact account order `process_liquidity_deposit` expects.
+    /// 
+    /// Tokens come from the user's associated token account for `deposit_mint`, and LP tokens
+    /// go to the user's associated token account for the matching LP mint, which must exist.
+    /// 
+    /// # Arguments
+    /// * `pool` - Pool addresses from `derive_pool_addresses`
+    /// * `user` - Depositing wallet (signer, pays the SOL fee)
+    /// * `deposit_mint` - Token being deposited (Token A or Token B)
+    /// * `amount` - Amount to deposit; LP tokens are minted 1:1
+    /// 
+    /// # Returns
+    /// * `Result<Instruction, PoolClientError>` - The instruction (11 accounts) or an error
+    /// 
+    /// # Errors
+    /// * `InvalidTokenMint` - If `deposit_mint` is not one of the pool's tokens
+    pub fn build_deposit_instruction(
+        &self,
+        pool: &PoolAddresses,
+        user: &Pubkey,
+        deposit_mint: &Pubkey,
+        amount: u64,
+    ) -> Result<Instruction, PoolClientError> {
+        let lp_mint = self.lp_mint_for(pool, deposit_mint)?;
+        let data = PoolInstruction::Deposit {
+            deposit_token_mint: *deposit_mint,
+            amount,
+            pool_id: pool.pool_state,
+        }.try_to_vec()?;
+        
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*user, true),                                             // 0: User Authority Signer
+                AccountMeta::new_readonly(system_program::id(), false),                    // 1: System Program
+                AccountMeta::new_readonly(self.system_state_address(), false),            // 2: System State PDA
+                AccountMeta::new(pool.pool_state, false),                                  // 3: Pool State PDA
+                AccountMeta::new_readonly(spl_token::id(), false),                         // 4: SPL Token Program
+                AccountMeta::new(pool.token_a_vault, false),                               // 5: Token A Vault PDA
+                AccountMeta::new(pool.token_b_vault, false),                               // 6: Token B Vault PDA
+                AccountMeta::new(get_associated_token_address(user, deposit_mint), false), // 7: User Input Token Account
+                AccountMeta::new(get_associated_token_address(user, &lp_mint), false),     // 8: User Output LP Token Account
+                AccountMeta::new(pool.lp_token_a_mint, false),                             // 9: LP Token A Mint PDA
+                AccountMeta::new(pool.lp_token_b_mint, false),                             // 10: LP Token B Mint PDA
+            ],
+            data,
+        })
+    }
+
+    /// Builds a `Withdraw` instruction in the exact account order `process_liquidity_withdraw` expects.
+    /// 
+    /// LP tokens are burned from the user's associated token account for the LP mint that
+    /// matches `withdraw_mint`, and the tokens go to the user's associated account for `withdraw_mint`.
+    /// 
+    /// # Arguments
+    /// * `pool` - Pool addresses from `derive_pool_addresses`
+    /// * `user` - Withdrawing wallet (signer, pays the SOL fee)
+    /// * `withdraw_mint` - Token to receive (Token A or Token B)
+    /// * `lp_amount_to_burn` - LP tokens to burn; the same amount of `withdraw_mint` is returned
+    /// 
+    /// # Returns
+    /// * `Result<Instruction, PoolClientError>` - The instruction (11 accounts) or an error
+    /// 
+    /// # Errors
+    /// * `InvalidTokenMint` - If `withdraw_mint` is not one of the pool's tokens
+    pub fn build_withdraw_instruction(
+        &self,
+        pool: &PoolAddresses,
+        user: &Pubkey,
+        withdraw_mint: &Pubkey,
+        lp_amount_to_burn: u64,
+    ) -> Result<Instruction, PoolClientError> {
+        let lp_mint = self.lp_mint_for(pool, withdraw_mint)?;
+        let data = PoolInstruction::Withdraw {
+            withdraw_token_mint: *withdraw_mint,
+            lp_amount_to_burn,
+            pool_id: pool.pool_state,
+        }.try_to_vec()?;
+        
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*user, true),                                              // 0: User Authority Signer
+                AccountMeta::new_readonly(system_program::id(), false),                     // 1: System Program
+                AccountMeta::new_readonly(self.system_state_address(), false),             // 2: System State PDA
+                AccountMeta::new(pool.pool_state, false),                                   // 3: Pool State PDA
+                AccountMeta::new_readonly(spl_token::id(), false),                          // 4: SPL Token Program
+                AccountMeta::new(pool.token_a_vault, false),                                // 5: Token A Vault PDA
+                AccountMeta::new(pool.token_b_vault, false),                                // 6: Token B Vault PDA
+                AccountMeta::new(get_associated_token_address(user, &lp_mint), false),      // 7: User Input LP Token Account
+                AccountMeta::new(get_associated_token_address(user, withdraw_mint), false), // 8: User Output Token Account
+                AccountMeta::new(pool.lp_token_a_mint, false),                              // 9: LP Token A Mint PDA
+                AccountMeta::new(pool.lp_token_b_mint, false),                              // 10: LP Token B Mint PDA
+            ],
+            data,
+        })
+    }
+
+    /// System State PDA of this client's program
+    fn system_state_address(&self) -> Pubkey {
+        Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &self.program_id).0
+    }
+
+    /// Main Treasury PDA of this client's program
+    fn main_treasury_address(&self) -> Pubkey {
+        Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &self.program_id).0
+    }
+
+    /// The pool token on the other side of `mint`
+    fn other_pool_mint(&self, pool: &PoolAddresses, mint: &Pubkey) -> Result<Pubkey, PoolClientError> {
+        if *mint == pool.token_a_mint {
+            Ok(pool.token_b_mint)
+        } else if *mint == pool.token_b_mint {
+            Ok(pool.token_a_mint)
+        } else {
+            Err(PoolClientError::InvalidTokenMint)
+        }
+    }
+
+    /// The LP mint that represents deposits of `mint`
+    fn lp_mint_for(&self, pool: &PoolAddresses, mint: &Pubkey) -> Result<Pubkey, PoolClientError> {
+        if *mint == pool.token_a_mint {
+            Ok(pool.lp_token_a_mint)
+        } else if *mint == pool.token_b_mint {
+            Ok(pool.lp_token_b_mint)
+        } else {
+            Err(PoolClientError::InvalidTokenMint)
+        }
+    }
+
 }
 
+/// Builds a complete `Swap` instruction from a pool configuration.
+/// 
+/// Shorthand for `PoolClient::build_swap_instruction` on the pool's derived addresses:
+/// every PDA is derived here and the 12 accounts are in `process_swap_execute` order.
+/// 
+/// # Arguments
+/// * `program_id` - Fixed Ratio Trading program ID
+/// * `pool_config` - Pool mints and ratio
+/// * `user` - Swapping wallet (signer)
+/// * `input_mint` - Mint being sold
+/// * `amount_in` - Amount of the input token to swap
+/// * `expected_amount_out` - Ratio-derived output; fixed-ratio swaps fail unless it matches exactly
+/// 
+/// # Errors
+/// * `InvalidTokenMint` - If `input_mint` is not one of the pool's tokens
+pub fn build_swap_instruction(
+    program_id: &Pubkey,
+    pool_config: &PoolConfig,
+    user: &Pubkey,
+    input_mint: &Pubkey,
+    amount_in: u64,
+    expected_amount_out: u64,
+) -> Result<Instruction, PoolClientError> {
+    let client = PoolClient::new(*program_id);
+    let pool = client.derive_pool_addresses(pool_config);
+    client.build_swap_instruction(&pool, user, input_mint, amount_in, expected_amount_out)
+}
+
+/// Converts a human-readable ratio into the base-unit ratio pools are created with.
+/// 
+/// `display_ratio` is `(whole Token A, whole Token B)`; each side is scaled by its
+/// mint's decimals, so a 1:1 ratio between a 6-decimal Token A and a 9-decimal
+/// Token B becomes `(1_000_000, 1_000_000_000)`. Pass the result to `PoolConfig::new`
+/// or `InitializePool`.
+/// 
+/// # Errors
+/// * `InvalidRatio` - If either side of `display_ratio` is 0
+/// * `RatioOverflow` - If either scaled side does not fit in a `u64`
+pub fn normalize_ratio(
+    display_ratio: (u64, u64),
+    decimals_a: u8,
+    decimals_b: u8,
+) -> Result<(u64, u64), PoolClientError> {
+    let (display_a, display_b) = display_ratio;
+    if display_a == 0 || display_b == 0 {
+        return Err(PoolClientError::InvalidRatio);
+    }
+    crate::types::ratio::scale_display_ratio(display_a, display_b, decimals_a, decimals_b)
+        .ok_or(PoolClientError::RatioOverflow)
+}
+
+/// Builds a complete `Deposit` instruction from a pool configuration.
+/// 
+/// Shorthand for `PoolClient::build_deposit_instruction` on the pool's derived addresses.
+/// 
+/// # Errors
+/// * `InvalidTokenMint` - If `deposit_mint` is not one of the pool's tokens
+pub fn build_deposit_instruction(
+    program_id: &Pubkey,
+    pool_config: &PoolConfig,
+    user: &Pubkey,
+    deposit_mint: &Pubkey,
+    amount: u64,
+) -> Result<Instruction, PoolClientError> {
+    let client = PoolClient::new(*program_id);
+    let pool = client.derive_pool_addresses(pool_config);
+    client.build_deposit_instruction(&pool, user, deposit_mint, amount)
+}
+
+/// Builds a complete `Withdraw` instruction from a pool configuration.
+/// 
+/// Shorthand for `PoolClient::build_withdraw_instruction` on the pool's derived addresses.
+/// 
+/// # Errors
+/// * `InvalidTokenMint` - If `withdraw_mint` is not one of the pool's tokens
+pub fn build_withdraw_instruction(
+    program_id: &Pubkey,
+    pool_config: &PoolConfig,
+    user: &Pubkey,
+    withdraw_mint: &Pubkey,
+    lp_amount_to_burn: u64,
+) -> Result<Instruction, PoolClientError> {
+    let client = PoolClient::new(*program_id);
+    let pool = client.derive_pool_addresses(pool_config);
+    client.build_withdraw_instruction(&pool, user, withdraw_mint, lp_amount_to_burn)
+}
 
+/// Compute Budget program, which `solana_program` does not expose
+const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = solana_program::pubkey!("ComputeBudget111111111111111111111111111111");
 
- 
\ No newline at end of file
+/// Compute unit limit requested for the `InitializePool` transaction
+const INITIALIZE_POOL_COMPUTE_UNITS: u32 = 500_000;
+
+/// Compute unit limit requested for the seed liquidity transaction (up to two deposits)
+const SEED_LIQUIDITY_COMPUTE_UNITS: u32 = 800_000;
+
+/// Builds a Compute Budget `SetComputeUnitLimit` instruction
+fn set_compute_unit_limit(units: u32) -> Instruction {
+    let mut data = vec![2u8]; // SetComputeUnitLimit
+    data.extend_from_slice(&units.to_le_bytes());
+    Instruction::new_with_bytes(COMPUTE_BUDGET_PROGRAM_ID, &data, vec![])
+}
+
+/// Parameters for `setup_pool`
+#[derive(Debug, Clone)]
+pub struct PoolSetupParams {
+    /// Wallet that pays rent and fees, is the mint authority of both new mints,
+    /// creates the pool and deposits the seed liquidity
+    pub payer: Pubkey,
+    /// Address of the new multiple token mint (the caller holds its keypair)
+    pub multiple_token_mint: Pubkey,
+    /// Address of the new base token mint (the caller holds its keypair)
+    pub base_token_mint: Pubkey,
+    /// Decimals of both new mints
+    pub decimals: u8,
+    /// Token A base units, as in `PoolConfig`
+    pub ratio_a_numerator: u64,
+    /// Token B base units, as in `PoolConfig`
+    pub ratio_b_denominator: u64,
+    /// Tokens of each mint minted to the payer's associated token accounts
+    pub initial_balance: u64,
+    /// Token A deposited as initial liquidity (0 = no Token A deposit)
+    pub seed_amount_a: u64,
+    /// Token B deposited as initial liquidity (0 = no Token B deposit)
+    pub seed_amount_b: u64,
+}
+
+/// Everything `setup_pool` produces: the pool's configuration and addresses, plus the
+/// instructions that create it, as three transactions to send in order
+#[derive(Debug, Clone)]
+pub struct PoolSetup {
+    /// Pool configuration for the new mints and ratio
+    pub config: PoolConfig,
+    /// Derived pool addresses (pool state, vaults, LP mints)
+    pub addresses: PoolAddresses,
+    /// Transaction 1: create both mints and fund the payer's token accounts
+    /// (signers: payer and both mint keypairs)
+    pub create_mints: Vec<Instruction>,
+    /// Transaction 2: `InitializePool` with a raised compute limit (signer: payer)
+    pub initialize_pool: Vec<Instruction>,
+    /// Transaction 3: create the payer's LP token accounts and deposit the seed liquidity
+    /// (signer: payer)
+    pub seed_liquidity: Vec<Instruction>,
+}
+
+impl PoolSetup {
+    /// The three setup transactions in the order they must be sent
+    pub fn transactions(&self) -> [&[Instruction]; 3] {
+        [&self.create_mints, &self.initialize_pool, &self.seed_liquidity]
+    }
+}
+
+/// Builds every instruction needed to stand up a usable pool from scratch.
+/// 
+/// Mirrors what the test suite does by hand: create two fresh SPL mints (with `payer`
+/// as mint authority), fund the payer's associated token accounts, `InitializePool`
+/// with the pool creation fee paid by `payer`, then create the payer's LP token accounts
+/// and deposit the seed liquidity. Nothing is sent; the caller signs and submits
+/// `PoolSetup::transactions()` in order. The program itself must already be initialized
+/// (`InitializeProgram`).
+/// 
+/// # Arguments
+/// * `program_id` - Fixed Ratio Trading program ID
+/// * `params` - Mints, ratio, balances and seed amounts
+/// 
+/// # Errors
+/// * `InvalidRatio` - If either side of the ratio is 0
+/// * `InvalidDepositToken` - If both mint addresses are the same
+/// * `SerializationError` - If instruction data cannot be serialized
+pub fn setup_pool(program_id: &Pubkey, params: &PoolSetupParams) -> Result<PoolSetup, PoolClientError> {
+    let config = PoolConfig::new(
+        params.multiple_token_mint,
+        params.base_token_mint,
+        params.ratio_a_numerator,
+        params.ratio_b_denominator,
+    )?;
+    let client = PoolClient::new(*program_id);
+    let addresses = client.derive_pool_addresses(&config);
+    let payer = &params.payer;
+
+    let mint_rent = Rent::default().minimum_balance(spl_token::state::Mint::LEN);
+    let mut create_mints = Vec::new();
+    for mint in [&params.multiple_token_mint, &params.base_token_mint] {
+        create_mints.push(system_instruction::create_account(
+            payer, mint, mint_rent, spl_token::state::Mint::LEN as u64, &spl_token::id(),
+        ));
+        create_mints.push(
+            spl_token::instruction::initialize_mint2(&spl_token::id(), mint, payer, None, params.decimals)
+                .map_err(|_| PoolClientError::SerializationError)?,
+        );
+        create_mints.push(create_associated_token_account(payer, payer, mint, &spl_token::id()));
+        create_mints.push(
+            spl_token::instruction::mint_to(
+                &spl_token::id(), mint, &get_associated_token_address(payer, mint), payer, &[], params.initial_balance,
+            ).map_err(|_| PoolClientError::SerializationError)?,
+        );
+    }
+
+    let initialize_pool_ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*payer, true),                                 // 0: User Authority Signer
+            AccountMeta::new_readonly(system_program::id(), false),         // 1: System Program
+            AccountMeta::new_readonly(client.system_state_address(), false), // 2: System State PDA
+            AccountMeta::new(addresses.pool_state, false),                  // 3: Pool State PDA
+            AccountMeta::new_readonly(spl_token::id(), false),              // 4: SPL Token Program
+            AccountMeta::new(client.main_treasury_address(), false),        // 5: Main Treasury PDA
+            AccountMeta::new_readonly(sysvar::rent::id(), false),           // 6: Rent Sysvar
+            AccountMeta::new_readonly(addresses.token_a_mint, false),       // 7: Token A Mint
+            AccountMeta::new_readonly(addresses.token_b_mint, false),       // 8: Token B Mint
+            AccountMeta::new(addresses.token_a_vault, false),               // 9: Token A Vault PDA
+            AccountMeta::new(addresses.token_b_vault, false),               // 10: Token B Vault PDA
+            AccountMeta::new(addresses.lp_token_a_mint, false),             // 11: LP Token A Mint PDA
+            AccountMeta::new(addresses.lp_token_b_mint, false),             // 12: LP Token B Mint PDA
+        ],
+        data: PoolInstruction::InitializePool {
+            ratio_a_numerator: addresses.ratio_a_numerator,
+            ratio_b_denominator: addresses.ratio_b_denominator,
+            flags: 0u8, // Default flags for standard pool behavior
+        }.try_to_vec()?,
+    };
+    let initialize_pool = vec![set_compute_unit_limit(INITIALIZE_POOL_COMPUTE_UNITS), initialize_pool_ix];
+
+    let mut seed_liquidity = vec![
+        set_compute_unit_limit(SEED_LIQUIDITY_COMPUTE_UNITS),
+        create_associated_token_account(payer, payer, &addresses.lp_token_a_mint, &spl_token::id()),
+        create_associated_token_account(payer, payer, &addresses.lp_token_b_mint, &spl_token::id()),
+    ];
+    for (mint, amount) in [(addresses.token_a_mint, params.seed_amount_a), (addresses.token_b_mint, params.seed_amount_b)] {
+        if amount > 0 {
+            seed_liquidity.push(client.build_deposit_instruction(&addresses, payer, &mint, amount)?);
+        }
+    }
+
+    Ok(PoolSetup {
+        config,
+        addresses,
+        create_mints,
+        initialize_pool,
+        seed_liquidity,
+    })
+}
@@ -112,6 +112,14 @@ pub const SWAP_CONTRACT_FEE: u64 = 271_500; // 0.0002715 SOL
 /// **Purpose**: Prevent accidental account closure due to rent calculation variations
 pub const MINIMUM_RENT_BUFFER: u64 = 1000;
 
+/// Base Solana transaction fee per signature in lamports
+/// 
+/// Used by cost-estimation views to approximate the network fee a user pays
+/// on top of the program's contract fees.
+/// 
+/// **Amount**: 5000 lamports (single signer transaction)
+pub const BASE_SIGNATURE_FEE: u64 = 5_000;
+
 //=============================================================================
 // TREASURY TYPE CODES
 //=============================================================================
@@ -158,6 +166,12 @@ pub const LP_TOKEN_A_MINT_SEED_PREFIX: &[u8] = b"lp_token_a_mint";
 
 pub const LP_TOKEN_B_MINT_SEED_PREFIX: &[u8] = b"lp_token_b_mint";
 
+/// Migration escrow seed prefixes: token accounts of a migrated pool (seeded with its pool
+/// state PDA) holding the destination LP tokens until source LP holders claim them
+pub const MIGRATION_ESCROW_LP_A_SEED_PREFIX: &[u8] = b"migration_escrow_lp_a";
+
+pub const MIGRATION_ESCROW_LP_B_SEED_PREFIX: &[u8] = b"migration_escrow_lp_b";
+
 
 
 //=============================================================================
@@ -172,26 +186,59 @@ pub const LP_TOKEN_B_MINT_SEED_PREFIX: &[u8] = b"lp_token_b_mint";
 /// This limit ensures the transaction stays within the 200K CU limit
 pub const MAX_POOLS_PER_CONSOLIDATION_BATCH: u8 = 20;
 
+/// Longest consolidation skip window the admin can set (7 days)
+pub const MAX_CONSOLIDATION_SKIP_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+/// Maximum length in bytes of the admin's on-chain system message (UTF-8)
+pub const SYSTEM_MESSAGE_MAX_LEN: usize = 128;
+
+/// Maximum number of pools reported by a single `GetAllPoolFees` call
+/// A 4-byte count plus 21 entries of 48 bytes fits the 1,024-byte return data limit
+pub const MAX_POOLS_PER_FEE_QUERY: usize = 21;
+
+/// Maximum number of pools reported by a single `GetMultiplePoolInfo` call
+/// Two 4-byte lengths, 12 summaries of 80 bytes and the skipped indices fit the 1,024-byte return data limit
+pub const MAX_POOLS_PER_INFO_QUERY: usize = 12;
+
 /// Pause reason code for consolidation operations
 /// This code indicates the system was paused specifically for fee consolidation
 pub const PAUSE_REASON_CONSOLIDATION: u8 = 15;
 
+//=============================================================================
+// SWAP ROUTING CONFIGURATION
+//=============================================================================
+
+/// Maximum number of pools a single SwapRoute instruction may pass through
+pub const MAX_SWAP_ROUTE_HOPS: usize = 3;
+
 //=============================================================================
 // POOL PAUSE BITWISE FLAGS
 //=============================================================================
 
 /// Pause liquidity operations (deposits and withdrawals only)
-/// Sets POOL_FLAG_LIQUIDITY_PAUSED in pool_state.flags
+/// Same as `PAUSE_DEPOSITS | PAUSE_WITHDRAWALS`; sets POOL_FLAG_LIQUIDITY_PAUSED in pool_state.flags
 pub const PAUSE_FLAG_LIQUIDITY: u8 = 0b01; // 1
 
 /// Pause swap operations only
-/// Sets POOL_FLAG_SWAPS_PAUSED in pool_state.flags
+/// Same as `PAUSE_SWAP_A_TO_B | PAUSE_SWAP_B_TO_A`; sets POOL_FLAG_SWAPS_PAUSED in pool_state.flags
 pub const PAUSE_FLAG_SWAPS: u8 = 0b10; // 2
 
 /// Pause all operations (liquidity + swaps)
 /// Required combination for consolidation eligibility
 pub const PAUSE_FLAG_ALL: u8 = PAUSE_FLAG_LIQUIDITY | PAUSE_FLAG_SWAPS; // 3
 
+/// Pause swaps from Token A to Token B only (B → A stays open)
+pub const PAUSE_SWAP_A_TO_B: u8 = 0b0000_0100; // 4
+
+/// Pause swaps from Token B to Token A only (A → B stays open)
+pub const PAUSE_SWAP_B_TO_A: u8 = 0b0000_1000; // 8
+
+/// Pause deposits only (withdrawals stay open)
+pub const PAUSE_DEPOSITS: u8 = 0b0001_0000; // 16
+
+/// Pause withdrawals only (deposits stay open)
+pub const PAUSE_WITHDRAWALS: u8 = 0b0010_0000; // 32
+
 //=============================================================================
 // POOL STATE BITWISE FLAGS
 //=============================================================================
@@ -267,6 +314,82 @@ pub const POOL_FLAG_SWAP_FOR_OWNERS_ONLY: u8 = 0b100000; // 32
 /// Default: Off unless explicitly enabled at initialization
 pub const POOL_FLAG_EXACT_EXCHANGE_REQUIRED: u8 = 0b0100_0000; // 64
 
+/// Pool state flag: LP token transfers frozen (non-transferable LP positions)
+/// 
+/// When this flag is set at pool creation, both LP mints get the pool state PDA as
+/// freeze authority and every user LP token account is kept frozen, so LP tokens
+/// cannot be transferred between wallets. Deposits and withdrawals thaw the user's
+/// LP account, mint or burn, and freeze it again within the same instruction.
+/// 
+/// Bit position: 7 (value 128)
+/// Set only at initialization: an LP mint's freeze authority cannot be added later
+pub const POOL_FLAG_LP_TRANSFERS_FROZEN: u8 = 0b1000_0000; // 128
+
+//=============================================================================
+// POOL STATE SCHEMA VERSIONS
+//=============================================================================
+
+/// Pool state layout version written by this program
+/// 
+/// - Version 1: Every field up to `reserve_safety_floor_b`, with no version field
+/// - Version 2: Version 1 plus the trailing `schema_version` field
+/// - Version 3: Version 2 plus the trailing `minimum_reserve` field
+/// - Version 4: Version 3 plus the trailing `swap_mode` field
+/// - Version 5: Version 4 plus the trailing `created_at` and `last_swap_at` fields
+/// - Version 6: Version 5 plus the trailing `min_allowed_fee` and `max_allowed_fee` fields
+/// - Version 7: Version 6 plus the trailing `lp_token_a_decimals` and `lp_token_b_decimals` fields
+/// - Version 8: Version 7 plus the trailing `partial_pause_flags` field
+/// - Version 9: Version 8 plus the trailing daily fee history (`daily_fee_snapshot_token_a`,
+///   `daily_fee_snapshot_token_b` and `snapshot_timestamp`)
+/// - Version 10: Version 9 plus the trailing `emit_events` field
+/// - Version 11: Version 10 plus the trailing owner transfer fields (`pending_owner` and
+///   `owner_transfer_requested_at`)
+/// - Version 12: Version 11 plus the trailing `total_operations` field
+/// - Version 13: Version 12 plus the trailing lifetime counters (`cumulative_volume_token_a`,
+///   `cumulative_volume_token_b` and `lifetime_fees`)
+/// - Version 14: Version 13 plus the trailing requested ratio (`original_ratio_a_numerator`
+///   and `original_ratio_b_denominator`); `ratio_a_numerator` and `ratio_b_denominator`
+///   are reduced by their GCD for pools created from this version on
+/// - Version 15: Version 14 plus the trailing `fee_recipient` (the MainTreasury when zeroed)
+/// 
+/// Accounts on an older layout are brought up to date with `MigratePoolState`.
+pub const POOL_STATE_SCHEMA_VERSION: u16 = 15;
+
+//=============================================================================
+// TOKEN DECIMALS
+//=============================================================================
+
+/// Most decimals a pool's underlying token mint may use
+/// 
+/// LP mints are created with the decimals of their underlying mint, so pool
+/// creation rejects mints outside SPL's customary 0-9 range.
+pub const MAX_TOKEN_DECIMALS: u8 = 9;
+
+//=============================================================================
+// POOL ACTIVITY
+//=============================================================================
+
+/// Seconds without a swap after which `GetPoolActivity` reports a pool as dormant (30 days)
+pub const POOL_DORMANCY_WINDOW_SECONDS: i64 = 30 * 24 * 3600;
+
+/// Length of a fee history day (UTC days, so day boundaries fall on multiples of this)
+pub const SECONDS_PER_DAY: i64 = 24 * 3600;
+
+/// Days of swap fee history each pool keeps for `GetFeeHistory`
+/// 
+/// The history is a fixed ring in `PoolState`, so this also fixes the account size.
+pub const FEE_HISTORY_DAYS: usize = 7;
+
+//=============================================================================
+// POOL OWNERSHIP TRANSFER
+//=============================================================================
+
+/// Seconds a nominated pool owner must wait before `AcceptOwnerTransfer` succeeds (72 hours)
+/// 
+/// Matches the program admin change timelock, leaving the current owner time to
+/// notice and cancel a transfer to the wrong address.
+pub const POOL_OWNER_TRANSFER_DELAY: i64 = 72 * 3600;
+
 //=============================================================================
 // FEE UPDATE BITWISE FLAGS
 //=============================================================================
@@ -309,10 +432,22 @@ pub const MIN_SWAP_FEE: u64 = 10_000; // 0.00001 SOL
 /// Prevents spam donations and ensures meaningful contributions
 pub const MIN_DONATION_AMOUNT: u64 = 100_000_000; // 0.1 SOL
 
+/// Number of recent donations kept in the treasury's ring buffer
+/// Sized so `GetRecentDonations` fits the 1024-byte return data limit
+pub const RECENT_DONATIONS_CAPACITY: usize = 12;
+
+/// Bytes of each donation message kept in the recent donations buffer
+/// Longer messages are truncated at a UTF-8 character boundary (the full text is only logged)
+pub const DONATION_MESSAGE_PREVIEW_LEN: usize = 32;
+
 /// Minimum treasury withdrawal amount in lamports (0.01 SOL)
 /// Prevents micro-withdrawals and ensures meaningful treasury operations
 pub const MIN_TREASURY_WITHDRAWAL_AMOUNT: u64 = 10_000_000; // 0.01 SOL
 
+/// Treasury withdrawal amount requesting the maximum currently allowed
+/// Withdraws everything above the rent reserve, capped at the current hourly rate limit
+pub const TREASURY_WITHDRAW_MAX_ALLOWED: u64 = u64::MAX;
+
 //=============================================================================
 // TREASURY WITHDRAWAL RATE LIMITING - DYNAMIC SCALING SYSTEM
 //=============================================================================
@@ -339,5 +474,13 @@ pub const TREASURY_RATE_SCALING_MULTIPLIER: u64 = 10;
 /// This creates a 3-day cooling-off period to prevent immediate fund drainage after system restart
 pub const TREASURY_SYSTEM_RESTART_PENALTY_SECONDS: i64 = 71 * 3600; // 71 hours in seconds
 
+/// Length of the treasury withdrawal cap window (24 hours in seconds)
+/// The window opens with the first withdrawal after the previous window ended
+pub const TREASURY_WITHDRAWAL_WINDOW_SECONDS: i64 = 24 * 3600; // 24 hours in seconds
+
+/// Most SOL the authority may withdraw from the treasury within one cap window (1,000 SOL)
+/// Bounds the damage of a compromised authority key regardless of the hourly rate tier
+pub const TREASURY_MAX_WITHDRAWAL_PER_WINDOW: u64 = 1_000_000_000_000; // 1,000 SOL in lamports
+
 
 
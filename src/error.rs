@@ -191,6 +191,251 @@ pub enum PoolError {
     /// Unsupported ratio type for pool creation
     #[error("Unsupported ratio type: {ratio_type}. Only SimpleRatio (1:2, 1:100) and DecimalRatio (1:100.24343) are supported")]
     UnsupportedRatioType { ratio_type: String },
+    
+    /// LP token mint account has not been initialized by the token program
+    #[error("LP token mint {mint} is not initialized")]
+    LpMintNotInitialized { mint: Pubkey },
+    
+    /// LP token mint has no mint authority, so no further LP tokens can be minted
+    #[error("LP token mint {mint} is frozen (no mint authority)")]
+    LpMintFrozen { mint: Pubkey },
+    
+    /// An account already exists at a derived LP token mint address
+    #[error("LP token mint {mint} already exists for another pool configuration")]
+    LpMintCollision { mint: Pubkey },
+    
+    /// Deposit would mint LP tokens beyond the pool's LP supply cap
+    #[error("LP supply cap reached: cap {cap}, current supply {current_supply}, requested {requested}")]
+    LpSupplyCapReached {
+        cap: u64,
+        current_supply: u64,
+        requested: u64,
+    },
+
+    /// Swap submitted with a deadline that has already passed
+    #[error("Swap deadline expired: deadline {deadline}, current timestamp {current_timestamp}")]
+    SwapDeadlineExpired {
+        deadline: i64,
+        current_timestamp: i64,
+    },
+
+    /// Treasury withdrawal would leave the treasury PDA below its rent-exempt minimum
+    #[error("Withdrawal would breach rent reserve: balance after {balance_after}, rent-exempt minimum {rent_exempt_minimum}")]
+    WouldBreachRentReserve {
+        balance_after: u64,
+        rent_exempt_minimum: u64,
+    },
+
+    /// Final leg of a multi-hop swap route produced less than the caller's minimum
+    #[error("Swap route output below minimum: minimum {minimum_final_out}, final output {final_amount_out}")]
+    RouteOutputBelowMinimum {
+        minimum_final_out: u64,
+        final_amount_out: u64,
+    },
+
+    /// Swap route has more hops than `MAX_SWAP_ROUTE_HOPS`
+    #[error("Swap route too long: {hops} hops, maximum {max_hops}")]
+    RouteTooLong {
+        hops: usize,
+        max_hops: usize,
+    },
+
+    /// Pool state account is empty, closed, not rent-exempt or was never initialized
+    #[error("Pool not initialized: {pool}")]
+    PoolNotInitialized {
+        pool: Pubkey,
+    },
+
+    /// Swap would leave the output token's liquidity below the pool's reserve safety floor
+    #[error("Reserve floor breached: swap would leave {remaining}, floor is {floor}")]
+    ReserveFloorBreached {
+        floor: u64,
+        remaining: u64,
+    },
+
+    /// Owner-only swaps cannot be enabled with the default pubkey as the designated owner
+    #[error("Invalid designated owner: owner-only swaps need a non-default owner")]
+    InvalidDesignatedOwner,
+
+    /// Swap (or the filled part of a partial swap, or the swap leg of `WithdrawAndSwap`) would pay out less than `minimum_amount_out`
+    #[error("Swap output below minimum: minimum {minimum_amount_out}, output {amount_out}")]
+    SwapOutputBelowMinimum {
+        minimum_amount_out: u64,
+        amount_out: u64,
+    },
+
+    /// Exact-output swap would need more input than `maximum_amount_in`
+    #[error("Swap input above maximum: maximum {maximum_amount_in}, required {amount_in}")]
+    SwapInputAboveMaximum {
+        maximum_amount_in: u64,
+        amount_in: u64,
+    },
+
+    /// Withdrawal would leave the withdrawn token's liquidity below the pool's minimum reserve
+    #[error("Below minimum reserve: withdrawal would leave {remaining}, minimum is {minimum_reserve}")]
+    BelowMinimumReserve {
+        minimum_reserve: u64,
+        remaining: u64,
+    },
+
+    /// Two-sided deposit where one side is zero; use a single-sided `Deposit` instead
+    #[error("Deposit ratio mismatch: both sides must contribute (Token A {amount_a}, Token B {amount_b})")]
+    DepositRatioMismatch {
+        amount_a: u64,
+        amount_b: u64,
+    },
+
+    /// The pool's swap mode does not accept this kind of swap (exact-in or exact-out)
+    #[error("Swap mode not allowed: pool only accepts {allowed} swaps")]
+    SwapModeNotAllowed {
+        allowed: String,
+    },
+
+    /// Swap math `amount * multiplier / divisor` (or the liquidity it would add) does not fit in a u64
+    #[error("Calculation overflow: {amount} * {multiplier} / {divisor} exceeds u64::MAX")]
+    CalculationOverflow {
+        amount: u64,
+        multiplier: u64,
+        divisor: u64,
+    },
+
+    /// `pool_count` does not match the number of pool accounts passed
+    #[error("Pool count mismatch: pool_count is {pool_count} but {pool_accounts} pool accounts were provided")]
+    PoolCountMismatch {
+        pool_count: u8,
+        pool_accounts: u64,
+    },
+
+    /// The treasury holds nothing above its rent-exempt minimum and auto-compound reserve
+    #[error("Nothing to compound: treasury balance {balance} does not exceed the {retained} lamports kept back")]
+    NothingToCompound {
+        balance: u64,
+        retained: u64,
+    },
+
+    /// Swap submitted with a `not_valid_after_slot` that has already passed
+    #[error("Swap slot expired: not valid after slot {not_valid_after_slot}, current slot {current_slot}")]
+    SwapSlotExpired {
+        not_valid_after_slot: u64,
+        current_slot: u64,
+    },
+
+    /// Swap input is below the pool's configured minimum swap amount
+    #[error("Swap amount below minimum: {amount_in} is less than the pool minimum of {min_swap_amount}")]
+    SwapAmountBelowMinimum {
+        min_swap_amount: u64,
+        amount_in: u64,
+    },
+
+    /// `WithdrawAll` found no LP tokens in the user's LP token account
+    #[error("No LP tokens to withdraw: LP token account {lp_account} is empty")]
+    NoLpTokensToWithdraw { lp_account: Pubkey },
+
+    /// Pool creation is blocked while the system is paused
+    #[error("Pool creation is blocked while the system is paused (pause code {pause_reason_code})")]
+    PoolCreationPausedBySystem { pause_reason_code: u8 },
+
+    /// Swap input is too small to produce any output at the pool ratio
+    #[error("Swap input {amount_in} is too small: output rounds down to zero at this ratio")]
+    AmountTooSmall { amount_in: u64 },
+
+    /// Swap fee change falls outside the pool owner's allowed fee band
+    #[error("Swap fee {fee} lamports is outside the pool's allowed band [{min_allowed_fee}, {max_allowed_fee}]")]
+    SwapFeeOutsideBand {
+        fee: u64,
+        min_allowed_fee: u64,
+        max_allowed_fee: u64,
+    },
+
+    /// Stranded token rescue attempted on one of the pool's own token mints
+    #[error("Cannot rescue pool token {mint}: only mints other than the pool's Token A and Token B can be rescued")]
+    CannotRescuePoolToken { mint: Pubkey },
+
+    /// Underlying token mint uses more decimals than SPL tokens support (0-9)
+    #[error("Token mint {mint} has {decimals} decimals; pool tokens must use 0 to {max_decimals} decimals")]
+    UnsupportedTokenDecimals {
+        mint: Pubkey,
+        decimals: u8,
+        max_decimals: u8,
+    },
+
+    /// Pool vault holds a different mint than the pool records for its side
+    #[error("Vault {vault} holds mint {actual_mint}, expected {expected_mint}")]
+    VaultMintMismatch {
+        vault: Pubkey,
+        expected_mint: Pubkey,
+        actual_mint: Pubkey,
+    },
+
+    /// Swaps in this direction are paused while the opposite direction stays open
+    #[error("Swaps paying in {input_mint} are paused for this pool")]
+    DirectionPaused {
+        input_mint: Pubkey,
+    },
+
+    /// Deposit would mint fewer LP tokens than the caller's slippage floor
+    #[error("Deposit would mint {lp_minted} LP tokens, below the requested minimum of {minimum_lp_out}")]
+    SlippageExceeded {
+        minimum_lp_out: u64,
+        lp_minted: u64,
+    },
+
+    /// Pending pool owner tried to accept before the transfer delay elapsed
+    #[error("Owner transfer can be accepted in {remaining_seconds} seconds")]
+    OwnerTransferTimelockActive {
+        remaining_seconds: i64,
+    },
+
+    /// Accept or cancel called on a pool with no pending owner transfer
+    #[error("No owner transfer is pending for this pool")]
+    NoPendingOwnerTransfer,
+
+    /// Liquidity migration between pools that do not trade the same token pair
+    #[error("Cannot migrate liquidity from pool {source_pool} to pool {dest_pool}: the pools must be different pools with the same Token A and Token B")]
+    MigrationTokenPairMismatch {
+        source_pool: Pubkey,
+        dest_pool: Pubkey,
+    },
+
+    /// Liquidity migration into a pool that already holds liquidity or LP tokens
+    #[error("Cannot migrate liquidity into pool {dest_pool}: it already holds liquidity or LP tokens")]
+    MigrationDestinationNotEmpty {
+        dest_pool: Pubkey,
+    },
+
+    /// A balance-tracking counter (pool liquidity or LP supply) would exceed u64::MAX
+    #[error("Math overflow: {counter} + {amount} exceeds u64::MAX")]
+    MathOverflow {
+        counter: String,
+        amount: u64,
+    },
+
+    /// Treasury withdrawal destination account is not a system account
+    #[error("Invalid treasury withdrawal destination {destination}: must be a system account")]
+    InvalidWithdrawalDestination {
+        destination: Pubkey,
+    },
+
+    /// System message longer than `SYSTEM_MESSAGE_MAX_LEN` bytes, not UTF-8, or containing zero bytes
+    #[error("Invalid system message of {length} bytes: must be at most 128 bytes of UTF-8 without zero bytes")]
+    InvalidSystemMessage {
+        length: u64,
+    },
+
+    /// Treasury withdrawal would exceed `TREASURY_MAX_WITHDRAWAL_PER_WINDOW` within the current window
+    #[error("Treasury withdrawal rate exceeded: requested {requested} lamports, {remaining} remaining in the current window")]
+    WithdrawalRateExceeded {
+        requested: u64,
+        remaining: u64,
+    },
+
+    /// Output vault holds less than the pool's tracked liquidity plus collected fees
+    #[error("Pool state corrupted: vault {vault} holds {vault_balance}, accounting requires {required}")]
+    PoolStateCorrupted {
+        vault: Pubkey,
+        vault_balance: u64,
+        required: u64,
+    },
 }
 
 impl PoolError {
@@ -237,6 +482,45 @@ impl PoolError {
             PoolError::AmountMismatch { .. } => 1047,
             PoolError::UnsafeRatioValues => 1048,
             PoolError::UnsupportedRatioType { .. } => 1049,
+            PoolError::LpMintNotInitialized { .. } => 1050,
+            PoolError::LpMintFrozen { .. } => 1051,
+            PoolError::LpMintCollision { .. } => 1052,
+            PoolError::LpSupplyCapReached { .. } => 1053,
+            PoolError::SwapDeadlineExpired { .. } => 1054,
+            PoolError::WouldBreachRentReserve { .. } => 1055,
+            PoolError::RouteOutputBelowMinimum { .. } => 1056,
+            PoolError::RouteTooLong { .. } => 1057,
+            PoolError::PoolNotInitialized { .. } => 1058,
+            PoolError::ReserveFloorBreached { .. } => 1059,
+            PoolError::InvalidDesignatedOwner => 1060,
+            PoolError::SwapOutputBelowMinimum { .. } => 1061,
+            PoolError::SwapInputAboveMaximum { .. } => 1062,
+            PoolError::BelowMinimumReserve { .. } => 1063,
+            PoolError::DepositRatioMismatch { .. } => 1064,
+            PoolError::SwapModeNotAllowed { .. } => 1065,
+            PoolError::CalculationOverflow { .. } => 1066,
+            PoolError::PoolCountMismatch { .. } => 1067,
+            PoolError::NothingToCompound { .. } => 1068,
+            PoolError::SwapSlotExpired { .. } => 1069,
+            PoolError::SwapAmountBelowMinimum { .. } => 1070,
+            PoolError::NoLpTokensToWithdraw { .. } => 1071,
+            PoolError::PoolCreationPausedBySystem { .. } => 1072,
+            PoolError::AmountTooSmall { .. } => 1073,
+            PoolError::SwapFeeOutsideBand { .. } => 1074,
+            PoolError::CannotRescuePoolToken { .. } => 1075,
+            PoolError::UnsupportedTokenDecimals { .. } => 1076,
+            PoolError::VaultMintMismatch { .. } => 1077,
+            PoolError::DirectionPaused { .. } => 1078,
+            PoolError::SlippageExceeded { .. } => 1079,
+            PoolError::OwnerTransferTimelockActive { .. } => 1080,
+            PoolError::NoPendingOwnerTransfer => 1081,
+            PoolError::MigrationTokenPairMismatch { .. } => 1082,
+            PoolError::MigrationDestinationNotEmpty { .. } => 1083,
+            PoolError::MathOverflow { .. } => 1084,
+            PoolError::InvalidWithdrawalDestination { .. } => 1085,
+            PoolError::InvalidSystemMessage { .. } => 1086,
+            PoolError::WithdrawalRateExceeded { .. } => 1087,
+            PoolError::PoolStateCorrupted { .. } => 1088,
         }
     }
 }
@@ -0,0 +1,105 @@
+//! Structured Program Events
+//!
+//! Event records emitted so indexers do not have to scrape free-form `msg!` strings.
+//! Every event is logged twice:
+//! - As a pipe-delimited text line via `msg!` (e.g. `SWAP|pool=..|in_mint=..|in=..|out=..|fee=..`)
+//!   whose prefix and field order never change, so it can be parsed positionally
+//! - As two `sol_log_data` blobs: a single `EVENT_VERSION` byte followed by the
+//!   Borsh-serialized `ProgramEvent`, decoded from the base64 `Program data:` log line
+//!
+//! The layout of an event version never changes. New fields go out with a bumped
+//! `EVENT_VERSION`; new `ProgramEvent` variants are only ever appended.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{log::sol_log_data, msg, pubkey::Pubkey};
+
+/// Layout version of the events emitted by this program
+pub const EVENT_VERSION: u8 = 1;
+
+/// A completed swap
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SwapEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub input_mint: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// Pool swap contract fee paid in lamports
+    pub fee: u64,
+}
+
+/// A completed single-sided deposit
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DepositEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub deposit_mint: Pubkey,
+    pub amount: u64,
+    pub lp_minted: u64,
+    /// Pool liquidity contract fee paid in lamports
+    pub fee: u64,
+}
+
+/// A completed single-sided withdrawal
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub withdraw_mint: Pubkey,
+    pub lp_burned: u64,
+    pub amount_out: u64,
+    /// Pool liquidity contract fee paid in lamports
+    pub fee: u64,
+}
+
+/// A completed treasury fee withdrawal
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FeeWithdrawalEvent {
+    pub treasury: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    /// Treasury balance left after the withdrawal (lamports)
+    pub remaining_balance: u64,
+}
+
+/// Every event the program emits; the Borsh variant index identifies the event type
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ProgramEvent {
+    Swap(SwapEvent),
+    Deposit(DepositEvent),
+    Withdraw(WithdrawEvent),
+    FeeWithdrawal(FeeWithdrawalEvent),
+}
+
+impl ProgramEvent {
+    /// Stable pipe-delimited text form of the event; prefixes and field order never change
+    pub fn log_line(&self) -> String {
+        match self {
+            ProgramEvent::Swap(e) => format!(
+                "SWAP|pool={}|in_mint={}|in={}|out={}|fee={}",
+                e.pool, e.input_mint, e.amount_in, e.amount_out, e.fee
+            ),
+            ProgramEvent::Deposit(e) => format!(
+                "DEPOSIT|pool={}|mint={}|amount={}|lp={}|fee={}",
+                e.pool, e.deposit_mint, e.amount, e.lp_minted, e.fee
+            ),
+            ProgramEvent::Withdraw(e) => format!(
+                "WITHDRAW|pool={}|mint={}|lp={}|out={}|fee={}",
+                e.pool, e.withdraw_mint, e.lp_burned, e.amount_out, e.fee
+            ),
+            ProgramEvent::FeeWithdrawal(e) => format!(
+                "FEE_WITHDRAWAL|treasury={}|destination={}|amount={}|remaining={}",
+                e.treasury, e.destination, e.amount, e.remaining_balance
+            ),
+        }
+    }
+
+    /// Logs the text line via `msg!` (never gated like `debug_msg!`), then
+    /// `[EVENT_VERSION]` and the Borsh bytes via `sol_log_data`
+    pub fn emit(&self) {
+        msg!("{}", self.log_line());
+        // Serializing plain fixed-size structs into a Vec cannot fail
+        let data = self.try_to_vec().unwrap_or_default();
+        sol_log_data(&[&[EVENT_VERSION], &data]);
+    }
+}
@@ -123,6 +123,7 @@ entrypoint!(process_instruction);
 pub mod client_sdk;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod state;
 pub mod types;
 pub mod utils;
@@ -144,15 +145,43 @@ use crate::processors::{
         process_pool_pause,
         process_pool_unpause,
         process_pool_update_fees,
+        process_pool_set_max_lp_supply,
+        process_pool_set_reserve_safety_floor,
+        process_pool_set_minimum_reserve,
+        process_pool_set_swap_mode,
+        process_pool_set_event_emission,
+        process_pool_initiate_owner_transfer,
+        process_pool_accept_owner_transfer,
+        process_pool_cancel_owner_transfer,
+        process_pool_set_min_swap_amount,
+        process_pool_set_swap_fee_band,
+        process_pool_rescue_stranded_tokens,
+        process_pool_sweep_rounding_surplus,
+        process_pool_migrate,
+        process_pool_validate_params,
     },
     liquidity::{
         process_liquidity_deposit,
+        process_liquidity_deposit_both,
         process_liquidity_withdraw,
+        process_liquidity_withdraw_all,
+        process_liquidity_withdraw_and_swap,
+        process_liquidity_migrate,
+        process_liquidity_claim_migrated,
+        process_liquidity_get_deposit_fee,
     },
     // fees module contains only governance-controlled fee architecture documentation
     swap::{
         process_swap_execute,
+        validate_swap_expiry,
         process_swap_set_owner_only,
+        process_swap_get_round_trip_cost,
+        process_swap_route,
+        process_swap_can_swap,
+        process_swap_quote,
+        process_swap_fee_breakdown,
+        process_swap_partial,
+        process_swap_exact_out,
     },
     // security module contains only governance-controlled security architecture documentation
     system::{
@@ -161,6 +190,12 @@ use crate::processors::{
         process_system_unpause,
         process_system_get_version,
         process_admin_change,
+        process_system_set_swap_sol_fee,
+        process_system_set_consolidation_skip_window,
+        process_system_set_allow_pool_creation_while_paused,
+        process_system_get_status,
+        process_system_set_message,
+        process_system_migrate,
     },
     utilities::{
         get_pool_state_pda,
@@ -170,16 +205,35 @@ use crate::processors::{
         get_liquidity_info,
         get_fee_info,
         get_pool_sol_balance,
-
+        get_swap_sol_cost,
+        get_rounding_surplus,
+        get_pool_config,
+        get_init_state,
+        get_pool_activity,
+        get_fee_history,
+        get_pool_state_raw,
+        encode_swap,
+        get_all_pool_fees,
+        get_multiple_pool_info,
+        get_canonical_ordering,
+        verify_pool_config,
     },
     treasury::{
         process_treasury_withdraw_fees,
         process_treasury_get_info,
+        process_treasury_get_program_stats,
         process_treasury_donate_sol,
+        process_treasury_set_autocompound,
+        process_treasury_compound,
+        process_treasury_withdraw_tokens,
+        process_treasury_get_recent_donations,
+        process_treasury_migrate,
     },
     consolidation::{
         process_consolidate_pool_fees,
         get_consolidation_status,
+        process_simulate_consolidation,
+        process_change_fee_recipient_and_consolidate,
     },
 
 
@@ -238,7 +292,7 @@ pub fn process_instruction<'a>(
             flags,
         } => {
             validate_account_count(accounts, INITIALIZE_POOL_ACCOUNTS, "InitializePool")?;
-            process_pool_initialize(program_id, ratio_a_numerator, ratio_b_denominator, flags, accounts)
+            process_pool_initialize(program_id, ratio_a_numerator, ratio_b_denominator, flags, None, accounts)
         },
 
         PoolInstruction::Deposit {
@@ -247,7 +301,7 @@ pub fn process_instruction<'a>(
             pool_id,
         } => {
             validate_account_count(accounts, DEPOSIT_ACCOUNTS, "Deposit")?;
-            process_liquidity_deposit(program_id, amount, deposit_token_mint, pool_id, accounts)
+            process_liquidity_deposit(program_id, amount, deposit_token_mint, pool_id, None, accounts)
         },
 
         PoolInstruction::Withdraw {
@@ -259,14 +313,50 @@ pub fn process_instruction<'a>(
             process_liquidity_withdraw(program_id, lp_amount_to_burn, withdraw_token_mint, pool_id, accounts)
         },
 
+        PoolInstruction::WithdrawAll {
+            withdraw_token_mint,
+            pool_id,
+        } => {
+            validate_account_count(accounts, WITHDRAW_ACCOUNTS, "WithdrawAll")?;
+            process_liquidity_withdraw_all(program_id, withdraw_token_mint, pool_id, accounts)
+        },
+
+        PoolInstruction::SetAllowPoolCreationWhilePaused {
+            allowed,
+        } => {
+            validate_account_count(accounts, SET_ALLOW_POOL_CREATION_WHILE_PAUSED_ACCOUNTS, "SetAllowPoolCreationWhilePaused")?;
+            process_system_set_allow_pool_creation_while_paused(program_id, allowed, accounts)
+        },
+
+        PoolInstruction::SetSwapFeeBand {
+            min_allowed_fee,
+            max_allowed_fee,
+            pool_id,
+        } => {
+            validate_account_count(accounts, SET_SWAP_FEE_BAND_ACCOUNTS, "SetSwapFeeBand")?;
+            process_pool_set_swap_fee_band(program_id, accounts, min_allowed_fee, max_allowed_fee, pool_id)
+        },
+
+        PoolInstruction::RescueStrandedTokens {
+            mint,
+            amount,
+            pool_id,
+        } => {
+            validate_account_count(accounts, RESCUE_STRANDED_TOKENS_ACCOUNTS, "RescueStrandedTokens")?;
+            process_pool_rescue_stranded_tokens(program_id, accounts, mint, amount, pool_id)
+        },
+
         PoolInstruction::Swap {
             input_token_mint: _,
             amount_in,
             expected_amount_out,
             pool_id,
         } => {
-            validate_account_count(accounts, SWAP_ACCOUNTS, "Swap")?;
-            process_swap_execute(program_id, amount_in, expected_amount_out, pool_id, accounts)
+            // Optional trailing Main Treasury PDA pays the protocol swap SOL fee
+            if accounts.len() != SWAP_ACCOUNTS + 1 {
+                validate_account_count(accounts, SWAP_ACCOUNTS, "Swap")?;
+            }
+            process_swap_execute(program_id, amount_in, expected_amount_out, pool_id, false, accounts)
         },
 
         PoolInstruction::SetSwapOwnerOnly {
@@ -331,7 +421,7 @@ pub fn process_instruction<'a>(
         
         PoolInstruction::GetConsolidationStatus {
             pool_count,
-        } => get_consolidation_status(program_id, &accounts[..pool_count as usize]),
+        } => get_consolidation_status(program_id, pool_count, accounts),
         
         // Pool Management Instructions
         PoolInstruction::PausePool {
@@ -339,7 +429,7 @@ pub fn process_instruction<'a>(
             pool_id,
         } => {
             validate_account_count(accounts, PAUSE_POOL_ACCOUNTS, "PausePool")?;
-            process_pool_pause(program_id, pause_flags, pool_id, accounts)
+            process_pool_pause(program_id, pause_flags, pool_id, None, accounts)
         },
         
         PoolInstruction::UnpausePool {
@@ -364,6 +454,414 @@ pub fn process_instruction<'a>(
             validate_account_count(accounts, PROCESS_ADMIN_CHANGE_ACCOUNTS, "ProcessAdminChange")?;
             process_admin_change(program_id, new_admin, accounts)
         },
+
+        PoolInstruction::GetSwapSolCost {} => {
+            validate_account_count(accounts, GET_SWAP_SOL_COST_ACCOUNTS, "GetSwapSolCost")?;
+            get_swap_sol_cost(program_id, accounts)
+        },
+
+        PoolInstruction::GetRoundTripCost {
+            amount_in,
+            start_token_mint,
+            pool_id,
+        } => {
+            validate_account_count(accounts, GET_ROUND_TRIP_COST_ACCOUNTS, "GetRoundTripCost")?;
+            process_swap_get_round_trip_cost(program_id, amount_in, start_token_mint, pool_id, accounts)
+        },
+
+        PoolInstruction::GetRoundingSurplus {} => {
+            validate_account_count(accounts, GET_ROUNDING_SURPLUS_ACCOUNTS, "GetRoundingSurplus")?;
+            get_rounding_surplus(program_id, accounts)
+        },
+
+        PoolInstruction::SetMaxLpSupply {
+            max_lp_supply,
+            pool_id,
+        } => {
+            validate_account_count(accounts, SET_MAX_LP_SUPPLY_ACCOUNTS, "SetMaxLpSupply")?;
+            process_pool_set_max_lp_supply(program_id, accounts, max_lp_supply, pool_id)
+        },
+
+        PoolInstruction::GetProgramStats {} => {
+            validate_account_count(accounts, GET_PROGRAM_STATS_ACCOUNTS, "GetProgramStats")?;
+            process_treasury_get_program_stats(program_id, accounts)
+        },
+
+        PoolInstruction::SetSwapSolFee {
+            swap_sol_fee,
+        } => {
+            validate_account_count(accounts, SET_SWAP_SOL_FEE_ACCOUNTS, "SetSwapSolFee")?;
+            process_system_set_swap_sol_fee(program_id, swap_sol_fee, accounts)
+        },
+
+        PoolInstruction::SwapRoute {
+            hops,
+            amount_in,
+            minimum_final_out,
+        } => {
+            validate_account_count(accounts, SWAP_ROUTE_BASE_ACCOUNTS + SWAP_ROUTE_HOP_ACCOUNTS * hops.len(), "SwapRoute")?;
+            process_swap_route(program_id, &hops, amount_in, minimum_final_out, accounts)
+        },
+
+        PoolInstruction::CanSwap {
+            input_token_mint,
+            amount_in,
+            pool_id,
+        } => {
+            validate_account_count(accounts, CAN_SWAP_ACCOUNTS, "CanSwap")?;
+            process_swap_can_swap(program_id, input_token_mint, amount_in, pool_id, accounts)
+        },
+
+        PoolInstruction::GetSwapQuote {
+            input_token_mint,
+            amount_in,
+            pool_id,
+        } => {
+            validate_account_count(accounts, GET_SWAP_QUOTE_ACCOUNTS, "GetSwapQuote")?;
+            process_swap_quote(program_id, input_token_mint, amount_in, pool_id, accounts)
+        },
+
+        PoolInstruction::GetSwapFeeBreakdown {
+            pool_id,
+        } => {
+            validate_account_count(accounts, GET_SWAP_FEE_BREAKDOWN_ACCOUNTS, "GetSwapFeeBreakdown")?;
+            process_swap_fee_breakdown(program_id, pool_id, accounts)
+        },
+
+        PoolInstruction::SwapPartial {
+            input_token_mint,
+            amount_in,
+            minimum_amount_out,
+            allow_partial,
+            pool_id,
+        } => {
+            // Optional trailing Main Treasury PDA pays the protocol swap SOL fee
+            if accounts.len() != SWAP_ACCOUNTS + 1 {
+                validate_account_count(accounts, SWAP_ACCOUNTS, "SwapPartial")?;
+            }
+            process_swap_partial(program_id, input_token_mint, amount_in, minimum_amount_out, allow_partial, pool_id, accounts)
+        },
+
+        PoolInstruction::InitializePoolWithSwapFee {
+            ratio_a_numerator,
+            ratio_b_denominator,
+            flags,
+            initial_swap_contract_fee,
+        } => {
+            validate_account_count(accounts, INITIALIZE_POOL_ACCOUNTS, "InitializePoolWithSwapFee")?;
+            process_pool_initialize(program_id, ratio_a_numerator, ratio_b_denominator, flags, initial_swap_contract_fee, accounts)
+        },
+
+        PoolInstruction::GetPoolConfig { pool_id } => {
+            validate_account_count(accounts, GET_POOL_CONFIG_ACCOUNTS, "GetPoolConfig")?;
+            get_pool_config(program_id, pool_id, accounts)
+        },
+
+        PoolInstruction::WithdrawAndSwap {
+            lp_amount,
+            lp_token_mint,
+            output_token_mint,
+            min_out,
+            pool_id,
+        } => {
+            // Optional trailing Main Treasury PDA pays the protocol swap SOL fee
+            if accounts.len() != WITHDRAW_AND_SWAP_ACCOUNTS + 1 {
+                validate_account_count(accounts, WITHDRAW_AND_SWAP_ACCOUNTS, "WithdrawAndSwap")?;
+            }
+            process_liquidity_withdraw_and_swap(program_id, lp_amount, lp_token_mint, output_token_mint, min_out, pool_id, accounts)
+        },
+
+        PoolInstruction::SwapExactOut {
+            output_token_mint,
+            amount_out,
+            maximum_amount_in,
+            pool_id,
+        } => {
+            // Optional trailing Main Treasury PDA pays the protocol swap SOL fee
+            if accounts.len() != SWAP_ACCOUNTS + 1 {
+                validate_account_count(accounts, SWAP_ACCOUNTS, "SwapExactOut")?;
+            }
+            process_swap_exact_out(program_id, output_token_mint, amount_out, maximum_amount_in, pool_id, accounts)
+        },
+
+        PoolInstruction::MigratePoolState { target_version } => {
+            validate_account_count(accounts, MIGRATE_POOL_STATE_ACCOUNTS, "MigratePoolState")?;
+            process_pool_migrate(program_id, target_version, accounts)
+        },
+
+        PoolInstruction::DepositBoth {
+            amount_a,
+            amount_b,
+            pool_id,
+        } => {
+            validate_account_count(accounts, DEPOSIT_BOTH_ACCOUNTS, "DepositBoth")?;
+            process_liquidity_deposit_both(program_id, amount_a, amount_b, pool_id, accounts)
+        },
+
+        PoolInstruction::SetReserveSafetyFloor {
+            reserve_safety_floor_a,
+            reserve_safety_floor_b,
+            pool_id,
+        } => {
+            validate_account_count(accounts, SET_RESERVE_SAFETY_FLOOR_ACCOUNTS, "SetReserveSafetyFloor")?;
+            process_pool_set_reserve_safety_floor(program_id, accounts, reserve_safety_floor_a, reserve_safety_floor_b, pool_id)
+        },
+
+        PoolInstruction::SetMinimumReserve {
+            minimum_reserve,
+            pool_id,
+        } => {
+            validate_account_count(accounts, SET_MINIMUM_RESERVE_ACCOUNTS, "SetMinimumReserve")?;
+            process_pool_set_minimum_reserve(program_id, accounts, minimum_reserve, pool_id)
+        },
+
+        PoolInstruction::GetInitState {} => {
+            validate_account_count(accounts, GET_INIT_STATE_ACCOUNTS, "GetInitState")?;
+            get_init_state(program_id, accounts)
+        },
+
+        PoolInstruction::SetSwapMode {
+            swap_mode,
+            pool_id,
+        } => {
+            validate_account_count(accounts, SET_SWAP_MODE_ACCOUNTS, "SetSwapMode")?;
+            process_pool_set_swap_mode(program_id, accounts, swap_mode, pool_id)
+        },
+
+        PoolInstruction::SimulateConsolidation {
+            pool_count,
+        } => process_simulate_consolidation(program_id, pool_count, accounts),
+
+        PoolInstruction::GetPoolActivity {
+            pool_id,
+        } => {
+            validate_account_count(accounts, GET_POOL_ACTIVITY_ACCOUNTS, "GetPoolActivity")?;
+            get_pool_activity(program_id, accounts, pool_id)
+        },
+
+        PoolInstruction::GetPoolStateRaw {} => {
+            validate_account_count(accounts, GET_POOL_STATE_RAW_ACCOUNTS, "GetPoolStateRaw")?;
+            get_pool_state_raw(program_id, accounts)
+        },
+
+        PoolInstruction::GetAllPoolFees {} => get_all_pool_fees(program_id, accounts),
+
+        PoolInstruction::SetTreasuryAutocompound {
+            pool_id,
+            reserve_lamports,
+        } => {
+            validate_account_count(accounts, SET_TREASURY_AUTOCOMPOUND_ACCOUNTS, "SetTreasuryAutocompound")?;
+            process_treasury_set_autocompound(program_id, pool_id, reserve_lamports, accounts)
+        },
+
+        PoolInstruction::CompoundTreasury {} => {
+            validate_account_count(accounts, COMPOUND_TREASURY_ACCOUNTS, "CompoundTreasury")?;
+            process_treasury_compound(program_id, accounts)
+        },
+
+        PoolInstruction::GetMultiplePoolInfo {} => get_multiple_pool_info(program_id, accounts),
+
+        PoolInstruction::SetMinSwapAmount {
+            min_swap_amount,
+            pool_id,
+        } => {
+            validate_account_count(accounts, SET_MIN_SWAP_AMOUNT_ACCOUNTS, "SetMinSwapAmount")?;
+            process_pool_set_min_swap_amount(program_id, accounts, min_swap_amount, pool_id)
+        },
+
+        PoolInstruction::SetConsolidationSkipWindow {
+            window_seconds,
+        } => {
+            validate_account_count(accounts, SET_CONSOLIDATION_SKIP_WINDOW_ACCOUNTS, "SetConsolidationSkipWindow")?;
+            process_system_set_consolidation_skip_window(program_id, window_seconds, accounts)
+        },
+
+        PoolInstruction::GetCanonicalOrdering {
+            mint_x,
+            mint_y,
+        } => {
+            validate_account_count(accounts, GET_CANONICAL_ORDERING_ACCOUNTS, "GetCanonicalOrdering")?;
+            get_canonical_ordering(mint_x, mint_y)
+        },
+
+        PoolInstruction::EncodeSwap {
+            input_token_mint,
+            amount_in,
+            expected_amount_out,
+            pool_id,
+            deadline,
+            not_valid_after_slot,
+        } => {
+            validate_account_count(accounts, ENCODE_SWAP_ACCOUNTS, "EncodeSwap")?;
+            encode_swap(input_token_mint, amount_in, expected_amount_out, pool_id, deadline, not_valid_after_slot)
+        },
+
+        PoolInstruction::GetSystemStatus {} => {
+            validate_account_count(accounts, GET_SYSTEM_STATUS_ACCOUNTS, "GetSystemStatus")?;
+            process_system_get_status(program_id, accounts)
+        },
+
+        PoolInstruction::GetFeeHistory {
+            pool_id,
+        } => {
+            validate_account_count(accounts, GET_FEE_HISTORY_ACCOUNTS, "GetFeeHistory")?;
+            get_fee_history(program_id, accounts, pool_id)
+        },
+
+        PoolInstruction::SetEventEmission {
+            emit_events,
+            pool_id,
+        } => {
+            validate_account_count(accounts, SET_EVENT_EMISSION_ACCOUNTS, "SetEventEmission")?;
+            process_pool_set_event_emission(program_id, accounts, emit_events, pool_id)
+        },
+
+        PoolInstruction::InitiateOwnerTransfer {
+            new_owner,
+            pool_id,
+        } => {
+            validate_account_count(accounts, INITIATE_OWNER_TRANSFER_ACCOUNTS, "InitiateOwnerTransfer")?;
+            process_pool_initiate_owner_transfer(program_id, accounts, new_owner, pool_id)
+        },
+
+        PoolInstruction::AcceptOwnerTransfer {
+            pool_id,
+        } => {
+            validate_account_count(accounts, ACCEPT_OWNER_TRANSFER_ACCOUNTS, "AcceptOwnerTransfer")?;
+            process_pool_accept_owner_transfer(program_id, accounts, pool_id)
+        },
+
+        PoolInstruction::CancelOwnerTransfer {
+            pool_id,
+        } => {
+            validate_account_count(accounts, CANCEL_OWNER_TRANSFER_ACCOUNTS, "CancelOwnerTransfer")?;
+            process_pool_cancel_owner_transfer(program_id, accounts, pool_id)
+        },
+
+        PoolInstruction::MigrateLiquidity {
+            source_pool_id,
+            dest_pool_id,
+        } => {
+            validate_account_count(accounts, MIGRATE_LIQUIDITY_ACCOUNTS, "MigrateLiquidity")?;
+            process_liquidity_migrate(program_id, source_pool_id, dest_pool_id, accounts)
+        },
+
+        PoolInstruction::WithdrawTreasuryTokens {
+            token_mint,
+            amount,
+        } => {
+            validate_account_count(accounts, WITHDRAW_TREASURY_TOKENS_ACCOUNTS, "WithdrawTreasuryTokens")?;
+            process_treasury_withdraw_tokens(program_id, token_mint, amount, accounts)
+        },
+
+        PoolInstruction::SetSystemMessage {
+            message,
+        } => {
+            validate_account_count(accounts, SET_SYSTEM_MESSAGE_ACCOUNTS, "SetSystemMessage")?;
+            process_system_set_message(program_id, &message, accounts)
+        },
+
+        PoolInstruction::ValidatePoolParams {
+            multiple_token_mint,
+            base_token_mint,
+            ratio_a_numerator,
+            ratio_b_denominator,
+        } => {
+            validate_account_count(accounts, VALIDATE_POOL_PARAMS_ACCOUNTS, "ValidatePoolParams")?;
+            process_pool_validate_params(program_id, multiple_token_mint, base_token_mint, ratio_a_numerator, ratio_b_denominator, accounts)
+        },
+
+        PoolInstruction::VerifyPoolConfig {
+            pool_id,
+            expected_hash,
+        } => {
+            validate_account_count(accounts, VERIFY_POOL_CONFIG_ACCOUNTS, "VerifyPoolConfig")?;
+            verify_pool_config(program_id, accounts, pool_id, expected_hash)
+        },
+
+        PoolInstruction::GetRecentDonations {} => {
+            validate_account_count(accounts, GET_RECENT_DONATIONS_ACCOUNTS, "GetRecentDonations")?;
+            process_treasury_get_recent_donations(program_id, accounts)
+        },
+
+        PoolInstruction::GetDepositFee {
+            deposit_token_mint,
+            amount,
+            pool_id,
+        } => {
+            validate_account_count(accounts, GET_DEPOSIT_FEE_ACCOUNTS, "GetDepositFee")?;
+            process_liquidity_get_deposit_fee(program_id, deposit_token_mint, amount, pool_id, accounts)
+        },
+
+        PoolInstruction::SweepRoundingSurplus {
+            sweep_token_mint,
+            pool_id,
+        } => {
+            validate_account_count(accounts, SWEEP_ROUNDING_SURPLUS_ACCOUNTS, "SweepRoundingSurplus")?;
+            process_pool_sweep_rounding_surplus(program_id, accounts, sweep_token_mint, pool_id)
+        },
+        
+        PoolInstruction::ChangeFeeRecipientAndConsolidate {
+            new_recipient,
+            pool_id,
+        } => {
+            validate_account_count(accounts, CHANGE_FEE_RECIPIENT_ACCOUNTS, "ChangeFeeRecipientAndConsolidate")?;
+            process_change_fee_recipient_and_consolidate(program_id, new_recipient, pool_id, accounts)
+        },
+        
+        PoolInstruction::SwapWithExpiry {
+            input_token_mint: _,
+            amount_in,
+            expected_amount_out,
+            pool_id,
+            deadline,
+            not_valid_after_slot,
+        } => {
+            // Optional trailing Main Treasury PDA pays the protocol swap SOL fee
+            if accounts.len() != SWAP_ACCOUNTS + 1 {
+                validate_account_count(accounts, SWAP_ACCOUNTS, "SwapWithExpiry")?;
+            }
+            validate_swap_expiry(deadline, not_valid_after_slot)?;
+            process_swap_execute(program_id, amount_in, expected_amount_out, pool_id, false, accounts)
+        },
+        
+        PoolInstruction::PausePoolUntil {
+            pause_flags,
+            pool_id,
+            auto_unpause_at,
+        } => {
+            validate_account_count(accounts, PAUSE_POOL_ACCOUNTS, "PausePoolUntil")?;
+            process_pool_pause(program_id, pause_flags, pool_id, Some(auto_unpause_at), accounts)
+        },
+        
+        PoolInstruction::MigrateSystemState {} => {
+            validate_account_count(accounts, MIGRATE_SYSTEM_STATE_ACCOUNTS, "MigrateSystemState")?;
+            process_system_migrate(program_id, accounts)
+        },
+        
+        PoolInstruction::DepositWithMinimumLp {
+            deposit_token_mint,
+            amount,
+            pool_id,
+            minimum_lp_out,
+        } => {
+            validate_account_count(accounts, DEPOSIT_ACCOUNTS, "DepositWithMinimumLp")?;
+            process_liquidity_deposit(program_id, amount, deposit_token_mint, pool_id, Some(minimum_lp_out), accounts)
+        },
+        
+        PoolInstruction::MigrateTreasuryState {} => {
+            validate_account_count(accounts, MIGRATE_TREASURY_STATE_ACCOUNTS, "MigrateTreasuryState")?;
+            process_treasury_migrate(program_id, accounts)
+        },
+        
+        PoolInstruction::ClaimMigratedLiquidity {
+            source_pool_id,
+            dest_pool_id,
+            lp_amount,
+        } => {
+            validate_account_count(accounts, CLAIM_MIGRATED_LIQUIDITY_ACCOUNTS, "ClaimMigratedLiquidity")?;
+            process_liquidity_claim_migrated(program_id, source_pool_id, dest_pool_id, lp_amount, accounts)
+        },
     }
 }
 
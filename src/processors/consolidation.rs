@@ -3,7 +3,7 @@
 //! This module implements the single batch consolidation process for SOL fees from
 //! multiple pool states to the MainTreasuryState with flexible pause support.
 
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::BorshSerialize;
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
@@ -16,7 +16,8 @@ use solana_program::{
 
 use crate::{
     constants::*,
-    state::{PoolState, MainTreasuryState, ConsolidatedOperations},
+    error::PoolError,
+    state::{PoolState, MainTreasuryState, ConsolidatedOperations, SystemState},
     utils::validation::validate_and_deserialize_pool_state_secure,
 };
 
@@ -53,9 +54,13 @@ use crate::{
 /// # Returns
 /// * `ProgramResult` - Success on full completion, error on critical failures
 /// * Individual pools may be skipped (logged) without failing the entire operation for:
+///   - Consolidation within the last `SystemState.consolidation_skip_window` seconds
 ///   - Insufficient fees above rent-exempt minimum
 ///   - Safety validation failures
 ///   - Fee consistency check failures
+/// * Sets return data to three little-endian `u64` values:
+///   `[pools_processed, pools_skipped, total_lamports]`, so a failed or partial batch
+///   can be retried with the same account list
 /// 
 /// # CU Estimate: ~185,000 CUs for 20 pools, scales down to ~12,000 for 1 pool
 /// - Admin authority validation: 2,500 CUs
@@ -92,20 +97,20 @@ pub fn process_consolidate_pool_fees(
         return Err(ProgramError::InvalidArgument);
     }
     
-    // Extract accounts
-    let admin_authority_signer = &accounts[0];
-    let system_state_pda = &accounts[1];
-    let main_treasury_pda = &accounts[2];
-    let program_data_account = &accounts[3];
-    let pool_accounts = &accounts[4..4 + pool_count as usize];
-    
-    // Validate account count
+    // Validate account count before slicing so a short account list errors instead of panicking
     let expected_accounts = 4 + pool_count as usize;
     if accounts.len() != expected_accounts {
         msg!("❌ Expected {} accounts, got {}", expected_accounts, accounts.len());
         return Err(ProgramError::NotEnoughAccountKeys);
     }
     
+    // Extract accounts
+    let admin_authority_signer = &accounts[0];
+    let system_state_pda = &accounts[1];
+    let main_treasury_pda = &accounts[2];
+    let program_data_account = &accounts[3];
+    let pool_accounts = &accounts[4..];
+    
     // ✅ SECURITY: Validate admin authority
     msg!("🔐 Validating admin authority for consolidation operation");
     use crate::utils::admin_validation::validate_admin_authority;
@@ -120,7 +125,11 @@ pub fn process_consolidate_pool_fees(
     let current_timestamp = Clock::get()?.unix_timestamp;
     
     // **PHASE 1: DETERMINE CONSOLIDATION MODE**
-    let consolidation_mode = determine_consolidation_mode(program_id, system_state_pda)?;
+    let system_state = crate::utils::validation::validate_and_deserialize_system_state_secure(
+        system_state_pda, 
+        program_id
+    )?;
+    let consolidation_mode = determine_consolidation_mode(&system_state);
     
     // **PHASE 2: BATCH CONSOLIDATION**
     perform_batch_consolidation(
@@ -129,6 +138,7 @@ pub fn process_consolidate_pool_fees(
         main_treasury_pda,
         current_timestamp,
         consolidation_mode,
+        system_state.consolidation_skip_window,
     )
 }
 
@@ -139,19 +149,12 @@ enum ConsolidationMode {
     SystemPaused,
     /// System is active - only consolidate individually paused pools
     IndividualPoolPause,
+    /// Fee recipient change - consolidate the one pool whatever its pause state
+    RecipientChange,
 }
 
 /// Determines the consolidation mode based on system state
-fn determine_consolidation_mode(
-    program_id: &Pubkey,
-    system_state_pda: &AccountInfo,
-) -> Result<ConsolidationMode, ProgramError> {
-    // Validate system state PDA
-    let system_state = crate::utils::validation::validate_and_deserialize_system_state_secure(
-        system_state_pda, 
-        program_id
-    )?;
-    
+fn determine_consolidation_mode(system_state: &SystemState) -> ConsolidationMode {
     if system_state.is_paused {
         // System is paused - consolidate all specified pools
         msg!("🔍 System is paused - consolidating all specified pools");
@@ -160,12 +163,234 @@ fn determine_consolidation_mode(
         } else {
             msg!("ℹ️ System paused with reason code: {}", system_state.pause_reason_code);
         }
-        Ok(ConsolidationMode::SystemPaused)
+        ConsolidationMode::SystemPaused
     } else {
         // System is active - only consolidate individually paused pools
         msg!("🔍 System is active - checking for individually paused pools");
-        Ok(ConsolidationMode::IndividualPoolPause)
+        ConsolidationMode::IndividualPoolPause
+    }
+}
+
+/// Per-pool consolidation outcome, computed before any lamports move
+struct PoolConsolidation {
+    /// Lamports moved from the pool to the Main Treasury
+    amount: u64,
+    liquidity_fees: u64,
+    regular_swap_fees: u64,
+    /// Share of the pool's pending fees being consolidated (logging only)
+    consolidation_ratio: f64,
+    rent_exempt_minimum: u64,
+}
+
+/// Decides whether a pool is consolidated and by how much
+/// 
+/// Applies the counter updates to `pool_state` in memory; nothing is written and no
+/// lamports move. Returns `None` for pools that are skipped (consolidated within the
+/// skip window, not eligible, no fees, below rent-exempt minimum or failing a safety
+/// check). Shared by the real
+/// consolidation and `SimulateConsolidation` so both report the same amounts.
+fn plan_pool_consolidation(
+    pool_account: &AccountInfo,
+    pool_state: &mut PoolState,
+    timestamp: i64,
+    consolidation_mode: ConsolidationMode,
+    skip_window: u64,
+) -> Result<Option<PoolConsolidation>, ProgramError> {
+    // Skip pools consolidated moments ago so a retried batch does not need trimming
+    if pool_state.consolidated_within(timestamp, skip_window) {
+        msg!("ℹ️ Pool {} was consolidated at {}, within the {}s skip window, skipping",
+             pool_account.key, pool_state.last_consolidation_timestamp, skip_window);
+        return Ok(None);
+    }
+    
+    // Pools with their own fee recipient are only paid out by ChangeFeeRecipientAndConsolidate
+    if pool_state.fee_recipient != Pubkey::default() && !matches!(consolidation_mode, ConsolidationMode::RecipientChange) {
+        msg!("ℹ️ Pool {} pays its fees to {}, skipping (use ChangeFeeRecipientAndConsolidate)",
+             pool_account.key, pool_state.fee_recipient);
+        return Ok(None);
+    }
+    
+    // Check if pool is eligible for consolidation based on mode
+    let is_eligible = match consolidation_mode {
+        ConsolidationMode::SystemPaused | ConsolidationMode::RecipientChange => {
+            // System paused or recipient change - all pools are eligible
+            true
+        }
+        ConsolidationMode::IndividualPoolPause => {
+            // System active - only pools with both swaps_paused AND paused are eligible
+            // (a pool past its auto-unpause time is live again and not eligible)
+            if pool_state.swaps_paused() && pool_state.liquidity_paused() && !pool_state.auto_unpause_elapsed(timestamp) {
+                msg!("✅ Pool {} is individually paused (swaps_paused: true, liquidity_paused: true)", pool_account.key);
+                true
+            } else {
+                msg!("ℹ️ Pool {} not eligible - swaps_paused: {}, liquidity_paused: {}", 
+                     pool_account.key, pool_state.swaps_paused(), pool_state.liquidity_paused());
+                false
+            }
+        }
+    };
+    
+    if !is_eligible {
+        return Ok(None);
+    }
+    
+    let pool_fees = pool_state.pending_sol_fees();
+    
+    // Skip pools with no fees (but don't error)
+    if pool_fees == 0 {
+        msg!("ℹ️ Pool {} has no fees to consolidate, skipping", pool_account.key);
+        return Ok(None);
+    }
+    
+    // **RENT EXEMPT PROTECTION: Calculate rent exempt minimum for pool state**
+    let rent_exempt_minimum = {
+        use solana_program::sysvar::{rent::Rent, Sysvar};
+        let rent = Rent::get()?;
+        rent.minimum_balance(std::mem::size_of::<PoolState>())
+    };
+    
+    // **RENT EXEMPT PROTECTION: Use helper method to safely calculate available consolidation**
+    let current_pool_balance = pool_account.lamports();
+    let available_for_consolidation = pool_state.calculate_available_for_consolidation(
+        current_pool_balance,
+        rent_exempt_minimum,
+    );
+    
+    if available_for_consolidation == 0 {
+        msg!("⚠️ Pool {} has {} lamports but needs {} for rent exemption, skipping consolidation", 
+             pool_account.key, current_pool_balance, rent_exempt_minimum);
+        return Ok(None);
+    }
+    
+    if available_for_consolidation < pool_fees {
+        msg!("⚠️ Pool {} has {} pending fees but only {} available above rent exempt minimum", 
+             pool_account.key, pool_fees, available_for_consolidation);
+        msg!("   Current balance: {} lamports", current_pool_balance);
+        msg!("   Rent exempt minimum: {} lamports", rent_exempt_minimum);
+        msg!("   Consolidating partial amount: {} lamports", available_for_consolidation);
+    }
+    
+    // **SAFETY VALIDATION: Double-check consolidation safety before proceeding**
+    if let Err(safety_error) = pool_state.validate_consolidation_safety(
+        available_for_consolidation,
+        current_pool_balance,
+        rent_exempt_minimum,
+    ) {
+        msg!("❌ Consolidation safety check failed for pool {}: {}", pool_account.key, safety_error);
+        return Ok(None);
+    }
+    
+    // **GITHUB_ISSUE_31960_WORKAROUND: BUFFER SERIALIZATION PATTERN**
+    // 
+    // ** CRITICAL: Update pool state BEFORE any SOL transfers **
+    // SOL transfer operations can corrupt PDA data buffers, so we must:
+    // 1. Calculate all state changes first
+    // 2. Serialize pool state to temporary buffer  
+    // 3. Then perform SOL transfers
+    // 4. Finally copy serialized data to account atomically
+    
+    // **IMPORTANT: Partial consolidation tracking**
+    // Since we may not consolidate all fees, we need to track what was actually consolidated
+    // Use integer math to avoid rounding drift. Ensure the two parts sum exactly to
+    // `available_for_consolidation` and do not exceed their respective collected counters.
+    let total_individual_pending: u64 = pool_state
+        .collected_liquidity_fees
+        .saturating_add(pool_state.collected_swap_contract_fees);
+
+    // Guard (should not happen since pool_fees > 0 above), but keep safe fallback.
+    if total_individual_pending == 0 {
+        msg!("ℹ️ Pool {} reports pending fees but individual counters are zero; skipping for safety", pool_account.key);
+        return Ok(None);
+    }
+
+    let a: u64 = available_for_consolidation;
+    let liq: u64 = pool_state.collected_liquidity_fees;
+    let swp: u64 = pool_state.collected_swap_contract_fees;
+    let s: u64 = total_individual_pending;
+
+    // Base proportional floors
+    let l_floor: u64 = ((a as u128 * liq as u128) / s as u128) as u64;
+    let r_floor: u64 = ((a as u128 * swp as u128) / s as u128) as u64;
+    let mut liquidity_fees_consolidated: u64 = l_floor;
+    let mut regular_swap_fees_consolidated: u64 = r_floor;
+
+    // Distribute the remainder deterministically to the side with larger fractional part
+    let mut remainder: u64 = a.saturating_sub(liquidity_fees_consolidated.saturating_add(regular_swap_fees_consolidated));
+    if remainder > 0 {
+        let l_mod: u128 = (a as u128 * liq as u128) % s as u128;
+        let r_mod: u128 = (a as u128 * swp as u128) % s as u128;
+
+        // First try to assign to the side with larger residual fraction, without exceeding available counters
+        if l_mod >= r_mod {
+            let capacity = liq.saturating_sub(liquidity_fees_consolidated);
+            let give = core::cmp::min(remainder, capacity);
+            liquidity_fees_consolidated = liquidity_fees_consolidated.saturating_add(give);
+            remainder -= give;
+        } else {
+            let capacity = swp.saturating_sub(regular_swap_fees_consolidated);
+            let give = core::cmp::min(remainder, capacity);
+            regular_swap_fees_consolidated = regular_swap_fees_consolidated.saturating_add(give);
+            remainder -= give;
+        }
+
+        // If 1 unit still remains (edge case due to capacity), give it to the other side within capacity
+        if remainder > 0 {
+            let capacity_l = liq.saturating_sub(liquidity_fees_consolidated);
+            let capacity_r = swp.saturating_sub(regular_swap_fees_consolidated);
+            if capacity_r >= remainder {
+                regular_swap_fees_consolidated = regular_swap_fees_consolidated.saturating_add(remainder);
+            } else if capacity_l >= remainder {
+                liquidity_fees_consolidated = liquidity_fees_consolidated.saturating_add(remainder);
+            } else {
+                // Should not occur because a <= liq+swp, but keep safety
+                let allocated = core::cmp::min(remainder, capacity_l);
+                liquidity_fees_consolidated = liquidity_fees_consolidated.saturating_add(allocated);
+                let remaining_after_l = remainder - allocated;
+                if remaining_after_l > 0 {
+                    let allocated_r = core::cmp::min(remaining_after_l, capacity_r);
+                    regular_swap_fees_consolidated = regular_swap_fees_consolidated.saturating_add(allocated_r);
+                }
+            }
+        }
     }
+
+    // For logging only
+    let consolidation_ratio = if pool_fees > 0 {
+        a as f64 / pool_fees as f64
+    } else {
+        0.0
+    };
+    
+    // **PARTIAL CONSOLIDATION: Update pool state based on what was actually consolidated**
+    if consolidation_ratio >= 1.0 {
+        // Full consolidation - reset all counters
+        pool_state.reset_consolidation_counters(timestamp);
+    } else {
+        // Partial consolidation - reduce counters proportionally
+        pool_state.collected_liquidity_fees -= liquidity_fees_consolidated;
+        pool_state.collected_swap_contract_fees -= regular_swap_fees_consolidated;
+        
+        // Update total consolidated amount
+        pool_state.total_fees_consolidated = pool_state.total_fees_consolidated.saturating_add(available_for_consolidation);
+        
+        // Update metadata
+        pool_state.last_consolidation_timestamp = timestamp;
+        pool_state.total_consolidations = pool_state.total_consolidations.saturating_add(1);
+    }
+    
+    // **CONSISTENCY VALIDATION**: Verify fee tracking integrity after consolidation
+    if pool_state.validate_fee_consistency().is_err() {
+        msg!("❌ Fee consistency check failed for pool {}", pool_account.key);
+        return Ok(None); // Skip this pool instead of panicking
+    }
+    
+    Ok(Some(PoolConsolidation {
+        amount: available_for_consolidation,
+        liquidity_fees: liquidity_fees_consolidated,
+        regular_swap_fees: regular_swap_fees_consolidated,
+        consolidation_ratio,
+        rent_exempt_minimum,
+    }))
 }
 
 /// Performs the actual batch consolidation logic
@@ -175,7 +400,10 @@ fn perform_batch_consolidation(
     main_treasury_pda: &AccountInfo,
     timestamp: i64,
     consolidation_mode: ConsolidationMode,
+    skip_window: u64,
 ) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
     let mut total_sol_collected = 0u64;
     let mut consolidated_ops = ConsolidatedOperations::default();
     let mut pools_processed = 0u8;
@@ -184,191 +412,28 @@ fn perform_batch_consolidation(
     for pool_account in pool_accounts {
         let mut pool_state = validate_and_deserialize_pool_state_secure(pool_account, pool_account.key, program_id)?;
         
-        // Check if pool is eligible for consolidation based on mode
-        let is_eligible = match consolidation_mode {
-            ConsolidationMode::SystemPaused => {
-                // System paused - all pools are eligible
-                true
-            }
-            ConsolidationMode::IndividualPoolPause => {
-                // System active - only pools with both swaps_paused AND paused are eligible
-                if pool_state.swaps_paused() && pool_state.liquidity_paused() {
-                    msg!("✅ Pool {} is individually paused (swaps_paused: true, liquidity_paused: true)", pool_account.key);
-                    true
-                } else {
-                    msg!("ℹ️ Pool {} not eligible - swaps_paused: {}, liquidity_paused: {}", 
-                         pool_account.key, pool_state.swaps_paused(), pool_state.liquidity_paused());
-                    false
-                }
-            }
-        };
-        
-        if !is_eligible {
-            continue;
-        }
-        
-        let pool_fees = pool_state.pending_sol_fees();
-        
-        // Skip pools with no fees (but don't error)
-        if pool_fees == 0 {
-            msg!("ℹ️ Pool {} has no fees to consolidate, skipping", pool_account.key);
-            continue;
-        }
-        
-        // **RENT EXEMPT PROTECTION: Calculate rent exempt minimum for pool state**
-        let rent_exempt_minimum = {
-            use solana_program::sysvar::{rent::Rent, Sysvar};
-            let rent = Rent::get()?;
-            rent.minimum_balance(std::mem::size_of::<PoolState>())
-        };
-        
-        // **RENT EXEMPT PROTECTION: Use helper method to safely calculate available consolidation**
-        let current_pool_balance = pool_account.lamports();
-        let available_for_consolidation = pool_state.calculate_available_for_consolidation(
-            current_pool_balance,
-            rent_exempt_minimum,
-        );
-        
-        if available_for_consolidation == 0 {
-            msg!("⚠️ Pool {} has {} lamports but needs {} for rent exemption, skipping consolidation", 
-                 pool_account.key, current_pool_balance, rent_exempt_minimum);
+        let Some(plan) = plan_pool_consolidation(pool_account, &mut pool_state, timestamp, consolidation_mode, skip_window)? else {
             continue;
-        }
-        
-        if available_for_consolidation < pool_fees {
-            msg!("⚠️ Pool {} has {} pending fees but only {} available above rent exempt minimum", 
-                 pool_account.key, pool_fees, available_for_consolidation);
-            msg!("   Current balance: {} lamports", current_pool_balance);
-            msg!("   Rent exempt minimum: {} lamports", rent_exempt_minimum);
-            msg!("   Consolidating partial amount: {} lamports", available_for_consolidation);
-        }
-        
-        // **SAFETY VALIDATION: Double-check consolidation safety before proceeding**
-        if let Err(safety_error) = pool_state.validate_consolidation_safety(
-            available_for_consolidation,
-            current_pool_balance,
-            rent_exempt_minimum,
-        ) {
-            msg!("❌ Consolidation safety check failed for pool {}: {}", pool_account.key, safety_error);
-            continue;
-        }
-        
-        // **GITHUB_ISSUE_31960_WORKAROUND: BUFFER SERIALIZATION PATTERN**
-        // 
-        // ** CRITICAL: Update pool state BEFORE any SOL transfers **
-        // SOL transfer operations can corrupt PDA data buffers, so we must:
-        // 1. Calculate all state changes first
-        // 2. Serialize pool state to temporary buffer  
-        // 3. Then perform SOL transfers
-        // 4. Finally copy serialized data to account atomically
-        
-        // **IMPORTANT: Partial consolidation tracking**
-        // Since we may not consolidate all fees, we need to track what was actually consolidated
-        // Use integer math to avoid rounding drift. Ensure the two parts sum exactly to
-        // `available_for_consolidation` and do not exceed their respective collected counters.
-        let total_individual_pending: u64 = pool_state
-            .collected_liquidity_fees
-            .saturating_add(pool_state.collected_swap_contract_fees);
-
-        // Guard (should not happen since pool_fees > 0 above), but keep safe fallback.
-        if total_individual_pending == 0 {
-            msg!("ℹ️ Pool {} reports pending fees but individual counters are zero; skipping for safety", pool_account.key);
-            continue;
-        }
-
-        let a: u64 = available_for_consolidation;
-        let liq: u64 = pool_state.collected_liquidity_fees;
-        let swp: u64 = pool_state.collected_swap_contract_fees;
-        let s: u64 = total_individual_pending;
-
-        // Base proportional floors
-        let l_floor: u64 = ((a as u128 * liq as u128) / s as u128) as u64;
-        let r_floor: u64 = ((a as u128 * swp as u128) / s as u128) as u64;
-        let mut liquidity_fees_consolidated: u64 = l_floor;
-        let mut regular_swap_fees_consolidated: u64 = r_floor;
-
-        // Distribute the remainder deterministically to the side with larger fractional part
-        let mut remainder: u64 = a.saturating_sub(liquidity_fees_consolidated.saturating_add(regular_swap_fees_consolidated));
-        if remainder > 0 {
-            let l_mod: u128 = (a as u128 * liq as u128) % s as u128;
-            let r_mod: u128 = (a as u128 * swp as u128) % s as u128;
-
-            // First try to assign to the side with larger residual fraction, without exceeding available counters
-            if l_mod >= r_mod {
-                let capacity = liq.saturating_sub(liquidity_fees_consolidated);
-                let give = core::cmp::min(remainder, capacity);
-                liquidity_fees_consolidated = liquidity_fees_consolidated.saturating_add(give);
-                remainder -= give;
-            } else {
-                let capacity = swp.saturating_sub(regular_swap_fees_consolidated);
-                let give = core::cmp::min(remainder, capacity);
-                regular_swap_fees_consolidated = regular_swap_fees_consolidated.saturating_add(give);
-                remainder -= give;
-            }
-
-            // If 1 unit still remains (edge case due to capacity), give it to the other side within capacity
-            if remainder > 0 {
-                let capacity_l = liq.saturating_sub(liquidity_fees_consolidated);
-                let capacity_r = swp.saturating_sub(regular_swap_fees_consolidated);
-                if capacity_r >= remainder {
-                    regular_swap_fees_consolidated = regular_swap_fees_consolidated.saturating_add(remainder);
-                } else if capacity_l >= remainder {
-                    liquidity_fees_consolidated = liquidity_fees_consolidated.saturating_add(remainder);
-                } else {
-                    // Should not occur because a <= liq+swp, but keep safety
-                    let allocated = core::cmp::min(remainder, capacity_l);
-                    liquidity_fees_consolidated = liquidity_fees_consolidated.saturating_add(allocated);
-                    let remaining_after_l = remainder - allocated;
-                    if remaining_after_l > 0 {
-                        let allocated_r = core::cmp::min(remaining_after_l, capacity_r);
-                        regular_swap_fees_consolidated = regular_swap_fees_consolidated.saturating_add(allocated_r);
-                    }
-                }
-            }
-        }
-
-        // For logging only
-        let consolidation_ratio = if pool_fees > 0 {
-            a as f64 / pool_fees as f64
-        } else {
-            0.0
         };
+        let available_for_consolidation = plan.amount;
+        let liquidity_fees_consolidated = plan.liquidity_fees;
+        let regular_swap_fees_consolidated = plan.regular_swap_fees;
+        let consolidation_ratio = plan.consolidation_ratio;
+        let rent_exempt_minimum = plan.rent_exempt_minimum;
         
         // Accumulate consolidated data
-        consolidated_ops.liquidity_fees += liquidity_fees_consolidated;
-        consolidated_ops.regular_swap_fees += regular_swap_fees_consolidated;
+        consolidated_ops.liquidity_fees = consolidated_ops.liquidity_fees.saturating_add(liquidity_fees_consolidated);
+        consolidated_ops.regular_swap_fees = consolidated_ops.regular_swap_fees.saturating_add(regular_swap_fees_consolidated);
         
         // Calculate operation counts from consolidated fees (using fixed fee constants)
         let liquidity_ops = liquidity_fees_consolidated / DEPOSIT_WITHDRAWAL_FEE;
         let regular_ops = regular_swap_fees_consolidated / SWAP_CONTRACT_FEE;
         
-        consolidated_ops.liquidity_operation_count += liquidity_ops;
-        consolidated_ops.regular_swap_count += regular_ops;
+        consolidated_ops.liquidity_operation_count = consolidated_ops.liquidity_operation_count.saturating_add(liquidity_ops);
+        consolidated_ops.regular_swap_count = consolidated_ops.regular_swap_count.saturating_add(regular_ops);
         
         total_sol_collected += available_for_consolidation;
-        
-        // **PARTIAL CONSOLIDATION: Update pool state based on what was actually consolidated**
-        if consolidation_ratio >= 1.0 {
-            // Full consolidation - reset all counters
-            pool_state.reset_consolidation_counters(timestamp);
-        } else {
-            // Partial consolidation - reduce counters proportionally
-            pool_state.collected_liquidity_fees -= liquidity_fees_consolidated;
-            pool_state.collected_swap_contract_fees -= regular_swap_fees_consolidated;
-            
-            // Update total consolidated amount
-            pool_state.total_fees_consolidated += available_for_consolidation;
-            
-            // Update metadata
-            pool_state.last_consolidation_timestamp = timestamp;
-            pool_state.total_consolidations += 1;
-        }
-        
-        // **CONSISTENCY VALIDATION**: Verify fee tracking integrity after consolidation
-        if pool_state.validate_fee_consistency().is_err() {
-            msg!("❌ Fee consistency check failed for pool {}", pool_account.key);
-            continue; // Skip this pool instead of panicking
-        }
+        pool_state.record_operation();
         
         // **STEP 1: Serialize pool state to temporary buffer BEFORE SOL transfers**
         let serialized_pool_data = pool_state.try_to_vec()?;
@@ -402,21 +467,7 @@ fn perform_batch_consolidation(
     }
     
     // **STEP 3: Update MainTreasuryState** (even if no pools processed, update timestamp)
-    let mut treasury_state = MainTreasuryState::try_from_slice(&main_treasury_pda.data.borrow())?;
-    
-    // Process batch consolidation
-    treasury_state.batch_consolidation(total_sol_collected, &consolidated_ops, timestamp);
-    
-    // Sync balance with actual account balance
-    treasury_state.sync_balance_with_account(main_treasury_pda.lamports());
-    
-    // Save updated treasury state with size validation
-    let serialized_data = treasury_state.try_to_vec()?;
-    if main_treasury_pda.data_len() < serialized_data.len() {
-        msg!("🚨 Critical Error: Treasury serialized data too large for account");
-        return Err(ProgramError::AccountDataTooSmall);
-    }
-    main_treasury_pda.data.borrow_mut()[..serialized_data.len()].copy_from_slice(&serialized_data);
+    record_treasury_consolidation(main_treasury_pda, total_sol_collected, &consolidated_ops, timestamp)?;
     
     // Report consolidation results
     match consolidation_mode {
@@ -426,9 +477,14 @@ fn perform_batch_consolidation(
         ConsolidationMode::IndividualPoolPause => {
             msg!("🎉 Individual pool consolidation completed:");
         }
+        ConsolidationMode::RecipientChange => {
+            msg!("🎉 Fee recipient consolidation completed:");
+        }
     }
     
+    let pools_skipped = pool_accounts.len() as u64 - pools_processed as u64;
     msg!("   Pools processed: {}", pools_processed);
+    msg!("   Pools skipped: {}", pools_skipped);
     msg!("   Total SOL consolidated: {} ({} SOL)", 
          total_sol_collected, total_sol_collected as f64 / 1_000_000_000.0);
     msg!("   Total operations: {}", 
@@ -446,18 +502,181 @@ fn perform_batch_consolidation(
                 msg!("ℹ️ No pools were individually paused or had sufficient fees above rent exempt minimum");
                 msg!("ℹ️ To consolidate specific pools, pause them individually or pause the entire system");
             }
+            ConsolidationMode::RecipientChange => {
+                msg!("ℹ️ The pool had no fees to consolidate above rent exempt minimum");
+            }
         }
     }
     
+    let mut return_data = [0u8; 24];
+    return_data[0..8].copy_from_slice(&(pools_processed as u64).to_le_bytes());
+    return_data[8..16].copy_from_slice(&pools_skipped.to_le_bytes());
+    return_data[16..24].copy_from_slice(&total_sol_collected.to_le_bytes());
+    set_return_data(&return_data);
+    
+    Ok(())
+}
+
+/// Records consolidated fees in the MainTreasuryState and syncs its balance
+fn record_treasury_consolidation(
+    main_treasury_pda: &AccountInfo,
+    total_sol_collected: u64,
+    consolidated_ops: &ConsolidatedOperations,
+    timestamp: i64,
+) -> ProgramResult {
+    let mut treasury_state = MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow())?;
+    
+    // Process batch consolidation
+    treasury_state.batch_consolidation(total_sol_collected, consolidated_ops, timestamp);
+    
+    // Sync balance with actual account balance
+    treasury_state.sync_balance_with_account(main_treasury_pda.lamports());
+    
+    // Save updated treasury state with size validation
+    let serialized_data = treasury_state.try_to_vec()?;
+    if main_treasury_pda.data_len() < serialized_data.len() {
+        msg!("🚨 Critical Error: Treasury serialized data too large for account");
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    main_treasury_pda.data.borrow_mut()[..serialized_data.len()].copy_from_slice(&serialized_data);
+    Ok(())
+}
+
+/// Consolidates one pool's pending fees to its current recipient, then changes the recipient
+/// 
+/// The payout follows `plan_pool_consolidation` (rent-exempt protection and partial
+/// consolidation included) but ignores pause state and the skip window: the pool
+/// state, with the new recipient already recorded, is written in the same
+/// instruction, so no fee can accrue between the payout and the switch. Fees paid to
+/// the MainTreasury are recorded in its state like a `ConsolidatePoolFees` batch;
+/// fees paid to a custom recipient are plain lamport credits.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `new_recipient` - Future fee recipient (`Pubkey::default()` or the Main Treasury PDA = MainTreasury)
+/// * `pool_id` - Expected pool state PDA
+/// * `accounts` - Array of accounts in the following order:
+///   - [0] Admin Authority Signer (must be admin authority)
+///   - [1] System State PDA (for admin validation)
+///   - [2] Main Treasury PDA (writable)
+///   - [3] Program Data Account (for upgrade authority validation)
+///   - [4] Pool State PDA (writable)
+///   - [5] Current Fee Recipient (writable; the Main Treasury PDA when the pool has no custom recipient)
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to the lamports paid out as a little-endian `u64`
+pub fn process_change_fee_recipient_and_consolidate(
+    program_id: &Pubkey,
+    new_recipient: Pubkey,
+    pool_id: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    use solana_program::program::set_return_data;
+    
+    msg!("🔄 Changing fee recipient of pool {}", pool_id);
+    
+    let admin_authority_signer = &accounts[0];
+    let system_state_pda = &accounts[1];
+    let main_treasury_pda = &accounts[2];
+    let program_data_account = &accounts[3];
+    let pool_state_pda = &accounts[4];
+    let current_recipient = &accounts[5];
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let (expected_main_treasury, _) = Pubkey::find_program_address(
+        &[MAIN_TREASURY_SEED_PREFIX],
+        program_id,
+    );
+    if *main_treasury_pda.key != expected_main_treasury {
+        msg!("❌ Invalid main treasury PDA. Expected: {}, Got: {}", 
+             expected_main_treasury, main_treasury_pda.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    
+    let mut pool_state = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    
+    let pays_treasury = pool_state.fee_recipient == Pubkey::default();
+    let expected_recipient = if pays_treasury { expected_main_treasury } else { pool_state.fee_recipient };
+    if *current_recipient.key != expected_recipient {
+        msg!("❌ Invalid current fee recipient. Expected: {}, Got: {}", 
+             expected_recipient, current_recipient.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    
+    let timestamp = Clock::get()?.unix_timestamp;
+    let plan = plan_pool_consolidation(pool_state_pda, &mut pool_state, timestamp, ConsolidationMode::RecipientChange, 0)?;
+    let amount = plan.as_ref().map_or(0, |plan| plan.amount);
+    
+    // The MainTreasury is stored as the default key so it never has to be looked up again
+    pool_state.fee_recipient = if new_recipient == expected_main_treasury { Pubkey::default() } else { new_recipient };
+    pool_state.record_operation();
+    
+    // **GITHUB_ISSUE_31960_WORKAROUND**: serialize before moving lamports
+    let serialized_pool_data = pool_state.try_to_vec()?;
+    if amount > 0 {
+        **pool_state_pda.try_borrow_mut_lamports()? -= amount;
+        **current_recipient.try_borrow_mut_lamports()? += amount;
+    }
+    {
+        if pool_state_pda.data_len() < serialized_pool_data.len() {
+            msg!("🚨 Critical Error: Pool state serialized data too large for account");
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        let mut account_data = pool_state_pda.data.borrow_mut();
+        account_data[..serialized_pool_data.len()].copy_from_slice(&serialized_pool_data);
+    }
+    
+    if let (true, Some(plan)) = (pays_treasury, plan.as_ref()) {
+        let consolidated_ops = ConsolidatedOperations {
+            liquidity_fees: plan.liquidity_fees,
+            regular_swap_fees: plan.regular_swap_fees,
+            liquidity_operation_count: plan.liquidity_fees / DEPOSIT_WITHDRAWAL_FEE,
+            regular_swap_count: plan.regular_swap_fees / SWAP_CONTRACT_FEE,
+        };
+        record_treasury_consolidation(main_treasury_pda, amount, &consolidated_ops, timestamp)?;
+    }
+    
+    msg!("✅ Paid {} lamports to {}; fees of pool {} now go to {}",
+         amount, current_recipient.key, pool_id,
+         if pool_state.fee_recipient == Pubkey::default() { expected_main_treasury } else { pool_state.fee_recipient });
+    
+    set_return_data(&amount.to_le_bytes());
     Ok(())
 }
 
 /// **NEW: Get consolidation status for pools**
 /// View-only function to check pool consolidation status
+/// 
+/// `pool_count` must be 1-20 and equal the number of accounts passed; every
+/// account must be a valid pool state PDA.
+/// 
+/// # Errors
+/// * `InvalidArgument` - `pool_count` is 0 or above `MAX_POOLS_PER_CONSOLIDATION_BATCH`
+/// * `PoolCountMismatch` - `pool_count` differs from the number of accounts
 pub fn get_consolidation_status(
     program_id: &Pubkey,
+    pool_count: u8,
     pool_accounts: &[AccountInfo],
 ) -> ProgramResult {
+    if pool_count == 0 || pool_count > MAX_POOLS_PER_CONSOLIDATION_BATCH {
+        msg!("❌ Pool count {} must be between 1 and {}", pool_count, MAX_POOLS_PER_CONSOLIDATION_BATCH);
+        return Err(ProgramError::InvalidArgument);
+    }
+    if pool_accounts.len() != pool_count as usize {
+        msg!("❌ Pool count {} does not match {} pool accounts", pool_count, pool_accounts.len());
+        return Err(PoolError::PoolCountMismatch {
+            pool_count,
+            pool_accounts: pool_accounts.len() as u64,
+        }.into());
+    }
+    
     msg!("📊 CONSOLIDATION STATUS REPORT");
     msg!("===============================");
     
@@ -499,4 +718,96 @@ pub fn get_consolidation_status(
     }
     
     Ok(())
-} 
\ No newline at end of file
+} 
+/// Dry run of `ConsolidatePoolFees`: reports what would be swept without moving funds
+/// 
+/// Takes the exact account list of the real instruction so operators can reuse it,
+/// and applies the same skip window, pause eligibility, rent-exempt and partial
+/// consolidation rules through `plan_pool_consolidation`. No account is written and no lamports
+/// move, so the admin authority does not need to sign.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `pool_count` - Number of pools to simulate (1-20)
+/// * `accounts` - Same order as `process_consolidate_pool_fees`:
+///   - [0] Admin Authority (signature not required)
+///   - [1] System State PDA (for consolidation mode)
+///   - [2] Main Treasury PDA
+///   - [3] Program Data Account
+///   - [4..4+pool_count] Pool State PDAs
+/// 
+/// # Returns (via `set_return_data`)
+/// Four little-endian `u64` values:
+/// `[total_lamports, liquidity_fees, regular_swap_fees, pools_consolidated]`
+pub fn process_simulate_consolidation(
+    program_id: &Pubkey,
+    pool_count: u8,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    msg!("🔍 Simulating batch consolidation for {} pools", pool_count);
+    
+    if pool_count == 0 {
+        msg!("❌ Pool count cannot be zero");
+        return Err(ProgramError::InvalidArgument);
+    }
+    if pool_count > MAX_POOLS_PER_CONSOLIDATION_BATCH {
+        msg!("❌ Pool count {} exceeds maximum {}", pool_count, MAX_POOLS_PER_CONSOLIDATION_BATCH);
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    let expected_accounts = 4 + pool_count as usize;
+    if accounts.len() != expected_accounts {
+        msg!("❌ Expected {} accounts, got {}", expected_accounts, accounts.len());
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    
+    let system_state_pda = &accounts[1];
+    let main_treasury_pda = &accounts[2];
+    let pool_accounts = &accounts[4..];
+    
+    let (expected_main_treasury, _) = Pubkey::find_program_address(
+        &[MAIN_TREASURY_SEED_PREFIX],
+        program_id,
+    );
+    if *main_treasury_pda.key != expected_main_treasury {
+        msg!("❌ Invalid main treasury PDA. Expected: {}, Got: {}", 
+             expected_main_treasury, main_treasury_pda.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    
+    let timestamp = Clock::get()?.unix_timestamp;
+    let system_state = crate::utils::validation::validate_and_deserialize_system_state_secure(
+        system_state_pda, 
+        program_id
+    )?;
+    let consolidation_mode = determine_consolidation_mode(&system_state);
+    
+    let mut total_lamports = 0u64;
+    let mut liquidity_fees = 0u64;
+    let mut regular_swap_fees = 0u64;
+    let mut pools_consolidated = 0u64;
+    
+    for pool_account in pool_accounts {
+        let mut pool_state = validate_and_deserialize_pool_state_secure(pool_account, pool_account.key, program_id)?;
+        if let Some(plan) = plan_pool_consolidation(pool_account, &mut pool_state, timestamp, consolidation_mode, system_state.consolidation_skip_window)? {
+            total_lamports += plan.amount;
+            liquidity_fees += plan.liquidity_fees;
+            regular_swap_fees += plan.regular_swap_fees;
+            pools_consolidated += 1;
+        }
+    }
+    
+    msg!("📊 Simulated consolidation: {} pools, {} lamports ({} liquidity, {} swap)",
+         pools_consolidated, total_lamports, liquidity_fees, regular_swap_fees);
+    
+    let mut return_data = [0u8; 32];
+    return_data[0..8].copy_from_slice(&total_lamports.to_le_bytes());
+    return_data[8..16].copy_from_slice(&liquidity_fees.to_le_bytes());
+    return_data[16..24].copy_from_slice(&regular_swap_fees.to_le_bytes());
+    return_data[24..32].copy_from_slice(&pools_consolidated.to_le_bytes());
+    set_return_data(&return_data);
+    
+    Ok(())
+}
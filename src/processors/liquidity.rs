@@ -85,16 +85,18 @@ use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
     msg,
-    program::{invoke, invoke_signed},
+    program::{invoke, invoke_signed, set_return_data},
     program_error::ProgramError,
+    program_pack::Pack,
     pubkey::Pubkey,
 };
 use spl_token::{
     instruction as token_instruction,
-    state::{Account as TokenAccount},
+    state::{Account as TokenAccount, AccountState, Mint},
 };
 use crate::utils::validation::validate_non_zero_amount;
-use crate::processors::utilities::validate_liquidity_not_paused;
+use crate::events::{DepositEvent, ProgramEvent, WithdrawEvent};
+use crate::processors::utilities::{validate_deposits_not_paused, validate_withdrawals_not_paused};
 
 /// **PHASE 10: USER LP TOKEN ACCOUNT ON-DEMAND CREATION**
 ///
@@ -115,6 +117,8 @@ use crate::processors::utilities::validate_liquidity_not_paused;
 /// * `amount` - Amount to deposit
 /// * `deposit_token_mint_key` - Token mint being deposited
 /// * `pool_id` - Expected Pool ID for security validation
+/// * `minimum_lp_out` - Optional minimum LP tokens to mint (`None` skips the slippage check;
+///   with the 1:1 mint it only fails when above `amount`)
 /// * `accounts` - Array of accounts in optimized order (11 accounts total)
 /// 
 /// # Account Info
@@ -162,7 +166,8 @@ pub fn process_liquidity_deposit<'a>(
     amount: u64,
     deposit_token_mint_key: Pubkey,
     pool_id: Pubkey,
-    accounts: &'a [AccountInfo<'a>],
+    minimum_lp_out: Option<u64>,
+    accounts: &[AccountInfo<'a>],
 ) -> ProgramResult {
     msg!("🏦 DEPOSIT: {} tokens to mint {}", amount, deposit_token_mint_key);
     
@@ -194,6 +199,18 @@ pub fn process_liquidity_deposit<'a>(
     // Core validation
     validate_non_zero_amount(amount, "Deposit")?;
     
+    // LP is minted 1:1, so the floor is checked before any transfer or mint and can
+    // only fail when it is above `amount`
+    if let Some(minimum_lp_out) = minimum_lp_out {
+        if amount < minimum_lp_out {
+            msg!("❌ Deposit slippage: would mint {} LP tokens, minimum {}", amount, minimum_lp_out);
+            return Err(crate::error::PoolError::SlippageExceeded {
+                minimum_lp_out,
+                lp_minted: amount,
+            }.into());
+        }
+    }
+    
     // ✅ SECURITY: User signer validation now properly implemented above
     // Critical security fix: Explicit signer checks are required for user operations
     // to prevent unauthorized access to user token accounts.
@@ -201,8 +218,11 @@ pub fn process_liquidity_deposit<'a>(
     // Read and validate pool state with Pool ID security validation
     let mut pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
     
-    // ✅ LIQUIDITY PAUSE CHECK: Validate that liquidity operations are not paused
-    validate_liquidity_not_paused(&pool_state_data)?;
+    // ✅ LIQUIDITY PAUSE CHECK: Validate that deposits are not paused
+    validate_deposits_not_paused(&pool_state_data)?;
+    
+    // The vaults must hold the pool's Token A and Token B mints, in that order
+    crate::utils::validation::validate_vault_mints(&pool_state_data, token_a_vault_pda, token_b_vault_pda)?;
     
     // **PHASE 1: POOL EXISTENCE = INITIALIZATION**
     // If we successfully deserialized pool_state_data, the pool is initialized
@@ -248,6 +268,17 @@ pub fn process_liquidity_deposit<'a>(
         msg!("   Depositing Token A: {}", is_depositing_token_a);
         return Err(ProgramError::InvalidAccountData);
     }
+
+    // ✅ NON-TRANSFERABLE LP: User LP accounts stay frozen between operations; thaw for the mint
+    if pool_state_data.lp_transfers_frozen() {
+        thaw_lp_account_if_frozen(
+            &pool_state_data,
+            pool_state_pda,
+            target_lp_mint_account,
+            user_output_account,
+            spl_token_program_account,
+        )?;
+    }
     
     // ✅ OPTIMIZATION: Only validate the LP token mint being used for this deposit
     // The other LP token mint may not exist yet (will be created when needed)
@@ -340,11 +371,29 @@ pub fn process_liquidity_deposit<'a>(
         false, // Vaults shouldn't have delegates
     )?;
     
-    use crate::utils::validation::{validate_vault_owner, validate_lp_mint_authority};
+    use crate::utils::validation::{validate_vault_owner, validate_lp_mint_ready, validate_lp_mint_authority};
     validate_vault_owner(&target_vault_data, pool_state_pda.key, "Target Vault")?;
+    validate_lp_mint_ready(target_lp_mint, "Target LP Mint")?;
     validate_lp_mint_authority(target_lp_mint, pool_state_pda.key, "Target LP Mint")?;
     msg!("✅ Vault and LP mint authorities validated successfully");
 
+    // ✅ LP SUPPLY CAP: Reject deposits that would mint past the pool's cap (0 = no limit)
+    if pool_state_data.max_lp_supply > 0 {
+        let current_lp_supply = Mint::unpack_from_slice(&target_lp_mint.data.borrow())?.supply;
+        let new_lp_supply = current_lp_supply
+            .checked_add(amount)
+            .ok_or_else(|| crate::error::PoolError::MathOverflow { counter: "LP supply".to_string(), amount })?;
+        if new_lp_supply > pool_state_data.max_lp_supply {
+            msg!("❌ LP SUPPLY CAP: Supply {} + deposit {} exceeds cap {}",
+                 current_lp_supply, amount, pool_state_data.max_lp_supply);
+            return Err(crate::error::PoolError::LpSupplyCapReached {
+                cap: pool_state_data.max_lp_supply,
+                current_supply: current_lp_supply,
+                requested: amount,
+            }.into());
+        }
+    }
+
     // Validate user accounts (user's LP token account must exist)
     let user_output_data = if let Some(output_data) = user_output_data {
         output_data
@@ -403,6 +452,7 @@ pub fn process_liquidity_deposit<'a>(
     let fresh_pool_state = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
     pool_state_data.collected_liquidity_fees = fresh_pool_state.collected_liquidity_fees;
     pool_state_data.total_sol_fees_collected = fresh_pool_state.total_sol_fees_collected;
+    pool_state_data.lifetime_fees = fresh_pool_state.lifetime_fees;
     
     // Process deposit with atomic guarantees - no reentrancy possible due to account locking
     {
@@ -426,13 +476,8 @@ pub fn process_liquidity_deposit<'a>(
             )?;
 
             // Update pool liquidity
-            if is_depositing_token_a {
-                pool_state_data.total_token_a_liquidity = pool_state_data.total_token_a_liquidity.checked_add(amount)
-                    .ok_or(ProgramError::ArithmeticOverflow)?;
-            } else {
-                pool_state_data.total_token_b_liquidity = pool_state_data.total_token_b_liquidity.checked_add(amount)
-                    .ok_or(ProgramError::ArithmeticOverflow)?;
-            }
+            pool_state_data.add_liquidity(is_depositing_token_a, amount)?;
+            pool_state_data.record_operation();
 
             // Buffer serialization pattern to prevent PDA corruption - use safe serialization with size validation
             crate::utils::serialization::serialize_to_account(&pool_state_data, pool_state_pda)?;
@@ -442,8 +487,8 @@ pub fn process_liquidity_deposit<'a>(
                 POOL_STATE_SEED_PREFIX,
                 pool_state_data.token_a_mint.as_ref(),
                 pool_state_data.token_b_mint.as_ref(),
-                &pool_state_data.ratio_a_numerator.to_le_bytes(),
-                &pool_state_data.ratio_b_denominator.to_le_bytes(),
+                &pool_state_data.original_ratio_a_numerator.to_le_bytes(),
+                &pool_state_data.original_ratio_b_denominator.to_le_bytes(),
                 &[pool_state_data.pool_authority_bump_seed],
             ];
 
@@ -488,7 +533,116 @@ pub fn process_liquidity_deposit<'a>(
         return Err(ProgramError::Custom(3001));
     }
 
+    // ✅ NON-TRANSFERABLE LP: Refreeze the user's LP account so the position cannot be transferred
+    if pool_state_data.lp_transfers_frozen() {
+        freeze_lp_account(
+            &pool_state_data,
+            pool_state_pda,
+            target_lp_mint_account,
+            user_output_account,
+            spl_token_program_account,
+        )?;
+    }
+
     msg!("✅ DEPOSIT SUCCESS: {} tokens → {} LP tokens", amount, lp_tokens_received);
+    
+    if pool_state_data.emit_events {
+        ProgramEvent::Deposit(DepositEvent {
+            pool: pool_id,
+            user: *user_authority_signer.key,
+            deposit_mint: deposit_token_mint_key,
+            amount,
+            lp_minted: lp_tokens_received,
+            fee: pool_state_data.contract_liquidity_fee,
+        }).emit();
+    }
+    
+    Ok(())
+}
+
+/// Handles a deposit of both pool tokens in a single instruction.
+///
+/// Each side is processed by `process_liquidity_deposit` with its own 11-account
+/// layout, so both sides get the exact same validation, fee collection, limits and
+/// 1:1 LP minting as a standalone deposit. Any failure on either side fails the whole
+/// instruction and the runtime rolls back the other side.
+///
+/// # Arguments
+/// * `program_id` - The program ID for PDA derivation
+/// * `amount_a` - Amount of Token A to deposit
+/// * `amount_b` - Amount of Token B to deposit
+/// * `pool_id` - Expected Pool ID for security validation
+/// * `accounts` - Array of accounts (13 accounts total)
+///
+/// # Account Info
+/// The accounts must be provided in the following order:
+/// 0. **User Authority Signer** (signer, writable) - User signer authorizing both deposits
+/// 1. **System Program Account** (readable) - Solana system program account
+/// 2. **System State PDA** (readable) - System state PDA for pause validation
+/// 3. **Pool State PDA** (writable) - Pool state PDA
+/// 4. **SPL Token Program Account** (readable) - Token program account
+/// 5. **Token A Vault PDA** (writable) - Pool's Token A vault PDA
+/// 6. **Token B Vault PDA** (writable) - Pool's Token B vault PDA
+/// 7. **User Token A Account** (writable) - User's Token A account
+/// 8. **User Token B Account** (writable) - User's Token B account
+/// 9. **User LP Token A Account** (writable) - User's LP Token A account
+/// 10. **User LP Token B Account** (writable) - User's LP Token B account
+/// 11. **LP Token A Mint PDA** (writable) - LP Token A mint PDA
+/// 12. **LP Token B Mint PDA** (writable) - LP Token B mint PDA
+///
+/// # Returns
+/// * `ProgramResult` - Success or error code
+/// * Return data: `[lp_a_minted, lp_b_minted]` as two little-endian u64 values
+///
+/// # Critical Notes
+/// - **TWO LIQUIDITY FEES**: Each side is a full deposit and pays the pool's liquidity fee
+/// - **ANY SPLIT**: Amounts need not follow the pool ratio (each side mints its own LP token),
+///   but a zero side fails with `DepositRatioMismatch`
+pub fn process_liquidity_deposit_both<'a>(
+    program_id: &Pubkey,
+    amount_a: u64,
+    amount_b: u64,
+    pool_id: Pubkey,
+    accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+    msg!("🏦 DEPOSIT BOTH: {} Token A + {} Token B", amount_a, amount_b);
+
+    // Each side mints its own LP token 1:1, so any split of amounts is valid (nothing is
+    // refunded), but both sides must contribute - a zero side is a single-sided Deposit
+    if amount_a == 0 || amount_b == 0 {
+        msg!("❌ DEPOSIT BOTH: Both sides must be non-zero; use Deposit for a single side");
+        return Err(crate::error::PoolError::DepositRatioMismatch { amount_a, amount_b }.into());
+    }
+
+    let pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(&accounts[3], &pool_id, program_id)?;
+
+    // Rebuild the single-sided deposit layout for each side
+    let side_accounts = |user_input_index: usize, user_lp_index: usize| [
+        accounts[0].clone(),                 // User Authority Signer
+        accounts[1].clone(),                 // System Program
+        accounts[2].clone(),                 // System State PDA
+        accounts[3].clone(),                 // Pool State PDA
+        accounts[4].clone(),                 // SPL Token Program
+        accounts[5].clone(),                 // Token A Vault PDA
+        accounts[6].clone(),                 // Token B Vault PDA
+        accounts[user_input_index].clone(),  // User Input Token Account
+        accounts[user_lp_index].clone(),     // User Output LP Token Account
+        accounts[11].clone(),                // LP Token A Mint PDA
+        accounts[12].clone(),                // LP Token B Mint PDA
+    ];
+
+    process_liquidity_deposit(program_id, amount_a, pool_state_data.token_a_mint, pool_id, None, &side_accounts(7, 9))?;
+    process_liquidity_deposit(program_id, amount_b, pool_state_data.token_b_mint, pool_id, None, &side_accounts(8, 10))?;
+
+    // process_liquidity_deposit enforces a strict 1:1 mint for each side
+    let lp_a_minted = amount_a;
+    let lp_b_minted = amount_b;
+    let mut return_data = [0u8; 16];
+    return_data[0..8].copy_from_slice(&lp_a_minted.to_le_bytes());
+    return_data[8..16].copy_from_slice(&lp_b_minted.to_le_bytes());
+    set_return_data(&return_data);
+
+    msg!("✅ DEPOSIT BOTH SUCCESS: {} LP Token A + {} LP Token B", lp_a_minted, lp_b_minted);
     Ok(())
 }
 
@@ -596,8 +750,8 @@ pub fn process_liquidity_withdraw<'a>(
     // ✅ LOAD POOL STATE: Single deserialization with Pool ID security validation
     let mut pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
     
-    // ✅ LIQUIDITY PAUSE CHECK: Validate that liquidity operations are not paused
-    validate_liquidity_not_paused(&pool_state_data)?;
+    // ✅ LIQUIDITY PAUSE CHECK: Validate that withdrawals are not paused
+    validate_withdrawals_not_paused(&pool_state_data)?;
     
     // **PHASE 1: POOL EXISTENCE = INITIALIZATION**
     // If we successfully deserialized pool_state_data, the pool is initialized
@@ -629,6 +783,23 @@ pub fn process_liquidity_withdraw<'a>(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    // ✅ NON-TRANSFERABLE LP: Thaw the user's LP account before validation and the burn
+    // (the LP account must belong to the LP mint of the withdrawn token, enforced by the thaw itself)
+    if pool_state_data.lp_transfers_frozen() {
+        let withdraw_lp_mint = if withdraw_token_mint_key == pool_state_data.token_a_mint {
+            lp_token_a_mint_pda
+        } else {
+            lp_token_b_mint_pda
+        };
+        thaw_lp_account_if_frozen(
+            &pool_state_data,
+            pool_state_pda,
+            withdraw_lp_mint,
+            user_input_account,
+            spl_token_program_account,
+        )?;
+    }
+
     // ✅ OPTIMIZATION: CACHED TOKEN ACCOUNT DESERIALIZATIONS
     // Cache user output token account data (eliminates redundant deserialization)
     // 🔒 SECURITY: Validate user output token account (where they'll receive tokens)
@@ -689,6 +860,21 @@ pub fn process_liquidity_withdraw<'a>(
         "Withdrawal",
     )?;
 
+    // ✅ MINIMUM RESERVE: Withdrawals may not take the vault below the pool's minimum reserve (1:1, so LP burned = tokens out)
+    if pool_state_data.below_minimum_reserve(is_withdrawing_token_a, lp_amount_to_burn) {
+        let liquidity = if is_withdrawing_token_a {
+            pool_state_data.total_token_a_liquidity
+        } else {
+            pool_state_data.total_token_b_liquidity
+        };
+        let remaining = liquidity.saturating_sub(lp_amount_to_burn);
+        msg!("❌ BELOW MINIMUM RESERVE: Withdrawal would leave {}, minimum is {}", remaining, pool_state_data.minimum_reserve);
+        return Err(crate::error::PoolError::BelowMinimumReserve {
+            minimum_reserve: pool_state_data.minimum_reserve,
+            remaining,
+        }.into());
+    }
+
     // Determine the actual vault to use based on the token being withdrawn
     let actual_source_vault = if is_withdrawing_token_a {
         token_a_vault_pda
@@ -715,11 +901,34 @@ pub fn process_liquidity_withdraw<'a>(
     );
 
     // Save final state - use safe serialization with size validation
+    pool_state_data.record_operation();
     crate::utils::serialization::serialize_to_account(&pool_state_data, pool_state_pda)?;
 
     // Ensure the withdrawal operations completed successfully before collecting fees
     result?;
 
+    // ✅ NON-TRANSFERABLE LP: Refreeze the user's LP account (also when fully burned)
+    if pool_state_data.lp_transfers_frozen() {
+        freeze_lp_account(
+            &pool_state_data,
+            pool_state_pda,
+            source_lp_mint,
+            user_input_account,
+            spl_token_program_account,
+        )?;
+    }
+    
+    if pool_state_data.emit_events {
+        ProgramEvent::Withdraw(WithdrawEvent {
+            pool: pool_id,
+            user: *user_authority_signer.key,
+            withdraw_mint: withdraw_token_mint_key,
+            lp_burned: lp_amount_to_burn,
+            amount_out: lp_amount_to_burn,
+            fee: pool_state_data.contract_liquidity_fee,
+        }).emit();
+    }
+
     // ✅ COLLECT SOL FEES TO POOL STATE AFTER INVOKE_SIGNED (GitHub Issue #31960 Workaround)
     // Note: Fee was already collected before token operations
     // This prevents users from getting free withdrawals if fee collection fails
@@ -727,6 +936,709 @@ pub fn process_liquidity_withdraw<'a>(
     Ok(())
 }
 
+/// Withdraws a user's entire LP position for one pool token.
+///
+/// Reads the balance of the user's LP token account (index 7) and runs a regular
+/// `process_liquidity_withdraw` for exactly that amount. LP tokens are burned 1:1
+/// for the underlying token, so the whole balance is burned and no LP dust remains.
+///
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `withdraw_token_mint_key` - Token to withdraw (Token A or Token B)
+/// * `pool_id` - Expected Pool ID for security validation
+/// * `accounts` - Array of accounts, same order as `process_liquidity_withdraw` (11 accounts)
+///
+/// # Returns
+/// * `ProgramResult` - Success or error code; fails with `NoLpTokensToWithdraw` when the
+///   LP token account is empty
+/// * Return data: LP tokens burned as a little-endian u64
+pub fn process_liquidity_withdraw_all<'a>(
+    program_id: &Pubkey,
+    withdraw_token_mint_key: Pubkey,
+    pool_id: Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    use crate::error::PoolError;
+    use solana_program::program::set_return_data;
+
+    let user_input_account = &accounts[7];
+    let lp_balance = TokenAccount::unpack_from_slice(&user_input_account.data.borrow())?.amount;
+    msg!("🏦 WITHDRAW ALL: {} LP tokens in {}", lp_balance, user_input_account.key);
+
+    if lp_balance == 0 {
+        msg!("❌ LP token account {} holds no LP tokens", user_input_account.key);
+        return Err(PoolError::NoLpTokensToWithdraw { lp_account: *user_input_account.key }.into());
+    }
+
+    process_liquidity_withdraw(program_id, lp_balance, withdraw_token_mint_key, pool_id, accounts)?;
+
+    set_return_data(&lp_balance.to_le_bytes());
+
+    Ok(())
+}
+
+/// **VIEW INSTRUCTION**: Reports the fees a deposit of `amount` would be charged.
+///
+/// Mirrors `process_liquidity_deposit`: LP tokens are minted 1:1 with the deposited
+/// tokens, so no fee is taken in token units, and the pool's flat
+/// `contract_liquidity_fee` is collected in SOL regardless of the deposit size.
+///
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `deposit_token_mint` - Token to deposit (Token A or Token B)
+/// * `amount` - Amount the user would deposit
+/// * `pool_id` - Expected Pool ID for security validation
+/// * `accounts` - Array of accounts (1 account)
+///
+/// # Account Layout
+/// 0. **Pool State PDA** (readable) - Pool liquidity fee
+///
+/// # Returns
+/// * `ProgramResult` - Sets return data to two little-endian `u64` values:
+///   `[token_fee, sol_fee]`
+pub fn process_liquidity_get_deposit_fee(
+    program_id: &Pubkey,
+    deposit_token_mint: Pubkey,
+    amount: u64,
+    pool_id: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    let pool_state_pda = &accounts[0];
+    let pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+
+    validate_non_zero_amount(amount, "Deposit")?;
+    if deposit_token_mint != pool_state_data.token_a_mint && deposit_token_mint != pool_state_data.token_b_mint {
+        msg!("❌ Invalid deposit token mint: {}. Expected {} or {}",
+             deposit_token_mint, pool_state_data.token_a_mint, pool_state_data.token_b_mint);
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // LP tokens are minted 1:1, so the whole amount is credited
+    let token_fee: u64 = 0;
+    let sol_fee = pool_state_data.contract_liquidity_fee;
+
+    msg!("=== DEPOSIT FEE ===");
+    msg!("Amount: {} of mint {}", amount, deposit_token_mint);
+    msg!("Token Fee: {}", token_fee);
+    msg!("SOL Fee: {} lamports", sol_fee);
+    msg!("===================");
+
+    let mut return_data = Vec::with_capacity(16);
+    return_data.extend_from_slice(&token_fee.to_le_bytes());
+    return_data.extend_from_slice(&sol_fee.to_le_bytes());
+    set_return_data(&return_data);
+
+    Ok(())
+}
+
+/// Withdraws one side of an LP position and swaps the underlying into the other token.
+///
+/// Lets an LP holding LP Token A exit entirely in Token B (or vice versa) in one
+/// atomic instruction. The withdrawal is a regular `process_liquidity_withdraw`
+/// (1:1 LP burn, liquidity fee), and the withdrawn tokens are then swapped through
+/// `process_swap_execute` at the pool's fixed ratio (swap fees apply). If either
+/// leg fails the whole instruction is rolled back.
+///
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `lp_amount` - Amount of LP tokens to burn
+/// * `lp_token_mint` - LP mint being burned (LP Token A or LP Token B mint PDA)
+/// * `output_token_mint` - Token to receive; must be the pool token the LP mint does not represent
+/// * `min_out` - Minimum acceptable output from the swap leg
+/// * `pool_id` - Expected Pool ID for security validation
+/// * `accounts` - Array of accounts (14 accounts, plus the optional Main Treasury PDA)
+///
+/// # Account Info
+/// The accounts must be provided in the following order:
+/// 0. **User Authority Signer** (signer, writable) - User signer authorizing both legs
+/// 1. **System Program Account** (readable) - Solana system program account
+/// 2. **System State PDA** (readable) - System state PDA for pause validation
+/// 3. **Pool State PDA** (writable) - Pool state PDA
+/// 4. **SPL Token Program Account** (readable) - Token program account
+/// 5. **Token A Vault PDA** (writable) - Pool's Token A vault PDA
+/// 6. **Token B Vault PDA** (writable) - Pool's Token B vault PDA
+/// 7. **User Input LP Token Account** (writable) - User's LP token account to burn from
+/// 8. **User Withdrawn Token Account** (writable) - Receives the withdrawal, spent by the swap
+/// 9. **LP Token A Mint PDA** (writable) - LP Token A mint PDA
+/// 10. **LP Token B Mint PDA** (writable) - LP Token B mint PDA
+/// 11. **User Output Token Account** (writable) - Receives the swap output
+/// 12. **Withdrawn Token Mint** (readable) - Mint of the withdrawn token
+/// 13. **Output Token Mint** (readable) - Mint of `output_token_mint`
+/// 14. **Main Treasury PDA** (writable, optional) - Required when the protocol swap SOL fee is set
+///
+/// # Returns
+/// * `ProgramResult` - Success or error code; fails with `SwapOutputBelowMinimum`
+///   when the swap output is below `min_out`
+/// * Return data: `[amount_withdrawn, amount_out]` as two little-endian u64 values
+pub fn process_liquidity_withdraw_and_swap<'a>(
+    program_id: &Pubkey,
+    lp_amount: u64,
+    lp_token_mint: Pubkey,
+    output_token_mint: Pubkey,
+    min_out: u64,
+    pool_id: Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    use crate::error::PoolError;
+    use crate::processors::swap::{calculate_swap_output, process_swap_execute};
+
+    msg!("🏦 WITHDRAW AND SWAP: {} LP tokens of {} into {}", lp_amount, lp_token_mint, output_token_mint);
+
+    let pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(&accounts[3], &pool_id, program_id)?;
+
+    // The LP mint picks the side being withdrawn; the output must be the other token
+    let (withdraw_is_token_a, withdraw_token_mint) = if lp_token_mint == *accounts[9].key {
+        (true, pool_state_data.token_a_mint)
+    } else if lp_token_mint == *accounts[10].key {
+        (false, pool_state_data.token_b_mint)
+    } else {
+        msg!("❌ LP token mint {} is not one of the pool's LP mint accounts", lp_token_mint);
+        return Err(ProgramError::InvalidArgument);
+    };
+    let expected_output_mint = if withdraw_is_token_a {
+        pool_state_data.token_b_mint
+    } else {
+        pool_state_data.token_a_mint
+    };
+    if output_token_mint != expected_output_mint {
+        msg!("❌ Output token mint {} must be the pool's other token {}", output_token_mint, expected_output_mint);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Quote the swap leg up front so slippage fails before anything moves
+    let amount_withdrawn = lp_amount;
+    let amount_out = calculate_swap_output(&pool_state_data, withdraw_is_token_a, amount_withdrawn)?;
+    if amount_out < min_out {
+        msg!("❌ SWAP OUTPUT TOO LOW: Minimum {}, output {}", min_out, amount_out);
+        return Err(PoolError::SwapOutputBelowMinimum { minimum_amount_out: min_out, amount_out }.into());
+    }
+
+    // Accounts 0..=10 are the standard withdrawal layout
+    process_liquidity_withdraw(program_id, lp_amount, withdraw_token_mint, pool_id, accounts)?;
+
+    // Rebuild the standard swap account layout, spending the withdrawn tokens
+    let mut swap_accounts = vec![
+        accounts[0].clone(),   // User Authority Signer
+        accounts[1].clone(),   // System Program
+        accounts[2].clone(),   // System State PDA
+        accounts[3].clone(),   // Pool State PDA
+        accounts[4].clone(),   // SPL Token Program
+        accounts[5].clone(),   // Token A Vault PDA
+        accounts[6].clone(),   // Token B Vault PDA
+        accounts[8].clone(),   // User Input Token Account (withdrawn tokens)
+        accounts[11].clone(),  // User Output Token Account
+        accounts[12].clone(),  // Input Token Mint
+        accounts[13].clone(),  // Output Token Mint
+    ];
+    if let Some(main_treasury) = accounts.get(14) {
+        swap_accounts.push(main_treasury.clone()); // Main Treasury PDA
+    }
+    process_swap_execute(program_id, amount_withdrawn, amount_out, pool_id, false, &swap_accounts)?;
+
+    let mut return_data = [0u8; 16];
+    return_data[0..8].copy_from_slice(&amount_withdrawn.to_le_bytes());
+    return_data[8..16].copy_from_slice(&amount_out.to_le_bytes());
+    set_return_data(&return_data);
+
+    msg!("✅ WITHDRAW AND SWAP SUCCESS: {} LP tokens → {} withdrawn → {} out", lp_amount, amount_withdrawn, amount_out);
+    Ok(())
+}
+
+/// Moves all vault liquidity from one pool into a fresh pool of the same token pair.
+///
+/// Used to re-launch a pool at a corrected ratio. Both vault balances of the source pool
+/// are transferred to the destination vaults (signed by the source pool PDA), the source
+/// liquidity counters are zeroed and the destination is credited with the amounts its
+/// vaults actually received. The destination LP Token A and LP Token B for the moved
+/// amounts are minted into two migration escrow token accounts, PDAs of the source pool
+/// that this instruction creates and the source pool PDA controls. Each source LP holder
+/// then exchanges their own source LP tokens 1:1 for the escrowed destination LP tokens
+/// with `process_liquidity_claim_migrated`; the owner receives no LP tokens here.
+///
+/// Both pools must have the signer as owner and the same Token A and Token B mints, and the
+/// destination must hold no liquidity and have no LP tokens outstanding. A pool can be
+/// migrated from only once, since its escrow accounts already exist afterwards. Allowed
+/// while either pool is paused, but not while the system is paused.
+///
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `source_pool_id` - Expected Pool ID of the pool being emptied
+/// * `dest_pool_id` - Expected Pool ID of the pool receiving the liquidity
+/// * `accounts` - Array of accounts (15 accounts)
+///
+/// # Account Info
+/// The accounts must be provided in the following order:
+/// 0. **Pool Owner Signer** (signer, writable) - Owner of both pools, pays the escrow rent
+/// 1. **System State PDA** (readable) - System state PDA for pause validation
+/// 2. **Source Pool State PDA** (writable) - Pool being emptied
+/// 3. **Source Token A Vault PDA** (writable) - Source pool's Token A vault
+/// 4. **Source Token B Vault PDA** (writable) - Source pool's Token B vault
+/// 5. **Destination Pool State PDA** (writable) - Pool receiving the liquidity
+/// 6. **Destination Token A Vault PDA** (writable) - Destination pool's Token A vault
+/// 7. **Destination Token B Vault PDA** (writable) - Destination pool's Token B vault
+/// 8. **Destination LP Token A Mint PDA** (writable) - Destination pool's LP Token A mint
+/// 9. **Destination LP Token B Mint PDA** (writable) - Destination pool's LP Token B mint
+/// 10. **Migration Escrow LP Token A PDA** (writable) - Created here, receives the destination LP Token A
+/// 11. **Migration Escrow LP Token B PDA** (writable) - Created here, receives the destination LP Token B
+/// 12. **SPL Token Program Account** (readable) - Token program account
+/// 13. **System Program Account** (readable) - For creating the escrow accounts
+/// 14. **Rent Sysvar Account** (readable) - For initializing the escrow accounts
+///
+/// # Returns
+/// * `ProgramResult` - Success or error code; fails with `MigrationTokenPairMismatch` when the
+///   pools differ in their token pair, `MigrationDestinationNotEmpty` when the destination
+///   already holds liquidity and `AccountAlreadyInitialized` when the source was migrated before
+/// * Return data: `[token_a_moved, token_b_moved]` as two little-endian u64 values
+pub fn process_liquidity_migrate<'a>(
+    program_id: &Pubkey,
+    source_pool_id: Pubkey,
+    dest_pool_id: Pubkey,
+    accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+    use crate::error::PoolError;
+    use crate::utils::validation::{validate_and_deserialize_pool_state_secure, validate_lp_mint_authority, validate_signer};
+    use solana_program::{rent::Rent, system_instruction, sysvar::Sysvar};
+
+    msg!("🚚 MIGRATE LIQUIDITY: {} → {}", source_pool_id, dest_pool_id);
+
+    let owner_signer = &accounts[0];              // Index 0: Pool Owner Signer
+    let system_state_pda = &accounts[1];          // Index 1: System State PDA
+    let source_pool_state_pda = &accounts[2];     // Index 2: Source Pool State PDA
+    let source_token_a_vault = &accounts[3];      // Index 3: Source Token A Vault PDA
+    let source_token_b_vault = &accounts[4];      // Index 4: Source Token B Vault PDA
+    let dest_pool_state_pda = &accounts[5];       // Index 5: Destination Pool State PDA
+    let dest_token_a_vault = &accounts[6];        // Index 6: Destination Token A Vault PDA
+    let dest_token_b_vault = &accounts[7];        // Index 7: Destination Token B Vault PDA
+    let dest_lp_token_a_mint = &accounts[8];      // Index 8: Destination LP Token A Mint PDA
+    let dest_lp_token_b_mint = &accounts[9];      // Index 9: Destination LP Token B Mint PDA
+    let escrow_lp_a_account = &accounts[10];      // Index 10: Migration Escrow LP Token A PDA
+    let escrow_lp_b_account = &accounts[11];      // Index 11: Migration Escrow LP Token B PDA
+    let spl_token_program_account = &accounts[12]; // Index 12: SPL Token Program Account
+    let system_program_account = &accounts[13];   // Index 13: System Program Account
+    let rent_sysvar_account = &accounts[14];      // Index 14: Rent Sysvar Account
+
+    validate_signer(owner_signer, "Pool owner")?;
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+
+    if *spl_token_program_account.key != spl_token::id() {
+        msg!("❌ Invalid token program: {}", spl_token_program_account.key);
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if source_pool_id == dest_pool_id {
+        msg!("❌ Source and destination pool are the same pool");
+        return Err(PoolError::MigrationTokenPairMismatch { source_pool: source_pool_id, dest_pool: dest_pool_id }.into());
+    }
+
+    let mut source_pool_state = validate_and_deserialize_pool_state_secure(source_pool_state_pda, &source_pool_id, program_id)?;
+    let mut dest_pool_state = validate_and_deserialize_pool_state_secure(dest_pool_state_pda, &dest_pool_id, program_id)?;
+
+    for (pool_state, pool_id) in [(&source_pool_state, source_pool_id), (&dest_pool_state, dest_pool_id)] {
+        if *owner_signer.key != pool_state.owner {
+            msg!("❌ Only the pool owner ({}) can migrate liquidity of pool {}", pool_state.owner, pool_id);
+            return Err(PoolError::Unauthorized.into());
+        }
+    }
+
+    if source_pool_state.token_a_mint != dest_pool_state.token_a_mint
+        || source_pool_state.token_b_mint != dest_pool_state.token_b_mint
+    {
+        msg!("❌ Pools trade different token pairs: {}/{} vs {}/{}",
+             source_pool_state.token_a_mint, source_pool_state.token_b_mint,
+             dest_pool_state.token_a_mint, dest_pool_state.token_b_mint);
+        return Err(PoolError::MigrationTokenPairMismatch { source_pool: source_pool_id, dest_pool: dest_pool_id }.into());
+    }
+
+    // Vaults and LP mints must be the ones recorded in each pool's state
+    if *source_token_a_vault.key != source_pool_state.token_a_vault
+        || *source_token_b_vault.key != source_pool_state.token_b_vault
+        || *dest_token_a_vault.key != dest_pool_state.token_a_vault
+        || *dest_token_b_vault.key != dest_pool_state.token_b_vault
+    {
+        msg!("❌ Vault accounts do not match the pools' recorded vaults");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if *dest_lp_token_a_mint.key != dest_pool_state.lp_token_a_mint
+        || *dest_lp_token_b_mint.key != dest_pool_state.lp_token_b_mint
+    {
+        msg!("❌ LP mint accounts do not match the destination pool's LP mints");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    validate_lp_mint_authority(dest_lp_token_a_mint, dest_pool_state_pda.key, "Destination LP Token A Mint")?;
+    validate_lp_mint_authority(dest_lp_token_b_mint, dest_pool_state_pda.key, "Destination LP Token B Mint")?;
+
+    // ✅ SECURITY: Escrow accounts must be the source pool's derived escrow PDAs, not yet created
+    let (expected_escrow_lp_a, escrow_lp_a_bump) = Pubkey::find_program_address(
+        &[MIGRATION_ESCROW_LP_A_SEED_PREFIX, source_pool_state_pda.key.as_ref()],
+        program_id,
+    );
+    let (expected_escrow_lp_b, escrow_lp_b_bump) = Pubkey::find_program_address(
+        &[MIGRATION_ESCROW_LP_B_SEED_PREFIX, source_pool_state_pda.key.as_ref()],
+        program_id,
+    );
+    if *escrow_lp_a_account.key != expected_escrow_lp_a || *escrow_lp_b_account.key != expected_escrow_lp_b {
+        msg!("❌ SECURITY VIOLATION: Migration escrow accounts do not match the source pool's derived PDAs");
+        msg!("   Expected: {} / {}", expected_escrow_lp_a, expected_escrow_lp_b);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if escrow_lp_a_account.data_len() > 0 || escrow_lp_b_account.data_len() > 0 {
+        msg!("❌ Source pool {} has already been migrated", source_pool_id);
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    let vault_balance = |vault: &AccountInfo<'a>, pool_state_pda: &AccountInfo<'a>, mint: &Pubkey, name: &str| -> Result<u64, ProgramError> {
+        Ok(safe_unpack_and_validate_token_account(vault, name, Some(pool_state_pda.key), Some(mint), false)?.amount)
+    };
+    let token_a_moved = vault_balance(source_token_a_vault, source_pool_state_pda, &source_pool_state.token_a_mint, "Source Token A Vault")?;
+    let token_b_moved = vault_balance(source_token_b_vault, source_pool_state_pda, &source_pool_state.token_b_mint, "Source Token B Vault")?;
+    let dest_a_before = vault_balance(dest_token_a_vault, dest_pool_state_pda, &dest_pool_state.token_a_mint, "Destination Token A Vault")?;
+    let dest_b_before = vault_balance(dest_token_b_vault, dest_pool_state_pda, &dest_pool_state.token_b_mint, "Destination Token B Vault")?;
+
+    // The destination must be freshly initialized: no liquidity, vault balances or LP tokens
+    let dest_lp_a_supply = Mint::unpack_from_slice(&dest_lp_token_a_mint.data.borrow())?.supply;
+    let dest_lp_b_supply = Mint::unpack_from_slice(&dest_lp_token_b_mint.data.borrow())?.supply;
+    if dest_pool_state.total_token_a_liquidity != 0
+        || dest_pool_state.total_token_b_liquidity != 0
+        || dest_a_before != 0
+        || dest_b_before != 0
+        || dest_lp_a_supply != 0
+        || dest_lp_b_supply != 0
+    {
+        msg!("❌ Destination pool {} is not empty: liquidity {}/{}, vaults {}/{}, LP supply {}/{}",
+             dest_pool_id,
+             dest_pool_state.total_token_a_liquidity, dest_pool_state.total_token_b_liquidity,
+             dest_a_before, dest_b_before, dest_lp_a_supply, dest_lp_b_supply);
+        return Err(PoolError::MigrationDestinationNotEmpty { dest_pool: dest_pool_id }.into());
+    }
+
+    if token_a_moved == 0 && token_b_moved == 0 {
+        msg!("❌ Source pool {} holds no liquidity to migrate", source_pool_id);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Burn the source liquidity accounting before the vaults are drained
+    source_pool_state.total_token_a_liquidity = 0;
+    source_pool_state.total_token_b_liquidity = 0;
+    source_pool_state.record_operation();
+    // Buffer serialization pattern to prevent PDA corruption
+    crate::utils::serialization::serialize_to_account(&source_pool_state, source_pool_state_pda)?;
+
+    let source_pool_seeds = &[
+        POOL_STATE_SEED_PREFIX,
+        source_pool_state.token_a_mint.as_ref(),
+        source_pool_state.token_b_mint.as_ref(),
+        &source_pool_state.original_ratio_a_numerator.to_le_bytes(),
+        &source_pool_state.original_ratio_b_denominator.to_le_bytes(),
+        &[source_pool_state.pool_authority_bump_seed],
+    ];
+    for (source_vault, dest_vault, amount) in [
+        (source_token_a_vault, dest_token_a_vault, token_a_moved),
+        (source_token_b_vault, dest_token_b_vault, token_b_moved),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        invoke_signed(
+            &token_instruction::transfer(
+                spl_token_program_account.key,
+                source_vault.key,
+                dest_vault.key,
+                source_pool_state_pda.key,
+                &[],
+                amount,
+            )?,
+            &[
+                source_vault.clone(),
+                dest_vault.clone(),
+                source_pool_state_pda.clone(),
+                spl_token_program_account.clone(),
+            ],
+            &[source_pool_seeds],
+        )?;
+    }
+
+    // ✅ RE-FETCH VAULTS AFTER CPI (GitHub Issue #31960 Workaround): credit what actually arrived
+    let token_a_received = TokenAccount::unpack_from_slice(&dest_token_a_vault.data.borrow())?.amount
+        .checked_sub(dest_a_before)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let token_b_received = TokenAccount::unpack_from_slice(&dest_token_b_vault.data.borrow())?.amount
+        .checked_sub(dest_b_before)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let source_a_left = TokenAccount::unpack_from_slice(&source_token_a_vault.data.borrow())?.amount;
+    let source_b_left = TokenAccount::unpack_from_slice(&source_token_b_vault.data.borrow())?.amount;
+    if token_a_received != token_a_moved || token_b_received != token_b_moved || source_a_left != 0 || source_b_left != 0 {
+        msg!("❌ Vault balances after transfer do not match: received {}/{} of {}/{}, {}/{} left in source",
+             token_a_received, token_b_received, token_a_moved, token_b_moved, source_a_left, source_b_left);
+        return Err(ProgramError::Custom(3001));
+    }
+
+    dest_pool_state.total_token_a_liquidity = token_a_received;
+    dest_pool_state.total_token_b_liquidity = token_b_received;
+    dest_pool_state.record_operation();
+    // Buffer serialization pattern to prevent PDA corruption
+    crate::utils::serialization::serialize_to_account(&dest_pool_state, dest_pool_state_pda)?;
+
+    // Create the escrow token accounts: destination LP mints, owned by the source pool PDA
+    let rent = &Rent::from_account_info(rent_sysvar_account)?;
+    let escrow_rent = rent.minimum_balance(TokenAccount::LEN);
+    for (escrow_account, seed_prefix, bump, lp_mint) in [
+        (escrow_lp_a_account, MIGRATION_ESCROW_LP_A_SEED_PREFIX, escrow_lp_a_bump, dest_lp_token_a_mint),
+        (escrow_lp_b_account, MIGRATION_ESCROW_LP_B_SEED_PREFIX, escrow_lp_b_bump, dest_lp_token_b_mint),
+    ] {
+        invoke_signed(
+            &system_instruction::create_account(
+                owner_signer.key,
+                escrow_account.key,
+                escrow_rent,
+                TokenAccount::LEN as u64,
+                &spl_token::id(),
+            ),
+            &[
+                owner_signer.clone(),
+                escrow_account.clone(),
+                system_program_account.clone(),
+            ],
+            &[&[seed_prefix, source_pool_state_pda.key.as_ref(), &[bump]]],
+        )?;
+        invoke(
+            &token_instruction::initialize_account(
+                spl_token_program_account.key,
+                escrow_account.key,
+                lp_mint.key,
+                source_pool_state_pda.key,
+            )?,
+            &[
+                escrow_account.clone(),
+                lp_mint.clone(),
+                source_pool_state_pda.clone(),
+                rent_sysvar_account.clone(),
+                spl_token_program_account.clone(),
+            ],
+        )?;
+    }
+
+    // Mint destination LP tokens 1:1 with the liquidity moved into the escrows; holders
+    // claim them against their source LP tokens
+    let dest_pool_seeds = &[
+        POOL_STATE_SEED_PREFIX,
+        dest_pool_state.token_a_mint.as_ref(),
+        dest_pool_state.token_b_mint.as_ref(),
+        &dest_pool_state.original_ratio_a_numerator.to_le_bytes(),
+        &dest_pool_state.original_ratio_b_denominator.to_le_bytes(),
+        &[dest_pool_state.pool_authority_bump_seed],
+    ];
+    for (lp_mint, escrow_account, amount) in [
+        (dest_lp_token_a_mint, escrow_lp_a_account, token_a_received),
+        (dest_lp_token_b_mint, escrow_lp_b_account, token_b_received),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        invoke_signed(
+            &token_instruction::mint_to(
+                spl_token_program_account.key,
+                lp_mint.key,
+                escrow_account.key,
+                dest_pool_state_pda.key,
+                &[],
+                amount,
+            )?,
+            &[
+                lp_mint.clone(),
+                escrow_account.clone(),
+                dest_pool_state_pda.clone(),
+                spl_token_program_account.clone(),
+            ],
+            &[dest_pool_seeds],
+        )?;
+    }
+
+    let mut return_data = [0u8; 16];
+    return_data[0..8].copy_from_slice(&token_a_received.to_le_bytes());
+    return_data[8..16].copy_from_slice(&token_b_received.to_le_bytes());
+    set_return_data(&return_data);
+
+    msg!("✅ LIQUIDITY MIGRATED: {} Token A and {} Token B from {} to {}",
+         token_a_received, token_b_received, source_pool_id, dest_pool_id);
+    msg!("   • Destination LP escrowed in {} and {} for source LP holders to claim", escrow_lp_a_account.key, escrow_lp_b_account.key);
+    Ok(())
+}
+
+/// Exchanges a holder's source LP tokens 1:1 for the destination LP tokens escrowed by a migration.
+///
+/// After `process_liquidity_migrate`, the source LP tokens have no liquidity behind them and
+/// the destination LP tokens for the moved amounts sit in the source pool's migration
+/// escrow. The holder burns `lp_amount` source LP tokens of one side and receives the same
+/// amount of the destination LP token of that side from the escrow, signed by the source
+/// pool PDA. The side is chosen by the source LP mint passed. Like `Withdraw`, each side is
+/// paid only from its own escrow; a claim above what is left fails with `InsufficientFunds`.
+///
+/// Allowed while either pool is paused, but not while the system is paused. LP accounts of
+/// non-transferable LP pools are thawed for the burn or transfer and frozen again.
+///
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `source_pool_id` - Expected Pool ID of the migrated pool
+/// * `dest_pool_id` - Expected Pool ID of the pool that received the liquidity
+/// * `lp_amount` - Source LP tokens to burn (and destination LP tokens to receive)
+/// * `accounts` - Array of accounts (10 accounts)
+///
+/// # Account Info
+/// The accounts must be provided in the following order:
+/// 0. **Holder Signer** (signer) - Owner of the source LP tokens
+/// 1. **System State PDA** (readable) - System state PDA for pause validation
+/// 2. **Source Pool State PDA** (readable) - Migrated pool, authority of the escrow
+/// 3. **Source LP Token Mint PDA** (writable) - Source LP Token A or B mint, selects the side
+/// 4. **Holder Source LP Token Account** (writable) - LP tokens burned
+/// 5. **Destination Pool State PDA** (readable) - Pool that received the liquidity
+/// 6. **Destination LP Token Mint PDA** (readable) - Destination LP mint of the same side
+/// 7. **Migration Escrow LP Token PDA** (writable) - Source pool's escrow of the same side
+/// 8. **Holder Destination LP Token Account** (writable) - Receives the destination LP tokens
+/// 9. **SPL Token Program Account** (readable) - Token program account
+///
+/// # Returns
+/// * `ProgramResult` - Success or error code
+pub fn process_liquidity_claim_migrated<'a>(
+    program_id: &Pubkey,
+    source_pool_id: Pubkey,
+    dest_pool_id: Pubkey,
+    lp_amount: u64,
+    accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+    use crate::error::PoolError;
+    use crate::utils::validation::{validate_and_deserialize_pool_state_secure, validate_signer};
+
+    msg!("🎟️ CLAIM MIGRATED LIQUIDITY: {} LP from {} → {}", lp_amount, source_pool_id, dest_pool_id);
+
+    let holder_signer = &accounts[0];             // Index 0: Holder Signer
+    let system_state_pda = &accounts[1];          // Index 1: System State PDA
+    let source_pool_state_pda = &accounts[2];     // Index 2: Source Pool State PDA
+    let source_lp_mint = &accounts[3];            // Index 3: Source LP Token Mint PDA
+    let holder_source_lp_account = &accounts[4];  // Index 4: Holder Source LP Token Account
+    let dest_pool_state_pda = &accounts[5];       // Index 5: Destination Pool State PDA
+    let dest_lp_mint = &accounts[6];              // Index 6: Destination LP Token Mint PDA
+    let escrow_account = &accounts[7];            // Index 7: Migration Escrow LP Token PDA
+    let holder_dest_lp_account = &accounts[8];    // Index 8: Holder Destination LP Token Account
+    let spl_token_program_account = &accounts[9]; // Index 9: SPL Token Program Account
+
+    validate_signer(holder_signer, "LP holder")?;
+    validate_non_zero_amount(lp_amount, "Migrated LP claim")?;
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+
+    if *spl_token_program_account.key != spl_token::id() {
+        msg!("❌ Invalid token program: {}", spl_token_program_account.key);
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let source_pool_state = validate_and_deserialize_pool_state_secure(source_pool_state_pda, &source_pool_id, program_id)?;
+    let dest_pool_state = validate_and_deserialize_pool_state_secure(dest_pool_state_pda, &dest_pool_id, program_id)?;
+    if source_pool_state.token_a_mint != dest_pool_state.token_a_mint
+        || source_pool_state.token_b_mint != dest_pool_state.token_b_mint
+    {
+        msg!("❌ Pools trade different token pairs");
+        return Err(PoolError::MigrationTokenPairMismatch { source_pool: source_pool_id, dest_pool: dest_pool_id }.into());
+    }
+
+    // The source LP mint selects the side; the destination mint and escrow must be that side's
+    let (expected_dest_lp_mint, escrow_seed_prefix) = if *source_lp_mint.key == source_pool_state.lp_token_a_mint {
+        (dest_pool_state.lp_token_a_mint, MIGRATION_ESCROW_LP_A_SEED_PREFIX)
+    } else if *source_lp_mint.key == source_pool_state.lp_token_b_mint {
+        (dest_pool_state.lp_token_b_mint, MIGRATION_ESCROW_LP_B_SEED_PREFIX)
+    } else {
+        msg!("❌ {} is not an LP mint of source pool {}", source_lp_mint.key, source_pool_id);
+        return Err(ProgramError::InvalidAccountData);
+    };
+    if *dest_lp_mint.key != expected_dest_lp_mint {
+        msg!("❌ Destination LP mint {} does not match the source side ({})", dest_lp_mint.key, expected_dest_lp_mint);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let (expected_escrow, _) = Pubkey::find_program_address(
+        &[escrow_seed_prefix, source_pool_state_pda.key.as_ref()],
+        program_id,
+    );
+    if *escrow_account.key != expected_escrow {
+        msg!("❌ SECURITY VIOLATION: Escrow account does not match the source pool's derived escrow PDA");
+        msg!("   Expected: {}", expected_escrow);
+        msg!("   Provided: {}", escrow_account.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Only MigrateLiquidity creates the escrow, so a destination-mint escrow owned by the
+    // source pool proves the migration went from this source into this destination
+    let escrow = safe_unpack_and_validate_token_account(escrow_account, "Migration Escrow", Some(source_pool_state_pda.key), Some(dest_lp_mint.key), false)?;
+    if escrow.amount < lp_amount {
+        msg!("❌ Escrow holds {} destination LP tokens, {} requested", escrow.amount, lp_amount);
+        return Err(PoolError::InsufficientFunds {
+            required: lp_amount,
+            available: escrow.amount,
+            account: *escrow_account.key,
+        }.into());
+    }
+
+    // Burn the holder's source LP tokens (non-transferable LP accounts are thawed for the burn)
+    if source_pool_state.lp_transfers_frozen() {
+        thaw_lp_account_if_frozen(&source_pool_state, source_pool_state_pda, source_lp_mint, holder_source_lp_account, spl_token_program_account)?;
+    }
+    safe_unpack_and_validate_token_account(holder_source_lp_account, "Holder Source LP Token Account", Some(holder_signer.key), Some(source_lp_mint.key), false)?;
+    invoke(
+        &token_instruction::burn(
+            spl_token_program_account.key,
+            holder_source_lp_account.key,
+            source_lp_mint.key,
+            holder_signer.key,
+            &[],
+            lp_amount,
+        )?,
+        &[
+            holder_source_lp_account.clone(),
+            source_lp_mint.clone(),
+            holder_signer.clone(),
+            spl_token_program_account.clone(),
+        ],
+    )?;
+    if source_pool_state.lp_transfers_frozen() {
+        freeze_lp_account(&source_pool_state, source_pool_state_pda, source_lp_mint, holder_source_lp_account, spl_token_program_account)?;
+    }
+
+    // Pay the same amount of destination LP out of the escrow, signed by the source pool PDA
+    safe_unpack_and_validate_token_account(holder_dest_lp_account, "Holder Destination LP Token Account", None, Some(dest_lp_mint.key), false)?;
+    if dest_pool_state.lp_transfers_frozen() {
+        thaw_lp_account_if_frozen(&dest_pool_state, dest_pool_state_pda, dest_lp_mint, holder_dest_lp_account, spl_token_program_account)?;
+    }
+    let source_pool_seeds = &[
+        POOL_STATE_SEED_PREFIX,
+        source_pool_state.token_a_mint.as_ref(),
+        source_pool_state.token_b_mint.as_ref(),
+        &source_pool_state.original_ratio_a_numerator.to_le_bytes(),
+        &source_pool_state.original_ratio_b_denominator.to_le_bytes(),
+        &[source_pool_state.pool_authority_bump_seed],
+    ];
+    invoke_signed(
+        &token_instruction::transfer(
+            spl_token_program_account.key,
+            escrow_account.key,
+            holder_dest_lp_account.key,
+            source_pool_state_pda.key,
+            &[],
+            lp_amount,
+        )?,
+        &[
+            escrow_account.clone(),
+            holder_dest_lp_account.clone(),
+            source_pool_state_pda.clone(),
+            spl_token_program_account.clone(),
+        ],
+        &[source_pool_seeds],
+    )?;
+    if dest_pool_state.lp_transfers_frozen() {
+        freeze_lp_account(&dest_pool_state, dest_pool_state_pda, dest_lp_mint, holder_dest_lp_account, spl_token_program_account)?;
+    }
+
+    msg!("✅ MIGRATED LP CLAIMED: {} source LP burned for {} destination LP", lp_amount, lp_amount);
+    Ok(())
+}
+
 /// Execute the core withdrawal logic
 /// 
 /// This function performs the actual token burning and transfer operations.
@@ -794,6 +1706,7 @@ fn execute_withdrawal_logic<'a>(
     let fresh_pool_state = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_account, pool_id, program_id)?;
     pool_state_data.collected_liquidity_fees = fresh_pool_state.collected_liquidity_fees;
     pool_state_data.total_sol_fees_collected = fresh_pool_state.total_sol_fees_collected;
+    pool_state_data.lifetime_fees = fresh_pool_state.lifetime_fees;
     
     // Process withdrawal with atomic guarantees - no reentrancy possible due to account locking
     {
@@ -821,8 +1734,8 @@ fn execute_withdrawal_logic<'a>(
                 POOL_STATE_SEED_PREFIX,
                 pool_state_data.token_a_mint.as_ref(),
                 pool_state_data.token_b_mint.as_ref(),
-                &pool_state_data.ratio_a_numerator.to_le_bytes(),
-                &pool_state_data.ratio_b_denominator.to_le_bytes(),
+                &pool_state_data.original_ratio_a_numerator.to_le_bytes(),
+                &pool_state_data.original_ratio_b_denominator.to_le_bytes(),
                 &[pool_state_data.pool_authority_bump_seed],
             ];
 
@@ -1034,6 +1947,102 @@ fn validate_withdrawal_lp_correspondence(
     }
 }
 
+/// Thaws a user LP token account of a non-transferable LP pool so it can be minted to or burned from.
+///
+/// Pools created with `POOL_FLAG_LP_TRANSFERS_FROZEN` keep every user LP account frozen; the
+/// pool state PDA is the freeze authority of both LP mints. Accounts that are not frozen
+/// (e.g. freshly created ones) are left untouched. The SPL Token program rejects the thaw if
+/// `lp_account` does not belong to `lp_mint`.
+///
+/// # Arguments
+/// * `pool_state` - Pool state providing the pool PDA signer seeds
+/// * `pool_state_pda` - Pool state PDA (freeze authority)
+/// * `lp_mint` - LP mint of the account
+/// * `lp_account` - User LP token account
+/// * `token_program` - SPL Token program account
+pub(crate) fn thaw_lp_account_if_frozen<'a>(
+    pool_state: &PoolState,
+    pool_state_pda: &AccountInfo<'a>,
+    lp_mint: &AccountInfo<'a>,
+    lp_account: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let is_frozen = TokenAccount::unpack_from_slice(&lp_account.data.borrow())
+        .map(|account| account.state == AccountState::Frozen)
+        .unwrap_or(false);
+    if !is_frozen {
+        return Ok(());
+    }
+
+    let pool_pda_seeds = &[
+        POOL_STATE_SEED_PREFIX,
+        pool_state.token_a_mint.as_ref(),
+        pool_state.token_b_mint.as_ref(),
+        &pool_state.original_ratio_a_numerator.to_le_bytes(),
+        &pool_state.original_ratio_b_denominator.to_le_bytes(),
+        &[pool_state.pool_authority_bump_seed],
+    ];
+    invoke_signed(
+        &token_instruction::thaw_account(
+            token_program.key,
+            lp_account.key,
+            lp_mint.key,
+            pool_state_pda.key,
+            &[],
+        )?,
+        &[
+            lp_account.clone(),
+            lp_mint.clone(),
+            pool_state_pda.clone(),
+            token_program.clone(),
+        ],
+        &[pool_pda_seeds],
+    )
+}
+
+/// Freezes a user LP token account of a non-transferable LP pool after a deposit or withdrawal.
+///
+/// Counterpart of `thaw_lp_account_if_frozen`; signed by the pool state PDA as freeze authority.
+///
+/// # Arguments
+/// * `pool_state` - Pool state providing the pool PDA signer seeds
+/// * `pool_state_pda` - Pool state PDA (freeze authority)
+/// * `lp_mint` - LP mint of the account
+/// * `lp_account` - User LP token account
+/// * `token_program` - SPL Token program account
+pub(crate) fn freeze_lp_account<'a>(
+    pool_state: &PoolState,
+    pool_state_pda: &AccountInfo<'a>,
+    lp_mint: &AccountInfo<'a>,
+    lp_account: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let pool_pda_seeds = &[
+        POOL_STATE_SEED_PREFIX,
+        pool_state.token_a_mint.as_ref(),
+        pool_state.token_b_mint.as_ref(),
+        &pool_state.original_ratio_a_numerator.to_le_bytes(),
+        &pool_state.original_ratio_b_denominator.to_le_bytes(),
+        &[pool_state.pool_authority_bump_seed],
+    ];
+    invoke_signed(
+        &token_instruction::freeze_account(
+            token_program.key,
+            lp_account.key,
+            lp_mint.key,
+            pool_state_pda.key,
+            &[],
+        )?,
+        &[
+            lp_account.clone(),
+            lp_mint.clone(),
+            pool_state_pda.clone(),
+            token_program.clone(),
+        ],
+        &[pool_pda_seeds],
+    )
+}
+
 //=============================================================================
 // PHASE 9 OPTIMIZATION 3: DYNAMIC ACCOUNT CONSOLIDATION (FUTURE)
 //=============================================================================
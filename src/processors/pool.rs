@@ -6,7 +6,7 @@
 use crate::{
     constants::*,
     error::PoolError,
-    state::{MainTreasuryState, PoolState},
+    state::{MainTreasuryState, PoolState, SwapMode, SystemState},
     utils::{
         serialization::serialize_to_account, 
         validation::{
@@ -16,7 +16,7 @@ use crate::{
         }
     },
 };
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::BorshSerialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
@@ -78,7 +78,11 @@ use spl_token::{
 ///   - Supported bits:
 ///     - `POOL_FLAG_SWAP_FOR_OWNERS_ONLY` (bit 5, value 32): Restrict swaps to owner-only
 ///     - `POOL_FLAG_EXACT_EXCHANGE_REQUIRED` (bit 6, value 64): Require exact exchange (no rounding)
+///     - `POOL_FLAG_LP_TRANSFERS_FROZEN` (bit 7, value 128): Non-transferable LP tokens; the LP
+///       mints are created with the pool state PDA as freeze authority
 ///   - Note: Flags are documented here for visibility; they are not yet applied in initialization
+/// * `initial_swap_contract_fee` - Starting swap contract fee in lamports, validated against
+///   `MIN_SWAP_FEE`..=`MAX_SWAP_FEE` like `UpdatePoolFees` (`None` uses `SWAP_CONTRACT_FEE`)
 /// * `accounts` - Array of accounts in secure order (13 accounts total)
 /// 
 /// # Account Info
@@ -130,6 +134,7 @@ pub fn process_pool_initialize(
     ratio_a_numerator: u64,
     ratio_b_denominator: u64,
     flags: u8,
+    initial_swap_contract_fee: Option<u64>,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     // ✅ ACCOUNT EXTRACTION: Extract accounts using updated indices
@@ -141,8 +146,6 @@ pub fn process_pool_initialize(
     // 🚨 CRITICAL SECURITY FIX: Validate user authority is a signer
     validate_signer(user_authority_signer, "User authority")?;
     
-    // Validate system is not paused
-    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
     let token_program_account = &accounts[4];                      // Index 4: SPL Token Program Account
     let main_treasury_pda = &accounts[5];                          // Index 5: Main Treasury PDA
     let rent_sysvar_account = &accounts[6];                        // Index 6: Rent Sysvar Account
@@ -155,9 +158,22 @@ pub fn process_pool_initialize(
 
     let rent = &Rent::from_account_info(rent_sysvar_account)?;
     
-    // 🔧 FIX: Read decimals from underlying token mints to ensure LP tokens match
-    let token_a_decimals = Mint::unpack_from_slice(&token_a_mint_account.data.borrow())?.decimals;
-    let token_b_decimals = Mint::unpack_from_slice(&token_b_mint_account.data.borrow())?.decimals;
+    // ✅ PARAMETER VALIDATION: Pause state, mints, ratio, pool PDA and ratio type, shared
+    // with ValidatePoolParams so a dry run rejects exactly what creation would reject
+    let ValidatedPoolParams {
+        token_a_decimals,
+        token_b_decimals,
+        ratio_type,
+        pool_authority_bump_seed,
+    } = validate_pool_params(
+        program_id,
+        system_state_pda,
+        pool_state_pda,
+        token_a_mint_account,
+        token_b_mint_account,
+        ratio_a_numerator,
+        ratio_b_denominator,
+    )?;
     
     // Consolidated pool creation summary (single message block)
     msg!("🏊 POOL CREATION | TokenA decimals: {} | TokenB decimals: {} | Registration: {} SOL", 
@@ -172,36 +188,12 @@ pub fn process_pool_initialize(
     // Critical security fix: Explicit signer checks are required for user operations
     // to prevent unauthorized pool creation and fee charges.
 
-    // Validate ratio values
-    crate::utils::validation::validate_ratio_values(ratio_a_numerator, ratio_b_denominator)?;
-
-    // 🔒 SECURITY GUARDS: Early validation to prevent invalid pool creation
-    // Guard 1: Same-mint rejection
-    if token_a_mint_account.key == token_b_mint_account.key {
-        msg!("❌ SECURITY VIOLATION: Token A and Token B cannot be the same mint");
-        msg!("   Provided Token A: {}", token_a_mint_account.key);
-        msg!("   Provided Token B: {}", token_b_mint_account.key);
-        msg!("   This would create a nonsensical single-token pool");
-        return Err(ProgramError::InvalidArgument);
-    }
-
-    // Guard 2: SPL Token program ID assertions for token mint accounts
-    if token_a_mint_account.owner != &spl_token::id() {
-        msg!("❌ SECURITY VIOLATION: Token A mint not owned by SPL Token program");
-        msg!("   Expected owner: {}", spl_token::id());
-        msg!("   Actual owner: {}", token_a_mint_account.owner);
-        msg!("   Account: {}", token_a_mint_account.key);
-        return Err(ProgramError::IncorrectProgramId);
-    }
-    if token_b_mint_account.owner != &spl_token::id() {
-        msg!("❌ SECURITY VIOLATION: Token B mint not owned by SPL Token program");
-        msg!("   Expected owner: {}", spl_token::id());
-        msg!("   Actual owner: {}", token_b_mint_account.owner);
-        msg!("   Account: {}", token_b_mint_account.key);
-        return Err(ProgramError::IncorrectProgramId);
+    // A custom starting swap fee obeys the same limits as UpdatePoolFees
+    if let Some(swap_fee) = initial_swap_contract_fee {
+        validate_fee_limits(FEE_UPDATE_FLAG_SWAP, 0, swap_fee)?;
     }
 
-    // Guard 3: SPL Token program account validation
+    // 🔒 SECURITY GUARD: SPL Token program account validation
     if *token_program_account.key != spl_token::id() {
         msg!("❌ SECURITY VIOLATION: Invalid SPL Token program account provided");
         msg!("   Expected: {}", spl_token::id());
@@ -255,7 +247,7 @@ pub fn process_pool_initialize(
     )?;
     
     // Update treasury state with real-time tracking
-    let mut treasury_state = MainTreasuryState::try_from_slice(&main_treasury_pda.data.borrow())?;
+    let mut treasury_state = MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow())?;
     treasury_state.add_pool_creation_fee(REGISTRATION_FEE, current_timestamp);
     treasury_state.sync_balance_with_account(main_treasury_pda.lamports());
     
@@ -292,25 +284,6 @@ pub fn process_pool_initialize(
         program_id,
     );
 
-    // ✅ SECURITY: Derive pool state PDA and validate provided account matches
-    let (expected_pool_state_pda, pool_authority_bump_seed) = Pubkey::find_program_address(
-        &[
-            POOL_STATE_SEED_PREFIX,
-            token_a_mint_key.as_ref(),
-            token_b_mint_key.as_ref(),
-            &ratio_a_numerator.to_le_bytes(),
-            &ratio_b_denominator.to_le_bytes(),
-        ],
-        program_id,
-    );
-    
-    if *pool_state_pda.key != expected_pool_state_pda {
-        msg!("❌ SECURITY VIOLATION: Pool State PDA does not match expected derived PDA");
-        msg!("   Expected: {}", expected_pool_state_pda);
-        msg!("   Provided: {}", pool_state_pda.key);
-        return Err(ProgramError::InvalidAccountData);
-    }
-
     let pool_state_pda_seeds = &[
         POOL_STATE_SEED_PREFIX,
         token_a_mint_key.as_ref(),
@@ -320,11 +293,6 @@ pub fn process_pool_initialize(
         &[pool_authority_bump_seed],
     ];
 
-    // Check if pool already exists
-    if pool_state_pda.data_len() > 0 && !pool_state_pda.data_is_empty() {
-        return Err(ProgramError::AccountAlreadyInitialized);
-    }
-
     // ✅ SECURITY: Derive vault PDAs and validate provided accounts match
     let (expected_token_a_vault, token_a_vault_bump_seed) = Pubkey::find_program_address(
         &[
@@ -369,6 +337,14 @@ pub fn process_pool_initialize(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    // ✅ SECURITY: LP token mints must be unique to this pool - refuse to reuse an existing account
+    for lp_mint_account in [lp_token_a_mint_pda, lp_token_b_mint_pda] {
+        if lp_mint_account.data_len() > 0 {
+            msg!("❌ LP MINT COLLISION: Account already exists at derived LP mint {}", lp_mint_account.key);
+            return Err(PoolError::LpMintCollision { mint: *lp_mint_account.key }.into());
+        }
+    }
+
     // Create seeds for signing
     let token_a_vault_seeds = &[
         TOKEN_A_VAULT_SEED_PREFIX,
@@ -519,13 +495,20 @@ pub fn process_pool_initialize(
         &[lp_token_a_mint_seeds],
     )?;
 
+    // Non-transferable LP mode needs the pool state PDA as freeze authority of both LP mints
+    let lp_freeze_authority = if flags & crate::constants::POOL_FLAG_LP_TRANSFERS_FROZEN != 0 {
+        Some(pool_state_pda.key)
+    } else {
+        None
+    };
+
     // Initialize LP Token A mint with pool state PDA as authority
     invoke_signed(
         &token_instruction::initialize_mint(
             token_program_account.key,
             lp_token_a_mint_pda.key,
             pool_state_pda.key,
-            None,
+            lp_freeze_authority,
             token_a_decimals, // 🔧 FIX: Use Token A decimals instead of hardcoded 6
         )?,
         &[
@@ -559,7 +542,7 @@ pub fn process_pool_initialize(
             token_program_account.key,
             lp_token_b_mint_pda.key,
             pool_state_pda.key,
-            None,
+            lp_freeze_authority,
             token_b_decimals, // 🔧 FIX: Use Token B decimals instead of hardcoded 6
         )?,
         &[
@@ -570,64 +553,6 @@ pub fn process_pool_initialize(
         &[pool_state_pda_seeds], // Pool state PDA signs as mint authority
     )?;
 
-    // ✅ EXTRACT TOKEN DECIMALS: Extract decimals from token mint accounts for one-to-many ratio calculation
-    let token_a_mint_data = token_a_mint_account.try_borrow_data()?;
-    let token_a_mint = spl_token::state::Mint::unpack(&token_a_mint_data)?;
-    let token_a_decimals_v2 = token_a_mint.decimals;
-    
-    let token_b_mint_data = token_b_mint_account.try_borrow_data()?;
-    let token_b_mint = spl_token::state::Mint::unpack(&token_b_mint_data)?;
-    let token_b_decimals_v2 = token_b_mint.decimals;
-    
-    // Check for variable shadowing issues
-    if token_a_decimals != token_a_decimals_v2 {
-        msg!("🚨 WARNING: Token A decimals mismatch! {} vs {}", token_a_decimals, token_a_decimals_v2);
-    }
-    if token_b_decimals != token_b_decimals_v2 {
-        msg!("🚨 WARNING: Token B decimals mismatch! {} vs {}", token_b_decimals, token_b_decimals_v2);
-    }
-
-    // ✅ FIXED: Determine correct decimals for one-to-many ratio calculation
-    // The ratios have been normalized, so we need to determine which token is which
-    // based on the same logic used in normalize_pool_config
-    let token_a_is_the_multiple = token_a_mint_account.key.to_bytes() < token_b_mint_account.key.to_bytes();
-    
-    // Determine which decimals correspond to which ratio after normalization
-    let (ratio_a_decimals, ratio_b_decimals) = if token_a_is_the_multiple {
-        // Token A is the multiple token (abundant) - use token A decimals for ratio A
-        (token_a_decimals_v2, token_b_decimals_v2)
-    } else {
-        // Token B is the multiple token (abundant) - ratios were swapped during normalization
-        // So ratio A now corresponds to token B decimals, and ratio B corresponds to token A decimals
-        (token_b_decimals_v2, token_a_decimals_v2)
-    };
-
-    // ✅ RATIO TYPE CLASSIFICATION: Determine the type of ratio for this pool
-    // This classifies the ratio into SimpleRatio, DecimalRatio, or EngineeringRatio
-    // based on whether values are whole numbers and if one equals exactly 1.0
-    
-    // 🔧 BASIS POINTS REFACTOR: Input ratios are already in basis points (client responsibility)
-    // Contract fetches decimals for validation only, no conversion needed
-    let ratio_type = get_ratio_type(
-        ratio_a_numerator,     // Already in basis points
-        ratio_b_denominator,   // Already in basis points
-        ratio_a_decimals,      // Correct decimals for ratio A after normalization
-        ratio_b_decimals       // Correct decimals for ratio B after normalization
-    );
-    
-    // Log the ratio type for debugging
-    msg!("📊 Pool ratio classified as: {}", ratio_type);
-
-    // ✅ RATIO TYPE VALIDATION: Only allow SimpleRatio and DecimalRatio
-    // EngineeringRatio pools are not supported for security and UX reasons
-    if ratio_type == crate::types::RatioType::EngineeringRatio {
-        msg!("❌ REJECTED: EngineeringRatio pools are not supported");
-        msg!("   Only SimpleRatio (1:2, 1:100) and DecimalRatio (1:100.24343) are allowed");
-        return Err(PoolError::UnsupportedRatioType {
-            ratio_type: ratio_type.short_name().to_string(),
-        }.into());
-    }
- 
     // ✅ POOL STATE: Create pool state with comprehensive configuration
     // Base flags: set SIMPLE_RATIO if applicable; OR-in allowed init flags without clearing
     let mut initial_flags: u8 = if ratio_type == crate::types::RatioType::SimpleRatio {
@@ -638,9 +563,13 @@ pub fn process_pool_initialize(
     // Only allow a curated subset of flags at initialization time
     let allowed_init_mask: u8 =
         crate::constants::POOL_FLAG_SWAP_FOR_OWNERS_ONLY |
-        crate::constants::POOL_FLAG_EXACT_EXCHANGE_REQUIRED;
+        crate::constants::POOL_FLAG_EXACT_EXCHANGE_REQUIRED |
+        crate::constants::POOL_FLAG_LP_TRANSFERS_FROZEN;
     initial_flags |= flags & allowed_init_mask;
 
+    // Store the ratio in lowest terms; the requested values stay the PDA seeds
+    let (reduced_ratio_a, reduced_ratio_b) = crate::utils::validation::reduce_ratio(ratio_a_numerator, ratio_b_denominator);
+
     let pool_state = PoolState {
         owner: *user_authority_signer.key,
         token_a_mint: *token_a_mint_key,
@@ -649,8 +578,8 @@ pub fn process_pool_initialize(
         token_b_vault: *token_b_vault_pda.key,
         lp_token_a_mint: lp_token_a_mint_pda_address,
         lp_token_b_mint: lp_token_b_mint_pda_address,
-        ratio_a_numerator,
-        ratio_b_denominator,
+        ratio_a_numerator: reduced_ratio_a,
+        ratio_b_denominator: reduced_ratio_b,
         total_token_a_liquidity: 0,
         total_token_b_liquidity: 0,
         pool_authority_bump_seed,
@@ -663,7 +592,7 @@ pub fn process_pool_initialize(
         
         // **NEW: CONFIGURABLE CONTRACT FEES** - Initialize with current constants
         contract_liquidity_fee: crate::constants::DEPOSIT_WITHDRAWAL_FEE,
-        swap_contract_fee: crate::constants::SWAP_CONTRACT_FEE,
+        swap_contract_fee: initial_swap_contract_fee.unwrap_or(crate::constants::SWAP_CONTRACT_FEE),
         
         collected_fees_token_a: 0,
         collected_fees_token_b: 0,
@@ -686,7 +615,35 @@ pub fn process_pool_initialize(
         min_deposit_amount: 0,      // 0 = no minimum
         max_withdrawal_amount: 0,   // 0 = no limit
         min_withdrawal_amount: 0,   // 0 = no minimum
-        _reserved: [0; 4],          // Reserved for future use
+        rounding_surplus_token_a: 0,
+        rounding_surplus_token_b: 0,
+        max_lp_supply: 0,           // 0 = no limit
+        auto_unpause_at: 0,         // No auto-unpause timer
+        reserve_safety_floor_a: 0,  // 0 = no reserve floor
+        reserve_safety_floor_b: 0,  // 0 = no reserve floor
+        schema_version: POOL_STATE_SCHEMA_VERSION,
+        minimum_reserve: 0,         // 0 = no minimum reserve
+        swap_mode: SwapMode::Both,  // Exact-in and exact-out swaps allowed
+        created_at: current_timestamp,
+        last_swap_at: 0,            // No swap yet
+        min_allowed_fee: 0,         // 0 = no lower bound
+        max_allowed_fee: 0,         // 0 = no upper bound
+        lp_token_a_decimals: token_a_decimals, // LP Token A mint was created with these
+        lp_token_b_decimals: token_b_decimals, // LP Token B mint was created with these
+        partial_pause_flags: 0,     // Nothing paused at creation
+        daily_fee_snapshot_token_a: [0; FEE_HISTORY_DAYS],
+        daily_fee_snapshot_token_b: [0; FEE_HISTORY_DAYS],
+        snapshot_timestamp: 0,      // No swap fee recorded yet
+        emit_events: true,          // Structured events on until an operator turns them off
+        pending_owner: Pubkey::default(), // No owner transfer pending
+        owner_transfer_requested_at: 0,
+        total_operations: 0,
+        cumulative_volume_token_a: 0, // Lifetime counters start empty
+        cumulative_volume_token_b: 0,
+        lifetime_fees: 0,
+        original_ratio_a_numerator: ratio_a_numerator,
+        original_ratio_b_denominator: ratio_b_denominator,
+        fee_recipient: Pubkey::default(), // Fees consolidate to the MainTreasury
     };
 
     // Serialize pool state to account
@@ -694,8 +651,247 @@ pub fn process_pool_initialize(
     
     // ✅ POOL ID: Emit the unique pool identifier for easy client parsing
     msg!("🎯 POOL_ID: {} | Ratio: {}:{} | Type: {}", 
-         pool_state_pda.key, ratio_a_numerator, ratio_b_denominator, ratio_type.short_name());
+         pool_state_pda.key, reduced_ratio_a, reduced_ratio_b, ratio_type.short_name());
+    if (reduced_ratio_a, reduced_ratio_b) != (ratio_a_numerator, ratio_b_denominator) {
+        msg!("   • Reduced from requested ratio {}:{}", ratio_a_numerator, ratio_b_denominator);
+    }
+    
+    Ok(())
+}
+
+/// Pool parameters that passed the checks shared by `InitializePool` and `ValidatePoolParams`
+struct ValidatedPoolParams {
+    token_a_decimals: u8,
+    token_b_decimals: u8,
+    ratio_type: crate::types::RatioType,
+    pool_authority_bump_seed: u8,
+}
+
+/// Runs every pool creation check that does not depend on fee payment or account creation.
+///
+/// In order: system pause (honouring `allow_pool_creation_while_paused`), mint decimals,
+/// non-zero ratio, same-mint rejection, SPL Token ownership of both mints, the pool state
+/// PDA derived from the normalized (Token A < Token B) configuration, pool existence and
+/// ratio type classification (EngineeringRatio is rejected).
+///
+/// # Arguments
+/// * `token_a_mint_account` / `token_b_mint_account` - Mints in normalized order
+/// * `ratio_a_numerator` / `ratio_b_denominator` - Normalized ratio in basis points
+fn validate_pool_params<'a>(
+    program_id: &Pubkey,
+    system_state_pda: &AccountInfo<'a>,
+    pool_state_pda: &AccountInfo<'a>,
+    token_a_mint_account: &AccountInfo<'a>,
+    token_b_mint_account: &AccountInfo<'a>,
+    ratio_a_numerator: u64,
+    ratio_b_denominator: u64,
+) -> Result<ValidatedPoolParams, ProgramError> {
+    // Validate system is not paused, unless the admin has allowed pool creation during a pause
+    let system_state = SystemState::load_from_account(system_state_pda, program_id)?;
+    if system_state.is_paused {
+        if !system_state.allow_pool_creation_while_paused {
+            msg!("🛑 SYSTEM PAUSED: Pool creation blocked (pause code {})", system_state.pause_reason_code);
+            return Err(PoolError::PoolCreationPausedBySystem {
+                pause_reason_code: system_state.pause_reason_code,
+            }.into());
+        }
+        msg!("⚠️ System paused (code {}), pool creation explicitly allowed", system_state.pause_reason_code);
+    }
+
+    // 🔧 FIX: Read decimals from underlying token mints to ensure LP tokens match
+    let token_a_decimals = Mint::unpack_from_slice(&token_a_mint_account.data.borrow())?.decimals;
+    let token_b_decimals = Mint::unpack_from_slice(&token_b_mint_account.data.borrow())?.decimals;
+    
+    // LP mints copy these decimals, so they must stay within SPL's 0-9 range
+    for (mint_account, decimals) in [(token_a_mint_account, token_a_decimals), (token_b_mint_account, token_b_decimals)] {
+        if decimals > MAX_TOKEN_DECIMALS {
+            msg!("❌ Token mint {} has {} decimals (maximum {})", mint_account.key, decimals, MAX_TOKEN_DECIMALS);
+            return Err(PoolError::UnsupportedTokenDecimals {
+                mint: *mint_account.key,
+                decimals,
+                max_decimals: MAX_TOKEN_DECIMALS,
+            }.into());
+        }
+    }
+
+    // Validate ratio values
+    crate::utils::validation::validate_ratio_values(ratio_a_numerator, ratio_b_denominator)?;
+
+    // 🔒 SECURITY GUARDS: Early validation to prevent invalid pool creation
+    // Guard 1: Same-mint rejection
+    if token_a_mint_account.key == token_b_mint_account.key {
+        msg!("❌ SECURITY VIOLATION: Token A and Token B cannot be the same mint");
+        msg!("   Provided Token A: {}", token_a_mint_account.key);
+        msg!("   Provided Token B: {}", token_b_mint_account.key);
+        msg!("   This would create a nonsensical single-token pool");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Guard 2: SPL Token program ID assertions for token mint accounts
+    if token_a_mint_account.owner != &spl_token::id() {
+        msg!("❌ SECURITY VIOLATION: Token A mint not owned by SPL Token program");
+        msg!("   Expected owner: {}", spl_token::id());
+        msg!("   Actual owner: {}", token_a_mint_account.owner);
+        msg!("   Account: {}", token_a_mint_account.key);
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if token_b_mint_account.owner != &spl_token::id() {
+        msg!("❌ SECURITY VIOLATION: Token B mint not owned by SPL Token program");
+        msg!("   Expected owner: {}", spl_token::id());
+        msg!("   Actual owner: {}", token_b_mint_account.owner);
+        msg!("   Account: {}", token_b_mint_account.key);
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Token normalization: Always store tokens in lexicographic order (Token A < Token B)
+    let (token_a_mint_key, token_b_mint_key) = 
+        if token_a_mint_account.key < token_b_mint_account.key {
+            (token_a_mint_account.key, token_b_mint_account.key)
+        } else {
+            (token_b_mint_account.key, token_a_mint_account.key)
+        };
+
+    // ✅ SECURITY: Derive pool state PDA and validate provided account matches
+    let (expected_pool_state_pda, pool_authority_bump_seed) = Pubkey::find_program_address(
+        &[
+            POOL_STATE_SEED_PREFIX,
+            token_a_mint_key.as_ref(),
+            token_b_mint_key.as_ref(),
+            &ratio_a_numerator.to_le_bytes(),
+            &ratio_b_denominator.to_le_bytes(),
+        ],
+        program_id,
+    );
+    
+    if *pool_state_pda.key != expected_pool_state_pda {
+        msg!("❌ SECURITY VIOLATION: Pool State PDA does not match expected derived PDA");
+        msg!("   Expected: {}", expected_pool_state_pda);
+        msg!("   Provided: {}", pool_state_pda.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Check if pool already exists
+    if pool_state_pda.data_len() > 0 && !pool_state_pda.data_is_empty() {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // ✅ FIXED: Determine correct decimals for one-to-many ratio calculation
+    // The ratios have been normalized, so we need to determine which token is which
+    // based on the same logic used in normalize_pool_config
+    let token_a_is_the_multiple = token_a_mint_account.key.to_bytes() < token_b_mint_account.key.to_bytes();
+    
+    // Determine which decimals correspond to which ratio after normalization
+    let (ratio_a_decimals, ratio_b_decimals) = if token_a_is_the_multiple {
+        // Token A is the multiple token (abundant) - use token A decimals for ratio A
+        (token_a_decimals, token_b_decimals)
+    } else {
+        // Token B is the multiple token (abundant) - ratios were swapped during normalization
+        // So ratio A now corresponds to token B decimals, and ratio B corresponds to token A decimals
+        (token_b_decimals, token_a_decimals)
+    };
+
+    // ✅ RATIO TYPE CLASSIFICATION: Determine the type of ratio for this pool
+    // This classifies the ratio into SimpleRatio, DecimalRatio, or EngineeringRatio
+    // based on whether values are whole numbers and if one equals exactly 1.0
+    
+    // 🔧 BASIS POINTS REFACTOR: Input ratios are already in basis points (client responsibility)
+    // Contract fetches decimals for validation only, no conversion needed
+    let ratio_type = get_ratio_type(
+        ratio_a_numerator,     // Already in basis points
+        ratio_b_denominator,   // Already in basis points
+        ratio_a_decimals,      // Correct decimals for ratio A after normalization
+        ratio_b_decimals       // Correct decimals for ratio B after normalization
+    );
     
+    // Log the ratio type for debugging
+    msg!("📊 Pool ratio classified as: {}", ratio_type);
+
+    // ✅ RATIO TYPE VALIDATION: Only allow SimpleRatio and DecimalRatio
+    // EngineeringRatio pools are not supported for security and UX reasons
+    if ratio_type == crate::types::RatioType::EngineeringRatio {
+        msg!("❌ REJECTED: EngineeringRatio pools are not supported");
+        msg!("   Only SimpleRatio (1:2, 1:100) and DecimalRatio (1:100.24343) are allowed");
+        return Err(PoolError::UnsupportedRatioType {
+            ratio_type: ratio_type.short_name().to_string(),
+        }.into());
+    }
+
+    Ok(ValidatedPoolParams {
+        token_a_decimals,
+        token_b_decimals,
+        ratio_type,
+        pool_authority_bump_seed,
+    })
+}
+
+/// Dry run of `InitializePool`: reports whether a pool configuration would be accepted.
+///
+/// Takes the pair in the user's terms (multiple token, base token, ratio of multiple to
+/// base), normalizes it the way clients must before `InitializePool` (Token A < Token B,
+/// ratios swapped with the mints) and runs `validate_pool_params` on the result. Nothing
+/// is created or charged, and the instruction succeeds whatever the outcome so the
+/// result can be read from a simulation.
+///
+/// The registration fee balance of the eventual creator is not checked.
+///
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `multiple_token_mint` - Mint of the abundant token (must match account 1)
+/// * `base_token_mint` - Mint of the base token (must match account 2)
+/// * `ratio_a_numerator` - Multiple token amount in basis points
+/// * `ratio_b_denominator` - Base token amount in basis points
+/// * `accounts` - System state, multiple mint, base mint, pool state PDA (4 accounts)
+///
+/// # Returns (via `set_return_data`)
+/// Little-endian `u64`: `0` when creation would pass validation, otherwise the error
+/// `InitializePool` would fail with (the `PoolError` code for custom errors, the
+/// `ProgramError` encoding for builtin ones)
+pub fn process_pool_validate_params(
+    program_id: &Pubkey,
+    multiple_token_mint: Pubkey,
+    base_token_mint: Pubkey,
+    ratio_a_numerator: u64,
+    ratio_b_denominator: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let system_state_pda = &accounts[0];
+    let multiple_mint_account = &accounts[1];
+    let base_mint_account = &accounts[2];
+    let pool_state_pda = &accounts[3];
+
+    let result = if *multiple_mint_account.key != multiple_token_mint || *base_mint_account.key != base_token_mint {
+        msg!("❌ Mint accounts do not match the instruction's multiple/base token mints");
+        Err(ProgramError::InvalidAccountData)
+    } else {
+        // Same normalization as the client-side normalize_pool_config
+        let (token_a_mint_account, token_b_mint_account, ratio_a, ratio_b) =
+            if multiple_token_mint.to_bytes() < base_token_mint.to_bytes() {
+                (multiple_mint_account, base_mint_account, ratio_a_numerator, ratio_b_denominator)
+            } else {
+                (base_mint_account, multiple_mint_account, ratio_b_denominator, ratio_a_numerator)
+            };
+        validate_pool_params(
+            program_id,
+            system_state_pda,
+            pool_state_pda,
+            token_a_mint_account,
+            token_b_mint_account,
+            ratio_a,
+            ratio_b,
+        ).map(|_| ())
+    };
+
+    let code = match result {
+        Ok(()) => {
+            msg!("✅ Pool parameters valid");
+            0
+        }
+        Err(error) => {
+            msg!("❌ Pool parameters rejected: {}", error);
+            u64::from(error)
+        }
+    };
+    solana_program::program::set_return_data(&code.to_le_bytes());
     Ok(())
 }
 
@@ -705,11 +901,15 @@ pub fn process_pool_initialize(
 /// - PAUSE_FLAG_LIQUIDITY (1): Pause deposits/withdrawals
 /// - PAUSE_FLAG_SWAPS (2): Pause swaps
 /// - PAUSE_FLAG_ALL (3): Pause both (required for consolidation eligibility)
+/// - PAUSE_SWAP_A_TO_B (4) / PAUSE_SWAP_B_TO_A (8): Pause one swap direction
+/// - PAUSE_DEPOSITS (16) / PAUSE_WITHDRAWALS (32): Pause one side of liquidity
 /// 
 /// # Arguments
 /// * `program_id` - The program ID for PDA validation
 /// * `pause_flags` - Bitwise flags indicating which operations to pause
 /// * `pool_id` - Expected Pool ID for security validation
+/// * `auto_unpause_at` - Future Unix timestamp after which the pause stops applying
+///   (`PausePoolUntil`); `None` for `PausePool`
 /// * `accounts` - Array of account infos (4 accounts)
 /// 
 /// **Security**: Only the Program Upgrade Authority can pause individual pools.
@@ -721,6 +921,7 @@ pub fn process_pool_pause(
     program_id: &Pubkey,
     pause_flags: u8,
     pool_id: Pubkey,
+    auto_unpause_at: Option<i64>,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     msg!("Processing PausePool instruction with flags: 0b{:08b} ({})", pause_flags, pause_flags);
@@ -747,17 +948,21 @@ pub fn process_pool_pause(
     let mut pool_state = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
     
     // Apply pause flags (idempotent - no error if already paused)
-    let mut operations_changed = Vec::new();
-    
-    if pause_flags & PAUSE_FLAG_LIQUIDITY != 0 && !pool_state.liquidity_paused() {
-        pool_state.set_liquidity_paused(true);
-        operations_changed.push("general operations");
-    }
-    
-    if pause_flags & PAUSE_FLAG_SWAPS != 0 && !pool_state.swaps_paused() {
-        pool_state.set_swaps_paused(true);
-        operations_changed.push("swaps");
+    let previously_paused = pool_state.paused_operations();
+    pool_state.set_paused_operations(previously_paused | expand_pause_flags(pause_flags));
+    let operations_changed = describe_pause_operations(pool_state.paused_operations() & !previously_paused);
+    
+    // Each pause replaces any previous auto-unpause timer; None means paused until unpaused
+    if let Some(unpause_at) = auto_unpause_at {
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if unpause_at <= current_timestamp {
+            msg!("❌ Auto-unpause time {} must be in the future (current timestamp {})", unpause_at, current_timestamp);
+            return Err(ProgramError::InvalidArgument);
+        }
+        msg!("⏲️ Pool will auto-unpause at {}", unpause_at);
     }
+    pool_state.auto_unpause_at = auto_unpause_at.unwrap_or(0);
+    pool_state.record_operation();
     
     // Save updated pool state with size validation
     let serialized_data = pool_state.try_to_vec()?;
@@ -775,8 +980,10 @@ pub fn process_pool_pause(
     }
     
     msg!("   Pool: {}", pool_state_pda.key);
-    msg!("   Liquidity operations: {}", if pool_state.liquidity_paused() { "PAUSED" } else { "ENABLED" });
-    msg!("   Swap operations: {}", if pool_state.swaps_paused() { "PAUSED" } else { "ENABLED" });
+    msg!("   Deposits: {}", if pool_state.paused_operations() & PAUSE_DEPOSITS != 0 { "PAUSED" } else { "ENABLED" });
+    msg!("   Withdrawals: {}", if pool_state.paused_operations() & PAUSE_WITHDRAWALS != 0 { "PAUSED" } else { "ENABLED" });
+    msg!("   Swaps A→B: {}", if pool_state.paused_operations() & PAUSE_SWAP_A_TO_B != 0 { "PAUSED" } else { "ENABLED" });
+    msg!("   Swaps B→A: {}", if pool_state.paused_operations() & PAUSE_SWAP_B_TO_A != 0 { "PAUSED" } else { "ENABLED" });
     msg!("   Consolidation eligible: {}", 
          if pool_state.liquidity_paused() && pool_state.swaps_paused() { "YES" } else { "NO" });
     
@@ -789,6 +996,8 @@ pub fn process_pool_pause(
 /// - PAUSE_FLAG_LIQUIDITY (1): Unpause deposits/withdrawals
 /// - PAUSE_FLAG_SWAPS (2): Unpause swaps
 /// - PAUSE_FLAG_ALL (3): Unpause both operations
+/// - PAUSE_SWAP_A_TO_B (4) / PAUSE_SWAP_B_TO_A (8): Unpause one swap direction
+/// - PAUSE_DEPOSITS (16) / PAUSE_WITHDRAWALS (32): Unpause one side of liquidity
 /// 
 /// # Arguments
 /// * `program_id` - The program ID for PDA validation
@@ -831,17 +1040,15 @@ pub fn process_pool_unpause(
     let mut pool_state = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
     
     // Apply unpause flags (idempotent - no error if already unpaused)
-    let mut operations_changed = Vec::new();
-    
-    if unpause_flags & PAUSE_FLAG_LIQUIDITY != 0 && pool_state.liquidity_paused() {
-        pool_state.set_liquidity_paused(false);
-        operations_changed.push("general operations");
-    }
+    let previously_paused = pool_state.paused_operations();
+    pool_state.set_paused_operations(previously_paused & !expand_pause_flags(unpause_flags));
+    let operations_changed = describe_pause_operations(previously_paused & !pool_state.paused_operations());
     
-    if unpause_flags & PAUSE_FLAG_SWAPS != 0 && pool_state.swaps_paused() {
-        pool_state.set_swaps_paused(false);
-        operations_changed.push("swaps");
+    // A fully unpaused pool has no use for an auto-unpause timer
+    if pool_state.paused_operations() == 0 {
+        pool_state.auto_unpause_at = 0;
     }
+    pool_state.record_operation();
     
     // Save updated pool state with size validation
     let serialized_data = pool_state.try_to_vec()?;
@@ -859,14 +1066,43 @@ pub fn process_pool_unpause(
     }
     
     msg!("   Pool: {}", pool_state_pda.key);
-    msg!("   Liquidity operations: {}", if pool_state.liquidity_paused() { "PAUSED" } else { "ENABLED" });
-    msg!("   Swap operations: {}", if pool_state.swaps_paused() { "PAUSED" } else { "ENABLED" });
+    msg!("   Deposits: {}", if pool_state.paused_operations() & PAUSE_DEPOSITS != 0 { "PAUSED" } else { "ENABLED" });
+    msg!("   Withdrawals: {}", if pool_state.paused_operations() & PAUSE_WITHDRAWALS != 0 { "PAUSED" } else { "ENABLED" });
+    msg!("   Swaps A→B: {}", if pool_state.paused_operations() & PAUSE_SWAP_A_TO_B != 0 { "PAUSED" } else { "ENABLED" });
+    msg!("   Swaps B→A: {}", if pool_state.paused_operations() & PAUSE_SWAP_B_TO_A != 0 { "PAUSED" } else { "ENABLED" });
     msg!("   Consolidation eligible: {}", 
          if pool_state.liquidity_paused() && pool_state.swaps_paused() { "YES" } else { "NO" });
     
     Ok(())
 }
 
+/// Expands `PausePool` / `UnpausePool` flags into individual operation bits
+/// (`PAUSE_FLAG_LIQUIDITY` and `PAUSE_FLAG_SWAPS` each stand for both halves of their category)
+fn expand_pause_flags(flags: u8) -> u8 {
+    let mut operations = flags & (PAUSE_SWAP_A_TO_B | PAUSE_SWAP_B_TO_A | PAUSE_DEPOSITS | PAUSE_WITHDRAWALS);
+    if flags & PAUSE_FLAG_LIQUIDITY != 0 {
+        operations |= PAUSE_DEPOSITS | PAUSE_WITHDRAWALS;
+    }
+    if flags & PAUSE_FLAG_SWAPS != 0 {
+        operations |= PAUSE_SWAP_A_TO_B | PAUSE_SWAP_B_TO_A;
+    }
+    operations
+}
+
+/// Names the operations in a set of operation bits, for pause/unpause logs
+fn describe_pause_operations(operations: u8) -> Vec<&'static str> {
+    [
+        (PAUSE_DEPOSITS, "deposits"),
+        (PAUSE_WITHDRAWALS, "withdrawals"),
+        (PAUSE_SWAP_A_TO_B, "swaps A→B"),
+        (PAUSE_SWAP_B_TO_A, "swaps B→A"),
+    ]
+    .iter()
+    .filter(|(bit, _)| operations & bit != 0)
+    .map(|(_, name)| *name)
+    .collect()
+}
+
 /// BPF Loader Upgradeable Program Data Account Structure
 /// 
 /// This structure represents the layout of the program data account
@@ -971,6 +1207,16 @@ pub fn process_pool_update_fees(
     }
     
     if update_flags & FEE_UPDATE_FLAG_SWAP != 0 {
+        // ✅ FEE BAND: The pool owner may bound the swap fee tighter than the global limits
+        if !pool_state_data.swap_fee_within_band(new_swap_fee) {
+            msg!("❌ Swap fee {} lamports is outside the pool's band [{}, {}]",
+                 new_swap_fee, pool_state_data.min_allowed_fee, pool_state_data.max_allowed_fee);
+            return Err(PoolError::SwapFeeOutsideBand {
+                fee: new_swap_fee,
+                min_allowed_fee: pool_state_data.min_allowed_fee,
+                max_allowed_fee: pool_state_data.max_allowed_fee,
+            }.into());
+        }
         let old_swap_fee = pool_state_data.swap_contract_fee;
         pool_state_data.swap_contract_fee = new_swap_fee;
         msg!("✅ Swap fee updated: {} → {} lamports", old_swap_fee, new_swap_fee);
@@ -982,6 +1228,7 @@ pub fn process_pool_update_fees(
     }
     
     // ✅ SERIALIZE UPDATED POOL STATE: Save changes to account
+    pool_state_data.record_operation();
     pool_state_data.serialize(&mut &mut pool_state_pda.data.borrow_mut()[..])?;
     msg!("✅ Pool state serialized with updated fees");
     
@@ -1012,6 +1259,912 @@ pub fn process_pool_update_fees(
 
 
 
+/// Processes the SetMaxLpSupply instruction
+/// 
+/// Sets the maximum supply of each LP token mint for a pool. Deposits that would
+/// mint past the cap are rejected; a cap of 0 removes the limit. Lowering the cap
+/// below the current supply is allowed and simply blocks further deposits.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (4 accounts)
+/// * `max_lp_supply` - New LP supply cap per LP mint (0 = no limit)
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_set_max_lp_supply(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_lp_supply: u64,
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🔧 POOL LP SUPPLY CAP UPDATE");
+    
+    let account_info_iter = &mut accounts.iter();
+    let admin_authority_signer = next_account_info(account_info_iter)?; // Index 0: Admin Authority Signer
+    let system_state_pda = next_account_info(account_info_iter)?;       // Index 1: System State PDA
+    let pool_state_pda = next_account_info(account_info_iter)?;         // Index 2: Pool State PDA
+    let program_data_account = next_account_info(account_info_iter)?;   // Index 3: Program Data Account
+    
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let mut pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    let old_max_lp_supply = pool_state_data.max_lp_supply;
+    pool_state_data.max_lp_supply = max_lp_supply;
+    
+    pool_state_data.record_operation();
+    serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    msg!("✅ LP supply cap updated: {} → {} (0 = no limit)", old_max_lp_supply, max_lp_supply);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    msg!("   • Updated by: {}", admin_authority_signer.key);
+    
+    Ok(())
+}
+
+/// Processes the SetReserveSafetyFloor instruction
+/// 
+/// Sets the minimum liquidity of each token that swaps must leave in the pool.
+/// Swaps whose output would take a token's liquidity below its floor are rejected;
+/// a floor of 0 disables the check for that token. Withdrawals are not affected.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (4 accounts)
+/// * `reserve_safety_floor_a` - Minimum Token A liquidity after a swap (0 = no floor)
+/// * `reserve_safety_floor_b` - Minimum Token B liquidity after a swap (0 = no floor)
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_set_reserve_safety_floor(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reserve_safety_floor_a: u64,
+    reserve_safety_floor_b: u64,
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🔧 POOL RESERVE SAFETY FLOOR UPDATE");
+    
+    let account_info_iter = &mut accounts.iter();
+    let admin_authority_signer = next_account_info(account_info_iter)?; // Index 0: Admin Authority Signer
+    let system_state_pda = next_account_info(account_info_iter)?;       // Index 1: System State PDA
+    let pool_state_pda = next_account_info(account_info_iter)?;         // Index 2: Pool State PDA
+    let program_data_account = next_account_info(account_info_iter)?;   // Index 3: Program Data Account
+    
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let mut pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    pool_state_data.reserve_safety_floor_a = reserve_safety_floor_a;
+    pool_state_data.reserve_safety_floor_b = reserve_safety_floor_b;
+    
+    pool_state_data.record_operation();
+    serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    msg!("✅ Reserve safety floors updated: Token A {}, Token B {} (0 = no floor)", reserve_safety_floor_a, reserve_safety_floor_b);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    msg!("   • Updated by: {}", admin_authority_signer.key);
+    
+    Ok(())
+}
+
+/// Processes the SetMinimumReserve instruction
+/// 
+/// Sets the minimum liquidity a withdrawal must leave in the vault it draws from,
+/// so LPs cannot drain a side to zero and strand the other token. The minimum
+/// applies to both vaults in each token's base units; 0 removes it. Swaps are not
+/// affected (see `SetReserveSafetyFloor`).
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (4 accounts)
+/// * `minimum_reserve` - Minimum liquidity left after a withdrawal (0 = no minimum)
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_set_minimum_reserve(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    minimum_reserve: u64,
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🔧 POOL MINIMUM RESERVE UPDATE");
+    
+    let account_info_iter = &mut accounts.iter();
+    let admin_authority_signer = next_account_info(account_info_iter)?; // Index 0: Admin Authority Signer
+    let system_state_pda = next_account_info(account_info_iter)?;       // Index 1: System State PDA
+    let pool_state_pda = next_account_info(account_info_iter)?;         // Index 2: Pool State PDA
+    let program_data_account = next_account_info(account_info_iter)?;   // Index 3: Program Data Account
+    
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let mut pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    let old_minimum_reserve = pool_state_data.minimum_reserve;
+    pool_state_data.minimum_reserve = minimum_reserve;
+    
+    pool_state_data.record_operation();
+    serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    msg!("✅ Minimum reserve updated: {} → {} (0 = no minimum)", old_minimum_reserve, minimum_reserve);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    msg!("   • Updated by: {}", admin_authority_signer.key);
+    
+    Ok(())
+}
+
+/// Processes the SetSwapMode instruction
+/// 
+/// Restricts which swap instructions the pool accepts. Structured-product pools can
+/// forbid exact-in swaps (and their dust) so every trade names its output and caps
+/// its input, or the reverse. `Both` lifts the restriction.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (4 accounts)
+/// * `swap_mode` - Swaps the pool accepts
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_set_swap_mode(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    swap_mode: SwapMode,
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🔧 POOL SWAP MODE UPDATE");
+    
+    let account_info_iter = &mut accounts.iter();
+    let admin_authority_signer = next_account_info(account_info_iter)?; // Index 0: Admin Authority Signer
+    let system_state_pda = next_account_info(account_info_iter)?;       // Index 1: System State PDA
+    let pool_state_pda = next_account_info(account_info_iter)?;         // Index 2: Pool State PDA
+    let program_data_account = next_account_info(account_info_iter)?;   // Index 3: Program Data Account
+    
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let mut pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    let old_swap_mode = pool_state_data.swap_mode;
+    pool_state_data.swap_mode = swap_mode;
+    
+    pool_state_data.record_operation();
+    serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    msg!("✅ Swap mode updated: {:?} → {:?}", old_swap_mode, swap_mode);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    msg!("   • Updated by: {}", admin_authority_signer.key);
+    
+    Ok(())
+}
+
+/// Processes the SetEventEmission instruction
+/// 
+/// Turns a pool's structured events (the `SWAP|..`, `DEPOSIT|..` and `WITHDRAW|..` log
+/// lines and their `sol_log_data` blobs) on or off. High-frequency pools can trade that
+/// observability for lower compute and log costs; the operations themselves are unchanged.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (4 accounts)
+/// * `emit_events` - Whether the pool emits structured events
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_set_event_emission(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    emit_events: bool,
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🔧 POOL EVENT EMISSION UPDATE");
+    
+    let account_info_iter = &mut accounts.iter();
+    let admin_authority_signer = next_account_info(account_info_iter)?; // Index 0: Admin Authority Signer
+    let system_state_pda = next_account_info(account_info_iter)?;       // Index 1: System State PDA
+    let pool_state_pda = next_account_info(account_info_iter)?;         // Index 2: Pool State PDA
+    let program_data_account = next_account_info(account_info_iter)?;   // Index 3: Program Data Account
+    
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let mut pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    let old_emit_events = pool_state_data.emit_events;
+    pool_state_data.emit_events = emit_events;
+    
+    pool_state_data.record_operation();
+    serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    msg!("✅ Event emission updated: {} → {}", old_emit_events, emit_events);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    msg!("   • Updated by: {}", admin_authority_signer.key);
+    
+    Ok(())
+}
+
+/// Processes the SetSwapFeeBand instruction
+/// 
+/// Lets the pool owner bound the swap contract fee that `UpdatePoolFees` may set. The
+/// band only constrains future fee changes; the current fee is left as it is. A bound
+/// of 0 leaves that side open, so `(0, 0)` removes the band.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (3 accounts)
+/// * `min_allowed_fee` - Lowest allowed swap fee in lamports (0 = no lower bound)
+/// * `max_allowed_fee` - Highest allowed swap fee in lamports (0 = no upper bound)
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Account Info
+/// 0. **Pool Owner Signer** (signer) - Must be the pool's owner
+/// 1. **System State PDA** (readable) - For pause validation
+/// 2. **Pool State PDA** (writable) - Pool to update
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_set_swap_fee_band(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    min_allowed_fee: u64,
+    max_allowed_fee: u64,
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🔧 POOL SWAP FEE BAND UPDATE");
+    
+    let account_info_iter = &mut accounts.iter();
+    let owner_signer = next_account_info(account_info_iter)?;         // Index 0: Pool Owner Signer
+    let system_state_pda = next_account_info(account_info_iter)?;     // Index 1: System State PDA
+    let pool_state_pda = next_account_info(account_info_iter)?;       // Index 2: Pool State PDA
+    
+    validate_signer(owner_signer, "Pool owner")?;
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    let mut pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    if *owner_signer.key != pool_state_data.owner {
+        msg!("❌ Only the pool owner ({}) can set the swap fee band", pool_state_data.owner);
+        return Err(PoolError::Unauthorized.into());
+    }
+    
+    if max_allowed_fee != 0 && min_allowed_fee > max_allowed_fee {
+        msg!("❌ Invalid swap fee band: minimum {} exceeds maximum {}", min_allowed_fee, max_allowed_fee);
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    pool_state_data.min_allowed_fee = min_allowed_fee;
+    pool_state_data.max_allowed_fee = max_allowed_fee;
+    pool_state_data.record_operation();
+    serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    msg!("✅ Swap fee band set to [{}, {}] lamports", min_allowed_fee, max_allowed_fee);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    
+    Ok(())
+}
+
+/// Processes the InitiateOwnerTransfer instruction
+/// 
+/// First step of the two-step pool ownership handoff: the current owner nominates
+/// `new_owner`, who can take over with `AcceptOwnerTransfer` once
+/// `POOL_OWNER_TRANSFER_DELAY` has passed. Nominating again replaces any pending
+/// nominee and restarts the delay.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (3 accounts)
+/// * `new_owner` - Proposed new pool owner
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Account Info
+/// 0. **Pool Owner Signer** (signer) - Must be the pool's owner
+/// 1. **System State PDA** (readable) - For pause validation
+/// 2. **Pool State PDA** (writable) - Pool to update
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_initiate_owner_transfer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_owner: Pubkey,
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🔑 POOL OWNER TRANSFER: INITIATE");
+    
+    let account_info_iter = &mut accounts.iter();
+    let owner_signer = next_account_info(account_info_iter)?;         // Index 0: Pool Owner Signer
+    let system_state_pda = next_account_info(account_info_iter)?;     // Index 1: System State PDA
+    let pool_state_pda = next_account_info(account_info_iter)?;       // Index 2: Pool State PDA
+    
+    validate_signer(owner_signer, "Pool owner")?;
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    let mut pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    if *owner_signer.key != pool_state_data.owner {
+        msg!("❌ Only the pool owner ({}) can transfer ownership", pool_state_data.owner);
+        return Err(PoolError::Unauthorized.into());
+    }
+    if new_owner == Pubkey::default() || new_owner == pool_state_data.owner {
+        msg!("❌ Invalid new owner {}: must be a different, non-default key", new_owner);
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    let requested_at = Clock::get()?.unix_timestamp;
+    pool_state_data.pending_owner = new_owner;
+    pool_state_data.owner_transfer_requested_at = requested_at;
+    pool_state_data.record_operation();
+    serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    msg!("✅ Owner transfer initiated: {} → {}", pool_state_data.owner, new_owner);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    msg!("   • Acceptable after: {} (timestamp)", requested_at + POOL_OWNER_TRANSFER_DELAY);
+    
+    Ok(())
+}
+
+/// Processes the AcceptOwnerTransfer instruction
+/// 
+/// Second step of the pool ownership handoff: the pending owner signs to take over
+/// once `POOL_OWNER_TRANSFER_DELAY` has passed since the transfer was initiated.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (3 accounts)
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Account Info
+/// 0. **Pending Owner Signer** (signer) - Must be the pool's pending owner
+/// 1. **System State PDA** (readable) - For pause validation
+/// 2. **Pool State PDA** (writable) - Pool to update
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_accept_owner_transfer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🔑 POOL OWNER TRANSFER: ACCEPT");
+    
+    let account_info_iter = &mut accounts.iter();
+    let pending_owner_signer = next_account_info(account_info_iter)?; // Index 0: Pending Owner Signer
+    let system_state_pda = next_account_info(account_info_iter)?;     // Index 1: System State PDA
+    let pool_state_pda = next_account_info(account_info_iter)?;       // Index 2: Pool State PDA
+    
+    validate_signer(pending_owner_signer, "Pending pool owner")?;
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    let mut pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    if !pool_state_data.owner_transfer_pending() {
+        msg!("❌ No owner transfer is pending for this pool");
+        return Err(PoolError::NoPendingOwnerTransfer.into());
+    }
+    if *pending_owner_signer.key != pool_state_data.pending_owner {
+        msg!("❌ Only the pending owner ({}) can accept the transfer", pool_state_data.pending_owner);
+        return Err(PoolError::Unauthorized.into());
+    }
+    
+    let elapsed = Clock::get()?.unix_timestamp.saturating_sub(pool_state_data.owner_transfer_requested_at);
+    if elapsed < POOL_OWNER_TRANSFER_DELAY {
+        let remaining_seconds = POOL_OWNER_TRANSFER_DELAY - elapsed;
+        msg!("❌ Owner transfer delay not met: {} seconds remaining", remaining_seconds);
+        return Err(PoolError::OwnerTransferTimelockActive { remaining_seconds }.into());
+    }
+    
+    let previous_owner = pool_state_data.owner;
+    pool_state_data.owner = pool_state_data.pending_owner;
+    pool_state_data.clear_pending_owner_transfer();
+    pool_state_data.record_operation();
+    serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    msg!("✅ Owner transfer completed: {} → {}", previous_owner, pool_state_data.owner);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    
+    Ok(())
+}
+
+/// Processes the CancelOwnerTransfer instruction
+/// 
+/// Lets the current owner withdraw a pending ownership transfer before it is accepted.
+/// Cancellation is allowed while the system is paused so the owner can always stop a
+/// transfer that would otherwise become acceptable when the system resumes.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (2 accounts)
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Account Info
+/// 0. **Pool Owner Signer** (signer) - Must be the pool's owner
+/// 1. **Pool State PDA** (writable) - Pool to update
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_cancel_owner_transfer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🔑 POOL OWNER TRANSFER: CANCEL");
+    
+    let account_info_iter = &mut accounts.iter();
+    let owner_signer = next_account_info(account_info_iter)?;         // Index 0: Pool Owner Signer
+    let pool_state_pda = next_account_info(account_info_iter)?;       // Index 1: Pool State PDA
+    
+    validate_signer(owner_signer, "Pool owner")?;
+    
+    let mut pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    if *owner_signer.key != pool_state_data.owner {
+        msg!("❌ Only the pool owner ({}) can cancel an ownership transfer", pool_state_data.owner);
+        return Err(PoolError::Unauthorized.into());
+    }
+    if !pool_state_data.owner_transfer_pending() {
+        msg!("❌ No owner transfer is pending for this pool");
+        return Err(PoolError::NoPendingOwnerTransfer.into());
+    }
+    
+    let cancelled_owner = pool_state_data.pending_owner;
+    pool_state_data.clear_pending_owner_transfer();
+    pool_state_data.record_operation();
+    serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    msg!("✅ Owner transfer to {} cancelled", cancelled_owner);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    
+    Ok(())
+}
+
+/// Processes the RescueStrandedTokens instruction
+/// 
+/// Recovers tokens of an unrelated mint that were sent to a token account owned by the
+/// pool state PDA. The pool owner moves them out with the PDA signing via its seeds.
+/// Token A and Token B can never be rescued, so pool liquidity in the vaults is untouchable.
+/// 
+/// Rescue is an emergency operation and is allowed while the system or pool is paused.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (5 accounts)
+/// * `mint` - Mint of the stranded tokens (must not be Token A or Token B)
+/// * `amount` - Amount to transfer out
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Account Info
+/// 0. **Pool Owner Signer** (signer) - Must be the pool's owner
+/// 1. **Pool State PDA** (readable) - Authority over the stranded token account
+/// 2. **Stranded Token Account** (writable) - Token account of `mint` owned by the pool state PDA
+/// 3. **Destination Token Account** (writable) - Token account of `mint` receiving the tokens
+/// 4. **SPL Token Program** (readable) - For the transfer
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_rescue_stranded_tokens(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint: Pubkey,
+    amount: u64,
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🛟 RESCUE STRANDED TOKENS: {} of mint {}", amount, mint);
+    
+    let account_info_iter = &mut accounts.iter();
+    let owner_signer = next_account_info(account_info_iter)?;           // Index 0: Pool Owner Signer
+    let pool_state_pda = next_account_info(account_info_iter)?;         // Index 1: Pool State PDA
+    let stranded_token_account = next_account_info(account_info_iter)?; // Index 2: Stranded Token Account
+    let destination_account = next_account_info(account_info_iter)?;    // Index 3: Destination Token Account
+    let token_program_account = next_account_info(account_info_iter)?;  // Index 4: SPL Token Program
+    
+    validate_signer(owner_signer, "Pool owner")?;
+    crate::utils::validation::validate_non_zero_amount(amount, "Rescue")?;
+    
+    if *token_program_account.key != spl_token::id() {
+        msg!("❌ Invalid token program: {}", token_program_account.key);
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    
+    let pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    if *owner_signer.key != pool_state_data.owner {
+        msg!("❌ Only the pool owner ({}) can rescue stranded tokens", pool_state_data.owner);
+        return Err(PoolError::Unauthorized.into());
+    }
+    
+    // The pool's own tokens are never rescuable
+    if mint == pool_state_data.token_a_mint || mint == pool_state_data.token_b_mint {
+        msg!("❌ Mint {} is one of the pool's tokens and cannot be rescued", mint);
+        return Err(PoolError::CannotRescuePoolToken { mint }.into());
+    }
+    
+    use crate::utils::token_validation::safe_unpack_and_validate_token_account;
+    safe_unpack_and_validate_token_account(
+        stranded_token_account,
+        "Stranded Token Account",
+        Some(pool_state_pda.key),
+        Some(&mint),
+        false,
+    )?;
+    safe_unpack_and_validate_token_account(
+        destination_account,
+        "Rescue Destination Account",
+        None,
+        Some(&mint),
+        false,
+    )?;
+    
+    let pool_state_pda_seeds = &[
+        POOL_STATE_SEED_PREFIX,
+        pool_state_data.token_a_mint.as_ref(),
+        pool_state_data.token_b_mint.as_ref(),
+        &pool_state_data.original_ratio_a_numerator.to_le_bytes(),
+        &pool_state_data.original_ratio_b_denominator.to_le_bytes(),
+        &[pool_state_data.pool_authority_bump_seed],
+    ];
+    invoke_signed(
+        &token_instruction::transfer(
+            token_program_account.key,
+            stranded_token_account.key,
+            destination_account.key,
+            pool_state_pda.key,
+            &[],
+            amount,
+        )?,
+        &[
+            stranded_token_account.clone(),
+            destination_account.clone(),
+            pool_state_pda.clone(),
+            token_program_account.clone(),
+        ],
+        &[pool_state_pda_seeds],
+    )?;
+    
+    msg!("✅ Rescued {} of mint {} to {}", amount, mint, destination_account.key);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    
+    Ok(())
+}
+
+/// Processes the SweepRoundingSurplus instruction
+/// 
+/// Moves the rounding surplus accumulated for one of the pool's tokens (see
+/// `GetRoundingSurplus`) out of its vault to a token account chosen by the pool owner.
+/// The swept amount is capped at the accumulated surplus and at the token's tracked
+/// liquidity, is removed from that liquidity, and is subtracted from the surplus
+/// counter, so the vault keeps backing everything else the pool tracks.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (6 accounts)
+/// * `sweep_token_mint` - Token whose surplus is swept (Token A or Token B)
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Account Info
+/// 0. **Pool Owner Signer** (signer) - Must be the pool's owner
+/// 1. **System State PDA** (readable) - For system pause validation
+/// 2. **Pool State PDA** (writable) - Surplus counter and liquidity to update
+/// 3. **Token Vault PDA** (writable) - The pool's vault for `sweep_token_mint`
+/// 4. **Destination Token Account** (writable) - Token account of `sweep_token_mint` receiving the surplus
+/// 5. **SPL Token Program** (readable) - For the transfer
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error; sets return data to the swept amount as a
+///   little-endian u64 (0 when there was nothing to sweep)
+pub fn process_pool_sweep_rounding_surplus(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    sweep_token_mint: Pubkey,
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🧹 SWEEP ROUNDING SURPLUS: mint {}", sweep_token_mint);
+    
+    let account_info_iter = &mut accounts.iter();
+    let owner_signer = next_account_info(account_info_iter)?;           // Index 0: Pool Owner Signer
+    let system_state_pda = next_account_info(account_info_iter)?;       // Index 1: System State PDA
+    let pool_state_pda = next_account_info(account_info_iter)?;         // Index 2: Pool State PDA
+    let vault_account = next_account_info(account_info_iter)?;          // Index 3: Token Vault PDA
+    let destination_account = next_account_info(account_info_iter)?;    // Index 4: Destination Token Account
+    let token_program_account = next_account_info(account_info_iter)?;  // Index 5: SPL Token Program
+    
+    validate_signer(owner_signer, "Pool owner")?;
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    if *token_program_account.key != spl_token::id() {
+        msg!("❌ Invalid token program: {}", token_program_account.key);
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    
+    let mut pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    if *owner_signer.key != pool_state_data.owner {
+        msg!("❌ Only the pool owner ({}) can sweep the rounding surplus", pool_state_data.owner);
+        return Err(PoolError::Unauthorized.into());
+    }
+    
+    let is_token_a = sweep_token_mint == pool_state_data.token_a_mint;
+    if !is_token_a && sweep_token_mint != pool_state_data.token_b_mint {
+        msg!("❌ Invalid sweep token mint: {}. Expected {} or {}",
+             sweep_token_mint, pool_state_data.token_a_mint, pool_state_data.token_b_mint);
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let expected_vault = if is_token_a { pool_state_data.token_a_vault } else { pool_state_data.token_b_vault };
+    if *vault_account.key != expected_vault {
+        msg!("❌ Vault {} is not the pool's vault for {} (expected {})", vault_account.key, sweep_token_mint, expected_vault);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    
+    use crate::utils::token_validation::safe_unpack_and_validate_token_account;
+    safe_unpack_and_validate_token_account(
+        destination_account,
+        "Sweep Destination Account",
+        None,
+        Some(&sweep_token_mint),
+        false,
+    )?;
+    
+    let (surplus, liquidity) = if is_token_a {
+        (pool_state_data.rounding_surplus_token_a, pool_state_data.total_token_a_liquidity)
+    } else {
+        (pool_state_data.rounding_surplus_token_b, pool_state_data.total_token_b_liquidity)
+    };
+    let amount = surplus.min(liquidity);
+    
+    if amount > 0 {
+        if is_token_a {
+            pool_state_data.rounding_surplus_token_a -= amount;
+            pool_state_data.total_token_a_liquidity -= amount;
+        } else {
+            pool_state_data.rounding_surplus_token_b -= amount;
+            pool_state_data.total_token_b_liquidity -= amount;
+        }
+        pool_state_data.record_operation();
+        
+        let pool_state_pda_seeds = &[
+            POOL_STATE_SEED_PREFIX,
+            pool_state_data.token_a_mint.as_ref(),
+            pool_state_data.token_b_mint.as_ref(),
+            &pool_state_data.original_ratio_a_numerator.to_le_bytes(),
+            &pool_state_data.original_ratio_b_denominator.to_le_bytes(),
+            &[pool_state_data.pool_authority_bump_seed],
+        ];
+        invoke_signed(
+            &token_instruction::transfer(
+                token_program_account.key,
+                vault_account.key,
+                destination_account.key,
+                pool_state_pda.key,
+                &[],
+                amount,
+            )?,
+            &[
+                vault_account.clone(),
+                destination_account.clone(),
+                pool_state_pda.clone(),
+                token_program_account.clone(),
+            ],
+            &[pool_state_pda_seeds],
+        )?;
+        
+        serialize_to_account(&pool_state_data, pool_state_pda)?;
+        msg!("✅ Swept {} of mint {} to {}", amount, sweep_token_mint, destination_account.key);
+    } else {
+        msg!("ℹ️ No rounding surplus to sweep for mint {}", sweep_token_mint);
+    }
+    
+    solana_program::program::set_return_data(&amount.to_le_bytes());
+    
+    Ok(())
+}
+
+/// Processes the SetMinSwapAmount instruction
+/// 
+/// Sets the smallest swap input the pool accepts. Rejected values are a minimum above a
+/// nonzero `max_swap_amount` and one whose output at the pool ratio would overflow a `u64`,
+/// since no vault could ever pay out such a swap.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `accounts` - Array of account infos (4 accounts)
+/// * `min_swap_amount` - Smallest accepted swap input (0 = no minimum)
+/// * `pool_id` - Expected Pool ID for security validation
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_set_min_swap_amount(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    min_swap_amount: u64,
+    pool_id: Pubkey,
+) -> ProgramResult {
+    msg!("🔧 POOL MINIMUM SWAP AMOUNT UPDATE");
+    
+    let account_info_iter = &mut accounts.iter();
+    let admin_authority_signer = next_account_info(account_info_iter)?; // Index 0: Admin Authority Signer
+    let system_state_pda = next_account_info(account_info_iter)?;       // Index 1: System State PDA
+    let pool_state_pda = next_account_info(account_info_iter)?;         // Index 2: Pool State PDA
+    let program_data_account = next_account_info(account_info_iter)?;   // Index 3: Program Data Account
+    
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let mut pool_state_data = validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    
+    if pool_state_data.max_swap_amount > 0 && min_swap_amount > pool_state_data.max_swap_amount {
+        msg!("❌ Minimum swap amount {} exceeds the maximum swap amount {}", 
+             min_swap_amount, pool_state_data.max_swap_amount);
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    let largest_ratio = pool_state_data.ratio_a_numerator.max(pool_state_data.ratio_b_denominator);
+    if min_swap_amount.checked_mul(largest_ratio).is_none() {
+        msg!("❌ Minimum swap amount {} is too large: its swap output would overflow u64", min_swap_amount);
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    let old_min_swap_amount = pool_state_data.min_swap_amount;
+    pool_state_data.min_swap_amount = min_swap_amount;
+    
+    pool_state_data.record_operation();
+    serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    msg!("✅ Minimum swap amount updated: {} → {}", old_min_swap_amount, min_swap_amount);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    msg!("   • Updated by: {}", admin_authority_signer.key);
+    
+    Ok(())
+}
+
+/// Processes the MigratePoolState instruction
+/// 
+/// Rewrites a pool state account written with an older layout in the current layout.
+/// The old bytes are read with `PoolState::from_versioned_data`, which maps them onto
+/// the current struct (fields the old layout lacks start at their zero defaults), the
+/// account is grown to the current size with the signer covering the extra rent, and
+/// the state is written back with the buffer-serialization workaround.
+/// 
+/// Migration is a maintenance operation, so it is allowed while the system is paused.
+/// Migrating a pool that is already at `target_version` is a no-op.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID
+/// * `target_version` - Layout version to migrate to (only `POOL_STATE_SCHEMA_VERSION` is supported)
+/// * `accounts` - Array of account infos (5 accounts)
+/// 
+/// # Account Info
+/// 0. **Authority Signer** (signer, writable) - Pool owner or admin authority; pays any extra rent
+/// 1. **System Program Account** (readable) - For the rent top-up transfer
+/// 2. **System State PDA** (readable) - For admin authority validation
+/// 3. **Pool State PDA** (writable) - Pool state account to migrate
+/// 4. **Program Data Account** (readable) - For upgrade authority fallback validation
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_pool_migrate(
+    program_id: &Pubkey,
+    target_version: u16,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("🔧 POOL STATE MIGRATION to schema version {}", target_version);
+    
+    let account_info_iter = &mut accounts.iter();
+    let authority_signer = next_account_info(account_info_iter)?;       // Index 0: Authority Signer
+    let system_program_account = next_account_info(account_info_iter)?; // Index 1: System Program
+    let system_state_pda = next_account_info(account_info_iter)?;       // Index 2: System State PDA
+    let pool_state_pda = next_account_info(account_info_iter)?;         // Index 3: Pool State PDA
+    let program_data_account = next_account_info(account_info_iter)?;   // Index 4: Program Data Account
+    
+    validate_signer(authority_signer, "Migration authority")?;
+    
+    if pool_state_pda.owner != program_id {
+        msg!("❌ SECURITY VIOLATION: Pool state account not owned by program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    
+    let mut pool_state_data = PoolState::from_versioned_data(&pool_state_pda.data.borrow())?;
+    
+    // The account must be the pool PDA its own data derives, not another program account
+    let (expected_pool_state_pda, _) = Pubkey::find_program_address(
+        &[
+            POOL_STATE_SEED_PREFIX,
+            pool_state_data.token_a_mint.as_ref(),
+            pool_state_data.token_b_mint.as_ref(),
+            &pool_state_data.original_ratio_a_numerator.to_le_bytes(),
+            &pool_state_data.original_ratio_b_denominator.to_le_bytes(),
+        ],
+        program_id,
+    );
+    if *pool_state_pda.key != expected_pool_state_pda {
+        msg!("❌ SECURITY VIOLATION: Account is not the pool state PDA its data derives");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    
+    // Pool owner may migrate its own pool; anyone else must be the admin authority
+    if *authority_signer.key != pool_state_data.owner {
+        use crate::utils::admin_validation::validate_admin_authority;
+        validate_admin_authority(
+            authority_signer,
+            system_state_pda,
+            Some(program_data_account),
+            program_id,
+        )?;
+    }
+    
+    let current_version = pool_state_data.schema_version;
+    if target_version != POOL_STATE_SCHEMA_VERSION || current_version > target_version {
+        msg!("❌ UNSUPPORTED MIGRATION: Version {} → {} (supported target: {})",
+             current_version, target_version, POOL_STATE_SCHEMA_VERSION);
+        return Err(ProgramError::InvalidArgument);
+    }
+    if current_version == target_version {
+        msg!("✅ Pool state already at schema version {}", current_version);
+        return Ok(());
+    }
+    
+    // Grow the account to the current layout, topping up rent from the signer
+    let new_len = PoolState::get_packed_len();
+    if pool_state_pda.data_len() < new_len {
+        let rent_shortfall = Rent::get()?.minimum_balance(new_len).saturating_sub(pool_state_pda.lamports());
+        if rent_shortfall > 0 {
+            invoke(
+                &system_instruction::transfer(authority_signer.key, pool_state_pda.key, rent_shortfall),
+                &[authority_signer.clone(), pool_state_pda.clone(), system_program_account.clone()],
+            )?;
+        }
+        pool_state_pda.realloc(new_len, false)?;
+    }
+    
+    pool_state_data.schema_version = target_version;
+    pool_state_data.record_operation();
+    serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    msg!("✅ Pool state migrated: schema version {} → {}", current_version, target_version);
+    msg!("   • Pool: {}", pool_state_pda.key);
+    msg!("   • Migrated by: {}", authority_signer.key);
+    
+    Ok(())
+}
+
 /// Validates the fee update flags
 /// 
 /// # Arguments
@@ -42,6 +42,7 @@
 use borsh::BorshSerialize;
 use solana_program::{
     account_info::AccountInfo,
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
     program::invoke,
@@ -49,6 +50,7 @@ use solana_program::{
     program_error::ProgramError,
     program_pack::Pack,
     pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 use spl_token::{
     instruction as token_instruction,
@@ -57,6 +59,8 @@ use spl_token::{
 use crate::{
     constants::*,
     error::PoolError,
+    events::{ProgramEvent, SwapEvent},
+    state::PoolState,
 
     utils::token_validation::safe_unpack_and_validate_token_account,
 
@@ -64,6 +68,32 @@ use crate::{
 
 // Removed the wrapper function - we'll use safe_unpack_and_validate_token_account directly
 
+/// Rejects a `Swap` that lands after the caller's expiry.
+///
+/// # Arguments
+/// * `deadline` - Optional Unix timestamp; fails with `SwapDeadlineExpired` once passed
+/// * `not_valid_after_slot` - Optional last slot; fails with `SwapSlotExpired` after it,
+///   giving slot-based expiry independent of blockhash age
+pub fn validate_swap_expiry(deadline: Option<i64>, not_valid_after_slot: Option<u64>) -> ProgramResult {
+    if let Some(deadline) = deadline {
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if current_timestamp > deadline {
+            msg!("❌ SWAP EXPIRED: Deadline {} passed (current timestamp {})", deadline, current_timestamp);
+            return Err(PoolError::SwapDeadlineExpired { deadline, current_timestamp }.into());
+        }
+    }
+
+    if let Some(not_valid_after_slot) = not_valid_after_slot {
+        let current_slot = Clock::get()?.slot;
+        if current_slot > not_valid_after_slot {
+            msg!("❌ SWAP EXPIRED: Slot {} passed (current slot {})", not_valid_after_slot, current_slot);
+            return Err(PoolError::SwapSlotExpired { not_valid_after_slot, current_slot }.into());
+        }
+    }
+
+    Ok(())
+}
+
 /// **Fixed-Ratio Token Swap with Basis Points Architecture**
 ///
 /// Performs deterministic token swaps using pre-configured fixed exchange ratios stored
@@ -94,6 +124,7 @@ use crate::{
 /// * `amount_in` - The amount of input tokens to swap (exact input model)
 /// * `expected_amount_out` - Expected output amount for validation
 /// * `pool_id` - Expected Pool ID for security validation
+/// * `exact_out` - True when called for an exact-out swap; checked against the pool's `swap_mode`
 /// * `accounts` - Array of accounts in required order (11 accounts total)
 /// 
 /// # Account Layout
@@ -109,6 +140,7 @@ use crate::{
 /// 8. **User Output Token Account** (writable) - User's output token account
 /// 9. **Input Token Mint Account** (readable) - Input token mint for decimal validation
 /// 10. **Output Token Mint Account** (readable) - Output token mint for decimal validation
+/// 11. **Main Treasury PDA** (writable, optional) - Required only when a protocol swap SOL fee is set
 ///
 /// # Returns
 /// * `ProgramResult` - Success or error with detailed error information
@@ -133,6 +165,8 @@ use crate::{
 /// - **No Slippage**: You get exactly the calculated amount or transaction fails
 /// - **Deterministic Pricing**: Eliminates front-running and MEV extraction
 /// - **Ratio Preservation**: Pool maintains its configured ratio regardless of trade size
+/// - **Rounding Policy**: Output is always rounded down (pool-favorable); an input too small
+///   to produce at least one output unit fails with `AmountTooSmall` instead of swapping for nothing
 /// 
 /// # Fee Structure
 /// - **Fixed SOL Fee**: Configurable per pool (default: 271,500 lamports / 0.0002715 SOL)
@@ -150,13 +184,15 @@ pub fn process_swap_execute<'a>(
     amount_in: u64,              // Input amount in basis points
     expected_amount_out: u64,    // Expected output amount in basis points
     pool_id: Pubkey,             // Expected Pool ID for security validation
-    accounts: &'a [AccountInfo<'a>],
+    exact_out: bool,             // Exact-out swap (SwapExactOut) rather than exact-in
+    accounts: &[AccountInfo<'a>],
 ) -> ProgramResult {
     // 🔒 CRITICAL SECURITY FIX: Validate input amount is non-zero
     if amount_in == 0 {
         msg!("❌ INVALID SWAP: Input amount cannot be zero");
         return Err(ProgramError::InvalidArgument);
     }
+
     
     // Extract required accounts from the accounts array
     let user_authority_signer = &accounts[0];      // Index 0: Authority/User Signer
@@ -178,14 +214,18 @@ pub fn process_swap_execute<'a>(
     // Security: MEV protection, pause protection, fixed-ratio
     
     // Step 1: Validating system and pool state
-    // Validate system is not paused
-    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    // Validate system is not paused; the loaded state also supplies the protocol swap SOL
+    // fee below, so the SystemState PDA is derived once per swap
+    let system_state = crate::utils::validation::load_unpaused_system_state(system_state_pda, program_id)?;
     
     // Load and validate pool state data with Pool ID security validation
     let mut pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    
+    // The vaults must hold the pool's Token A and Token B mints, in that order
+    crate::utils::validation::validate_vault_mints(&pool_state_data, pool_token_a_vault_pda, pool_token_b_vault_pda)?;
 
-    // Check if pool swaps are paused
-    if pool_state_data.swaps_paused() {
+    // Check if pool swaps are paused (an elapsed auto-unpause timer lifts the pause)
+    if pool_state_data.swaps_paused() && !pool_state_data.auto_unpause_elapsed(Clock::get()?.unix_timestamp) {
         msg!("❌ SWAP BLOCKED: Pool swaps are currently paused");
         msg!("   • Pool owner has paused trading");
         msg!("   • Contact pool owner to resume trading");
@@ -203,6 +243,18 @@ pub fn process_swap_execute<'a>(
         }
     }
     
+    // Check the pool accepts this kind of swap
+    validate_swap_mode(&pool_state_data, exact_out)?;
+    
+    // Reject dust swaps below the pool's minimum (0 = no minimum)
+    if pool_state_data.min_swap_amount > 0 && amount_in < pool_state_data.min_swap_amount {
+        msg!("❌ Swap input {} is below the pool minimum of {}", amount_in, pool_state_data.min_swap_amount);
+        return Err(PoolError::SwapAmountBelowMinimum {
+            min_swap_amount: pool_state_data.min_swap_amount,
+            amount_in,
+        }.into());
+    }
+    
     // Step 1 complete
 
     // Step 3: Validating user accounts
@@ -249,6 +301,18 @@ pub fn process_swap_execute<'a>(
             return Err(ProgramError::InvalidArgument);
         };
 
+    // Check if this swap direction is paused on its own
+    let direction_pause = if input_is_token_a { PAUSE_SWAP_A_TO_B } else { PAUSE_SWAP_B_TO_A };
+    if pool_state_data.operation_paused(direction_pause, Clock::get()?.unix_timestamp) {
+        msg!("❌ SWAP BLOCKED: Swaps paying in {} are paused", input_token_mint_key);
+        msg!("   • The opposite direction may still be available");
+        return Err(PoolError::DirectionPaused { input_mint: input_token_mint_key }.into());
+    }
+
+    // Quote the swap before any balance checks so an oversized amount fails with
+    // CalculationOverflow up front (ratio math runs in u128, the result must fit in u64)
+    let amount_out = calculate_swap_output(&pool_state_data, input_is_token_a, amount_in)?;
+
     // 🔒 CRITICAL SECURITY FIX: Validate vault authorities
     // 🔒 SECURITY: Validate vault accounts - these should be owned by the pool PDA
     let input_vault_data = safe_unpack_and_validate_token_account(
@@ -270,6 +334,19 @@ pub fn process_swap_execute<'a>(
     validate_vault_owner(&input_vault_data, pool_state_pda.key, "Input Pool Vault")?;
     validate_vault_owner(&output_vault_data, pool_state_pda.key, "Output Pool Vault")?;
 
+    // Corruption tripwire: the output vault must back the tracked liquidity and fees,
+    // otherwise the accounting is wrong and paying out could hand over other LPs' tokens
+    let required_output_balance = pool_state_data.required_vault_balance(!input_is_token_a);
+    if output_vault_data.amount < required_output_balance {
+        msg!("❌ POOL STATE CORRUPTED: Output vault holds {}, accounting requires {}", output_vault_data.amount, required_output_balance);
+        msg!("   • Swap halted before any transfer; the pool needs investigation");
+        return Err(PoolError::PoolStateCorrupted {
+            vault: *output_pool_vault_acc.key,
+            vault_balance: output_vault_data.amount,
+            required: required_output_balance,
+        }.into());
+    }
+
     // Validate user account ownership and sufficient balance
     if user_input_token_data.mint != input_token_mint_key ||
        user_input_token_data.owner != *user_authority_signer.key ||
@@ -321,92 +398,24 @@ pub fn process_swap_execute<'a>(
         return Err(ProgramError::InvalidAccountData);
     }
     
-    // Unpack mint accounts to get decimals
-    let input_mint_data = spl_token::state::Mint::unpack_from_slice(&input_mint_account.data.borrow())
+    // Mint accounts must hold valid mint data
+    spl_token::state::Mint::unpack_from_slice(&input_mint_account.data.borrow())
         .map_err(|_| {
             msg!("❌ FAILED TO UNPACK INPUT TOKEN MINT");
             ProgramError::InvalidAccountData
         })?;
     
-    let output_mint_data = spl_token::state::Mint::unpack_from_slice(&output_mint_account.data.borrow())
+    spl_token::state::Mint::unpack_from_slice(&output_mint_account.data.borrow())
         .map_err(|_| {
             msg!("❌ FAILED TO UNPACK OUTPUT TOKEN MINT");
             ProgramError::InvalidAccountData
         })?;
-    
-    let input_decimals = input_mint_data.decimals as u32;
-    let output_decimals = output_mint_data.decimals as u32;
-    
-    
-    
-    // Get exchange ratios - always use the same values regardless of direction
-    let ratio_a_num = pool_state_data.ratio_a_numerator;
-    let ratio_b_den = pool_state_data.ratio_b_denominator;
-    
-    if ratio_a_num == 0 {
-        msg!("❌ INVALID POOL RATIO: Token A numerator is zero");
-        return Err(ProgramError::InvalidAccountData);
-    }
-    if ratio_b_den == 0 {
-        msg!("❌ INVALID POOL RATIO: Token B denominator is zero");
-        return Err(ProgramError::InvalidAccountData);
-    }
-
-    // Swap direction determined
-    
-    // Using pool ratio for calculation
-    
-    // Calculation parameters: amount={}, ratio={}:{}
-
-    // Debug logging removed to reduce CU usage
-
-    // Starting calculation
-
-    // 🔧 PRECISE DECIMAL CALCULATION using u128 for accuracy
-    // Based on user's mathematically sound approach that properly handles basis points
-    //
-    // Key insight: ratio values are already stored in basis points (smallest token units)
-    // We need to properly handle decimal scaling between different token decimal places
-    
-    
-    // Determine if exact exchange is required by pool flags
-    let require_exact = (pool_state_data.flags & crate::constants::POOL_FLAG_EXACT_EXCHANGE_REQUIRED) != 0;
-
-    let amount_out = if input_is_token_a {
-        
-        // Swapping Token A → Token B
-        // Formula: amount_b = (amount_a * ratio_b_denominator) / ratio_a_numerator
-        let result = swap_a_to_b(
-            amount_in,
-            ratio_a_num,    // ratio_a_numerator (basis points)
-            ratio_b_den,    // ratio_b_denominator (basis points) 
-            input_decimals as u8,  // token_a_decimals
-            output_decimals as u8, // token_b_decimals
-            require_exact,
-        )?;
-        
-        result
-    } else {
-        
-        // Swapping Token B → Token A  
-        // Formula: amount_a = (amount_b * ratio_a_numerator) / ratio_b_denominator
-        let result = swap_b_to_a(
-            amount_in,
-            ratio_a_num,    // ratio_a_numerator (basis points)
-            ratio_b_den,    // ratio_b_denominator (basis points)
-            input_decimals as u8,  // token_b_decimals
-            output_decimals as u8, // token_a_decimals
-            require_exact,
-        )?;
-        
-    result
-};
 
     
     // 🔒 CRITICAL SECURITY FIX: Validate output amount is non-zero
     if amount_out == 0 {
         msg!("❌ ZERO OUTPUT: Invalid swap configuration - refusing zero-output swap");
-        return Err(ProgramError::InvalidArgument);
+        return Err(PoolError::AmountTooSmall { amount_in }.into());
     }
 
     // Validate calculated amount matches expected amount (both in basis points)
@@ -439,6 +448,24 @@ pub fn process_swap_execute<'a>(
         return Err(ProgramError::InsufficientFunds);
     }
     
+    // Keep the output token's liquidity at or above its reserve safety floor
+    if pool_state_data.reserve_floor_breached(!input_is_token_a, amount_out) {
+        let floor = if input_is_token_a { pool_state_data.reserve_safety_floor_b } else { pool_state_data.reserve_safety_floor_a };
+        msg!("❌ RESERVE FLOOR BREACHED: Swap would leave {} basis points, floor is {}", available_liquidity - amount_out, floor);
+        return Err(PoolError::ReserveFloorBreached { floor, remaining: available_liquidity - amount_out }.into());
+    }
+    
+    // The input must still fit in the input side's liquidity once it lands in the vault
+    let input_liquidity = if input_is_token_a {
+        pool_state_data.total_token_a_liquidity
+    } else {
+        pool_state_data.total_token_b_liquidity
+    };
+    let new_input_liquidity = input_liquidity.checked_add(amount_in).ok_or_else(|| {
+        msg!("❌ LIQUIDITY OVERFLOW: {} + {} exceeds u64::MAX", input_liquidity, amount_in);
+        PoolError::CalculationOverflow { amount: amount_in, multiplier: 1, divisor: 1 }
+    })?;
+    
 
     // Step 6: Executing transfers
     
@@ -447,8 +474,8 @@ pub fn process_swap_execute<'a>(
         POOL_STATE_SEED_PREFIX,
         pool_state_data.token_a_mint.as_ref(),
         pool_state_data.token_b_mint.as_ref(),
-        &pool_state_data.ratio_a_numerator.to_le_bytes(),
-        &pool_state_data.ratio_b_denominator.to_le_bytes(),
+        &pool_state_data.original_ratio_a_numerator.to_le_bytes(),
+        &pool_state_data.original_ratio_b_denominator.to_le_bytes(),
         &[pool_state_data.pool_authority_bump_seed],
     ];
 
@@ -466,12 +493,30 @@ pub fn process_swap_execute<'a>(
         FeeType::RegularSwap,
     )?;
     
+    // Protocol swap SOL fee (admin-set in SystemState) is paid straight to the treasury
+    let swap_sol_fee = system_state.swap_sol_fee;
+    if swap_sol_fee > 0 {
+        if accounts.len() <= 11 {
+            msg!("❌ MISSING TREASURY: Swap SOL fee of {} lamports requires the Main Treasury PDA as account 11", swap_sol_fee);
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        crate::utils::fee_validation::collect_swap_sol_fee_to_treasury(
+            user_authority_signer,
+            &accounts[11],
+            system_program_account,
+            program_id,
+            swap_sol_fee,
+        )?;
+    }
+    
     // 🔧 CRITICAL FIX: Reload pool state after fee collection to get updated fee tracking fields
     // The fee collection function updates collected_swap_contract_fees and total_sol_fees_collected
     // but pool_state_data was loaded before fee collection, so we need fresh data
     let fresh_pool_state = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    let swap_fee_collected = fresh_pool_state.collected_swap_contract_fees.saturating_sub(pool_state_data.collected_swap_contract_fees);
     pool_state_data.collected_swap_contract_fees = fresh_pool_state.collected_swap_contract_fees;
     pool_state_data.total_sol_fees_collected = fresh_pool_state.total_sol_fees_collected;
+    pool_state_data.lifetime_fees = fresh_pool_state.lifetime_fees;
     
     // 🔒 REENTRANCY SAFETY: Swap operations are protected by Solana's built-in mechanisms:
     // 1. Account locking: All accounts (user tokens, pool vaults) are exclusively locked
@@ -524,21 +569,30 @@ pub fn process_swap_execute<'a>(
 
     // Update pool liquidity balances based on swap direction
     if input_is_token_a {
-        pool_state_data.total_token_a_liquidity = pool_state_data.total_token_a_liquidity
-            .checked_add(amount_in)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
+        pool_state_data.total_token_a_liquidity = new_input_liquidity;
         pool_state_data.total_token_b_liquidity = pool_state_data.total_token_b_liquidity
             .checked_sub(amount_out)
             .ok_or(ProgramError::ArithmeticOverflow)?;
     } else {
-        pool_state_data.total_token_b_liquidity = pool_state_data.total_token_b_liquidity
-            .checked_add(amount_in)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
+        pool_state_data.total_token_b_liquidity = new_input_liquidity;
         pool_state_data.total_token_a_liquidity = pool_state_data.total_token_a_liquidity
             .checked_sub(amount_out)
             .ok_or(ProgramError::ArithmeticOverflow)?;
     }
 
+    // Track whole input-token units the pool kept because the output was truncated
+    let rounding_surplus = calculate_rounding_surplus(&pool_state_data, input_is_token_a, amount_in, amount_out);
+    if input_is_token_a {
+        pool_state_data.rounding_surplus_token_a = pool_state_data.rounding_surplus_token_a.saturating_add(rounding_surplus);
+    } else {
+        pool_state_data.rounding_surplus_token_b = pool_state_data.rounding_surplus_token_b.saturating_add(rounding_surplus);
+    }
+    
+    // Record swap activity and volume for GetPoolActivity and the day's fee for GetFeeHistory
+    pool_state_data.last_swap_at = Clock::get()?.unix_timestamp;
+    pool_state_data.record_daily_swap_fee(input_is_token_a, swap_fee_collected, pool_state_data.last_swap_at);
+    pool_state_data.record_swap_volume(input_is_token_a, amount_in);
+    pool_state_data.record_operation();
     
     // Serialize updated pool state
     let mut serialized_data = Vec::new();
@@ -562,9 +616,777 @@ pub fn process_swap_execute<'a>(
     msg!("✅ SWAP COMPLETED SUCCESSFULLY!");
     msg!("📈 SUMMARY: {} → {} tokens, Fee: {} lamports", amount_in, amount_out, pool_state_data.swap_contract_fee);
     
+    if pool_state_data.emit_events {
+        ProgramEvent::Swap(SwapEvent {
+            pool: pool_id,
+            user: *user_authority_signer.key,
+            input_mint: input_token_mint_key,
+            amount_in,
+            amount_out,
+            fee: pool_state_data.swap_contract_fee,
+        }).emit();
+    }
+    
+    Ok(())
+}
+
+/// **Multi-Hop Swap Route**
+///
+/// Chains up to `MAX_SWAP_ROUTE_HOPS` swaps so a user can trade between two tokens that
+/// have no direct pool but are connected through pools sharing a common mint. Each leg
+/// is quoted with `calculate_swap_output` and executed through `process_swap_execute`,
+/// so every leg gets the same validation, fee collection and liquidity accounting as a
+/// standalone swap. The output of each leg is the input of the next, and each hop's
+/// input mint must be the previous hop's output mint.
+///
+/// Only the final leg is checked against `minimum_final_out`. Any failing leg (e.g. a
+/// middle pool without enough liquidity) aborts the instruction and the runtime rolls
+/// back every earlier leg.
+///
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation and signing authority
+/// * `hops` - Pool, input mint and vault PDAs of each leg, in execution order
+/// * `amount_in` - Amount of the first leg's input token
+/// * `minimum_final_out` - Minimum acceptable output of the last leg
+/// * `accounts` - 5 shared accounts followed by 7 accounts per hop
+///
+/// # Account Layout
+/// 0. **Authority/User Signer** (signer, writable) - User authorizing every leg
+/// 1. **System Program Account** (readable) - Solana system program account
+/// 2. **System State PDA** (readable) - System state PDA for pause validation
+/// 3. **SPL Token Program Account** (readable) - Token program account
+/// 4. **Main Treasury PDA** (writable) - Receives the protocol swap SOL fee when set
+///
+/// Then for each hop `i`, starting at index `5 + 7 * i`:
+/// 0. **Pool State PDA** (writable) - Must match `hops[i].pool_id`
+/// 1. **Token A Vault PDA** (writable) - Must match `hops[i].token_a_vault`
+/// 2. **Token B Vault PDA** (writable) - Must match `hops[i].token_b_vault`
+/// 3. **User Input Token Account** (writable) - Previous hop's output account for `i > 0`
+/// 4. **User Output Token Account** (writable) - User's account for the hop's output mint
+/// 5. **Input Token Mint Account** (readable) - `hops[i].input_token_mint`
+/// 6. **Output Token Mint Account** (readable) - The pool's other mint
+///
+/// # Returns
+/// * `ProgramResult` - Success, `RouteTooLong` for more than `MAX_SWAP_ROUTE_HOPS` hops,
+///   or `RouteOutputBelowMinimum` if the last leg falls short
+pub fn process_swap_route<'a>(
+    program_id: &Pubkey,
+    hops: &[crate::types::RouteHop],
+    amount_in: u64,
+    minimum_final_out: u64,
+    accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+    use crate::utils::input_validation::{SWAP_ROUTE_BASE_ACCOUNTS, SWAP_ROUTE_HOP_ACCOUNTS};
+
+    if hops.is_empty() {
+        msg!("❌ INVALID ROUTE: At least one hop is required");
+        return Err(ProgramError::InvalidArgument);
+    }
+    if hops.len() > MAX_SWAP_ROUTE_HOPS {
+        msg!("❌ ROUTE TOO LONG: {} hops (maximum {})", hops.len(), MAX_SWAP_ROUTE_HOPS);
+        return Err(PoolError::RouteTooLong { hops: hops.len(), max_hops: MAX_SWAP_ROUTE_HOPS }.into());
+    }
+
+    let shared_accounts = &accounts[..SWAP_ROUTE_BASE_ACCOUNTS];
+    let mut leg_amount_in = amount_in;
+    let mut previous_output_mint: Option<Pubkey> = None;
+
+    for (i, hop) in hops.iter().enumerate() {
+        let hop_start = SWAP_ROUTE_BASE_ACCOUNTS + i * SWAP_ROUTE_HOP_ACCOUNTS;
+        let hop_accounts = &accounts[hop_start..hop_start + SWAP_ROUTE_HOP_ACCOUNTS];
+
+        if *hop_accounts[1].key != hop.token_a_vault || *hop_accounts[2].key != hop.token_b_vault {
+            msg!("❌ INVALID ROUTE: Hop {} vault accounts do not match the hop's vault PDAs", i);
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Each leg must spend exactly what the previous leg paid out
+        if let Some(previous_output_mint) = previous_output_mint {
+            if hop.input_token_mint != previous_output_mint {
+                msg!("❌ INVALID ROUTE: Hop {} takes {} but hop {} outputs {}",
+                     i, hop.input_token_mint, i - 1, previous_output_mint);
+                return Err(ProgramError::InvalidArgument);
+            }
+            let previous_output_account = &accounts[hop_start - SWAP_ROUTE_HOP_ACCOUNTS + 4];
+            if hop_accounts[3].key != previous_output_account.key {
+                msg!("❌ INVALID ROUTE: Hop {} input account must be hop {} output account", i, i - 1);
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        // Quote the leg against the pool so it can be executed as an exact swap
+        let pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(&hop_accounts[0], &hop.pool_id, program_id)?;
+        let (input_is_token_a, output_mint) = if hop.input_token_mint == pool_state_data.token_a_mint {
+            (true, pool_state_data.token_b_mint)
+        } else if hop.input_token_mint == pool_state_data.token_b_mint {
+            (false, pool_state_data.token_a_mint)
+        } else {
+            msg!("❌ INVALID ROUTE: Hop {} input mint {} is not part of pool {}", i, hop.input_token_mint, hop.pool_id);
+            return Err(ProgramError::InvalidArgument);
+        };
+        let leg_amount_out = calculate_swap_output(&pool_state_data, input_is_token_a, leg_amount_in)?;
+
+        // Rebuild the standard swap account layout for this leg
+        let swap_accounts = [
+            shared_accounts[0].clone(),  // User Authority Signer
+            shared_accounts[1].clone(),  // System Program
+            shared_accounts[2].clone(),  // System State PDA
+            hop_accounts[0].clone(),     // Pool State PDA
+            shared_accounts[3].clone(),  // SPL Token Program
+            hop_accounts[1].clone(),     // Token A Vault PDA
+            hop_accounts[2].clone(),     // Token B Vault PDA
+            hop_accounts[3].clone(),     // User Input Token Account
+            hop_accounts[4].clone(),     // User Output Token Account
+            hop_accounts[5].clone(),     // Input Token Mint
+            hop_accounts[6].clone(),     // Output Token Mint
+            shared_accounts[4].clone(),  // Main Treasury PDA
+        ];
+
+        msg!("🔀 ROUTE HOP {}/{}: {} → {} via pool {}", i + 1, hops.len(), leg_amount_in, leg_amount_out, hop.pool_id);
+        process_swap_execute(program_id, leg_amount_in, leg_amount_out, hop.pool_id, false, &swap_accounts)?;
+        leg_amount_in = leg_amount_out;
+        previous_output_mint = Some(output_mint);
+    }
+
+    let final_amount_out = leg_amount_in;
+    if final_amount_out < minimum_final_out {
+        msg!("❌ ROUTE OUTPUT TOO LOW: Minimum {}, final output {}", minimum_final_out, final_amount_out);
+        return Err(PoolError::RouteOutputBelowMinimum { minimum_final_out, final_amount_out }.into());
+    }
+
+    msg!("✅ SWAP ROUTE COMPLETED: {} → {} across {} hops", amount_in, final_amount_out, hops.len());
+    Ok(())
+}
+
+/// **Partial-Fill Swap**
+///
+/// Swaps up to `amount_in` at the pool's fixed ratio. When the full output fits in
+/// the pool's output liquidity (above its reserve safety floor) this is a regular
+/// swap. Otherwise, with `allow_partial`, it swaps the largest input whose output
+/// fits, so a large swap fills what it can instead of failing. Only the filled input
+/// is transferred from the user; the rest stays in their account.
+///
+/// The filled input is rounded in the pool's favor: it is the largest input that
+/// pays out the filled output, never a smaller one. Pools with the exact-exchange
+/// flag round it down to the nearest input that converts without remainder. Execution goes through
+/// `process_swap_execute`, so fees, validation and liquidity accounting are the same
+/// as a standalone swap and the emitted `SwapEvent` carries the filled amounts.
+///
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation and signing authority
+/// * `input_token_mint` - Mint the user is swapping from
+/// * `amount_in` - Maximum amount of input tokens to swap
+/// * `minimum_amount_out` - Minimum acceptable filled output
+/// * `allow_partial` - Fill as much as possible when liquidity is short
+/// * `pool_id` - Expected Pool ID for security validation
+/// * `accounts` - Same account layout as `process_swap_execute`
+///
+/// # Returns
+/// * `ProgramResult` - Sets return data to three little-endian `u64` values:
+///   `[amount_in_filled, amount_out_filled, amount_in_unfilled]`. Fails with `InsufficientFunds` when the
+///   pool has no output liquidity to fill, or `SwapOutputBelowMinimum` when the filled
+///   output is below `minimum_amount_out`
+pub fn process_swap_partial<'a>(
+    program_id: &Pubkey,
+    input_token_mint: Pubkey,
+    amount_in: u64,
+    minimum_amount_out: u64,
+    allow_partial: bool,
+    pool_id: Pubkey,
+    accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    if amount_in == 0 {
+        msg!("❌ INVALID SWAP: Input amount cannot be zero");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(&accounts[3], &pool_id, program_id)?;
+    let input_is_token_a = if input_token_mint == pool_state_data.token_a_mint {
+        true
+    } else if input_token_mint == pool_state_data.token_b_mint {
+        false
+    } else {
+        msg!("❌ Input token mint {} is not part of this pool", input_token_mint);
+        return Err(ProgramError::InvalidArgument);
+    };
+
+    // Output the pool can pay without going below the output token's reserve floor
+    let available_out = if input_is_token_a {
+        pool_state_data.total_token_b_liquidity.saturating_sub(pool_state_data.reserve_safety_floor_b)
+    } else {
+        pool_state_data.total_token_a_liquidity.saturating_sub(pool_state_data.reserve_safety_floor_a)
+    };
+
+    let full_amount_out = calculate_swap_output(&pool_state_data, input_is_token_a, amount_in)?;
+    let (fill_amount_in, fill_amount_out) = if full_amount_out <= available_out || !allow_partial {
+        // Fits, or partial fills not allowed: the regular swap checks apply as-is
+        (amount_in, full_amount_out)
+    } else {
+        let fill_amount_in = max_input_for_output(&pool_state_data, input_is_token_a, available_out);
+        if fill_amount_in == 0 {
+            msg!("❌ INSUFFICIENT LIQUIDITY: Pool has {} basis points available to fill", available_out);
+            return Err(ProgramError::InsufficientFunds);
+        }
+        (fill_amount_in, calculate_swap_output(&pool_state_data, input_is_token_a, fill_amount_in)?)
+    };
+
+    if fill_amount_out < minimum_amount_out {
+        msg!("❌ SWAP OUTPUT TOO LOW: Minimum {}, output {}", minimum_amount_out, fill_amount_out);
+        return Err(PoolError::SwapOutputBelowMinimum { minimum_amount_out, amount_out: fill_amount_out }.into());
+    }
+
+    if fill_amount_in < amount_in {
+        msg!("✂️ PARTIAL FILL: {} of {} input filled for {} out ({} left with user)",
+             fill_amount_in, amount_in, fill_amount_out, amount_in - fill_amount_in);
+    }
+    process_swap_execute(program_id, fill_amount_in, fill_amount_out, pool_id, false, accounts)?;
+
+    let mut return_data = Vec::with_capacity(24);
+    return_data.extend_from_slice(&fill_amount_in.to_le_bytes());
+    return_data.extend_from_slice(&fill_amount_out.to_le_bytes());
+    return_data.extend_from_slice(&(amount_in - fill_amount_in).to_le_bytes());
+    set_return_data(&return_data);
+
+    Ok(())
+}
+
+/// **Exact-Output Swap**
+///
+/// Swaps the smallest input that pays out at least `amount_out`. Pricing is a fixed
+/// ratio, so the required input is `amount_out` converted back through the ratio,
+/// rounded up so the vault is never short-changed. When the ratio cannot hit
+/// `amount_out` exactly (one input unit buys several output units) the user receives
+/// the smallest output above it. Execution goes through `process_swap_execute`, so
+/// validation, liquidity accounting and events match a standalone swap.
+///
+/// The swap fees are flat SOL amounts paid by the user signer, not a cut of the
+/// input tokens, so they never change the required input.
+///
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation and signing authority
+/// * `output_token_mint` - Mint the user wants to receive
+/// * `amount_out` - Output amount to receive
+/// * `maximum_amount_in` - Most input the user is willing to spend
+/// * `pool_id` - Expected Pool ID for security validation
+/// * `accounts` - Same account layout as `process_swap_execute`
+///
+/// # Returns
+/// * `ProgramResult` - Sets return data to two little-endian `u64` values:
+///   `[amount_in, amount_out]` as executed. Fails with `SwapInputAboveMaximum` when
+///   the required input exceeds `maximum_amount_in`
+pub fn process_swap_exact_out<'a>(
+    program_id: &Pubkey,
+    output_token_mint: Pubkey,
+    amount_out: u64,
+    maximum_amount_in: u64,
+    pool_id: Pubkey,
+    accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    if amount_out == 0 {
+        msg!("❌ INVALID SWAP: Output amount cannot be zero");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(&accounts[3], &pool_id, program_id)?;
+    let input_is_token_a = if output_token_mint == pool_state_data.token_b_mint {
+        true
+    } else if output_token_mint == pool_state_data.token_a_mint {
+        false
+    } else {
+        msg!("❌ Output token mint {} is not part of this pool", output_token_mint);
+        return Err(ProgramError::InvalidArgument);
+    };
+
+    let required_amount_in = min_input_for_output(&pool_state_data, input_is_token_a, amount_out)?;
+    if required_amount_in > maximum_amount_in {
+        msg!("❌ SWAP INPUT TOO HIGH: Maximum {}, required {}", maximum_amount_in, required_amount_in);
+        return Err(PoolError::SwapInputAboveMaximum { maximum_amount_in, amount_in: required_amount_in }.into());
+    }
+    let executed_amount_out = calculate_swap_output(&pool_state_data, input_is_token_a, required_amount_in)?;
+
+    msg!("🎯 EXACT OUT: {} in for {} out (requested {})", required_amount_in, executed_amount_out, amount_out);
+    process_swap_execute(program_id, required_amount_in, executed_amount_out, pool_id, true, accounts)?;
+
+    let mut return_data = Vec::with_capacity(16);
+    return_data.extend_from_slice(&required_amount_in.to_le_bytes());
+    return_data.extend_from_slice(&executed_amount_out.to_le_bytes());
+    set_return_data(&return_data);
+
     Ok(())
 }
 
+/// Rejects a swap the pool's `swap_mode` does not accept.
+///
+/// # Arguments
+/// * `pool_state` - Pool being swapped against
+/// * `exact_out` - True for an exact-out swap, false for an exact-in swap
+///
+/// # Returns
+/// * `ProgramResult` - Success, or `SwapModeNotAllowed`
+fn validate_swap_mode(pool_state: &PoolState, exact_out: bool) -> ProgramResult {
+    swap_mode_check(pool_state, exact_out).map_err(|error| {
+        msg!("❌ SWAP MODE NOT ALLOWED: Pool swap mode is {:?}", pool_state.swap_mode);
+        error.into()
+    })
+}
+
+/// Shared by `validate_swap_mode` and `CanSwap` so both report the same error
+fn swap_mode_check(pool_state: &PoolState, exact_out: bool) -> Result<(), PoolError> {
+    let allowed = if exact_out {
+        pool_state.swap_mode.allows_exact_out()
+    } else {
+        pool_state.swap_mode.allows_exact_in()
+    };
+    if allowed {
+        return Ok(());
+    }
+    Err(PoolError::SwapModeNotAllowed {
+        allowed: if exact_out { "exact-in" } else { "exact-out" }.to_string(),
+    })
+}
+
+/// Calculates the smallest input whose swap output is at least `amount_out`.
+///
+/// With output `floor(in * out_ratio / in_ratio)`, this is
+/// `ceil(amount_out * in_ratio / out_ratio)`. Pools requiring exact exchange round
+/// it up to the nearest input that converts without remainder.
+///
+/// # Arguments
+/// * `pool_state` - Pool to swap against
+/// * `input_is_token_a` - True for A → B, false for B → A
+/// * `amount_out` - Output the swap must pay at least
+///
+/// # Returns
+/// * `Result<u64, ProgramError>` - Required input in basis points
+pub(crate) fn min_input_for_output(
+    pool_state: &PoolState,
+    input_is_token_a: bool,
+    amount_out: u64,
+) -> Result<u64, ProgramError> {
+    let (in_ratio, out_ratio) = if input_is_token_a {
+        (pool_state.ratio_a_numerator as u128, pool_state.ratio_b_denominator as u128)
+    } else {
+        (pool_state.ratio_b_denominator as u128, pool_state.ratio_a_numerator as u128)
+    };
+    if in_ratio == 0 || out_ratio == 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut min_in = (amount_out as u128 * in_ratio).div_ceil(out_ratio);
+    if (pool_state.flags & POOL_FLAG_EXACT_EXCHANGE_REQUIRED) != 0 {
+        let (mut a, mut b) = (in_ratio, out_ratio);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        let step = in_ratio / a;
+        min_in = min_in.div_ceil(step) * step;
+    }
+    u64::try_from(min_in).map_err(|_| {
+        PoolError::CalculationOverflow { amount: amount_out, multiplier: in_ratio as u64, divisor: out_ratio as u64 }.into()
+    })
+}
+
+/// Calculates the largest input whose swap output does not exceed `max_amount_out`.
+///
+/// With output `floor(in * out_ratio / in_ratio)`, this is
+/// `((max_amount_out + 1) * in_ratio - 1) / out_ratio`. Pools requiring exact
+/// exchange round it down to the nearest input that converts without remainder.
+///
+/// # Arguments
+/// * `pool_state` - Pool to fill against
+/// * `input_is_token_a` - True for A → B, false for B → A
+/// * `max_amount_out` - Most output the pool can pay
+///
+/// # Returns
+/// * `u64` - Largest fillable input in basis points (0 when nothing can be filled)
+pub(crate) fn max_input_for_output(
+    pool_state: &PoolState,
+    input_is_token_a: bool,
+    max_amount_out: u64,
+) -> u64 {
+    let (in_ratio, out_ratio) = if input_is_token_a {
+        (pool_state.ratio_a_numerator as u128, pool_state.ratio_b_denominator as u128)
+    } else {
+        (pool_state.ratio_b_denominator as u128, pool_state.ratio_a_numerator as u128)
+    };
+    if in_ratio == 0 || out_ratio == 0 || max_amount_out == 0 {
+        return 0;
+    }
+
+    let mut max_in = ((max_amount_out as u128 + 1) * in_ratio - 1) / out_ratio;
+    if (pool_state.flags & POOL_FLAG_EXACT_EXCHANGE_REQUIRED) != 0 {
+        let (mut a, mut b) = (in_ratio, out_ratio);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        let step = in_ratio / a;
+        max_in -= max_in % step;
+    }
+    max_in.min(u64::MAX as u128) as u64
+}
+
+
+/// Calculates the output of a swap against a pool without moving any tokens.
+///
+/// Shared by swap execution helpers and read-only swap views so that quotes always
+/// match what `process_swap_execute` would compute. Honors the pool's
+/// exact-exchange flag.
+///
+/// # Arguments
+/// * `pool_state` - Pool to quote against
+/// * `input_is_token_a` - True for A → B, false for B → A
+/// * `amount_in` - Input amount in basis points
+///
+/// # Returns
+/// * `Result<u64, ProgramError>` - Output amount in basis points
+pub(crate) fn calculate_swap_output(
+    pool_state: &PoolState,
+    input_is_token_a: bool,
+    amount_in: u64,
+) -> Result<u64, ProgramError> {
+    let require_exact = (pool_state.flags & POOL_FLAG_EXACT_EXCHANGE_REQUIRED) != 0;
+    if input_is_token_a {
+        swap_a_to_b(amount_in, pool_state.ratio_a_numerator, pool_state.ratio_b_denominator, 0, 0, require_exact)
+    } else {
+        swap_b_to_a(amount_in, pool_state.ratio_a_numerator, pool_state.ratio_b_denominator, 0, 0, require_exact)
+    }
+}
+
+/// Calculates the whole input-token units a swap leaves in the pool due to truncation.
+///
+/// The output is floored, so the input is usually worth slightly more than what the
+/// user receives. This returns `amount_in` minus the value of `amount_out` expressed
+/// in input-token units (rounded up), i.e. the surplus that stays in the input vault.
+///
+/// # Arguments
+/// * `pool_state` - Pool the swap executed against
+/// * `input_is_token_a` - True for A → B, false for B → A
+/// * `amount_in` - Input amount in basis points
+/// * `amount_out` - Output amount in basis points
+///
+/// # Returns
+/// * `u64` - Surplus in input-token basis points (0 when the swap was exact)
+pub(crate) fn calculate_rounding_surplus(
+    pool_state: &PoolState,
+    input_is_token_a: bool,
+    amount_in: u64,
+    amount_out: u64,
+) -> u64 {
+    let (input_per_output_num, input_per_output_den) = if input_is_token_a {
+        (pool_state.ratio_a_numerator as u128, pool_state.ratio_b_denominator as u128)
+    } else {
+        (pool_state.ratio_b_denominator as u128, pool_state.ratio_a_numerator as u128)
+    };
+    if input_per_output_den == 0 {
+        return 0;
+    }
+
+    let output_value_in_input = (amount_out as u128 * input_per_output_num)
+        .div_ceil(input_per_output_den);
+    (amount_in as u128).saturating_sub(output_value_in_input) as u64
+}
+
+/// **VIEW INSTRUCTION**: Reports the cost of swapping into the other token and back.
+///
+/// Computes the first leg (start token → other token) and then swaps the full output
+/// back into the start token. The difference between `amount_in` and the final amount
+/// is the token-side rounding loss; the SOL side is the swap contract fee paid on
+/// each leg.
+///
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `amount_in` - Amount of the start token to round-trip
+/// * `start_token_mint` - Mint of the token the user starts (and ends) with
+/// * `pool_id` - Expected Pool ID for security validation
+/// * `accounts` - Array of accounts (1 account)
+///
+/// # Account Layout
+/// 0. **Pool State PDA** (readable) - Pool to quote against
+///
+/// # Returns
+/// * `ProgramResult` - Sets return data to four little-endian `u64` values:
+///   `[first_leg_out, final_amount, token_loss, sol_fees]`
+pub fn process_swap_get_round_trip_cost(
+    program_id: &Pubkey,
+    amount_in: u64,
+    start_token_mint: Pubkey,
+    pool_id: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    let pool_state_pda = &accounts[0];
+    let pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+
+    let start_is_token_a = if start_token_mint == pool_state_data.token_a_mint {
+        true
+    } else if start_token_mint == pool_state_data.token_b_mint {
+        false
+    } else {
+        msg!("❌ Start token mint {} is not part of this pool", start_token_mint);
+        return Err(ProgramError::InvalidArgument);
+    };
+
+    let first_leg_out = calculate_swap_output(&pool_state_data, start_is_token_a, amount_in)?;
+    let final_amount = calculate_swap_output(&pool_state_data, !start_is_token_a, first_leg_out)?;
+    let token_loss = amount_in.saturating_sub(final_amount);
+    let sol_fees = pool_state_data.swap_contract_fee
+        .checked_mul(2)
+        .ok_or(PoolError::ArithmeticOverflow)?;
+
+    msg!("=== ROUND TRIP COST ===");
+    msg!("Start Amount: {} basis points", amount_in);
+    msg!("First Leg Output: {} basis points", first_leg_out);
+    msg!("Final Amount: {} basis points", final_amount);
+    msg!("Token Loss (rounding): {} basis points", token_loss);
+    msg!("SOL Fees (both legs): {} lamports", sol_fees);
+    msg!("=======================");
+
+    let mut return_data = Vec::with_capacity(32);
+    for value in [first_leg_out, final_amount, token_loss, sol_fees] {
+        return_data.extend_from_slice(&value.to_le_bytes());
+    }
+    set_return_data(&return_data);
+
+    Ok(())
+}
+
+/// **VIEW INSTRUCTION**: Quotes a swap without moving any tokens or SOL.
+///
+/// Uses `calculate_swap_output`, the same fixed-ratio math and rounding as
+/// `process_swap_execute`, so the quoted output matches an executed swap exactly.
+/// Swap fees are charged in SOL rather than deducted from the output token, so the
+/// pool's swap contract fee is returned alongside the output instead of being
+/// subtracted from it. The quote also reports whether the pool currently holds
+/// enough output liquidity, above its reserve safety floor, to honor it.
+///
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `input_token_mint` - Mint the user would swap from
+/// * `amount_in` - Amount the user would swap
+/// * `pool_id` - Expected Pool ID for security validation
+/// * `accounts` - Array of accounts (1 account)
+///
+/// # Account Layout
+/// 0. **Pool State PDA** (readable) - Pool to quote against
+///
+/// # Returns
+/// * `ProgramResult` - Sets return data to three little-endian `u64` values:
+///   `[amount_out, swap_fee_lamports, has_liquidity]` where `has_liquidity` is 1 or 0
+pub fn process_swap_quote(
+    program_id: &Pubkey,
+    input_token_mint: Pubkey,
+    amount_in: u64,
+    pool_id: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    let pool_state_pda = &accounts[0];
+    let pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+
+    let input_is_token_a = if input_token_mint == pool_state_data.token_a_mint {
+        true
+    } else if input_token_mint == pool_state_data.token_b_mint {
+        false
+    } else {
+        msg!("❌ Input token mint {} is not part of this pool", input_token_mint);
+        return Err(ProgramError::InvalidArgument);
+    };
+
+    let amount_out = calculate_swap_output(&pool_state_data, input_is_token_a, amount_in)?;
+    let swap_fee = pool_state_data.swap_contract_fee;
+
+    // Same liquidity and reserve floor checks the swap itself applies
+    let available_liquidity = if input_is_token_a {
+        pool_state_data.total_token_b_liquidity
+    } else {
+        pool_state_data.total_token_a_liquidity
+    };
+    let has_liquidity = available_liquidity >= amount_out
+        && !pool_state_data.reserve_floor_breached(!input_is_token_a, amount_out);
+
+    msg!("=== SWAP QUOTE ===");
+    msg!("Amount In: {} basis points", amount_in);
+    msg!("Amount Out: {} basis points", amount_out);
+    msg!("Swap Fee: {} lamports", swap_fee);
+    msg!("Liquidity Available: {} basis points (sufficient: {})", available_liquidity, has_liquidity);
+    msg!("==================");
+
+    let mut return_data = Vec::with_capacity(24);
+    return_data.extend_from_slice(&amount_out.to_le_bytes());
+    return_data.extend_from_slice(&swap_fee.to_le_bytes());
+    return_data.extend_from_slice(&(has_liquidity as u64).to_le_bytes());
+    set_return_data(&return_data);
+
+    Ok(())
+}
+
+/// **VIEW INSTRUCTION**: Splits a swap's SOL fee into its protocol and pool portions.
+///
+/// A swap pays two flat lamport fees: the pool's swap contract fee, collected into
+/// the pool state, and the protocol swap SOL fee from system state, paid directly to
+/// the main treasury. Neither depends on the swap size.
+///
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `pool_id` - Expected Pool ID for security validation
+/// * `accounts` - Array of accounts (2 accounts)
+///
+/// # Account Layout
+/// 0. **Pool State PDA** (readable) - Pool swap contract fee
+/// 1. **System State PDA** (readable) - Protocol swap SOL fee
+///
+/// # Returns
+/// * `ProgramResult` - Sets return data to three little-endian `u64` values:
+///   `[total_fee, protocol_portion, pool_portion]`
+pub fn process_swap_fee_breakdown(
+    program_id: &Pubkey,
+    pool_id: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    let pool_state_pda = &accounts[0];
+    let system_state_pda = &accounts[1];
+    let pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    let system_state = crate::state::SystemState::load_from_account(system_state_pda, program_id)?;
+
+    let pool_portion = pool_state_data.swap_contract_fee;
+    let protocol_portion = system_state.swap_sol_fee;
+    let total_fee = pool_portion
+        .checked_add(protocol_portion)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    msg!("=== SWAP FEE BREAKDOWN ===");
+    msg!("Total Fee: {} lamports", total_fee);
+    msg!("Protocol Portion: {} lamports", protocol_portion);
+    msg!("Pool Portion: {} lamports", pool_portion);
+    msg!("==========================");
+
+    let mut return_data = Vec::with_capacity(24);
+    return_data.extend_from_slice(&total_fee.to_le_bytes());
+    return_data.extend_from_slice(&protocol_portion.to_le_bytes());
+    return_data.extend_from_slice(&pool_portion.to_le_bytes());
+    set_return_data(&return_data);
+
+    Ok(())
+}
+
+/// **VIEW INSTRUCTION**: Reports whether a swap of the given size would currently succeed.
+///
+/// Runs the same go/no-go checks as `process_swap_execute` without moving any tokens
+/// or SOL, in the order the swap would hit them: system pause, pool swap pause
+/// (honoring an elapsed auto-unpause timer), owner-only access, input mint, amount,
+/// output liquidity, the output token's reserve safety floor, and the user's SOL
+/// balance for the pool and protocol swap fees.
+///
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `input_token_mint` - Mint the user would swap from
+/// * `amount_in` - Amount the user would swap
+/// * `pool_id` - Expected Pool ID for security validation
+/// * `accounts` - Array of accounts (3 accounts)
+///
+/// # Account Layout
+/// 0. **Pool State PDA** (readable) - Pool to check against
+/// 1. **System State PDA** (readable) - Global pause state and protocol swap SOL fee
+/// 2. **User Authority** (readable) - Would-be swapper
+///
+/// # Returns
+/// * `ProgramResult` - Sets return data to two little-endian `u64` values:
+///   `[can_swap, reason_code]` where `can_swap` is 1 or 0 and `reason_code` is the
+///   `PoolError` code of the first blocking check (0 when the swap can proceed)
+pub fn process_swap_can_swap(
+    program_id: &Pubkey,
+    input_token_mint: Pubkey,
+    amount_in: u64,
+    pool_id: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    let pool_state_pda = &accounts[0];
+    let system_state_pda = &accounts[1];
+    let user_authority = &accounts[2];
+
+    let pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
+    let system_state = crate::state::SystemState::load_from_account(system_state_pda, program_id)?;
+
+    let blocking_error = if system_state.is_paused {
+        Some(PoolError::SystemPaused.error_code())
+    } else if pool_state_data.swaps_paused() && !pool_state_data.auto_unpause_elapsed(Clock::get()?.unix_timestamp) {
+        Some(PoolError::PoolSwapsPaused.error_code())
+    } else if pool_state_data.swap_for_owners_only() && *user_authority.key != pool_state_data.owner {
+        Some(PoolError::SwapAccessRestricted.error_code())
+    } else if let Err(error) = swap_mode_check(&pool_state_data, false) {
+        Some(error.error_code())
+    } else if input_token_mint != pool_state_data.token_a_mint && input_token_mint != pool_state_data.token_b_mint {
+        Some(PoolError::InvalidTokenPair {
+            token_a: pool_state_data.token_a_mint,
+            token_b: pool_state_data.token_b_mint,
+            reason: format!("Input mint {} is not part of this pool", input_token_mint),
+        }.error_code())
+    } else if pool_state_data.operation_paused(
+        if input_token_mint == pool_state_data.token_a_mint { PAUSE_SWAP_A_TO_B } else { PAUSE_SWAP_B_TO_A },
+        Clock::get()?.unix_timestamp,
+    ) {
+        Some(PoolError::DirectionPaused { input_mint: input_token_mint }.error_code())
+    } else if amount_in == 0 {
+        Some(PoolError::InvalidSwapAmount { amount: 0, min_amount: 1, max_amount: u64::MAX }.error_code())
+    } else {
+        let input_is_token_a = input_token_mint == pool_state_data.token_a_mint;
+        let (available_liquidity, reserve_floor) = if input_is_token_a {
+            (pool_state_data.total_token_b_liquidity, pool_state_data.reserve_safety_floor_b)
+        } else {
+            (pool_state_data.total_token_a_liquidity, pool_state_data.reserve_safety_floor_a)
+        };
+        let required_lamports = pool_state_data.swap_contract_fee.saturating_add(system_state.swap_sol_fee);
+
+        match calculate_swap_output(&pool_state_data, input_is_token_a, amount_in) {
+            Ok(0) => Some(PoolError::InvalidSwapAmount { amount: amount_in, min_amount: 1, max_amount: u64::MAX }.error_code()),
+            Ok(amount_out) if amount_out > available_liquidity => Some(PoolError::InsufficientFunds {
+                required: amount_out,
+                available: available_liquidity,
+                account: pool_id,
+            }.error_code()),
+            Ok(amount_out) if pool_state_data.reserve_floor_breached(!input_is_token_a, amount_out) => Some(PoolError::ReserveFloorBreached {
+                floor: reserve_floor,
+                remaining: available_liquidity - amount_out,
+            }.error_code()),
+            Ok(_) if user_authority.lamports() < required_lamports => Some(PoolError::InsufficientFeeBalance {
+                required: required_lamports,
+                available: user_authority.lamports(),
+                account: *user_authority.key,
+            }.error_code()),
+            Ok(_) => None,
+            Err(ProgramError::Custom(code)) => Some(code),
+            Err(_) => Some(PoolError::InvalidSwapAmount { amount: amount_in, min_amount: 1, max_amount: u64::MAX }.error_code()),
+        }
+    };
+
+    let (can_swap, reason_code) = match blocking_error {
+        Some(code) => (0u64, code as u64),
+        None => (1u64, 0u64),
+    };
+
+    msg!("=== CAN SWAP ===");
+    msg!("Amount In: {} basis points", amount_in);
+    msg!("Can Swap: {}", can_swap == 1);
+    msg!("Reason Code: {}", reason_code);
+    msg!("================");
+
+    let mut return_data = Vec::with_capacity(16);
+    return_data.extend_from_slice(&can_swap.to_le_bytes());
+    return_data.extend_from_slice(&reason_code.to_le_bytes());
+    set_return_data(&return_data);
+
+    Ok(())
+}
 
 /// Calculate precise swap output for Token A → Token B with EXACT EXCHANGE validation
 ///
@@ -573,6 +1395,8 @@ pub fn process_swap_execute<'a>(
 ///
 /// **Formula**: B_out = (A_in * ratioB_den) / ratioA_num (must divide exactly)
 /// **Validation**: Verifies exchange is perfectly reversible with no value loss
+/// **Rounding**: Without `POOL_FLAG_EXACT_EXCHANGE_REQUIRED` the output is floored (pool-favorable);
+/// a result of zero fails with `AmountTooSmall`
 fn swap_a_to_b(
     amount_a: u64,
     ratio_a_numerator: u64,     // Token A ratio in basis points
@@ -613,7 +1437,7 @@ fn swap_a_to_b(
         msg!("❌ SWAP CALCULATION OVERFLOW: Multiplication would exceed u128::MAX");
         msg!("   amount_a: {}, ratio_b_den: {}", amount_a, ratio_b_denominator);
         msg!("   This indicates an extremely large swap amount or ratio");
-        return Err(crate::error::PoolError::ArithmeticOverflow.into());
+        return Err(crate::error::PoolError::CalculationOverflow { amount: amount_a, multiplier: ratio_b_denominator, divisor: ratio_a_numerator }.into());
     }
     
     // Perform multiplication with overflow detection
@@ -623,7 +1447,7 @@ fn swap_a_to_b(
             msg!("❌ MULTIPLICATION OVERFLOW in swap_a_to_b calculation");
             msg!("   amount_a: {}, ratio_b_den: {}", amount_a, ratio_b_denominator);
             msg!("   Intermediate calculation: {} * {}", amount_a_base, ratio_b_den);
-            crate::error::PoolError::ArithmeticOverflow
+            crate::error::PoolError::CalculationOverflow { amount: amount_a, multiplier: ratio_b_denominator, divisor: ratio_a_numerator }
         })?;
     
     // 🔒 EXACT EXCHANGE (optional): enforce no remainder; otherwise allow floor division
@@ -656,7 +1480,7 @@ fn swap_a_to_b(
         msg!("   amount_a: {}, ratio_a_num: {}, ratio_b_den: {}", 
              amount_a, ratio_a_numerator, ratio_b_denominator);
         msg!("   This indicates the swap would produce an impossibly large output");
-        return Err(crate::error::PoolError::ArithmeticOverflow.into());
+        return Err(crate::error::PoolError::CalculationOverflow { amount: amount_a, multiplier: ratio_b_denominator, divisor: ratio_a_numerator }.into());
     }
     
     let final_result = result as u64;
@@ -683,7 +1507,7 @@ fn swap_a_to_b(
         msg!("   Input amount: {} basis points", amount_a);
         msg!("   This indicates the input amount is too small for this ratio");
         msg!("   🚫 EXACT EXCHANGE REQUIRED: Must receive meaningful output");
-        return Err(ProgramError::InvalidArgument);
+        return Err(crate::error::PoolError::AmountTooSmall { amount_in: amount_a }.into());
     }
     
     // Logging omitted for CU efficiency; expected_amount_out check enforces correctness
@@ -698,6 +1522,8 @@ fn swap_a_to_b(
 ///
 /// **Formula**: A_out = (B_in * ratioA_num) / ratioB_den (must divide exactly)
 /// **Validation**: Verifies exchange is perfectly reversible with no value loss
+/// **Rounding**: Without `POOL_FLAG_EXACT_EXCHANGE_REQUIRED` the output is floored (pool-favorable);
+/// a result of zero fails with `AmountTooSmall`
 fn swap_b_to_a(
     amount_b: u64,
     ratio_a_numerator: u64,     // Token A ratio in basis points
@@ -738,7 +1564,7 @@ fn swap_b_to_a(
         msg!("❌ SWAP CALCULATION OVERFLOW: Multiplication would exceed u128::MAX");
         msg!("   amount_b: {}, ratio_a_num: {}", amount_b, ratio_a_numerator);
         msg!("   This indicates an extremely large swap amount or ratio");
-        return Err(crate::error::PoolError::ArithmeticOverflow.into());
+        return Err(crate::error::PoolError::CalculationOverflow { amount: amount_b, multiplier: ratio_a_numerator, divisor: ratio_b_denominator }.into());
     }
     
     // Perform multiplication with overflow detection
@@ -748,7 +1574,7 @@ fn swap_b_to_a(
             msg!("❌ MULTIPLICATION OVERFLOW in swap_b_to_a calculation");
             msg!("   amount_b: {}, ratio_a_num: {}", amount_b, ratio_a_numerator);
             msg!("   Intermediate calculation: {} * {}", amount_b_base, ratio_a_num);
-            crate::error::PoolError::ArithmeticOverflow
+            crate::error::PoolError::CalculationOverflow { amount: amount_b, multiplier: ratio_a_numerator, divisor: ratio_b_denominator }
         })?;
     
     // 🔒 EXACT EXCHANGE (optional): enforce no remainder; otherwise allow floor division
@@ -781,7 +1607,7 @@ fn swap_b_to_a(
         msg!("   amount_b: {}, ratio_a_num: {}, ratio_b_den: {}", 
              amount_b, ratio_a_numerator, ratio_b_denominator);
         msg!("   This indicates the swap would produce an impossibly large output");
-        return Err(crate::error::PoolError::ArithmeticOverflow.into());
+        return Err(crate::error::PoolError::CalculationOverflow { amount: amount_b, multiplier: ratio_a_numerator, divisor: ratio_b_denominator }.into());
     }
     
     let final_result = result as u64;
@@ -808,7 +1634,7 @@ fn swap_b_to_a(
         msg!("   Input amount: {} basis points", amount_b);
         msg!("   This indicates the input amount is too small for this ratio");
         msg!("   🚫 EXACT EXCHANGE REQUIRED: Must receive meaningful output");
-        return Err(ProgramError::InvalidArgument);
+        return Err(crate::error::PoolError::AmountTooSmall { amount_in: amount_b }.into());
     }
     
     // Logging omitted for CU efficiency; expected_amount_out check enforces correctness
@@ -942,7 +1768,11 @@ pub fn process_swap_set_owner_only<'a>(
         program_id,
     )?;
     
-    
+    // Restricting swaps to the default pubkey would leave nobody able to swap
+    if enable_restriction && designated_owner == Pubkey::default() {
+        msg!("❌ Designated owner cannot be the default pubkey when enabling owner-only swaps");
+        return Err(PoolError::InvalidDesignatedOwner.into());
+    }
     
     // Load and validate pool state data with Pool ID security validation
     let mut pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_pda, &pool_id, program_id)?;
@@ -973,7 +1803,7 @@ pub fn process_swap_set_owner_only<'a>(
         msg!("ℹ️ Restrictions disabled - ownership delegation not applicable");
     }
     
-    
+    pool_state_data.record_operation();
     
     // Serialize and save updated pool state
     let mut serialized_data = Vec::new();
@@ -3,13 +3,13 @@
 //! This module handles all system-wide management functions including
 //! initialization, pause/unpause operations, and version information.
 
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::BorshSerialize;
 use solana_program::{
     account_info::AccountInfo,
     clock::Clock,
     entrypoint::ProgramResult,
     msg,
-    program::{invoke_signed},
+    program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
     system_instruction,
@@ -284,7 +284,7 @@ pub fn process_system_pause(
     let current_timestamp = clock.unix_timestamp;
     
     // Pause the system
-    system_state.pause(reason_code, current_timestamp);
+    system_state.pause(reason_code, current_timestamp, *system_authority_signer.key);
     
     // Serialize updated state back to account with size validation
     let serialized_data = system_state.try_to_vec()?;
@@ -297,7 +297,7 @@ pub fn process_system_pause(
     // Log the system pause
     msg!("🛑 SYSTEM PAUSED: All operations blocked");
     msg!("Authority: {}", system_authority_signer.key);
-    msg!("Pause code: {}", reason_code);
+    msg!("Pause code: {} ({:?})", reason_code, system_state.pause_reason());
     msg!("Timestamp: {}", current_timestamp);
     msg!("System pause takes precedence over all pool pause states");
     
@@ -391,6 +391,7 @@ pub fn process_system_unpause(
     // Store pause info for logging before clearing
     let pause_duration = Clock::get()?.unix_timestamp - system_state.pause_timestamp;
     let previous_pause_code = system_state.pause_reason_code;
+    let previous_paused_by = system_state.paused_by;
     
     // Get current timestamp for restart penalty
     let current_timestamp = Clock::get()?.unix_timestamp;
@@ -408,7 +409,7 @@ pub fn process_system_unpause(
     
     // **APPLY SYSTEM RESTART PENALTY**: Block treasury withdrawals for 3 days
     // Load and update main treasury state with restart penalty (tolerant deserialize)
-    let mut main_treasury_state = MainTreasuryState::deserialize(&mut &main_treasury_pda.data.borrow()[..])
+    let mut main_treasury_state = MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow())
         .map_err(|e| {
             msg!("❌ Failed to deserialize MainTreasuryState (unpause penalty): {:?}", e);
             ProgramError::InvalidAccountData
@@ -429,7 +430,7 @@ pub fn process_system_unpause(
     msg!("✅ SYSTEM UNPAUSED: All operations resumed");
     msg!("🔒 RESTART PENALTY APPLIED: Treasury withdrawals blocked for 3 days");
     msg!("Authority: {}", system_authority_signer.key);
-    msg!("Previous pause code: {}", previous_pause_code);
+    msg!("Previous pause code: {} (paused by {})", previous_pause_code, previous_paused_by);
     msg!("Pause duration: {} seconds", pause_duration);
     msg!("Treasury penalty expires at: {} (timestamp)", main_treasury_state.last_withdrawal_timestamp);
     msg!("Pool-specific pause states remain active if previously set");
@@ -464,6 +465,32 @@ pub fn process_system_get_version(_accounts: &[AccountInfo]) -> ProgramResult {
     Ok(())
 }
 
+/// **VIEW INSTRUCTION**: Returns the system pause status.
+/// 
+/// Reports whether the system is paused, the named reason, when the pause began,
+/// which authority paused it and the admin's system message, so clients can explain
+/// a halt without decoding the raw system state.
+/// 
+/// # Account Layout (Read-Only)
+/// 0. **System State PDA** (readable) - System state to inspect
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to the Borsh-serialized `SystemStatus`
+pub fn process_system_get_status(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    use crate::state::SystemStatus;
+    use solana_program::program::set_return_data;
+    
+    let system_state_pda = &accounts[0];                     // Index 0: System State PDA
+    let system_state = SystemState::load_from_account(system_state_pda, program_id)?;
+    let status = SystemStatus::from(&system_state);
+    
+    msg!("System paused: {} | Reason: {:?} | Since: {} | By: {}",
+         status.is_paused, status.pause_reason, status.pause_timestamp, status.paused_by);
+    set_return_data(&status.try_to_vec()?);
+    
+    Ok(())
+}
+
 /// **ADMIN AUTHORITY MANAGEMENT**: Process admin authority change with automatic completion
 /// 
 /// This unified function handles both initiation and completion of admin changes:
@@ -590,3 +617,271 @@ pub fn process_admin_change(
     }
 }
 
+
+/// **PROTOCOL SWAP FEE**: Sets the global SOL fee charged on every swap (admin authority only)
+/// 
+/// The fee is paid by the swapper directly to the main treasury, on top of the pool's
+/// own swap contract fee. A fee of 0 disables it.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `swap_sol_fee` - New fee in lamports (0 to `MAX_SWAP_FEE`)
+/// * `accounts` - Array of accounts in the following order:
+///   - [0] Admin Authority (signer) - Must be current admin
+///   - [1] System State PDA (writable) - To store the fee
+///   - [2] Program Data Account (readable) - For upgrade authority fallback
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_system_set_swap_sol_fee(
+    program_id: &Pubkey,
+    swap_sol_fee: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("💰 Processing SetSwapSolFee: {} lamports", swap_sol_fee);
+    
+    let admin_authority_signer = &accounts[0];               // Index 0: Admin Authority Signer
+    let system_state_pda = &accounts[1];                    // Index 1: System State PDA
+    let program_data_account = &accounts[2];                 // Index 2: Program Data Account
+    
+    validate_writable(system_state_pda, "System state PDA")?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    if swap_sol_fee > MAX_SWAP_FEE {
+        msg!("❌ Swap SOL fee too high: {} lamports (maximum: {} lamports)", swap_sol_fee, MAX_SWAP_FEE);
+        return Err(PoolError::InvalidSwapFee {
+            fee: swap_sol_fee,
+            min: 0,
+            max: MAX_SWAP_FEE,
+        }.into());
+    }
+    
+    let mut system_state = SystemState::load_from_account(system_state_pda, program_id)?;
+    let old_fee = system_state.swap_sol_fee;
+    system_state.swap_sol_fee = swap_sol_fee;
+    serialize_to_account(&system_state, system_state_pda)?;
+    
+    msg!("✅ Swap SOL fee updated: {} → {} lamports", old_fee, swap_sol_fee);
+    
+    Ok(())
+}
+
+/// **CONSOLIDATION SKIP WINDOW**: Sets how long after its last consolidation a pool is
+/// skipped by `ConsolidatePoolFees` (admin authority only)
+/// 
+/// Makes batch consolidation safe to retry: pools already swept within the window are
+/// skipped rather than processed again. A window of 0 disables skipping.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `window_seconds` - New window in seconds (0 to `MAX_CONSOLIDATION_SKIP_WINDOW`)
+/// * `accounts` - Array of accounts in the following order:
+///   - [0] Admin Authority (signer) - Must be current admin
+///   - [1] System State PDA (writable) - To store the window
+///   - [2] Program Data Account (readable) - For upgrade authority fallback
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_system_set_consolidation_skip_window(
+    program_id: &Pubkey,
+    window_seconds: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("⏱️ Processing SetConsolidationSkipWindow: {} seconds", window_seconds);
+    
+    let admin_authority_signer = &accounts[0];               // Index 0: Admin Authority Signer
+    let system_state_pda = &accounts[1];                    // Index 1: System State PDA
+    let program_data_account = &accounts[2];                 // Index 2: Program Data Account
+    
+    validate_writable(system_state_pda, "System state PDA")?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    if window_seconds > MAX_CONSOLIDATION_SKIP_WINDOW {
+        msg!("❌ Consolidation skip window too long: {} seconds (maximum: {} seconds)", 
+             window_seconds, MAX_CONSOLIDATION_SKIP_WINDOW);
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    let mut system_state = SystemState::load_from_account(system_state_pda, program_id)?;
+    let old_window = system_state.consolidation_skip_window;
+    system_state.consolidation_skip_window = window_seconds;
+    serialize_to_account(&system_state, system_state_pda)?;
+    
+    msg!("✅ Consolidation skip window updated: {} → {} seconds", old_window, window_seconds);
+    
+    Ok(())
+}
+
+/// **POOL CREATION DURING PAUSE**: Sets whether `InitializePool` is allowed while the
+/// system is paused (admin authority only)
+/// 
+/// Pool creation is blocked during a pause by default. Like the other system settings,
+/// this can be changed while the system is paused.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `allowed` - Whether pools can be created while the system is paused
+/// * `accounts` - Array of accounts in the following order:
+///   - [0] Admin Authority (signer) - Must be current admin
+///   - [1] System State PDA (writable) - To store the flag
+///   - [2] Program Data Account (readable) - For upgrade authority fallback
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_system_set_allow_pool_creation_while_paused(
+    program_id: &Pubkey,
+    allowed: bool,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("🏊 Processing SetAllowPoolCreationWhilePaused: {}", allowed);
+    
+    let admin_authority_signer = &accounts[0];               // Index 0: Admin Authority Signer
+    let system_state_pda = &accounts[1];                    // Index 1: System State PDA
+    let program_data_account = &accounts[2];                 // Index 2: Program Data Account
+    
+    validate_writable(system_state_pda, "System state PDA")?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let mut system_state = SystemState::load_from_account(system_state_pda, program_id)?;
+    let old_allowed = system_state.allow_pool_creation_while_paused;
+    system_state.allow_pool_creation_while_paused = allowed;
+    serialize_to_account(&system_state, system_state_pda)?;
+    
+    msg!("✅ Pool creation while paused updated: {} → {}", old_allowed, allowed);
+    
+    Ok(())
+}
+
+/// **SYSTEM MESSAGE**: Sets the admin's on-chain broadcast message (admin authority only)
+/// 
+/// Stores up to `SYSTEM_MESSAGE_MAX_LEN` bytes of UTF-8 in the system state for
+/// `GetSystemStatus` to report, so users can be told what is happening during an
+/// incident. An empty message clears it. Like the other system settings, the message
+/// can be changed while the system is paused.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `message` - UTF-8 message bytes (no zero bytes)
+/// * `accounts` - Array of accounts in the following order:
+///   - [0] Admin Authority (signer) - Must be current admin
+///   - [1] System State PDA (writable) - To store the message
+///   - [2] Program Data Account (readable) - For upgrade authority fallback
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_system_set_message(
+    program_id: &Pubkey,
+    message: &[u8],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("📢 Processing SetSystemMessage: {} bytes", message.len());
+    
+    let admin_authority_signer = &accounts[0];               // Index 0: Admin Authority Signer
+    let system_state_pda = &accounts[1];                    // Index 1: System State PDA
+    let program_data_account = &accounts[2];                 // Index 2: Program Data Account
+    
+    validate_writable(system_state_pda, "System state PDA")?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let mut system_state = SystemState::load_from_account(system_state_pda, program_id)?;
+    system_state.set_system_message(message).map_err(|e| {
+        msg!("❌ {}", e);
+        ProgramError::from(e)
+    })?;
+    serialize_to_account(&system_state, system_state_pda)?;
+    
+    msg!("✅ System message updated: \"{}\"", system_state.system_message());
+    
+    Ok(())
+}
+
+/// **SYSTEM STATE MIGRATION**: Grows a system state account created with an older,
+/// shorter layout to `SystemState::LEN` (admin authority only)
+/// 
+/// The account is read with the tolerant loader, so fields the old layout lacks start
+/// at zero. The signer tops up any extra rent. An account already at full size is
+/// left unchanged. Allowed while the system is paused.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `accounts` - Array of accounts in the following order:
+///   - [0] Admin Authority (signer, writable) - Must be current admin, pays any extra rent
+///   - [1] System Program (readable) - For the rent top-up transfer
+///   - [2] System State PDA (writable) - Account to migrate
+///   - [3] Program Data Account (readable) - For upgrade authority fallback
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_system_migrate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("🔧 SYSTEM STATE MIGRATION");
+    
+    let admin_authority_signer = &accounts[0];               // Index 0: Admin Authority Signer
+    let system_program_account = &accounts[1];               // Index 1: System Program
+    let system_state_pda = &accounts[2];                    // Index 2: System State PDA
+    let program_data_account = &accounts[3];                 // Index 3: Program Data Account
+    
+    validate_writable(system_state_pda, "System state PDA")?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let system_state = SystemState::load_from_account(system_state_pda, program_id)?;
+    
+    let old_len = system_state_pda.data_len();
+    if old_len >= SystemState::LEN {
+        msg!("✅ System state already at current size ({} bytes)", old_len);
+        return Ok(());
+    }
+    
+    // Grow the account to the current layout, topping up rent from the signer
+    let rent_shortfall = Rent::get()?.minimum_balance(SystemState::LEN).saturating_sub(system_state_pda.lamports());
+    if rent_shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(admin_authority_signer.key, system_state_pda.key, rent_shortfall),
+            &[admin_authority_signer.clone(), system_state_pda.clone(), system_program_account.clone()],
+        )?;
+    }
+    system_state_pda.realloc(SystemState::LEN, false)?;
+    serialize_to_account(&system_state, system_state_pda)?;
+    
+    msg!("✅ System state migrated: {} → {} bytes", old_len, SystemState::LEN);
+    msg!("   • Migrated by: {}", admin_authority_signer.key);
+    
+    Ok(())
+}
@@ -10,7 +10,7 @@
 //! - Specialized treasury balance queries (no longer needed)
 //! - Complex consolidation race condition handling (eliminated by design)
 
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::BorshSerialize;
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
@@ -22,7 +22,9 @@ use solana_program::{
 
 use crate::{
     constants::*,
-    state::{MainTreasuryState},
+    error::PoolError,
+    events::{FeeWithdrawalEvent, ProgramEvent},
+    state::{DonationRecord, MainTreasuryState},
     utils::validation::{validate_writable},
 };
 
@@ -47,10 +49,16 @@ use crate::{
 /// - Tier 2: ≤4,800 SOL available → 100 SOL/hour (10x multiplier)
 /// - Tier 3: ≤48,000 SOL available → 1,000 SOL/hour (100x multiplier)
 /// - Each tier scales by 10x when 48-hour drain threshold is exceeded
+/// 
+/// **Window Cap:**
+/// - At most `TREASURY_MAX_WITHDRAWAL_PER_WINDOW` (1,000 SOL) per `TREASURY_WITHDRAWAL_WINDOW_SECONDS` (24 hours)
+/// - The window opens with the first withdrawal after the previous one ended
+/// - Exceeding the remaining allowance fails with `WithdrawalRateExceeded`
 /// ======================================================================================
 /// # Arguments
 /// * `program_id` - The program ID for PDA derivation
-/// * `amount` - Amount to withdraw in lamports (0 = withdraw all available)
+/// * `amount` - Amount to withdraw in lamports (0 = withdraw all available,
+///   `TREASURY_WITHDRAW_MAX_ALLOWED` = the most the rent reserve, hourly rate limit and window cap allow)
 /// * `system_authority_signer` - System upgrade authority signer authorizing withdrawal
 /// * `main_treasury_pda` - Main treasury PDA for withdrawal
 /// * `rent_sysvar_account` - For rent calculations
@@ -63,12 +71,13 @@ use crate::{
 /// 0. **System Authority Signer** (signer, writable) - System upgrade authority signer authorizing withdrawal
 /// 1. **Main Treasury PDA** (writable) - Main treasury PDA for withdrawal
 /// 2. **Rent Sysvar Account** (readable) - For rent calculations
-/// 3. **Destination Account** (writable) - Account receiving the withdrawn SOL
+/// 3. **Destination Account** (writable) - System account receiving the withdrawn SOL
 /// 4. **System State PDA** (readable) - For authority validation and pause check
 /// 5. **Program Data Account** (readable) - Program data account for authority validation
 /// 
 /// # Returns
 /// * `ProgramResult` - Success or error
+/// * Return data: lamports actually withdrawn as a little-endian u64
 ///
 pub fn process_treasury_withdraw_fees(
     program_id: &Pubkey,
@@ -96,6 +105,14 @@ pub fn process_treasury_withdraw_fees(
     validate_writable(main_treasury_pda, "Main treasury PDA")?;
     validate_writable(destination_account, "Destination account")?;
     
+    // The authority picks the destination per call; it must be a plain wallet so
+    // lamports cannot be pushed into a program-owned account
+    if *destination_account.owner != solana_program::system_program::id() {
+        msg!("Invalid destination {} (owner {}), expected a system account",
+             destination_account.key, destination_account.owner);
+        return Err(PoolError::InvalidWithdrawalDestination { destination: *destination_account.key }.into());
+    }
+    
     // Verify main treasury PDA
     let (expected_main_treasury, _treasury_bump) = Pubkey::find_program_address(
         &[MAIN_TREASURY_SEED_PREFIX],
@@ -120,7 +137,7 @@ pub fn process_treasury_withdraw_fees(
     )?;
     
     // Load main treasury state with robust error handling for production environments
-    let mut main_treasury_state = match MainTreasuryState::try_from_slice(&main_treasury_pda.data.borrow()) {
+    let mut main_treasury_state = match MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow()) {
         Ok(state) => state,
         Err(e) => {
             msg!("Failed to deserialize treasury state: {:?}", e);
@@ -148,9 +165,33 @@ pub fn process_treasury_withdraw_fees(
     let current_balance = main_treasury_pda.lamports();
     let available_balance = current_balance.saturating_sub(rent_exempt_minimum);
     
+    let required_reserve = rent.minimum_balance(main_treasury_pda.data_len()).max(rent_exempt_minimum);
+    let current_hourly_limit = main_treasury_state.calculate_current_hourly_rate_limit();
+    
+    // Get current timestamp for rate limiting validation
+    use solana_program::clock::Clock;
+    use solana_program::sysvar::Sysvar;
+    
+    let current_timestamp = match Clock::get() {
+        Ok(clock) => clock.unix_timestamp,
+        Err(e) => {
+            msg!("Failed to get timestamp: {:?}", e);
+            0 // Fallback timestamp
+        }
+    };
+    
+    let remaining_window_allowance = main_treasury_state.remaining_window_allowance(current_timestamp);
+    
     // Determine actual withdrawal amount
+    let withdraw_max_allowed = amount == crate::constants::TREASURY_WITHDRAW_MAX_ALLOWED;
     let withdrawal_amount = if amount == 0 {
         available_balance // Withdraw all available
+    } else if withdraw_max_allowed {
+        // Everything above the rent reserve, capped at the hourly rate limit and window allowance
+        current_balance
+            .saturating_sub(required_reserve)
+            .min(current_hourly_limit)
+            .min(remaining_window_allowance)
     } else {
         amount
     };
@@ -160,34 +201,30 @@ pub fn process_treasury_withdraw_fees(
         return Err(ProgramError::InsufficientFunds);
     }
     
-    // Validate minimum withdrawal amount (except for withdraw-all case)
-    if amount != 0 && withdrawal_amount < crate::constants::MIN_TREASURY_WITHDRAWAL_AMOUNT {
+    // Validate minimum withdrawal amount (except for withdraw-all and max-allowed cases)
+    if amount != 0 && !withdraw_max_allowed && withdrawal_amount < crate::constants::MIN_TREASURY_WITHDRAWAL_AMOUNT {
         msg!("Amount {} below minimum {} (0.01 SOL)", 
              withdrawal_amount, crate::constants::MIN_TREASURY_WITHDRAWAL_AMOUNT);
         return Err(ProgramError::InvalidInstructionData);
     }
     
-    if withdrawal_amount > available_balance {
-        msg!("Amount {} exceeds available {}", withdrawal_amount, available_balance);
+    if withdrawal_amount > current_balance {
+        msg!("Amount {} exceeds treasury balance {}", withdrawal_amount, current_balance);
         return Err(ProgramError::InsufficientFunds);
     }
     
-    // Get current timestamp for rate limiting validation
-    use solana_program::clock::Clock;
-    use solana_program::sysvar::Sysvar;
-    
-    let current_timestamp = match Clock::get() {
-        Ok(clock) => clock.unix_timestamp,
-        Err(e) => {
-            msg!("Failed to get timestamp: {:?}", e);
-            0 // Fallback timestamp
-        }
-    };
+    // The treasury PDA must stay rent-exempt for its actual data size after the withdrawal
+    let balance_after = current_balance - withdrawal_amount;
+    if balance_after < required_reserve {
+        msg!("Amount {} exceeds available {} (rent reserve {})", withdrawal_amount, available_balance, required_reserve);
+        return Err(PoolError::WouldBreachRentReserve {
+            balance_after,
+            rent_exempt_minimum: required_reserve,
+        }.into());
+    }
     
     // **DYNAMIC RATE LIMITING VALIDATION**
     // Enforce dynamic hourly withdrawal rate limiting with rolling 60-minute window
-    let current_hourly_limit = main_treasury_state.calculate_current_hourly_rate_limit();
-    
     if let Err(rate_limit_error) = main_treasury_state.validate_withdrawal_rate_limit(withdrawal_amount, current_timestamp) {
         msg!("🚫 WITHDRAWAL BLOCKED: {}", rate_limit_error);
         
@@ -221,6 +258,19 @@ pub fn process_treasury_withdraw_fees(
         return Err(ProgramError::InvalidInstructionData);
     }
     
+    // **WINDOW CAP**: Bound the total withdrawn per TREASURY_WITHDRAWAL_WINDOW_SECONDS so a
+    // compromised authority key cannot drain the treasury one hourly withdrawal at a time
+    if withdrawal_amount > remaining_window_allowance {
+        msg!("🚫 WITHDRAWAL BLOCKED: {} lamports requested, {} of the {} lamport window cap remaining",
+             withdrawal_amount, remaining_window_allowance, TREASURY_MAX_WITHDRAWAL_PER_WINDOW);
+        msg!("   Window opened at {} (timestamp), resets {} seconds after opening",
+             main_treasury_state.withdrawal_window_start, TREASURY_WITHDRAWAL_WINDOW_SECONDS);
+        return Err(PoolError::WithdrawalRateExceeded {
+            requested: withdrawal_amount,
+            remaining: remaining_window_allowance,
+        }.into());
+    }
+    
     msg!("Withdrawing: {} lamports", withdrawal_amount);
     
     // Transfer SOL from treasury to destination account
@@ -256,6 +306,16 @@ pub fn process_treasury_withdraw_fees(
     msg!("   Amount withdrawn: {} lamports", withdrawal_amount);
     msg!("   Remaining treasury balance: {} lamports", main_treasury_state.total_balance);
     
+    ProgramEvent::FeeWithdrawal(FeeWithdrawalEvent {
+        treasury: *main_treasury_pda.key,
+        destination: *destination_account.key,
+        amount: withdrawal_amount,
+        remaining_balance: main_treasury_state.total_balance,
+    }).emit();
+    
+    // Report the amount actually withdrawn (useful for the 0 and max-allowed modes)
+    solana_program::program::set_return_data(&withdrawal_amount.to_le_bytes());
+    
     Ok(())
 }
 
@@ -291,7 +351,7 @@ pub fn process_treasury_get_info(
     let main_treasury_pda = &accounts[0];            // Index 0: Main Treasury PDA
     
     // Load main treasury data with robust error handling for production environments
-    let main_treasury_state = match MainTreasuryState::try_from_slice(&main_treasury_pda.data.borrow()) {
+    let main_treasury_state = match MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow()) {
         Ok(state) => {
             msg!("✅ Successfully loaded treasury state from account data");
             state
@@ -370,6 +430,53 @@ pub fn process_treasury_get_info(
     Ok(())
 }
 
+/// **VIEW INSTRUCTION**: Returns lifetime protocol statistics for monitoring.
+/// 
+/// Reads the main treasury state, which is the program's registry of protocol-wide
+/// counters: pool creations are recorded directly and consolidations are counted
+/// every time pool fees are swept into the treasury.
+/// 
+/// # Account Info
+/// The accounts must be provided in the following order:
+/// 0. **Main Treasury PDA** (readable) - Main treasury PDA holding the counters
+/// 
+/// # Returns (via `set_return_data`)
+/// Three little-endian `u64` values:
+/// `[total_pools, treasury_balance, total_consolidations]`
+pub fn process_treasury_get_program_stats(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    let main_treasury_pda = &accounts[0];            // Index 0: Main Treasury PDA
+
+    let (expected_main_treasury, _treasury_bump) = Pubkey::find_program_address(
+        &[MAIN_TREASURY_SEED_PREFIX],
+        program_id,
+    );
+    if *main_treasury_pda.key != expected_main_treasury {
+        msg!("❌ Invalid main treasury PDA. Expected: {}, Got: {}", 
+             expected_main_treasury, main_treasury_pda.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let main_treasury_state = MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow())?;
+
+    msg!("📊 Program stats: {} pools, {} lamports in treasury, {} consolidations",
+         main_treasury_state.pool_creation_count,
+         main_treasury_state.total_balance,
+         main_treasury_state.total_consolidations_performed);
+
+    let mut return_data = [0u8; 24];
+    return_data[0..8].copy_from_slice(&main_treasury_state.pool_creation_count.to_le_bytes());
+    return_data[8..16].copy_from_slice(&main_treasury_state.total_balance.to_le_bytes());
+    return_data[16..24].copy_from_slice(&main_treasury_state.total_consolidations_performed.to_le_bytes());
+    set_return_data(&return_data);
+
+    Ok(())
+}
+
 /// Processes voluntary SOL donations to the treasury
 /// 
 /// This function allows anyone to donate SOL to the protocol treasury.
@@ -384,7 +491,8 @@ pub fn process_treasury_get_info(
 /// # Arguments:
 /// * `program_id` - The program ID for PDA derivation
 /// * `amount` - Amount to donate in lamports (must be > 0)
-/// * `message` - Optional message (logged but not stored)
+/// * `message` - Optional message (logged in full; the first `DONATION_MESSAGE_PREVIEW_LEN`
+///   bytes are kept in the recent donations buffer)
 /// * `accounts` - Array of accounts in order
 /// 
 /// # Account Info
@@ -480,7 +588,7 @@ pub fn process_treasury_donate_sol(
     msg!("✅ Transfer successful");
     
     // Load and update treasury state
-    let mut main_treasury_state = match MainTreasuryState::try_from_slice(&main_treasury_pda.data.borrow()) {
+    let mut main_treasury_state = match MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow()) {
         Ok(state) => state,
         Err(e) => {
             msg!("⚠️ Failed to deserialize treasury state: {:?}", e);
@@ -502,6 +610,7 @@ pub fn process_treasury_donate_sol(
     
     // Update treasury state with donation
     main_treasury_state.add_donation(amount, current_timestamp);
+    main_treasury_state.record_recent_donation(DonationRecord::new(*donor_account.key, amount, &message, current_timestamp));
     main_treasury_state.total_balance = main_treasury_pda.lamports();
     
     // Serialize updated state back to account
@@ -526,3 +635,627 @@ pub fn process_treasury_donate_sol(
     
     Ok(())
 }
+
+/// **VIEW INSTRUCTION**: Returns the treasury's most recent donations.
+/// 
+/// Reads the ring buffer filled by `DonateSol`: up to `RECENT_DONATIONS_CAPACITY`
+/// entries with the donor, amount, message preview and timestamp of each donation.
+/// Lifetime totals stay in `donation_count` and `total_donations`.
+/// 
+/// # Account Info
+/// The accounts must be provided in the following order:
+/// 0. **Main Treasury PDA** (readable) - Main treasury PDA holding the buffer
+/// 
+/// # Returns (via `set_return_data`)
+/// Borsh-serialized `Vec<DonationRecord>`, newest first
+pub fn process_treasury_get_recent_donations(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    let main_treasury_pda = &accounts[0];            // Index 0: Main Treasury PDA
+
+    let (expected_main_treasury, _treasury_bump) = Pubkey::find_program_address(
+        &[MAIN_TREASURY_SEED_PREFIX],
+        program_id,
+    );
+    if *main_treasury_pda.key != expected_main_treasury {
+        msg!("❌ Invalid main treasury PDA. Expected: {}, Got: {}", 
+             expected_main_treasury, main_treasury_pda.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let main_treasury_state = MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow())?;
+    let recent_donations = main_treasury_state.recent_donations_newest_first();
+
+    msg!("💖 {} recent donations returned ({} donations, {} lamports in total)",
+         recent_donations.len(),
+         main_treasury_state.donation_count,
+         main_treasury_state.total_donations);
+    set_return_data(&recent_donations.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Processes the SetTreasuryAutocompound instruction
+/// 
+/// Designates the pool that receives idle treasury SOL as liquidity and the reserve
+/// kept back when compounding. The pool must hold wrapped SOL (the native mint) on
+/// one side. Passing `Pubkey::default()` as `pool_id` disables auto-compounding; the
+/// pool state account is then not inspected.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA derivation
+/// * `pool_id` - Pool to compound into (`Pubkey::default()` disables)
+/// * `reserve_lamports` - Lamports kept in the treasury above its rent-exempt minimum
+/// * `accounts` - Array of accounts (5 accounts)
+/// 
+/// # Account Info
+/// The accounts must be provided in the following order:
+/// 0. **Admin Authority Signer** (signer) - Admin authority
+/// 1. **System State PDA** (readable) - For pause and admin validation
+/// 2. **Main Treasury PDA** (writable) - Stores the auto-compound configuration
+/// 3. **Program Data Account** (readable) - For upgrade authority fallback validation
+/// 4. **Pool State PDA** (readable) - Pool to compound into
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_treasury_set_autocompound(
+    program_id: &Pubkey,
+    pool_id: Pubkey,
+    reserve_lamports: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("🔧 TREASURY AUTO-COMPOUND UPDATE");
+    
+    let admin_authority_signer = &accounts[0];       // Index 0: Admin Authority Signer
+    let system_state_pda = &accounts[1];             // Index 1: System State PDA
+    let main_treasury_pda = &accounts[2];            // Index 2: Main Treasury PDA
+    let program_data_account = &accounts[3];         // Index 3: Program Data Account
+    let pool_state_pda = &accounts[4];               // Index 4: Pool State PDA
+    
+    validate_writable(main_treasury_pda, "Main treasury PDA")?;
+    validate_main_treasury_pda(main_treasury_pda, program_id)?;
+    
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    if pool_id != Pubkey::default() {
+        let pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(
+            pool_state_pda,
+            &pool_id,
+            program_id,
+        )?;
+        if pool_state_data.token_a_mint != spl_token::native_mint::id()
+            && pool_state_data.token_b_mint != spl_token::native_mint::id()
+        {
+            msg!("❌ Auto-compound pool {} has no wrapped SOL side", pool_id);
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+    
+    let mut main_treasury_state = MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow())?;
+    main_treasury_state.autocompound_pool = pool_id;
+    main_treasury_state.autocompound_reserve = reserve_lamports;
+    
+    use crate::utils::serialization::serialize_to_account;
+    serialize_to_account(&main_treasury_state, main_treasury_pda)?;
+    
+    msg!("✅ Auto-compound pool set to {} (default = disabled)", pool_id);
+    msg!("   • Reserve: {} lamports above rent exemption", reserve_lamports);
+    msg!("   • Updated by: {}", admin_authority_signer.key);
+    
+    Ok(())
+}
+
+/// Processes the CompoundTreasury instruction
+/// 
+/// Deposits the treasury's idle SOL into the configured auto-compound pool as
+/// single-sided liquidity on the wrapped SOL side:
+/// 1. Everything above the rent-exempt minimum plus `autocompound_reserve` is moved
+///    from the treasury PDA into the treasury's wrapped SOL account and synced
+/// 2. The wrapped SOL is transferred into the pool vault, signed by the treasury PDA
+/// 3. LP tokens are minted 1:1 to the treasury's LP token account, signed by the pool PDA
+/// 
+/// No liquidity fee is charged: the fee would be collected into the pool and
+/// consolidated straight back into the treasury. The treasury's LP tokens are
+/// tracked in `autocompound_lp_tokens`.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA derivation
+/// * `accounts` - Array of accounts (10 accounts)
+/// 
+/// # Account Info
+/// The accounts must be provided in the following order:
+/// 0. **Admin Authority Signer** (signer) - Admin authority
+/// 1. **System State PDA** (readable) - For pause and admin validation
+/// 2. **Main Treasury PDA** (writable) - Source of the compounded SOL
+/// 3. **Program Data Account** (readable) - For upgrade authority fallback validation
+/// 4. **Pool State PDA** (writable) - The configured auto-compound pool
+/// 5. **Treasury Wrapped SOL Account** (writable) - Native mint token account owned by the treasury PDA
+/// 6. **Pool Vault PDA** (writable) - The pool's vault on the wrapped SOL side
+/// 7. **LP Token Mint PDA** (writable) - The pool's LP mint on the wrapped SOL side
+/// 8. **Treasury LP Token Account** (writable) - LP token account owned by the treasury PDA
+/// 9. **SPL Token Program Account** (readable) - Token program account
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+/// * Return data: lamports compounded as a little-endian `u64`
+/// 
+/// # Errors
+/// * `NothingToCompound` - The treasury holds nothing above the rent-exempt minimum and reserve
+pub fn process_treasury_compound<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+    use solana_program::{
+        clock::Clock,
+        program::{invoke, invoke_signed, set_return_data},
+        program_pack::Pack,
+        sysvar::Sysvar,
+    };
+    use spl_token::{instruction as token_instruction, state::Mint};
+    use crate::processors::liquidity::{freeze_lp_account, thaw_lp_account_if_frozen};
+    use crate::processors::utilities::validate_deposits_not_paused;
+    use crate::utils::token_validation::safe_unpack_and_validate_token_account;
+    use crate::utils::validation::{validate_vault_owner, validate_lp_mint_ready, validate_lp_mint_authority};
+    
+    msg!("🔁 TREASURY AUTO-COMPOUND");
+    
+    let admin_authority_signer = &accounts[0];       // Index 0: Admin Authority Signer
+    let system_state_pda = &accounts[1];             // Index 1: System State PDA
+    let main_treasury_pda = &accounts[2];            // Index 2: Main Treasury PDA
+    let program_data_account = &accounts[3];         // Index 3: Program Data Account
+    let pool_state_pda = &accounts[4];               // Index 4: Pool State PDA
+    let treasury_wsol_account = &accounts[5];        // Index 5: Treasury Wrapped SOL Account
+    let pool_vault = &accounts[6];                   // Index 6: Pool Vault PDA
+    let lp_mint = &accounts[7];                      // Index 7: LP Token Mint PDA
+    let treasury_lp_account = &accounts[8];          // Index 8: Treasury LP Token Account
+    let spl_token_program_account = &accounts[9];    // Index 9: SPL Token Program Account
+    
+    validate_writable(main_treasury_pda, "Main treasury PDA")?;
+    let treasury_bump = validate_main_treasury_pda(main_treasury_pda, program_id)?;
+    
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let mut main_treasury_state = MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow())?;
+    let pool_id = main_treasury_state.autocompound_pool;
+    if pool_id == Pubkey::default() {
+        msg!("❌ Treasury auto-compounding is not configured");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    
+    let mut pool_state_data = crate::utils::validation::validate_and_deserialize_pool_state_secure(
+        pool_state_pda,
+        &pool_id,
+        program_id,
+    )?;
+    validate_deposits_not_paused(&pool_state_data)?;
+    
+    let native_mint = spl_token::native_mint::id();
+    let is_token_a = pool_state_data.token_a_mint == native_mint;
+    if !is_token_a && pool_state_data.token_b_mint != native_mint {
+        msg!("❌ Auto-compound pool {} has no wrapped SOL side", pool_id);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    
+    let (vault_seed, lp_mint_seed) = if is_token_a {
+        (TOKEN_A_VAULT_SEED_PREFIX, LP_TOKEN_A_MINT_SEED_PREFIX)
+    } else {
+        (TOKEN_B_VAULT_SEED_PREFIX, LP_TOKEN_B_MINT_SEED_PREFIX)
+    };
+    let (expected_vault, _) = Pubkey::find_program_address(&[vault_seed, pool_state_pda.key.as_ref()], program_id);
+    let (expected_lp_mint, _) = Pubkey::find_program_address(&[lp_mint_seed, pool_state_pda.key.as_ref()], program_id);
+    if *pool_vault.key != expected_vault || *lp_mint.key != expected_lp_mint {
+        msg!("❌ Vault or LP mint does not match the pool's wrapped SOL side");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    
+    let vault_data = safe_unpack_and_validate_token_account(
+        pool_vault,
+        "Pool Vault",
+        Some(pool_state_pda.key),
+        Some(&native_mint),
+        false,
+    )?;
+    validate_vault_owner(&vault_data, pool_state_pda.key, "Pool Vault")?;
+    validate_lp_mint_ready(lp_mint, "LP Mint")?;
+    validate_lp_mint_authority(lp_mint, pool_state_pda.key, "LP Mint")?;
+    
+    safe_unpack_and_validate_token_account(
+        treasury_wsol_account,
+        "Treasury Wrapped SOL Account",
+        Some(main_treasury_pda.key),
+        Some(&native_mint),
+        true,
+    )?;
+    let initial_lp_balance = safe_unpack_and_validate_token_account(
+        treasury_lp_account,
+        "Treasury LP Token Account",
+        Some(main_treasury_pda.key),
+        Some(&expected_lp_mint),
+        true,
+    )?.amount;
+    
+    // ✅ RESERVE GUARD: Only the balance above rent exemption and the reserve is compounded
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(main_treasury_pda.data_len());
+    let current_balance = main_treasury_pda.lamports();
+    let amount = main_treasury_state.compoundable_balance(current_balance, rent_exempt_minimum);
+    if amount == 0 {
+        msg!("❌ Nothing to compound: balance {}, rent minimum {}, reserve {}",
+             current_balance, rent_exempt_minimum, main_treasury_state.autocompound_reserve);
+        return Err(PoolError::NothingToCompound {
+            balance: current_balance,
+            retained: rent_exempt_minimum.saturating_add(main_treasury_state.autocompound_reserve),
+        }.into());
+    }
+    
+    if pool_state_data.max_lp_supply > 0 {
+        let current_lp_supply = Mint::unpack_from_slice(&lp_mint.data.borrow())?.supply;
+        let new_lp_supply = current_lp_supply
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        if new_lp_supply > pool_state_data.max_lp_supply {
+            msg!("❌ LP SUPPLY CAP: Supply {} + compound {} exceeds cap {}",
+                 current_lp_supply, amount, pool_state_data.max_lp_supply);
+            return Err(PoolError::LpSupplyCapReached {
+                cap: pool_state_data.max_lp_supply,
+                current_supply: current_lp_supply,
+                requested: amount,
+            }.into());
+        }
+    }
+    
+    msg!("Compounding {} lamports into pool {}", amount, pool_id);
+    
+    // Wrap the SOL: the treasury PDA is program-owned, so lamports move directly
+    **main_treasury_pda.try_borrow_mut_lamports()? -= amount;
+    **treasury_wsol_account.try_borrow_mut_lamports()? += amount;
+    // The treasury rides along as a trailing account (ignored by the token program) so the
+    // lamports it gave up are part of the CPI and the instruction stays balanced
+    let mut sync_native_ix = token_instruction::sync_native(spl_token_program_account.key, treasury_wsol_account.key)?;
+    sync_native_ix.accounts.push(solana_program::instruction::AccountMeta::new(*main_treasury_pda.key, false));
+    invoke(
+        &sync_native_ix,
+        &[treasury_wsol_account.clone(), main_treasury_pda.clone(), spl_token_program_account.clone()],
+    )?;
+    
+    let treasury_seeds: &[&[u8]] = &[MAIN_TREASURY_SEED_PREFIX, &[treasury_bump]];
+    invoke_signed(
+        &token_instruction::transfer(
+            spl_token_program_account.key,
+            treasury_wsol_account.key,
+            pool_vault.key,
+            main_treasury_pda.key,
+            &[],
+            amount,
+        )?,
+        &[
+            treasury_wsol_account.clone(),
+            pool_vault.clone(),
+            main_treasury_pda.clone(),
+            spl_token_program_account.clone(),
+        ],
+        &[treasury_seeds],
+    )?;
+    
+    pool_state_data.add_liquidity(is_token_a, amount)?;
+    pool_state_data.record_operation();
+    
+    // Buffer serialization pattern to prevent PDA corruption
+    crate::utils::serialization::serialize_to_account(&pool_state_data, pool_state_pda)?;
+    
+    if pool_state_data.lp_transfers_frozen() {
+        thaw_lp_account_if_frozen(
+            &pool_state_data,
+            pool_state_pda,
+            lp_mint,
+            treasury_lp_account,
+            spl_token_program_account,
+        )?;
+    }
+    
+    let pool_pda_seeds = &[
+        POOL_STATE_SEED_PREFIX,
+        pool_state_data.token_a_mint.as_ref(),
+        pool_state_data.token_b_mint.as_ref(),
+        &pool_state_data.original_ratio_a_numerator.to_le_bytes(),
+        &pool_state_data.original_ratio_b_denominator.to_le_bytes(),
+        &[pool_state_data.pool_authority_bump_seed],
+    ];
+    invoke_signed(
+        &token_instruction::mint_to(
+            spl_token_program_account.key,
+            lp_mint.key,
+            treasury_lp_account.key,
+            pool_state_pda.key,
+            &[],
+            amount,
+        )?,
+        &[
+            lp_mint.clone(),
+            treasury_lp_account.clone(),
+            pool_state_pda.clone(),
+            spl_token_program_account.clone(),
+        ],
+        &[pool_pda_seeds],
+    )?;
+    
+    if pool_state_data.lp_transfers_frozen() {
+        freeze_lp_account(
+            &pool_state_data,
+            pool_state_pda,
+            lp_mint,
+            treasury_lp_account,
+            spl_token_program_account,
+        )?;
+    }
+    
+    let final_lp_balance = safe_unpack_and_validate_token_account(
+        treasury_lp_account,
+        "Treasury LP Token Account",
+        Some(main_treasury_pda.key),
+        Some(&expected_lp_mint),
+        true,
+    )?.amount;
+    let lp_tokens_received = final_lp_balance.checked_sub(initial_lp_balance)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if lp_tokens_received != amount {
+        msg!("❌ Strict 1:1 violation: expected {}, received {}", amount, lp_tokens_received);
+        return Err(ProgramError::Custom(3001));
+    }
+    
+    main_treasury_state.add_autocompound(lp_tokens_received, Clock::get()?.unix_timestamp);
+    main_treasury_state.sync_balance_with_account(main_treasury_pda.lamports());
+    crate::utils::serialization::serialize_to_account(&main_treasury_state, main_treasury_pda)?;
+    
+    msg!("✅ TREASURY COMPOUNDED: {} lamports → {} LP tokens", amount, lp_tokens_received);
+    msg!("   • Treasury LP total: {}", main_treasury_state.autocompound_lp_tokens);
+    msg!("   • Treasury balance: {} lamports", main_treasury_state.total_balance);
+    
+    set_return_data(&amount.to_le_bytes());
+    
+    Ok(())
+}
+
+/// Processes WithdrawTreasuryTokens: withdraws SPL tokens held by the treasury
+/// 
+/// The SPL counterpart of `process_treasury_withdraw_fees` for tokens the treasury
+/// accrues, such as the LP tokens minted to it by `CompoundTreasury`. The tokens sit in
+/// a token account owned by the main treasury PDA, which signs the transfer. When the
+/// mint is one of the auto-compound pool's LP mints, `autocompound_lp_tokens` is reduced
+/// by the amount withdrawn.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA derivation
+/// * `token_mint` - Mint of the tokens to withdraw
+/// * `amount` - Amount to withdraw (0 = the whole balance of the treasury token account)
+/// * `accounts` - Array of accounts (7 accounts)
+/// 
+/// # Account Info
+/// The accounts must be provided in the following order:
+/// 0. **Admin Authority Signer** (signer) - Admin authority
+/// 1. **System State PDA** (readable) - For pause and admin validation
+/// 2. **Main Treasury PDA** (writable) - Owner of the treasury token account
+/// 3. **Program Data Account** (readable) - For upgrade authority fallback validation
+/// 4. **Treasury Token Account** (writable) - Token account of `token_mint` owned by the treasury PDA
+/// 5. **Destination Token Account** (writable) - Token account of `token_mint` receiving the tokens
+/// 6. **SPL Token Program Account** (readable) - Token program account
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+/// * Return data: tokens withdrawn as a little-endian `u64`
+/// 
+/// # Errors
+/// * `InsufficientFunds` - `amount` exceeds the treasury token account balance, or the balance is 0
+pub fn process_treasury_withdraw_tokens(
+    program_id: &Pubkey,
+    token_mint: Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    use solana_program::program::{invoke_signed, set_return_data};
+    use spl_token::instruction as token_instruction;
+    use crate::utils::token_validation::safe_unpack_and_validate_token_account;
+    
+    msg!("🏦 TREASURY TOKEN WITHDRAWAL: {} of mint {}", amount, token_mint);
+    
+    let admin_authority_signer = &accounts[0];       // Index 0: Admin Authority Signer
+    let system_state_pda = &accounts[1];             // Index 1: System State PDA
+    let main_treasury_pda = &accounts[2];            // Index 2: Main Treasury PDA
+    let program_data_account = &accounts[3];         // Index 3: Program Data Account
+    let treasury_token_account = &accounts[4];       // Index 4: Treasury Token Account
+    let destination_account = &accounts[5];          // Index 5: Destination Token Account
+    let spl_token_program_account = &accounts[6];    // Index 6: SPL Token Program Account
+    
+    validate_writable(main_treasury_pda, "Main treasury PDA")?;
+    validate_writable(treasury_token_account, "Treasury token account")?;
+    validate_writable(destination_account, "Destination token account")?;
+    let treasury_bump = validate_main_treasury_pda(main_treasury_pda, program_id)?;
+    
+    if *spl_token_program_account.key != spl_token::id() {
+        msg!("❌ Invalid token program: {}", spl_token_program_account.key);
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    
+    crate::utils::validation::validate_system_not_paused_secure(system_state_pda, program_id)?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let balance = safe_unpack_and_validate_token_account(
+        treasury_token_account,
+        "Treasury Token Account",
+        Some(main_treasury_pda.key),
+        Some(&token_mint),
+        false,
+    )?.amount;
+    safe_unpack_and_validate_token_account(
+        destination_account,
+        "Destination Token Account",
+        None,
+        Some(&token_mint),
+        false,
+    )?;
+    
+    let withdrawal_amount = if amount == 0 { balance } else { amount };
+    if withdrawal_amount == 0 || withdrawal_amount > balance {
+        msg!("❌ Treasury holds {} of mint {}, requested {}", balance, token_mint, withdrawal_amount);
+        return Err(PoolError::InsufficientFunds {
+            required: withdrawal_amount,
+            available: balance,
+            account: *treasury_token_account.key,
+        }.into());
+    }
+    
+    let treasury_seeds: &[&[u8]] = &[MAIN_TREASURY_SEED_PREFIX, &[treasury_bump]];
+    invoke_signed(
+        &token_instruction::transfer(
+            spl_token_program_account.key,
+            treasury_token_account.key,
+            destination_account.key,
+            main_treasury_pda.key,
+            &[],
+            withdrawal_amount,
+        )?,
+        &[
+            treasury_token_account.clone(),
+            destination_account.clone(),
+            main_treasury_pda.clone(),
+            spl_token_program_account.clone(),
+        ],
+        &[treasury_seeds],
+    )?;
+    
+    // Keep the auto-compound LP tally in step when its LP tokens leave the treasury
+    let mut main_treasury_state = MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow())?;
+    let pool = main_treasury_state.autocompound_pool;
+    if pool != Pubkey::default() {
+        let is_autocompound_lp = [LP_TOKEN_A_MINT_SEED_PREFIX, LP_TOKEN_B_MINT_SEED_PREFIX]
+            .iter()
+            .any(|seed| Pubkey::find_program_address(&[seed, pool.as_ref()], program_id).0 == token_mint);
+        if is_autocompound_lp {
+            main_treasury_state.autocompound_lp_tokens = main_treasury_state.autocompound_lp_tokens.saturating_sub(withdrawal_amount);
+            crate::utils::serialization::serialize_to_account(&main_treasury_state, main_treasury_pda)?;
+        }
+    }
+    
+    msg!("✅ Withdrew {} of mint {} to {}", withdrawal_amount, token_mint, destination_account.key);
+    msg!("   • Remaining in treasury: {}", balance - withdrawal_amount);
+    
+    set_return_data(&withdrawal_amount.to_le_bytes());
+    
+    Ok(())
+}
+
+/// Verifies the main treasury PDA address and returns its bump seed
+fn validate_main_treasury_pda(main_treasury_pda: &AccountInfo, program_id: &Pubkey) -> Result<u8, ProgramError> {
+    let (expected_main_treasury, treasury_bump) = Pubkey::find_program_address(
+        &[MAIN_TREASURY_SEED_PREFIX],
+        program_id,
+    );
+    if *main_treasury_pda.key != expected_main_treasury {
+        msg!("❌ Invalid main treasury PDA. Expected: {}, Got: {}", 
+             expected_main_treasury, main_treasury_pda.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(treasury_bump)
+}
+
+/// Processes the MigrateTreasuryState instruction
+/// 
+/// Grows a main treasury account created with an older, shorter layout to
+/// `MainTreasuryState::LEN`. The account is read with the tolerant loader, so fields the
+/// old layout lacks start at zero; the signer tops up the extra rent and
+/// `rent_exempt_minimum` is updated to the new size. An account already at full size is
+/// left unchanged. Allowed while the system is paused.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA derivation
+/// * `accounts` - Array of accounts (5 accounts)
+/// 
+/// # Account Info
+/// The accounts must be provided in the following order:
+/// 0. **Admin Authority Signer** (signer, writable) - Admin authority, pays any extra rent
+/// 1. **System Program** (readable) - For the rent top-up transfer
+/// 2. **System State PDA** (readable) - For admin validation
+/// 3. **Main Treasury PDA** (writable) - Account to migrate
+/// 4. **Program Data Account** (readable) - For upgrade authority fallback validation
+/// 
+/// # Returns
+/// * `ProgramResult` - Success or error
+pub fn process_treasury_migrate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    use solana_program::{program::invoke, system_instruction, sysvar::Sysvar};
+
+    msg!("🔧 TREASURY STATE MIGRATION");
+    
+    let admin_authority_signer = &accounts[0];       // Index 0: Admin Authority Signer
+    let system_program_account = &accounts[1];       // Index 1: System Program
+    let system_state_pda = &accounts[2];             // Index 2: System State PDA
+    let main_treasury_pda = &accounts[3];            // Index 3: Main Treasury PDA
+    let program_data_account = &accounts[4];         // Index 4: Program Data Account
+    
+    validate_writable(main_treasury_pda, "Main treasury PDA")?;
+    validate_main_treasury_pda(main_treasury_pda, program_id)?;
+    
+    use crate::utils::admin_validation::validate_admin_authority;
+    validate_admin_authority(
+        admin_authority_signer,
+        system_state_pda,
+        Some(program_data_account),
+        program_id,
+    )?;
+    
+    let mut main_treasury_state = MainTreasuryState::from_versioned_data(&main_treasury_pda.data.borrow())?;
+    
+    let old_len = main_treasury_pda.data_len();
+    if old_len >= MainTreasuryState::LEN {
+        msg!("✅ Main treasury already at current size ({} bytes)", old_len);
+        return Ok(());
+    }
+    
+    // Grow the account to the current layout, topping up rent from the signer
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(MainTreasuryState::LEN);
+    let rent_shortfall = rent_exempt_minimum.saturating_sub(main_treasury_pda.lamports());
+    if rent_shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(admin_authority_signer.key, main_treasury_pda.key, rent_shortfall),
+            &[admin_authority_signer.clone(), main_treasury_pda.clone(), system_program_account.clone()],
+        )?;
+    }
+    main_treasury_pda.realloc(MainTreasuryState::LEN, false)?;
+    
+    main_treasury_state.rent_exempt_minimum = rent_exempt_minimum;
+    main_treasury_state.sync_balance_with_account(main_treasury_pda.lamports());
+    use crate::utils::serialization::serialize_to_account;
+    serialize_to_account(&main_treasury_state, main_treasury_pda)?;
+    
+    msg!("✅ Main treasury migrated: {} → {} bytes", old_len, MainTreasuryState::LEN);
+    msg!("   • Migrated by: {}", admin_authority_signer.key);
+    
+    Ok(())
+}
@@ -14,7 +14,7 @@ use solana_program::{
     pubkey::Pubkey,
     account_info::next_account_info,
 };
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use crate::error::PoolError;
 
 // ================================================================================================
@@ -227,33 +227,59 @@ pub fn get_pool_info(accounts: &[AccountInfo]) -> ProgramResult {
 /// Provides public visibility into pool operation status and distinguishes between
 /// system-wide pause and pool-specific swap pause for user transparency.
 /// 
+/// Reports the effective status: once the clock passes `auto_unpause_at`, the pause
+/// no longer blocks anything and every operation reads as enabled, even though the
+/// pause flags stay set until the next `PausePool` or `UnpausePool`.
+/// 
 /// # Account Layout (Read-Only)
 /// 0. Pool State PDA (read-only)
 /// 
 /// # Returns
-/// * `ProgramResult` - Logs comprehensive pause status information
+/// * `ProgramResult` - Logs the pause status and sets return data to the effectively
+///   paused operations as a `u8` of `PAUSE_*` bits, followed by `auto_unpause_at` as a
+///   little-endian `i64` (0 when no timer is set)
 pub fn get_pool_pause_status(accounts: &[AccountInfo]) -> ProgramResult {
+    use solana_program::{clock::Clock, program::set_return_data, sysvar::Sysvar};
+
     // ✅ READ-ONLY OPERATION: This operation can continue during system pause
     // Users need transparency about pause status especially during system pause
     
     let pool_state_account = &accounts[0];
     let pool_state_data = PoolState::try_from_slice(&pool_state_account.data.borrow())?;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let effective_paused = if pool_state_data.auto_unpause_elapsed(current_timestamp) {
+        0
+    } else {
+        pool_state_data.paused_operations()
+    };
+    let status = |operations: u8| if effective_paused & operations != 0 { "PAUSED" } else { "ENABLED" };
     
     // Log comprehensive pause status for public visibility
     msg!("=== POOL STATUS ===");
-    msg!("Swaps: {}", if pool_state_data.swaps_paused() { "PAUSED" } else { "ENABLED" });
-    msg!("Deposits: ENABLED");  // Always enabled (only system pause affects)
-    msg!("Withdrawals: ENABLED"); // Always enabled (only system pause affects)
+    msg!("Swaps A→B: {}", status(PAUSE_SWAP_A_TO_B));
+    msg!("Swaps B→A: {}", status(PAUSE_SWAP_B_TO_A));
+    msg!("Deposits: {}", status(PAUSE_DEPOSITS));
+    msg!("Withdrawals: {}", status(PAUSE_WITHDRAWALS));
     
-    if pool_state_data.swaps_paused() {
+    if pool_state_data.paused_operations() != 0 {
         msg!("=== OWNER PAUSE ===");
-        msg!("Swaps paused by owner action");
         msg!("Control: Pool owner");
-        msg!("Note: No auto-unpause - requires manual unpause action");
+        if pool_state_data.auto_unpause_elapsed(current_timestamp) {
+            msg!("Auto-unpause: elapsed at {} - pause no longer applies", pool_state_data.auto_unpause_at);
+        } else if pool_state_data.auto_unpause_at != 0 {
+            msg!("Auto-unpause: at {}", pool_state_data.auto_unpause_at);
+        } else {
+            msg!("Note: No auto-unpause - requires manual unpause action");
+        }
     }
     
     msg!("==================");
     
+    let mut return_data = Vec::with_capacity(9);
+    return_data.push(effective_paused);
+    return_data.extend_from_slice(&pool_state_data.auto_unpause_at.to_le_bytes());
+    set_return_data(&return_data);
+    
     Ok(())
 }
 
@@ -353,6 +379,80 @@ pub fn get_fee_info(accounts: &[AccountInfo]) -> ProgramResult {
     Ok(())
 }
 
+/// **VIEW INSTRUCTION**: Returns the collected token fees of several pools at once.
+/// 
+/// Batch form of `get_fee_info` for dashboards: each account is validated the same
+/// way and reported as a `PoolFeeSummary`. Accounts that are not valid pool states
+/// are logged and skipped instead of failing the call, so the response may hold
+/// fewer entries than accounts passed.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `accounts` - Pool State PDAs (0 to `MAX_POOLS_PER_FEE_QUERY`)
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to a Borsh `Vec<PoolFeeSummary>` (length-prefixed)
+pub fn get_all_pool_fees(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    use crate::types::PoolFeeSummary;
+    use solana_program::program::set_return_data;
+
+    if accounts.len() > MAX_POOLS_PER_FEE_QUERY {
+        msg!("❌ {} pool accounts exceed the maximum of {} per call", accounts.len(), MAX_POOLS_PER_FEE_QUERY);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut fees = Vec::with_capacity(accounts.len());
+    for pool_state_account in accounts {
+        match crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_account, pool_state_account.key, program_id) {
+            Ok(pool_state) => fees.push(PoolFeeSummary::from_pool_state(*pool_state_account.key, &pool_state)),
+            Err(_) => msg!("⚠️ Skipping {}: not a valid pool state", pool_state_account.key),
+        }
+    }
+
+    msg!("📊 Fee info for {} of {} accounts", fees.len(), accounts.len());
+    set_return_data(&fees.try_to_vec()?);
+
+    Ok(())
+}
+
+/// **VIEW INSTRUCTION**: Returns ratio, fees and liquidity of several pools at once.
+/// 
+/// Batch form of `get_pool_info` so pool lists need one simulation instead of one
+/// per pool. Accounts that are not valid initialized pool states are skipped
+/// rather than failing the call; their indices are reported in `skipped`.
+/// 
+/// # Arguments
+/// * `program_id` - The program ID for PDA validation
+/// * `accounts` - Pool State PDAs (0 to `MAX_POOLS_PER_INFO_QUERY`)
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to a Borsh `MultiplePoolInfo`
+pub fn get_multiple_pool_info(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    use crate::types::{MultiplePoolInfo, PoolSummary};
+    use solana_program::program::set_return_data;
+
+    if accounts.len() > MAX_POOLS_PER_INFO_QUERY {
+        msg!("❌ {} pool accounts exceed the maximum of {} per call", accounts.len(), MAX_POOLS_PER_INFO_QUERY);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut info = MultiplePoolInfo::default();
+    for (index, pool_state_account) in accounts.iter().enumerate() {
+        match crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_account, pool_state_account.key, program_id) {
+            Ok(pool_state) => info.pools.push(PoolSummary::from_pool_state(*pool_state_account.key, &pool_state)),
+            Err(_) => {
+                msg!("⚠️ Skipping account {} ({}): not a valid pool state", index, pool_state_account.key);
+                info.skipped.push(index as u8);
+            }
+        }
+    }
+
+    msg!("📊 Pool info for {} of {} accounts", info.pools.len(), accounts.len());
+    set_return_data(&info.try_to_vec()?);
+
+    Ok(())
+}
+
 /// **VIEW INSTRUCTION**: Returns the actual SOL balance of the pool state PDA.
 /// 
 /// This function provides direct access to the pool state account's SOL balance,
@@ -384,6 +484,340 @@ pub fn get_pool_sol_balance(accounts: &[AccountInfo]) -> ProgramResult {
     Ok(())
 }
 
+/// **VIEW INSTRUCTION**: Estimates the minimum SOL balance a user needs for a swap.
+/// 
+/// New users frequently fail swaps because they hold enough tokens but not enough
+/// SOL to cover the network fee, the pool's swap contract fee, and rent for a
+/// freshly created output token account. This view sums those costs so clients
+/// can warn users before they submit a swap.
+/// 
+/// # Account Layout (Read-Only)
+/// 0. **Pool State PDA** (readable) - Pool the swap will execute against
+/// 1. **User Output Token Account** (readable) - Destination account (may not exist yet)
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to the estimated lamports (`u64`, little-endian)
+pub fn get_swap_sol_cost(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    use solana_program::{program::set_return_data, program_pack::Pack, rent::Rent, sysvar::Sysvar};
+
+    let account_info_iter = &mut accounts.iter();
+    let pool_state_account = next_account_info(account_info_iter)?;
+    let user_output_account = next_account_info(account_info_iter)?;
+
+    let pool_state = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_account, pool_state_account.key, program_id)?;
+
+    // An output account counts as existing only if it is an SPL token account with data
+    let output_account_exists = user_output_account.lamports() > 0
+        && *user_output_account.owner == spl_token::id()
+        && user_output_account.data_len() == spl_token::state::Account::LEN;
+
+    let output_account_rent = if output_account_exists {
+        0
+    } else {
+        Rent::get()?.minimum_balance(spl_token::state::Account::LEN)
+    };
+
+    let total_cost = BASE_SIGNATURE_FEE
+        .checked_add(pool_state.swap_contract_fee)
+        .and_then(|cost| cost.checked_add(output_account_rent))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    msg!("=== SWAP SOL COST ===");
+    msg!("Transaction Fee Estimate: {} lamports", BASE_SIGNATURE_FEE);
+    msg!("Swap Contract Fee: {} lamports", pool_state.swap_contract_fee);
+    msg!("Output Account Exists: {}", output_account_exists);
+    msg!("Output Account Rent: {} lamports", output_account_rent);
+    msg!("Total Required: {} lamports ({:.6} SOL)", total_cost, total_cost as f64 / 1_000_000_000.0);
+    msg!("=====================");
+
+    set_return_data(&total_cost.to_le_bytes());
+
+    Ok(())
+}
+
+/// **VIEW INSTRUCTION**: Returns the rounding surplus the pool has accumulated.
+/// 
+/// Fixed-ratio swaps floor their output, so every inexact swap leaves a few
+/// input-token units in the pool. These are tracked per token so owners can see
+/// how much "rounding dust" has built up in each vault.
+/// 
+/// # Account Layout (Read-Only)
+/// 0. **Pool State PDA** (readable) - Pool to report on
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to `[surplus_a, surplus_b]` (two `u64`, little-endian)
+pub fn get_rounding_surplus(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    let account_info_iter = &mut accounts.iter();
+    let pool_state_account = next_account_info(account_info_iter)?;
+
+    let pool_state = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_account, pool_state_account.key, program_id)?;
+
+    msg!("=== ROUNDING SURPLUS ===");
+    msg!("Token A Surplus: {} basis points", pool_state.rounding_surplus_token_a);
+    msg!("Token B Surplus: {} basis points", pool_state.rounding_surplus_token_b);
+    msg!("========================");
+
+    let mut return_data = Vec::with_capacity(16);
+    return_data.extend_from_slice(&pool_state.rounding_surplus_token_a.to_le_bytes());
+    return_data.extend_from_slice(&pool_state.rounding_surplus_token_b.to_le_bytes());
+    set_return_data(&return_data);
+
+    Ok(())
+}
+
+/// Exports a pool's configuration for backup or cross-network replication.
+/// 
+/// Returns the pool's settings as a Borsh-serialized `PortablePoolConfig`, which a
+/// client can decode and use to recreate the pool elsewhere. Runtime balances are
+/// not part of the snapshot.
+/// 
+/// # Account Layout (Read-Only)
+/// 0. **Pool State PDA** (readable) - Pool to export (must match `pool_id`)
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to the Borsh-serialized `PortablePoolConfig`
+pub fn get_pool_config(program_id: &Pubkey, pool_id: Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    let account_info_iter = &mut accounts.iter();
+    let pool_state_account = next_account_info(account_info_iter)?;
+
+    let pool_state = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_account, &pool_id, program_id)?;
+    let pool_config = crate::types::PortablePoolConfig::from(&pool_state);
+
+    msg!("=== POOL CONFIG ===");
+    msg!("Pool: {}", pool_id);
+    msg!("Ratio: {} : {}", pool_config.ratio_a_numerator, pool_config.ratio_b_denominator);
+    msg!("Flags: 0b{:08b}", pool_config.flags);
+    msg!("Fees: {} liquidity / {} swap lamports", pool_config.contract_liquidity_fee, pool_config.swap_contract_fee);
+    msg!("===================");
+
+    set_return_data(&pool_config.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Reports which of two mints a pool would store as Token A and which as Token B.
+/// 
+/// Applies the same lexicographic rule as pool creation, so clients can order a
+/// pair before the pool exists. Reads no accounts.
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to the Borsh-serialized `CanonicalOrdering`,
+///   or `InvalidArgument` if both mints are the same
+pub fn get_canonical_ordering(mint_x: Pubkey, mint_y: Pubkey) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    if mint_x == mint_y {
+        msg!("❌ Token A and Token B cannot be the same mint: {}", mint_x);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let ordering = crate::types::CanonicalOrdering::from_mints(mint_x, mint_y);
+
+    msg!("=== CANONICAL ORDERING ===");
+    msg!("Token A: {}", ordering.token_a_mint);
+    msg!("Token B: {}", ordering.token_b_mint);
+    msg!("==========================");
+
+    set_return_data(&ordering.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Returns the canonical instruction data of a `Swap` with the given arguments.
+/// 
+/// Lets hardware wallet integrations compare the bytes they are about to sign with
+/// the program's own encoding. Reads no accounts and validates nothing about the pool.
+/// Any expiry selects `SwapWithExpiry`; without one the plain `Swap` layout is returned.
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to the Borsh-serialized `PoolInstruction::Swap`
+///   or `PoolInstruction::SwapWithExpiry`
+pub fn encode_swap(
+    input_token_mint: Pubkey,
+    amount_in: u64,
+    expected_amount_out: u64,
+    pool_id: Pubkey,
+    deadline: Option<i64>,
+    not_valid_after_slot: Option<u64>,
+) -> ProgramResult {
+    use crate::types::instructions::PoolInstruction;
+    use solana_program::program::set_return_data;
+
+    let swap_data = if deadline.is_none() && not_valid_after_slot.is_none() {
+        PoolInstruction::Swap {
+            input_token_mint,
+            amount_in,
+            expected_amount_out,
+            pool_id,
+        }
+    } else {
+        PoolInstruction::SwapWithExpiry {
+            input_token_mint,
+            amount_in,
+            expected_amount_out,
+            pool_id,
+            deadline,
+            not_valid_after_slot,
+        }
+    }.try_to_vec()?;
+
+    msg!("Encoded Swap of {} {} on pool {}: {} bytes", amount_in, input_token_mint, pool_id, swap_data.len());
+    set_return_data(&swap_data);
+
+    Ok(())
+}
+
+/// Reports whether a pool account is uninitialized, active or paused.
+/// 
+/// An account with no lamports or no data is reported as `Uninitialized` instead of
+/// failing, so clients can probe a derived pool address before the pool exists.
+/// 
+/// # Account Layout (Read-Only)
+/// 0. **Pool State PDA** (readable) - Pool to inspect (may not exist yet)
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to the Borsh-serialized `PoolInitState`
+pub fn get_init_state(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    use crate::types::PoolInitState;
+    use solana_program::{clock::Clock, program::set_return_data, sysvar::Sysvar};
+
+    let account_info_iter = &mut accounts.iter();
+    let pool_state_account = next_account_info(account_info_iter)?;
+
+    let init_state = if pool_state_account.lamports() == 0 || pool_state_account.data_is_empty() {
+        PoolInitState::Uninitialized
+    } else {
+        let pool_state = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_account, pool_state_account.key, program_id)?;
+        PoolInitState::from_pool_state(&pool_state, Clock::get()?.unix_timestamp)
+    };
+
+    msg!("Pool {} init state: {:?}", pool_state_account.key, init_state);
+    set_return_data(&init_state.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Reports a pool's age, time since its last swap, whether it is dormant and how
+/// many state-changing instructions it has processed.
+/// 
+/// Surfaces inactive pools for cleanup: a pool is dormant when it has not swapped
+/// within `POOL_DORMANCY_WINDOW_SECONDS`.
+/// 
+/// # Account Layout (Read-Only)
+/// 0. **Pool State PDA** (readable) - Pool to inspect
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to the Borsh-serialized `PoolActivity`
+pub fn get_pool_activity(program_id: &Pubkey, accounts: &[AccountInfo], pool_id: Pubkey) -> ProgramResult {
+    use crate::types::PoolActivity;
+    use solana_program::{clock::Clock, program::set_return_data, sysvar::Sysvar};
+
+    let account_info_iter = &mut accounts.iter();
+    let pool_state_account = next_account_info(account_info_iter)?;
+
+    let pool_state = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_account, &pool_id, program_id)?;
+    let activity = PoolActivity::from_pool_state(&pool_state, Clock::get()?.unix_timestamp);
+
+    msg!("Pool {} activity: age {}s, last swap {}s ago, dormant: {}, operations: {}",
+         pool_id, activity.age_seconds, activity.seconds_since_last_swap, activity.dormant, activity.total_operations);
+    set_return_data(&activity.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Checks a pool against an expected configuration hash for deployment verification.
+/// 
+/// Compares `PoolState::config_hash` (mints, original ratio, vaults and LP mints) with
+/// `expected_hash`. A mismatch is reported, not raised, so CI can read the answer
+/// from a simulation.
+/// 
+/// # Account Layout (Read-Only)
+/// 0. **Pool State PDA** (readable) - Pool to verify (must match `pool_id`)
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to a Borsh `bool`, true when the hashes match
+pub fn verify_pool_config(program_id: &Pubkey, accounts: &[AccountInfo], pool_id: Pubkey, expected_hash: [u8; 32]) -> ProgramResult {
+    use solana_program::program::set_return_data;
+
+    let account_info_iter = &mut accounts.iter();
+    let pool_state_account = next_account_info(account_info_iter)?;
+
+    let pool_state = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_account, &pool_id, program_id)?;
+    let config_hash = pool_state.config_hash();
+    let matches = config_hash == expected_hash;
+
+    msg!("Pool {} config hash {} (expected {}): {}",
+         pool_id,
+         solana_program::hash::Hash::new_from_array(config_hash),
+         solana_program::hash::Hash::new_from_array(expected_hash),
+         if matches { "MATCH" } else { "MISMATCH" });
+    set_return_data(&matches.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Reports a pool's swap contract fees for each of the last `FEE_HISTORY_DAYS` UTC days.
+/// 
+/// # Account Layout (Read-Only)
+/// 0. **Pool State PDA** (readable) - Pool to inspect
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to the Borsh-serialized `FeeHistory`
+pub fn get_fee_history(program_id: &Pubkey, accounts: &[AccountInfo], pool_id: Pubkey) -> ProgramResult {
+    use crate::types::FeeHistory;
+    use solana_program::{clock::Clock, program::set_return_data, sysvar::Sysvar};
+
+    let account_info_iter = &mut accounts.iter();
+    let pool_state_account = next_account_info(account_info_iter)?;
+
+    let pool_state = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_account, &pool_id, program_id)?;
+    let history = FeeHistory::from_pool_state(&pool_state, Clock::get()?.unix_timestamp);
+
+    msg!("Pool {} fee history from day {}: A {:?}, B {:?}",
+         pool_id, history.day_start, history.fees_token_a, history.fees_token_b);
+    set_return_data(&history.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Dumps a pool's complete state for debugging.
+/// 
+/// Returns the whole `PoolState` in one call instead of piecing it together from
+/// `GetPoolInfo`, `GetLiquidityInfo` and `GetFeeInfo`. The first byte of the return
+/// data is the layout version of the bytes that follow (`POOL_STATE_SCHEMA_VERSION`),
+/// so clients can pick the matching decoder when the layout changes. A dump that
+/// would exceed `MAX_RETURN_DATA` is truncated, which is logged.
+/// 
+/// # Account Layout (Read-Only)
+/// 0. **Pool State PDA** (readable) - Pool to dump
+/// 
+/// # Returns
+/// * `ProgramResult` - Sets return data to `[layout version: u8] ++ Borsh-serialized PoolState`
+pub fn get_pool_state_raw(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    use solana_program::program::{set_return_data, MAX_RETURN_DATA};
+
+    let account_info_iter = &mut accounts.iter();
+    let pool_state_account = next_account_info(account_info_iter)?;
+
+    let pool_state = crate::utils::validation::validate_and_deserialize_pool_state_secure(pool_state_account, pool_state_account.key, program_id)?;
+
+    let mut dump = vec![crate::constants::POOL_STATE_SCHEMA_VERSION as u8];
+    dump.extend_from_slice(&pool_state.try_to_vec()?);
+    if dump.len() > MAX_RETURN_DATA {
+        msg!("⚠️ Pool state dump truncated from {} to {} bytes", dump.len(), MAX_RETURN_DATA);
+        dump.truncate(MAX_RETURN_DATA);
+    }
+
+    msg!("Pool {} state dump: layout version {}, {} bytes", pool_state_account.key, dump[0], dump.len());
+    set_return_data(&dump);
+
+    Ok(())
+}
+
 /// Validates that an account is a signer.
 pub fn validate_signer(account: &AccountInfo, context: &str) -> ProgramResult {
     if !account.is_signer {
@@ -415,8 +849,10 @@ pub fn validate_non_zero_amount(amount: u64, context: &str) -> ProgramResult {
 /// **PHASE 1 UPDATE**: Pool existence = initialization status
 ///
 /// Validates that liquidity operations are not paused.
+/// An elapsed auto-unpause timer lifts the pause without clearing the flag.
 pub fn validate_liquidity_not_paused(pool_state: &PoolState) -> ProgramResult {
-    if pool_state.liquidity_paused() {
+    use solana_program::{clock::Clock, sysvar::Sysvar};
+    if pool_state.liquidity_paused() && !pool_state.auto_unpause_elapsed(Clock::get()?.unix_timestamp) {
         msg!("❌ LIQUIDITY BLOCKED: Pool liquidity operations are currently paused");
         msg!("   • Pool owner has paused liquidity deposits and withdrawals");
         msg!("   • Swaps may still be available if not separately paused");
@@ -426,4 +862,28 @@ pub fn validate_liquidity_not_paused(pool_state: &PoolState) -> ProgramResult {
     Ok(())
 }
 
+/// Validates that deposits are not paused, either with the rest of liquidity or on their own
+pub fn validate_deposits_not_paused(pool_state: &PoolState) -> ProgramResult {
+    use solana_program::{clock::Clock, sysvar::Sysvar};
+    validate_liquidity_not_paused(pool_state)?;
+    if pool_state.operation_paused(crate::constants::PAUSE_DEPOSITS, Clock::get()?.unix_timestamp) {
+        msg!("❌ DEPOSIT BLOCKED: Pool deposits are currently paused");
+        msg!("   • Withdrawals may still be available if not separately paused");
+        return Err(PoolError::PoolLiquidityPaused.into());
+    }
+    Ok(())
+}
+
+/// Validates that withdrawals are not paused, either with the rest of liquidity or on their own
+pub fn validate_withdrawals_not_paused(pool_state: &PoolState) -> ProgramResult {
+    use solana_program::{clock::Clock, sysvar::Sysvar};
+    validate_liquidity_not_paused(pool_state)?;
+    if pool_state.operation_paused(crate::constants::PAUSE_WITHDRAWALS, Clock::get()?.unix_timestamp) {
+        msg!("❌ WITHDRAWAL BLOCKED: Pool withdrawals are currently paused");
+        msg!("   • Deposits may still be available if not separately paused");
+        return Err(PoolError::PoolLiquidityPaused.into());
+    }
+    Ok(())
+}
+
  
\ No newline at end of file
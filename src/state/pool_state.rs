@@ -8,6 +8,35 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
+/// Which swap instructions a pool accepts.
+/// 
+/// Exact-in swaps (`Swap`, `SwapPartial`, `SwapRoute`, the swap leg of `WithdrawAndSwap`)
+/// spend a given input; exact-out swaps (`SwapExactOut`) receive a given output for at
+/// most a maximum input. `Both` is variant 0 so older accounts, whose missing bytes are
+/// read as zeros, keep accepting every swap.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SwapMode {
+    /// Exact-in and exact-out swaps are both allowed
+    #[default]
+    Both,
+    /// Only exact-in swaps are allowed
+    ExactInOnly,
+    /// Only exact-out swaps are allowed
+    ExactOutOnly,
+}
+
+impl SwapMode {
+    /// Checks whether exact-in swaps are allowed
+    pub fn allows_exact_in(&self) -> bool {
+        *self != SwapMode::ExactOutOnly
+    }
+    
+    /// Checks whether exact-out swaps are allowed
+    pub fn allows_exact_out(&self) -> bool {
+        *self != SwapMode::ExactInOnly
+    }
+}
+
 /// Main pool state containing all configuration and runtime data.
 /// 
 /// **PHASE 1: DISTRIBUTED COLLECTION ARCHITECTURE**
@@ -141,6 +170,7 @@ pub struct PoolState {
     pub max_swap_amount: u64,
     
     /// Minimum amount required for swap operations (helps prevent dust attacks)
+    /// Set with `SetMinSwapAmount`; 0 = no minimum
     pub min_swap_amount: u64,
     
     /// Maximum amount allowed for single deposit (0 = no limit)
@@ -157,9 +187,118 @@ pub struct PoolState {
     /// Minimum amount required for withdrawals
     pub min_withdrawal_amount: u64,
     
-    /// Reserved space for future pool-specific configuration
-    /// Allows adding new fields without breaking existing pools
-    pub _reserved: [u64; 4],
+    // **NEW: ROUNDING SURPLUS TRACKING** (carved out of reserved space)
+    /// Whole Token A units kept by the pool from truncated A → B swap outputs
+    pub rounding_surplus_token_a: u64,
+    
+    /// Whole Token B units kept by the pool from truncated B → A swap outputs
+    pub rounding_surplus_token_b: u64,
+    
+    // **NEW: LP SUPPLY CAP** (carved out of reserved space)
+    /// Maximum supply of each LP token mint (0 = no limit)
+    /// Deposits that would mint past this cap are rejected
+    pub max_lp_supply: u64,
+    
+    // **NEW: AUTO-UNPAUSE TIMER** (last slot of the former reserved space)
+    /// Unix timestamp after which pause flags no longer block operations (0 = no timer)
+    /// Set when the pool is paused; cleared by a pause without a timer or by unpausing
+    pub auto_unpause_at: i64,
+    
+    // **NEW: RESERVE SAFETY FLOORS** (appended, grows the account by 16 bytes)
+    /// Minimum Token A liquidity a swap may leave in the pool (0 = no floor)
+    pub reserve_safety_floor_a: u64,
+    
+    /// Minimum Token B liquidity a swap may leave in the pool (0 = no floor)
+    pub reserve_safety_floor_b: u64,
+    
+    // **NEW: SCHEMA VERSION** (appended, grows the account by 2 bytes)
+    /// Layout version of this account (see `POOL_STATE_SCHEMA_VERSION`)
+    /// Accounts written before this field existed are version 1 and are
+    /// upgraded in place by `MigratePoolState`
+    pub schema_version: u16,
+    
+    // **NEW: MINIMUM RESERVE** (appended with schema version 3, grows the account by 8 bytes)
+    /// Minimum liquidity a withdrawal must leave in the vault it draws from, in that
+    /// token's base units (0 = no minimum). Swaps are governed by the reserve safety floors
+    pub minimum_reserve: u64,
+    
+    // **NEW: SWAP MODE** (appended with schema version 4, grows the account by 1 byte)
+    /// Which swap instructions the pool accepts (exact-in, exact-out or both)
+    pub swap_mode: SwapMode,
+    
+    // **NEW: ACTIVITY TIMESTAMPS** (appended with schema version 5, grows the account by 16 bytes)
+    /// Unix timestamp of pool creation (0 = created before schema version 5)
+    pub created_at: i64,
+    /// Unix timestamp of the most recent swap (0 = no swap recorded yet)
+    pub last_swap_at: i64,
+    
+    // **NEW: SWAP FEE BAND** (appended with schema version 6, grows the account by 16 bytes)
+    /// Lowest swap contract fee `UpdatePoolFees` may set, in lamports (owner-set, 0 = no lower bound)
+    pub min_allowed_fee: u64,
+    /// Highest swap contract fee `UpdatePoolFees` may set, in lamports (owner-set, 0 = no upper bound)
+    pub max_allowed_fee: u64,
+    
+    // **NEW: LP TOKEN DECIMALS** (appended with schema version 7, grows the account by 2 bytes)
+    /// Decimals of the LP Token A mint, copied from the Token A mint at pool creation
+    /// (0 on accounts migrated from an older layout; read the LP mint for those)
+    pub lp_token_a_decimals: u8,
+    /// Decimals of the LP Token B mint, copied from the Token B mint at pool creation
+    /// (0 on accounts migrated from an older layout; read the LP mint for those)
+    pub lp_token_b_decimals: u8,
+    
+    // **NEW: PARTIAL PAUSES** (appended with schema version 8, grows the account by 1 byte)
+    /// Individually paused operations (`PAUSE_SWAP_A_TO_B`, `PAUSE_SWAP_B_TO_A`,
+    /// `PAUSE_DEPOSITS`, `PAUSE_WITHDRAWALS`) whose counterpart is still open; a fully
+    /// paused category is kept in the liquidity/swaps pause flags instead
+    pub partial_pause_flags: u8,
+    
+    // **NEW: DAILY FEE HISTORY** (appended with schema version 9, grows the account by 120 bytes)
+    /// Swap contract fees (lamports) paid by swaps with Token A input, one slot per UTC day;
+    /// a ring indexed by day number modulo `FEE_HISTORY_DAYS`
+    pub daily_fee_snapshot_token_a: [u64; crate::constants::FEE_HISTORY_DAYS],
+    /// Swap contract fees (lamports) paid by swaps with Token B input, same ring layout
+    pub daily_fee_snapshot_token_b: [u64; crate::constants::FEE_HISTORY_DAYS],
+    /// Start (UTC midnight) of the newest day recorded in the ring (0 = nothing recorded)
+    pub snapshot_timestamp: i64,
+    
+    // **NEW: EVENT TOGGLE** (appended with schema version 10, grows the account by 1 byte)
+    /// Whether swaps, deposits and withdrawals emit their structured events (on by default;
+    /// accounts migrated from an older layout keep emitting)
+    pub emit_events: bool,
+    
+    // **NEW: OWNER TRANSFER** (appended with schema version 11, grows the account by 40 bytes)
+    /// Owner nominated by `InitiateOwnerTransfer`, who may accept once the delay has passed
+    /// (`Pubkey::default()` = no transfer pending)
+    pub pending_owner: Pubkey,
+    /// Unix timestamp of the pending transfer request (0 = no transfer pending)
+    pub owner_transfer_requested_at: i64,
+    
+    // **NEW: OPERATION COUNTER** (appended with schema version 12, grows the account by 8 bytes)
+    /// Number of state-changing instructions applied to this pool (saturating; accounts
+    /// migrated from an older layout start counting at 0)
+    pub total_operations: u64,
+    
+    // **NEW: LIFETIME COUNTERS** (appended with schema version 13, grows the account by 48 bytes)
+    /// Total Token A ever swapped into the pool (saturates at `u128::MAX`)
+    pub cumulative_volume_token_a: u128,
+    /// Total Token B ever swapped into the pool (saturates at `u128::MAX`)
+    pub cumulative_volume_token_b: u128,
+    /// Total SOL fees (lamports) ever charged by the pool; unlike `total_sol_fees_collected`
+    /// it never wraps back to a u64 ceiling (saturates at `u128::MAX`)
+    pub lifetime_fees: u128,
+    
+    // **NEW: REQUESTED RATIO** (appended with schema version 14, grows the account by 16 bytes)
+    /// `ratio_a_numerator` as requested at creation, before GCD reduction; kept for
+    /// auditing and as the pool state PDA seed (migrated pools copy the stored ratio)
+    pub original_ratio_a_numerator: u64,
+    /// `ratio_b_denominator` as requested at creation, before GCD reduction; kept for
+    /// auditing and as the pool state PDA seed (migrated pools copy the stored ratio)
+    pub original_ratio_b_denominator: u64,
+    
+    // **NEW: FEE RECIPIENT** (appended with schema version 15, grows the account by 32 bytes)
+    /// Account that receives this pool's consolidated SOL fees; `Pubkey::default()` means
+    /// the MainTreasury (changed only through `ChangeFeeRecipientAndConsolidate`)
+    pub fee_recipient: Pubkey,
 }
 
 
@@ -211,7 +350,71 @@ impl PoolState {
         8 +  // min_deposit_amount
         8 +  // max_withdrawal_amount
         8 +  // min_withdrawal_amount
-        32   // _reserved [u64; 4]
+        
+        // **NEW: ROUNDING SURPLUS TRACKING** (+16 bytes, taken from _reserved)
+        8 +  // rounding_surplus_token_a
+        8 +  // rounding_surplus_token_b
+        
+        // **NEW: LP SUPPLY CAP** (+8 bytes, taken from _reserved)
+        8 +  // max_lp_supply
+        
+        // **NEW: AUTO-UNPAUSE TIMER** (+8 bytes, last of _reserved)
+        8 +  // auto_unpause_at
+        
+        // **NEW: RESERVE SAFETY FLOORS** (+16 bytes, appended)
+        8 +  // reserve_safety_floor_a
+        8 +  // reserve_safety_floor_b
+        
+        // **NEW: SCHEMA VERSION** (+2 bytes, appended)
+        2 +  // schema_version
+        
+        // **NEW: MINIMUM RESERVE** (+8 bytes, appended in schema version 3)
+        8 +  // minimum_reserve
+        
+        // **NEW: SWAP MODE** (+1 byte, appended in schema version 4)
+        1 +  // swap_mode
+        
+        // **NEW: ACTIVITY TIMESTAMPS** (+16 bytes, appended in schema version 5)
+        8 +  // created_at
+        8 +  // last_swap_at
+        
+        // **NEW: SWAP FEE BAND** (+16 bytes, appended in schema version 6)
+        8 +  // min_allowed_fee
+        8 +  // max_allowed_fee
+        
+        // **NEW: LP TOKEN DECIMALS** (+2 bytes, appended in schema version 7)
+        1 +  // lp_token_a_decimals
+        1 +  // lp_token_b_decimals
+        
+        // **NEW: PARTIAL PAUSES** (+1 byte, appended in schema version 8)
+        1 +  // partial_pause_flags
+        
+        // **NEW: DAILY FEE HISTORY** (+120 bytes, appended in schema version 9)
+        8 * crate::constants::FEE_HISTORY_DAYS + // daily_fee_snapshot_token_a
+        8 * crate::constants::FEE_HISTORY_DAYS + // daily_fee_snapshot_token_b
+        8 +  // snapshot_timestamp
+        
+        // **NEW: EVENT TOGGLE** (+1 byte, appended in schema version 10)
+        1 +  // emit_events
+        
+        // **NEW: OWNER TRANSFER** (+40 bytes, appended in schema version 11)
+        32 + // pending_owner
+        8 +  // owner_transfer_requested_at
+        
+        // **NEW: OPERATION COUNTER** (+8 bytes, appended in schema version 12)
+        8 +  // total_operations
+        
+        // **NEW: LIFETIME COUNTERS** (+48 bytes, appended in schema version 13)
+        16 + // cumulative_volume_token_a
+        16 + // cumulative_volume_token_b
+        16 + // lifetime_fees
+        
+        // **NEW: REQUESTED RATIO** (+16 bytes, appended in schema version 14)
+        8 +  // original_ratio_a_numerator
+        8 +  // original_ratio_b_denominator
+        
+        // **NEW: FEE RECIPIENT** (+32 bytes, appended in schema version 15)
+        32   // fee_recipient
         
         // **REMOVED FIELDS** (-57 bytes):
         // - is_initialized: bool (1 byte) - Pool existence = initialization
@@ -222,6 +425,203 @@ impl PoolState {
         // **NET ADDITION: +15 bytes per pool** (72 added - 57 removed)
     }
     
+    /// Size of a version 1 pool state account (every field before `schema_version`)
+    pub fn get_v1_packed_len() -> usize {
+        Self::get_v2_packed_len() - 2
+    }
+    
+    /// Size of a version 2 pool state account (every field up to `schema_version`)
+    pub fn get_v2_packed_len() -> usize {
+        Self::get_v3_packed_len() - 8
+    }
+    
+    /// Size of a version 3 pool state account (every field up to `minimum_reserve`)
+    pub fn get_v3_packed_len() -> usize {
+        Self::get_v4_packed_len() - 1
+    }
+    
+    /// Size of a version 4 pool state account (every field up to `swap_mode`)
+    pub fn get_v4_packed_len() -> usize {
+        Self::get_v5_packed_len() - 16
+    }
+    
+    /// Size of a version 5 pool state account (every field up to `last_swap_at`)
+    pub fn get_v5_packed_len() -> usize {
+        Self::get_v6_packed_len() - 16
+    }
+    
+    /// Size of a version 6 pool state account (every field up to `max_allowed_fee`)
+    pub fn get_v6_packed_len() -> usize {
+        Self::get_v7_packed_len() - 2
+    }
+    
+    /// Size of a version 7 pool state account (every field up to `lp_token_b_decimals`)
+    pub fn get_v7_packed_len() -> usize {
+        Self::get_v8_packed_len() - 1
+    }
+    
+    /// Size of a version 8 pool state account (every field up to `partial_pause_flags`)
+    pub fn get_v8_packed_len() -> usize {
+        Self::get_v9_packed_len() - (16 * crate::constants::FEE_HISTORY_DAYS + 8)
+    }
+    
+    /// Size of a version 9 pool state account (every field up to `snapshot_timestamp`)
+    pub fn get_v9_packed_len() -> usize {
+        Self::get_v10_packed_len() - 1
+    }
+    
+    /// Size of a version 10 pool state account (every field up to `emit_events`)
+    pub fn get_v10_packed_len() -> usize {
+        Self::get_v11_packed_len() - 40
+    }
+    
+    /// Size of a version 11 pool state account (every field up to `owner_transfer_requested_at`)
+    pub fn get_v11_packed_len() -> usize {
+        Self::get_v12_packed_len() - 8
+    }
+    
+    /// Size of a version 12 pool state account (every field up to `total_operations`)
+    pub fn get_v12_packed_len() -> usize {
+        Self::get_v13_packed_len() - 48
+    }
+    
+    /// Size of a version 13 pool state account (every field up to `lifetime_fees`)
+    pub fn get_v13_packed_len() -> usize {
+        Self::get_v14_packed_len() - 16
+    }
+    
+    /// Size of a version 14 pool state account (every field up to `original_ratio_b_denominator`)
+    pub fn get_v14_packed_len() -> usize {
+        Self::get_packed_len() - 32
+    }
+    
+    /// Deserializes a pool state account written with any supported layout version
+    /// 
+    /// Older accounts are a few trailing fields short of the current layout, so the
+    /// missing bytes are read as zeros, except `emit_events`, which stays on for pools
+    /// that predate the toggle, and the requested ratio, which older pools never reduced
+    /// and so copy from the stored ratio. Version 2 to 14 accounts carry their own version;
+    /// version 1 accounts predate the field and are reported as version 1. The
+    /// returned state keeps the account's stored version; callers bump it when they
+    /// write the account back in the current layout.
+    /// 
+    /// # Arguments
+    /// * `data` - Raw pool state account data
+    /// 
+    /// # Returns
+    /// * `Result<PoolState, ProgramError>` - State mapped onto the current layout
+    pub fn from_versioned_data(data: &[u8]) -> Result<Self, solana_program::program_error::ProgramError> {
+        use solana_program::program_error::ProgramError;
+        
+        if data.len() >= Self::get_packed_len() {
+            return Self::deserialize(&mut &data[..]).map_err(|_| ProgramError::InvalidAccountData);
+        }
+        if data.len() != Self::get_v14_packed_len()
+            && data.len() != Self::get_v13_packed_len()
+            && data.len() != Self::get_v12_packed_len()
+            && data.len() != Self::get_v11_packed_len()
+            && data.len() != Self::get_v10_packed_len()
+            && data.len() != Self::get_v9_packed_len()
+            && data.len() != Self::get_v8_packed_len()
+            && data.len() != Self::get_v7_packed_len()
+            && data.len() != Self::get_v6_packed_len()
+            && data.len() != Self::get_v5_packed_len()
+            && data.len() != Self::get_v4_packed_len()
+            && data.len() != Self::get_v3_packed_len()
+            && data.len() != Self::get_v2_packed_len()
+            && data.len() != Self::get_v1_packed_len()
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        
+        let mut current_layout = data.to_vec();
+        current_layout.resize(Self::get_packed_len(), 0);
+        let mut pool_state = Self::deserialize(&mut &current_layout[..])
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if data.len() == Self::get_v1_packed_len() {
+            pool_state.schema_version = 1;
+        }
+        // Every layout before version 10 emitted events unconditionally
+        if data.len() < Self::get_v10_packed_len() {
+            pool_state.emit_events = true;
+        }
+        // Every layout before version 14 stored the requested ratio, which is the PDA seed
+        if data.len() < Self::get_v14_packed_len() {
+            pool_state.original_ratio_a_numerator = pool_state.ratio_a_numerator;
+            pool_state.original_ratio_b_denominator = pool_state.ratio_b_denominator;
+        }
+        Ok(pool_state)
+    }
+    
+    /// Checks whether an owner transfer is waiting to be accepted
+    pub fn owner_transfer_pending(&self) -> bool {
+        self.pending_owner != Pubkey::default()
+    }
+    
+    /// Clears any pending owner transfer
+    pub fn clear_pending_owner_transfer(&mut self) {
+        self.pending_owner = Pubkey::default();
+        self.owner_transfer_requested_at = 0;
+    }
+    
+    /// Counts one state-changing instruction against this pool
+    /// 
+    /// Saturates at `u64::MAX` rather than failing the operation.
+    pub fn record_operation(&mut self) {
+        self.total_operations = self.total_operations.saturating_add(1);
+    }
+    
+    /// Adds a swap's input amount to the lifetime volume of the input token
+    /// 
+    /// Saturates at `u128::MAX`, which the u64 amounts cannot realistically reach;
+    /// a saturated counter simply stops growing and never fails the swap.
+    pub fn record_swap_volume(&mut self, input_is_token_a: bool, amount_in: u64) {
+        let volume = if input_is_token_a {
+            &mut self.cumulative_volume_token_a
+        } else {
+            &mut self.cumulative_volume_token_b
+        };
+        *volume = volume.saturating_add(amount_in as u128);
+    }
+    
+    /// SHA-256 of the pool's immutable configuration, for deployment verification
+    /// 
+    /// Hashes, in order: Token A mint, Token B mint, `original_ratio_a_numerator` and
+    /// `original_ratio_b_denominator` (8 bytes little-endian each), Token A vault, Token B
+    /// vault, LP Token A mint and LP Token B mint. The ratio is hashed as requested at
+    /// creation, the same values that seed the pool PDA, so 4:2 and 2:1 pools (distinct
+    /// pools that store the same reduced ratio) hash differently. Settings that can change
+    /// after creation (owner, flags, fees, limits) are left out so the hash never drifts.
+    pub fn config_hash(&self) -> [u8; 32] {
+        solana_program::hash::hashv(&[
+            self.token_a_mint.as_ref(),
+            self.token_b_mint.as_ref(),
+            &self.original_ratio_a_numerator.to_le_bytes(),
+            &self.original_ratio_b_denominator.to_le_bytes(),
+            self.token_a_vault.as_ref(),
+            self.token_b_vault.as_ref(),
+            self.lp_token_a_mint.as_ref(),
+            self.lp_token_b_mint.as_ref(),
+        ]).to_bytes()
+    }
+    
+    /// Credits `amount` to the Token A or Token B liquidity counter
+    /// 
+    /// Liquidity mirrors real vault balances, so unlike the cumulative fee counters it
+    /// never saturates: an overflow fails with `MathOverflow` instead.
+    pub fn add_liquidity(&mut self, token_a: bool, amount: u64) -> Result<(), crate::error::PoolError> {
+        let (counter, name) = if token_a {
+            (&mut self.total_token_a_liquidity, "total_token_a_liquidity")
+        } else {
+            (&mut self.total_token_b_liquidity, "total_token_b_liquidity")
+        };
+        *counter = counter.checked_add(amount).ok_or_else(|| crate::error::PoolError::MathOverflow {
+            counter: name.to_string(),
+            amount,
+        })?;
+        Ok(())
+    }
+    
     // **NEW: BITWISE FLAG HELPER METHODS**
     
     /// Checks if one-to-many ratio is configured
@@ -297,6 +697,117 @@ impl PoolState {
         }
     }
     
+    /// Every paused operation as `PAUSE_SWAP_A_TO_B | PAUSE_SWAP_B_TO_A | PAUSE_DEPOSITS | PAUSE_WITHDRAWALS` bits
+    pub fn paused_operations(&self) -> u8 {
+        use crate::constants::{PAUSE_DEPOSITS, PAUSE_SWAP_A_TO_B, PAUSE_SWAP_B_TO_A, PAUSE_WITHDRAWALS};
+        let mut operations = self.partial_pause_flags & (PAUSE_SWAP_A_TO_B | PAUSE_SWAP_B_TO_A | PAUSE_DEPOSITS | PAUSE_WITHDRAWALS);
+        if self.liquidity_paused() {
+            operations |= PAUSE_DEPOSITS | PAUSE_WITHDRAWALS;
+        }
+        if self.swaps_paused() {
+            operations |= PAUSE_SWAP_A_TO_B | PAUSE_SWAP_B_TO_A;
+        }
+        operations
+    }
+    
+    /// Stores a set of paused operations (see `paused_operations`)
+    /// 
+    /// A category with both halves paused is stored in the liquidity/swaps pause
+    /// flags, so consolidation eligibility and older clients see it as before; only a
+    /// half-paused category lands in `partial_pause_flags`.
+    pub fn set_paused_operations(&mut self, operations: u8) {
+        use crate::constants::{PAUSE_DEPOSITS, PAUSE_SWAP_A_TO_B, PAUSE_SWAP_B_TO_A, PAUSE_WITHDRAWALS};
+        let liquidity = PAUSE_DEPOSITS | PAUSE_WITHDRAWALS;
+        let swaps = PAUSE_SWAP_A_TO_B | PAUSE_SWAP_B_TO_A;
+        let liquidity_paused = operations & liquidity == liquidity;
+        let swaps_paused = operations & swaps == swaps;
+        self.set_liquidity_paused(liquidity_paused);
+        self.set_swaps_paused(swaps_paused);
+        
+        let mut partial = operations & (liquidity | swaps);
+        if liquidity_paused {
+            partial &= !liquidity;
+        }
+        if swaps_paused {
+            partial &= !swaps;
+        }
+        self.partial_pause_flags = partial;
+    }
+    
+    /// Checks whether any of the `operations` bits is paused at `current_timestamp`
+    /// (an elapsed auto-unpause timer lifts every pause)
+    pub fn operation_paused(&self, operations: u8, current_timestamp: i64) -> bool {
+        self.paused_operations() & operations != 0 && !self.auto_unpause_elapsed(current_timestamp)
+    }
+    
+    /// Adds a swap's contract fee to the fee history slot of the UTC day containing `timestamp`
+    /// 
+    /// Crossing into a new day freezes the previous days and clears the slots of the
+    /// new day (and of any skipped days) before the fee is added, so the ring never
+    /// holds more than `FEE_HISTORY_DAYS` days.
+    pub fn record_daily_swap_fee(&mut self, input_is_token_a: bool, fee_lamports: u64, timestamp: i64) {
+        use crate::constants::{FEE_HISTORY_DAYS, SECONDS_PER_DAY};
+        let day = timestamp.div_euclid(SECONDS_PER_DAY);
+        let newest_day = self.snapshot_timestamp.div_euclid(SECONDS_PER_DAY);
+        
+        if self.snapshot_timestamp == 0 || day > newest_day {
+            let first_new_day = if self.snapshot_timestamp == 0 { day } else { (newest_day + 1).max(day - FEE_HISTORY_DAYS as i64 + 1) };
+            for new_day in first_new_day..=day {
+                let slot = new_day.rem_euclid(FEE_HISTORY_DAYS as i64) as usize;
+                self.daily_fee_snapshot_token_a[slot] = 0;
+                self.daily_fee_snapshot_token_b[slot] = 0;
+            }
+            self.snapshot_timestamp = day * SECONDS_PER_DAY;
+        }
+        
+        // A clock that runs behind the newest day still counts towards the newest day
+        let slot = self.snapshot_timestamp.div_euclid(SECONDS_PER_DAY).rem_euclid(FEE_HISTORY_DAYS as i64) as usize;
+        let history = if input_is_token_a { &mut self.daily_fee_snapshot_token_a } else { &mut self.daily_fee_snapshot_token_b };
+        history[slot] = history[slot].saturating_add(fee_lamports);
+    }
+    
+    /// Checks if the auto-unpause timer has passed, so pause flags no longer apply
+    pub fn auto_unpause_elapsed(&self, current_timestamp: i64) -> bool {
+        self.auto_unpause_at != 0 && current_timestamp >= self.auto_unpause_at
+    }
+    
+    /// Checks whether paying `amount_out` of the output token would leave its liquidity
+    /// below that token's reserve safety floor (a floor of 0 never blocks)
+    pub fn reserve_floor_breached(&self, output_is_token_a: bool, amount_out: u64) -> bool {
+        let (liquidity, floor) = if output_is_token_a {
+            (self.total_token_a_liquidity, self.reserve_safety_floor_a)
+        } else {
+            (self.total_token_b_liquidity, self.reserve_safety_floor_b)
+        };
+        floor != 0 && liquidity.saturating_sub(amount_out) < floor
+    }
+    
+    /// Token balance a vault must hold to back the pool's accounting: the tracked
+    /// liquidity plus uncollected token fees of that side (saturating at u64::MAX)
+    pub fn required_vault_balance(&self, token_a: bool) -> u64 {
+        if token_a {
+            self.total_token_a_liquidity.saturating_add(self.collected_fees_token_a)
+        } else {
+            self.total_token_b_liquidity.saturating_add(self.collected_fees_token_b)
+        }
+    }
+    
+    /// Checks whether `fee` lies within the owner-set swap fee band (bounds of 0 are open)
+    pub fn swap_fee_within_band(&self, fee: u64) -> bool {
+        fee >= self.min_allowed_fee && (self.max_allowed_fee == 0 || fee <= self.max_allowed_fee)
+    }
+    
+    /// Checks whether withdrawing `amount_out` of a token would leave its liquidity
+    /// below the pool's minimum reserve (a minimum of 0 never blocks)
+    pub fn below_minimum_reserve(&self, withdraw_is_token_a: bool, amount_out: u64) -> bool {
+        let liquidity = if withdraw_is_token_a {
+            self.total_token_a_liquidity
+        } else {
+            self.total_token_b_liquidity
+        };
+        self.minimum_reserve != 0 && liquidity.saturating_sub(amount_out) < self.minimum_reserve
+    }
+    
     /// Checks if withdrawal protection is active
     pub fn withdrawal_protection_active(&self) -> bool {
         self.flags & crate::constants::POOL_FLAG_WITHDRAWAL_PROTECTION != 0
@@ -346,6 +857,26 @@ impl PoolState {
         }
     }
     
+    /// Checks if LP token transfers are frozen (non-transferable LP mode)
+    /// 
+    /// Set only at pool creation, when the LP mints are created with the pool state PDA
+    /// as freeze authority. User LP accounts stay frozen outside of deposits and withdrawals.
+    pub fn lp_transfers_frozen(&self) -> bool {
+        self.flags & crate::constants::POOL_FLAG_LP_TRANSFERS_FROZEN != 0
+    }
+    
+    /// Sets or clears the LP transfers frozen flag
+    /// 
+    /// **IMPORTANT**: Only meaningful at pool creation; the LP mints' freeze authority
+    /// is fixed when they are initialized.
+    pub fn set_lp_transfers_frozen(&mut self, value: bool) {
+        if value {
+            self.flags |= crate::constants::POOL_FLAG_LP_TRANSFERS_FROZEN;
+        } else {
+            self.flags &= !crate::constants::POOL_FLAG_LP_TRANSFERS_FROZEN;
+        }
+    }
+    
     // **NEW: Pool-level fee collection methods with atomic updates**
     
     /// Records liquidity operation fee collection
@@ -353,25 +884,13 @@ impl PoolState {
     /// **ATOMIC UPDATE**: Updates both specific fee counter and total in single operation
     /// to prevent race conditions and ensure consistency.
     /// 
-    /// **OVERFLOW PROTECTION**: Uses checked arithmetic to prevent counter overflow.
-    pub fn add_liquidity_fee(&mut self, fee_amount: u64, _timestamp: i64) -> Result<(), crate::error::PoolError> {
-        // 🔒 SECURITY FIX: Use checked arithmetic to prevent overflow
-        self.collected_liquidity_fees = self.collected_liquidity_fees
-            .checked_add(fee_amount)
-            .ok_or(crate::error::PoolError::ArithmeticOverflow)?;
-            
-        self.total_sol_fees_collected = self.total_sol_fees_collected
-            .checked_add(fee_amount)
-            .ok_or(crate::error::PoolError::ArithmeticOverflow)?;
-        
-        // Invariant check (debug mode only) - simplified since pending_sol_fees() uses the mathematical relationship
-        debug_assert_eq!(
-            self.pending_sol_fees(),
-            self.collected_liquidity_fees + self.collected_swap_contract_fees,
-            "Pending fees calculation should match sum of individual pending fee types"
-        );
-        
-        Ok(())
+    /// **SATURATING**: Fee counters are bookkeeping only and saturate at `u64::MAX`
+    /// (`u128::MAX` for `lifetime_fees`) instead of failing, so fee tracking can never
+    /// block a deposit or withdrawal.
+    pub fn add_liquidity_fee(&mut self, fee_amount: u64, _timestamp: i64) {
+        self.collected_liquidity_fees = self.collected_liquidity_fees.saturating_add(fee_amount);
+        self.total_sol_fees_collected = self.total_sol_fees_collected.saturating_add(fee_amount);
+        self.lifetime_fees = self.lifetime_fees.saturating_add(fee_amount as u128);
     }
     
     /// Adds a swap contract fee to the accumulated fees
@@ -379,33 +898,19 @@ impl PoolState {
     /// This function records a swap contract fee (fixed SOL amount) collected during
     /// swap operations. These fees cover computational costs.
     /// 
-    /// **OVERFLOW PROTECTION**: Uses checked arithmetic to prevent counter overflow.
+    /// **SATURATING**: Fee counters are bookkeeping only and saturate at `u64::MAX`
+    /// (`u128::MAX` for `lifetime_fees`) instead of failing, so fee tracking can never
+    /// block a swap.
     /// 
     /// # Arguments
     /// * `fee_amount` - The swap contract fee amount in lamports
     /// * `_timestamp` - Timestamp of the fee collection (currently unused)
-    pub fn add_swap_contract_fee(&mut self, fee_amount: u64, _timestamp: i64) -> Result<(), crate::error::PoolError> {
-        // 🔒 SECURITY FIX: Use checked arithmetic to prevent overflow
-        self.collected_swap_contract_fees = self.collected_swap_contract_fees
-            .checked_add(fee_amount)
-            .ok_or(crate::error::PoolError::ArithmeticOverflow)?;
-            
-        self.total_sol_fees_collected = self.total_sol_fees_collected
-            .checked_add(fee_amount)
-            .ok_or(crate::error::PoolError::ArithmeticOverflow)?;
-        
-        // Invariant check (debug mode only) - simplified since pending_sol_fees() uses the mathematical relationship
-        debug_assert_eq!(
-            self.pending_sol_fees(),
-            self.collected_liquidity_fees + self.collected_swap_contract_fees,
-            "Pending fees calculation should match sum of individual pending fee types"
-        );
-        
-        Ok(())
+    pub fn add_swap_contract_fee(&mut self, fee_amount: u64, _timestamp: i64) {
+        self.collected_swap_contract_fees = self.collected_swap_contract_fees.saturating_add(fee_amount);
+        self.total_sol_fees_collected = self.total_sol_fees_collected.saturating_add(fee_amount);
+        self.lifetime_fees = self.lifetime_fees.saturating_add(fee_amount as u128);
     }
     
-
-    
     /// Calculates current pending SOL fees awaiting consolidation
     /// 
     /// **ACCURATE CALCULATION**: Uses the mathematical relationship:
@@ -477,6 +982,16 @@ impl PoolState {
         (liquidity_ops, regular_swap_ops)
     }
     
+    /// Whether the pool was consolidated less than `window` seconds before `timestamp`
+    /// 
+    /// A window of 0 disables the check, and pools that were never consolidated
+    /// (`last_consolidation_timestamp` of 0) are never considered recent.
+    pub fn consolidated_within(&self, timestamp: i64, window: u64) -> bool {
+        window > 0
+            && self.last_consolidation_timestamp > 0
+            && (timestamp.saturating_sub(self.last_consolidation_timestamp) as i128) < window as i128
+    }
+    
     /// Resets consolidation counters (called after successful consolidation)
     /// 
     /// **RACE CONDITION PROTECTION**: This method performs atomic updates to ensure
@@ -490,7 +1005,7 @@ impl PoolState {
         // **ATOMIC CONSOLIDATION UPDATE**: 
         // Move pending fees from "collected" to "consolidated" state
         // NOTE: total_sol_fees_collected does NOT change - it's the lifetime total
-        self.total_fees_consolidated += pending_fees;
+        self.total_fees_consolidated = self.total_fees_consolidated.saturating_add(pending_fees);
         
         // Reset collected fees (operation counts are calculated from these)
         self.collected_liquidity_fees = 0;
@@ -498,7 +1013,7 @@ impl PoolState {
         
         // Update consolidation metadata
         self.last_consolidation_timestamp = timestamp;
-        self.total_consolidations += 1;
+        self.total_consolidations = self.total_consolidations.saturating_add(1);
         
         // **INVARIANT VERIFICATION**: Ensure consistency after consolidation
         debug_assert_eq!(
@@ -527,8 +1042,8 @@ impl PoolState {
         }
         
         // Verify individual pending fees sum matches the mathematical pending
-        let individual_sum = self.collected_liquidity_fees + 
-                           self.collected_swap_contract_fees;
+        let individual_sum = self.collected_liquidity_fees
+            .saturating_add(self.collected_swap_contract_fees);
         
         if actual_pending != individual_sum {
             return Err("Individual pending fees don't match calculated pending fees");
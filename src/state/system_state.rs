@@ -35,6 +35,55 @@ pub enum AdminChangeResult {
     },
 }
 
+/// Structured view of a system pause reason code.
+/// 
+/// The system state stores the raw `pause_reason_code`; this enum names the codes
+/// the program and its clients act on so they can be displayed without a lookup
+/// table. Codes without a named variant are carried through as `Other`.
+/// 
+/// Variants are only ever appended; the Borsh variant index is the wire format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum PauseReason {
+    /// No pause active (code 0)
+    None,
+    /// Contract upgrade in progress (code 2)
+    Upgrade,
+    /// Critical security issue detected (code 3)
+    SecurityIncident,
+    /// Routine maintenance and debugging (code 4)
+    Maintenance,
+    /// Paused for fee consolidation (code `PAUSE_REASON_CONSOLIDATION`, 15)
+    Consolidation,
+    /// Any other code (see the code list below)
+    Other(u8),
+}
+
+impl PauseReason {
+    /// Names a stored pause reason code
+    pub fn from_code(code: u8) -> Self {
+        match code {
+            0 => PauseReason::None,
+            2 => PauseReason::Upgrade,
+            3 => PauseReason::SecurityIncident,
+            4 => PauseReason::Maintenance,
+            crate::constants::PAUSE_REASON_CONSOLIDATION => PauseReason::Consolidation,
+            other => PauseReason::Other(other),
+        }
+    }
+    
+    /// Code to pass to `PauseSystem` for this reason
+    pub fn code(&self) -> u8 {
+        match self {
+            PauseReason::None => 0,
+            PauseReason::Upgrade => 2,
+            PauseReason::SecurityIncident => 3,
+            PauseReason::Maintenance => 4,
+            PauseReason::Consolidation => crate::constants::PAUSE_REASON_CONSOLIDATION,
+            PauseReason::Other(code) => *code,
+        }
+    }
+}
+
 /// **PAUSE REASON CODES** (Documentation Only - Not Part of Smart Contract Logic)
 /// 
 /// These standardized codes are used for efficient storage. Client applications
@@ -83,6 +132,54 @@ pub struct SystemState {
     
     /// Timestamp when admin authority change was initiated (0 if no change pending)
     pub admin_change_timestamp: i64,
+    
+    /// **PROTOCOL SWAP FEE**
+    /// SOL fee in lamports charged on every swap and paid directly to the main treasury (0 = disabled)
+    pub swap_sol_fee: u64,
+    
+    /// **CONSOLIDATION SKIP WINDOW**
+    /// Seconds after a pool's last consolidation during which `ConsolidatePoolFees` skips it (0 = disabled)
+    pub consolidation_skip_window: u64,
+    
+    /// **POOL CREATION DURING PAUSE**
+    /// When true, `InitializePool` remains available while the system is paused (default false)
+    pub allow_pool_creation_while_paused: bool,
+    
+    /// **PAUSING AUTHORITY**
+    /// Signer of the active pause (default pubkey when not paused)
+    pub paused_by: Pubkey,
+    
+    /// **SYSTEM MESSAGE**
+    /// Admin broadcast for incidents (e.g. "paused for audit, funds safe"), UTF-8
+    /// padded with zero bytes (all zeros = no message)
+    pub system_message: [u8; crate::constants::SYSTEM_MESSAGE_MAX_LEN],
+}
+
+/// System pause status returned by `GetSystemStatus`.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SystemStatus {
+    /// Whether the system is paused
+    pub is_paused: bool,
+    /// Named reason for the active pause (`None` when not paused)
+    pub pause_reason: PauseReason,
+    /// Unix timestamp when the active pause began (0 when not paused)
+    pub pause_timestamp: i64,
+    /// Authority that paused the system (default pubkey when not paused)
+    pub paused_by: Pubkey,
+    /// Admin broadcast set with `SetSystemMessage` (empty when none)
+    pub system_message: String,
+}
+
+impl From<&SystemState> for SystemStatus {
+    fn from(system_state: &SystemState) -> Self {
+        Self {
+            is_paused: system_state.is_paused,
+            pause_reason: system_state.pause_reason(),
+            pause_timestamp: system_state.pause_timestamp,
+            paused_by: system_state.paused_by,
+            system_message: system_state.system_message().to_string(),
+        }
+    }
 }
 
 impl SystemState {
@@ -95,9 +192,18 @@ impl SystemState {
     /// - admin_authority: 32 bytes (Pubkey)
     /// - pending_admin_authority: 33 bytes (Option<Pubkey> = 1 + 32)
     /// - admin_change_timestamp: 8 bytes (i64)
+    /// - swap_sol_fee: 8 bytes (u64)
+    /// - consolidation_skip_window: 8 bytes (u64)
+    /// - allow_pool_creation_while_paused: 1 byte (bool)
+    /// - paused_by: 32 bytes (Pubkey)
+    /// - system_message: 128 bytes ([u8; SYSTEM_MESSAGE_MAX_LEN])
     /// 
-    /// **TOTAL: 83 bytes**
-    pub const LEN: usize = 1 + 8 + 1 + 32 + 33 + 8; // 83 bytes - exact calculation
+    /// **TOTAL: 260 bytes**
+    pub const LEN: usize = 1 + 8 + 1 + 32 + 33 + 8 + 8 + 8 + 1 + 32 + crate::constants::SYSTEM_MESSAGE_MAX_LEN; // 260 bytes - exact calculation
+    
+    /// Size of system state accounts created before `swap_sol_fee` was appended
+    /// (every field up to `admin_change_timestamp`: 83 bytes)
+    pub const V1_LEN: usize = 1 + 8 + 1 + 32 + 33 + 8;
     
     /// Creates a new SystemState in unpaused state with specified admin authority.
     /// 
@@ -114,18 +220,25 @@ impl SystemState {
             admin_authority,
             pending_admin_authority: None,
             admin_change_timestamp: 0,
+            swap_sol_fee: 0,
+            consolidation_skip_window: 0,
+            allow_pool_creation_while_paused: false,
+            paused_by: Pubkey::default(),
+            system_message: [0; crate::constants::SYSTEM_MESSAGE_MAX_LEN],
         }
     }
     
-    /// Pauses the system with the specified reason code and timestamp.
+    /// Pauses the system with the specified reason code, timestamp and authority.
     /// 
     /// # Arguments
     /// * `reason_code` - Pause reason code (see documentation above)
     /// * `timestamp` - Unix timestamp when the pause was initiated
-    pub fn pause(&mut self, reason_code: u8, timestamp: i64) {
+    /// * `paused_by` - Authority that signed the pause
+    pub fn pause(&mut self, reason_code: u8, timestamp: i64, paused_by: Pubkey) {
         self.is_paused = true;
         self.pause_timestamp = timestamp;
         self.pause_reason_code = reason_code;
+        self.paused_by = paused_by;
     }
     
     /// Unpauses the system, clearing pause state.
@@ -133,6 +246,34 @@ impl SystemState {
         self.is_paused = false;
         self.pause_timestamp = 0;
         self.pause_reason_code = 0; // 0 = No pause active
+        self.paused_by = Pubkey::default();
+    }
+    
+    /// Named reason for the current pause (`PauseReason::None` when not paused)
+    pub fn pause_reason(&self) -> PauseReason {
+        PauseReason::from_code(self.pause_reason_code)
+    }
+    
+    /// Current system message without its zero padding (empty when none is set)
+    pub fn system_message(&self) -> &str {
+        let len = self.system_message.iter().position(|&b| b == 0).unwrap_or(self.system_message.len());
+        std::str::from_utf8(&self.system_message[..len]).unwrap_or_default()
+    }
+    
+    /// Replaces the system message; an empty message clears it.
+    /// 
+    /// The message must fit in `SYSTEM_MESSAGE_MAX_LEN` bytes, be valid UTF-8 and
+    /// contain no zero bytes, which would end it early.
+    pub fn set_system_message(&mut self, message: &[u8]) -> Result<(), crate::error::PoolError> {
+        if message.len() > crate::constants::SYSTEM_MESSAGE_MAX_LEN
+            || message.contains(&0)
+            || std::str::from_utf8(message).is_err()
+        {
+            return Err(crate::error::PoolError::InvalidSystemMessage { length: message.len() as u64 });
+        }
+        self.system_message = [0; crate::constants::SYSTEM_MESSAGE_MAX_LEN];
+        self.system_message[..message.len()].copy_from_slice(message);
+        Ok(())
     }
 
     /// **CENTRALIZED DESERIALIZATION** - Robust loading from account data
@@ -181,13 +322,38 @@ impl SystemState {
             return Err(ProgramError::UninitializedAccount);
         }
         
-        // Deserialize with tolerance for trailing bytes
-        Self::deserialize(&mut &account_data[..])
+        Self::from_versioned_data(&account_data)
             .map_err(|e| {
                 msg!("❌ SystemState deserialization failed: {:?}", e);
-                ProgramError::InvalidAccountData
+                e
             })
     }
+    
+    /// Deserializes system state data written with the current or an older layout
+    /// 
+    /// Fields are only ever appended, so an account created before the later fields
+    /// existed is short of the trailing bytes; they are read as zeros (no swap SOL fee,
+    /// no skip window, no pool creation while paused, no pauser, no message). Trailing
+    /// bytes past the current layout are ignored. Such accounts can be read but not
+    /// written until `MigrateSystemState` grows them to `SystemState::LEN`.
+    /// 
+    /// # Arguments
+    /// * `data` - Raw system state account data
+    /// 
+    /// # Returns
+    /// * `Result<SystemState, ProgramError>` - State mapped onto the current layout
+    pub fn from_versioned_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.is_empty() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if data.len() >= Self::LEN {
+            return Self::deserialize(&mut &data[..]).map_err(|_| ProgramError::InvalidAccountData);
+        }
+        
+        let mut current_layout = data.to_vec();
+        current_layout.resize(Self::LEN, 0);
+        Self::deserialize(&mut &current_layout[..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
 
     /// **TEST-FRIENDLY DESERIALIZATION** - For use in test environments only
     /// 
@@ -203,13 +369,8 @@ impl SystemState {
     /// # Returns
     /// * `Result<SystemState, ProgramError>` - Loaded state or error
     pub fn from_account_data_unchecked(data: &[u8]) -> Result<Self, ProgramError> {
-        if data.is_empty() {
-            return Err(ProgramError::UninitializedAccount);
-        }
-        
         // Tolerant deserialization for test environments
-        Self::deserialize(&mut &data[..])
-            .map_err(|_| ProgramError::InvalidAccountData)
+        Self::from_versioned_data(data)
     }
     
     /// **ADMIN AUTHORITY MANAGEMENT WITH 72-HOUR TIMELOCK**
@@ -18,8 +18,50 @@
 //! - Batch consolidation improves efficiency
 //! - Complete treasury visibility through consolidation
 //! - Optimal fee routing based on operation frequency
+//!
+//! **COUNTER OVERFLOW**
+//!
+//! All cumulative fee totals and operation counts are statistics, not balances: they
+//! saturate at `u64::MAX` instead of failing, so a full counter can never block a fee
+//! payment, consolidation or withdrawal.
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use crate::constants::{DONATION_MESSAGE_PREVIEW_LEN, RECENT_DONATIONS_CAPACITY};
+
+/// One entry of the treasury's recent donations buffer
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DonationRecord {
+    /// Account that donated
+    pub donor: Pubkey,
+    /// Lamports donated
+    pub amount: u64,
+    /// Donation message truncated to `DONATION_MESSAGE_PREVIEW_LEN` bytes, zero-padded
+    pub message: [u8; DONATION_MESSAGE_PREVIEW_LEN],
+    /// Unix timestamp of the donation
+    pub timestamp: i64,
+}
+
+impl DonationRecord {
+    pub const LEN: usize = 32 + 8 + DONATION_MESSAGE_PREVIEW_LEN + 8;
+
+    /// Builds a record, truncating `message` at a character boundary
+    pub fn new(donor: Pubkey, amount: u64, message: &str, timestamp: i64) -> Self {
+        let mut end = message.len().min(DONATION_MESSAGE_PREVIEW_LEN);
+        while !message.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut preview = [0u8; DONATION_MESSAGE_PREVIEW_LEN];
+        preview[..end].copy_from_slice(&message.as_bytes()[..end]);
+        Self { donor, amount, message: preview, timestamp }
+    }
+
+    /// The stored message preview without its zero padding
+    pub fn message(&self) -> &str {
+        let end = self.message.iter().position(|&byte| byte == 0).unwrap_or(DONATION_MESSAGE_PREVIEW_LEN);
+        std::str::from_utf8(&self.message[..end]).unwrap_or_default()
+    }
+}
 
 /// **MAIN TREASURY: CONSOLIDATION TARGET FOR DISTRIBUTED FEES**
 /// 
@@ -90,6 +132,33 @@ pub struct MainTreasuryState {
     /// **DONATION TRACKING: Total SOL donated to the protocol**
     /// Sum of all voluntary donations in lamports
     pub total_donations: u64,
+    
+    /// **AUTO-COMPOUNDING: Pool receiving idle treasury SOL as liquidity**
+    /// `Pubkey::default()` when auto-compounding is not configured
+    pub autocompound_pool: Pubkey,
+    
+    /// **AUTO-COMPOUNDING: Lamports kept in the treasury above rent exemption**
+    /// Only the balance above the rent-exempt minimum plus this reserve is compounded
+    pub autocompound_reserve: u64,
+    
+    /// **AUTO-COMPOUNDING: LP tokens received for compounded SOL**
+    /// Deposits mint LP 1:1, so this is also the total lamports compounded
+    pub autocompound_lp_tokens: u64,
+    
+    /// **RATE LIMITING: Start of the current withdrawal cap window**
+    /// Set by the first withdrawal after the previous `TREASURY_WITHDRAWAL_WINDOW_SECONDS` window ended
+    pub withdrawal_window_start: i64,
+    
+    /// **RATE LIMITING: Lamports withdrawn since `withdrawal_window_start`**
+    /// Capped at `TREASURY_MAX_WITHDRAWAL_PER_WINDOW`
+    pub withdrawn_in_window: u64,
+    
+    /// **DONATION TRACKING: Ring buffer of the most recent donations**
+    /// Once full, each donation overwrites the oldest entry
+    pub recent_donations: [DonationRecord; RECENT_DONATIONS_CAPACITY],
+    
+    /// **DONATION TRACKING: Slot of `recent_donations` the next donation is written to**
+    pub next_donation_slot: u8,
 }
 
 /// **NEW: Consolidated operations data structure**
@@ -122,14 +191,44 @@ impl MainTreasuryState {
         8 +   // total_consolidations_performed ← NEW
         8 +   // last_withdrawal_timestamp ← NEW (for rate limiting)
         8 +   // donation_count ← NEW
-        8;    // total_donations ← NEW
-        // **TOTAL ADDITION: +40 bytes** (includes 16 bytes for donation tracking)
+        8 +   // total_donations ← NEW
+        32 +  // autocompound_pool
+        8 +   // autocompound_reserve
+        8 +   // autocompound_lp_tokens
+        8 +   // withdrawal_window_start
+        8 +   // withdrawn_in_window
+        DonationRecord::LEN * RECENT_DONATIONS_CAPACITY + // recent_donations
+        1;    // next_donation_slot
+        // **TOTAL ADDITION: +1065 bytes** (includes 977 bytes for donation tracking, 48 for auto-compounding, 16 for the withdrawal cap window)
         // Authority removed: 32 bytes saved, validation handled through SystemState
 
+    /// Size of treasury accounts created before auto-compounding was added (every field
+    /// up to `total_donations`: 136 bytes)
+    pub const V1_LEN: usize = 17 * 8;
+
     pub fn get_packed_len() -> usize {
         Self::LEN
     }
 
+    /// Deserializes treasury data written with the current or an older layout
+    /// 
+    /// Fields are only ever appended, so older accounts are short of the trailing bytes;
+    /// they are read as zeros (no auto-compounding, an empty withdrawal window, no recent
+    /// donations). Such accounts can be read but not written until `MigrateTreasuryState`
+    /// grows them to `MainTreasuryState::LEN`.
+    pub fn from_versioned_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.is_empty() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if data.len() >= Self::LEN {
+            return Self::deserialize(&mut &data[..]).map_err(|_| ProgramError::InvalidAccountData);
+        }
+
+        let mut current_layout = data.to_vec();
+        current_layout.resize(Self::LEN, 0);
+        Self::deserialize(&mut &current_layout[..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
     pub fn new() -> Self {
         Self {
             total_balance: 0,
@@ -149,6 +248,13 @@ impl MainTreasuryState {
             last_withdrawal_timestamp: 0,
             donation_count: 0,
             total_donations: 0,
+            autocompound_pool: Pubkey::default(),
+            autocompound_reserve: 0,
+            autocompound_lp_tokens: 0,
+            withdrawal_window_start: 0,
+            withdrawn_in_window: 0,
+            recent_donations: [DonationRecord::default(); RECENT_DONATIONS_CAPACITY],
+            next_donation_slot: 0,
         }
     }
     
@@ -172,14 +278,21 @@ impl MainTreasuryState {
             last_withdrawal_timestamp: 0,
             donation_count: 0,
             total_donations: 0,
+            autocompound_pool: Pubkey::default(),
+            autocompound_reserve: 0,
+            autocompound_lp_tokens: 0,
+            withdrawal_window_start: 0,
+            withdrawn_in_window: 0,
+            recent_donations: [DonationRecord::default(); RECENT_DONATIONS_CAPACITY],
+            next_donation_slot: 0,
         }
     }
     
     /// **DIRECT COLLECTION: Pool creation fee tracking**
     /// Records a pool creation fee collected directly to treasury
     pub fn add_pool_creation_fee(&mut self, fee_amount: u64, timestamp: i64) {
-        self.pool_creation_count += 1;
-        self.total_pool_creation_fees += fee_amount;
+        self.pool_creation_count = self.pool_creation_count.saturating_add(1);
+        self.total_pool_creation_fees = self.total_pool_creation_fees.saturating_add(fee_amount);
         self.last_update_timestamp = timestamp;
     }
     
@@ -187,8 +300,8 @@ impl MainTreasuryState {
     /// Records liquidity operation fees consolidated from pool states
     /// Note: This is called during consolidation, not during individual operations
     pub fn add_liquidity_fee(&mut self, fee_amount: u64, timestamp: i64) {
-        self.liquidity_operation_count += 1;
-        self.total_liquidity_fees += fee_amount;
+        self.liquidity_operation_count = self.liquidity_operation_count.saturating_add(1);
+        self.total_liquidity_fees = self.total_liquidity_fees.saturating_add(fee_amount);
         self.last_update_timestamp = timestamp;
     }
     
@@ -202,13 +315,13 @@ impl MainTreasuryState {
     /// * `fee_amount` - The swap contract fee amount in lamports
     /// * `timestamp` - Timestamp of the fee collection
     pub fn add_swap_contract_fee(&mut self, fee_amount: u64, timestamp: i64) {
-        self.regular_swap_count += 1;  // Increment the operation count
-        self.total_swap_contract_fees += fee_amount;
+        self.regular_swap_count = self.regular_swap_count.saturating_add(1);  // Increment the operation count
+        self.total_swap_contract_fees = self.total_swap_contract_fees.saturating_add(fee_amount);
         self.last_update_timestamp = timestamp;
         
         // Also update the legacy regular_swap_fees for backward compatibility
         // TODO: Remove this after migration period
-        self.total_regular_swap_fees += fee_amount;
+        self.total_regular_swap_fees = self.total_regular_swap_fees.saturating_add(fee_amount);
     }
 
     /// Adds a regular swap fee to the treasury (legacy method)
@@ -221,16 +334,25 @@ impl MainTreasuryState {
     }
     
     /// **NEW: Records a treasury withdrawal operation**
+    /// 
+    /// Also counts the amount against the withdrawal cap window, opening a new window
+    /// when the previous one has ended.
     pub fn add_treasury_withdrawal(&mut self, withdrawal_amount: u64, timestamp: i64) {
-        self.treasury_withdrawal_count += 1;
-        self.total_withdrawn += withdrawal_amount;
+        self.treasury_withdrawal_count = self.treasury_withdrawal_count.saturating_add(1);
+        self.total_withdrawn = self.total_withdrawn.saturating_add(withdrawal_amount);
         self.last_update_timestamp = timestamp;
         self.last_withdrawal_timestamp = timestamp;
+        if self.withdrawal_window_elapsed(timestamp) {
+            self.withdrawal_window_start = timestamp;
+            self.withdrawn_in_window = withdrawal_amount;
+        } else {
+            self.withdrawn_in_window = self.withdrawn_in_window.saturating_add(withdrawal_amount);
+        }
     }
     
     /// **NEW: Records a failed operation for debugging and analytics**
     pub fn add_failed_operation(&mut self, timestamp: i64) {
-        self.failed_operation_count += 1;
+        self.failed_operation_count = self.failed_operation_count.saturating_add(1);
         self.last_update_timestamp = timestamp;
     }
     
@@ -243,11 +365,52 @@ impl MainTreasuryState {
     /// * `donation_amount` - The donation amount in lamports
     /// * `timestamp` - Timestamp of the donation
     pub fn add_donation(&mut self, donation_amount: u64, timestamp: i64) {
-        self.donation_count += 1;
-        self.total_donations += donation_amount;
+        self.donation_count = self.donation_count.saturating_add(1);
+        self.total_donations = self.total_donations.saturating_add(donation_amount);
         self.last_update_timestamp = timestamp;
     }
     
+    /// **DONATION TRACKING: Stores a donation in the recent donations buffer**
+    /// 
+    /// Overwrites the oldest entry once the buffer is full. Totals are kept by
+    /// `add_donation`.
+    pub fn record_recent_donation(&mut self, record: DonationRecord) {
+        let slot = self.next_donation_slot as usize % RECENT_DONATIONS_CAPACITY;
+        self.recent_donations[slot] = record;
+        self.next_donation_slot = ((slot + 1) % RECENT_DONATIONS_CAPACITY) as u8;
+    }
+    
+    /// **DONATION TRACKING: Recorded donations, newest first**
+    /// 
+    /// At most `RECENT_DONATIONS_CAPACITY` entries; fewer until that many donations
+    /// have been made.
+    pub fn recent_donations_newest_first(&self) -> Vec<DonationRecord> {
+        let filled = (self.donation_count as usize).min(RECENT_DONATIONS_CAPACITY);
+        let next = self.next_donation_slot as usize % RECENT_DONATIONS_CAPACITY;
+        (1..=filled)
+            .map(|age| self.recent_donations[(next + RECENT_DONATIONS_CAPACITY - age) % RECENT_DONATIONS_CAPACITY])
+            .collect()
+    }
+    
+    /// **AUTO-COMPOUNDING: Records SOL moved into the auto-compound pool**
+    /// 
+    /// # Arguments
+    /// * `lp_tokens` - LP tokens minted to the treasury for the compounded SOL
+    /// * `timestamp` - Timestamp of the compounding
+    pub fn add_autocompound(&mut self, lp_tokens: u64, timestamp: i64) {
+        self.autocompound_lp_tokens = self.autocompound_lp_tokens.saturating_add(lp_tokens);
+        self.last_update_timestamp = timestamp;
+    }
+    
+    /// **AUTO-COMPOUNDING: Lamports that may be compounded at the given balance**
+    /// 
+    /// Everything above the rent-exempt minimum plus the configured reserve.
+    pub fn compoundable_balance(&self, account_lamports: u64, rent_exempt_minimum: u64) -> u64 {
+        account_lamports
+            .saturating_sub(rent_exempt_minimum)
+            .saturating_sub(self.autocompound_reserve)
+    }
+    
     /// **NEW: Calculate total successful operations across all types**
     pub fn total_successful_operations(&self) -> u64 {
         self.pool_creation_count + 
@@ -307,16 +470,16 @@ impl MainTreasuryState {
         timestamp: i64,
     ) {
         // Update fee totals (pool creation fees handled during initial creation)
-        self.total_liquidity_fees += consolidated_operations.liquidity_fees;
-        self.total_regular_swap_fees += consolidated_operations.regular_swap_fees;
-        self.total_swap_contract_fees += consolidated_operations.regular_swap_fees; // Same fees, different tracking
+        self.total_liquidity_fees = self.total_liquidity_fees.saturating_add(consolidated_operations.liquidity_fees);
+        self.total_regular_swap_fees = self.total_regular_swap_fees.saturating_add(consolidated_operations.regular_swap_fees);
+        self.total_swap_contract_fees = self.total_swap_contract_fees.saturating_add(consolidated_operations.regular_swap_fees); // Same fees, different tracking
         
         // Update operation counts
-        self.liquidity_operation_count += consolidated_operations.liquidity_operation_count;
-        self.regular_swap_count += consolidated_operations.regular_swap_count;
+        self.liquidity_operation_count = self.liquidity_operation_count.saturating_add(consolidated_operations.liquidity_operation_count);
+        self.regular_swap_count = self.regular_swap_count.saturating_add(consolidated_operations.regular_swap_count);
         
         // Update consolidation metadata
-        self.total_consolidations_performed += 1;
+        self.total_consolidations_performed = self.total_consolidations_performed.saturating_add(1);
         self.last_update_timestamp = timestamp;
     }
     
@@ -410,6 +573,35 @@ impl MainTreasuryState {
         Err("Rate limit exceeded: withdrawals are limited to once per hour")
     }
     
+    /// **RATE LIMITING: Whether the withdrawal cap window has ended**
+    /// 
+    /// True when nothing was withdrawn yet or `TREASURY_WITHDRAWAL_WINDOW_SECONDS` have
+    /// passed since the window opened; the next withdrawal then starts a fresh window.
+    pub fn withdrawal_window_elapsed(&self, current_timestamp: i64) -> bool {
+        use crate::constants::TREASURY_WITHDRAWAL_WINDOW_SECONDS;
+        
+        self.withdrawn_in_window == 0
+            || current_timestamp.saturating_sub(self.withdrawal_window_start) >= TREASURY_WITHDRAWAL_WINDOW_SECONDS
+    }
+    
+    /// **RATE LIMITING: Lamports that may still be withdrawn in the current cap window**
+    /// 
+    /// # Arguments
+    /// * `current_timestamp` - Current timestamp for the window calculation
+    /// 
+    /// # Returns
+    /// * `TREASURY_MAX_WITHDRAWAL_PER_WINDOW` once the window has ended, otherwise the
+    ///   cap minus what the window has already withdrawn
+    pub fn remaining_window_allowance(&self, current_timestamp: i64) -> u64 {
+        use crate::constants::TREASURY_MAX_WITHDRAWAL_PER_WINDOW;
+        
+        if self.withdrawal_window_elapsed(current_timestamp) {
+            TREASURY_MAX_WITHDRAWAL_PER_WINDOW
+        } else {
+            TREASURY_MAX_WITHDRAWAL_PER_WINDOW.saturating_sub(self.withdrawn_in_window)
+        }
+    }
+    
     /// **RATE LIMITING: Get time remaining until next withdrawal is allowed**
     /// 
     /// # Arguments
@@ -508,24 +700,24 @@ impl MainTreasuryState {
         }
         
         self.total_balance -= amount;
-        self.total_withdrawn += amount;
+        self.total_withdrawn = self.total_withdrawn.saturating_add(amount);
         Ok(())
     }
     
     /// **ANALYTICS: Total fees calculation**
     /// Calculates total fees collected across all categories
     pub fn total_fees_collected(&self) -> u64 {
-        self.total_pool_creation_fees +
-        self.total_liquidity_fees +
-        self.total_regular_swap_fees
+        self.total_pool_creation_fees
+            .saturating_add(self.total_liquidity_fees)
+            .saturating_add(self.total_regular_swap_fees)
     }
     
     /// **ANALYTICS: Total operations calculation**
     /// Calculates total operations processed across all categories
     pub fn total_operations_processed(&self) -> u64 {
-        self.pool_creation_count +
-        self.liquidity_operation_count +
-        self.regular_swap_count
+        self.pool_creation_count
+            .saturating_add(self.liquidity_operation_count)
+            .saturating_add(self.regular_swap_count)
     }
     
     /// **ANALYTICS: Average fee calculation**
@@ -545,17 +737,17 @@ impl MainTreasuryState {
     /// updating the treasury's tracking of all fee types and operation counts.
     pub fn record_consolidated_operations(&mut self, consolidated_operations: &ConsolidatedOperations, timestamp: i64) {
         // Add all fee types
-        self.total_liquidity_fees += consolidated_operations.liquidity_fees;
-        self.total_regular_swap_fees += consolidated_operations.regular_swap_fees;
-        self.total_swap_contract_fees += consolidated_operations.regular_swap_fees; // Same fees, different tracking
+        self.total_liquidity_fees = self.total_liquidity_fees.saturating_add(consolidated_operations.liquidity_fees);
+        self.total_regular_swap_fees = self.total_regular_swap_fees.saturating_add(consolidated_operations.regular_swap_fees);
+        self.total_swap_contract_fees = self.total_swap_contract_fees.saturating_add(consolidated_operations.regular_swap_fees); // Same fees, different tracking
         
         // Update operation counts using correct field names
-        self.liquidity_operation_count += consolidated_operations.liquidity_operation_count;
-        self.regular_swap_count += consolidated_operations.regular_swap_count;
+        self.liquidity_operation_count = self.liquidity_operation_count.saturating_add(consolidated_operations.liquidity_operation_count);
+        self.regular_swap_count = self.regular_swap_count.saturating_add(consolidated_operations.regular_swap_count);
         
         // Update metadata
         self.last_update_timestamp = timestamp;
-        self.total_consolidations_performed += 1;
+        self.total_consolidations_performed = self.total_consolidations_performed.saturating_add(1);
     }
 }
 
@@ -6,6 +6,8 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+use crate::state::SwapMode;
+
 /// All supported instructions for the Solana Trading Pool Program.
 /// 
 /// This enum defines every operation that can be performed on the pool,
@@ -72,6 +74,8 @@ pub enum PoolInstruction {
     /// 
     /// # Note:
     /// - `one_to_many_ratio` is automatically determined by the contract based on the ratio values
+    /// - The ratio is stored reduced by its GCD; the requested values are kept as
+    ///   `original_ratio_a_numerator` / `original_ratio_b_denominator` and seed the pool PDA
     /// - Display preferences are handled by individual applications, not the contract
     /// - Bump seeds for all PDAs are derived internally using `find_program_address`
     InitializePool {
@@ -176,6 +180,13 @@ pub enum PoolInstruction {
     /// Get current pool pause status (publicly readable)
     /// Returns swap pause status, deposit/withdrawal status, and pause details
     /// Distinguishes between system-wide pause and pool-specific swap pause
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// The effectively paused operations as a `u8` of `PAUSE_*` bits (0 once the
+    /// `auto_unpause_at` time has passed), then `auto_unpause_at` as a little-endian i64
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
     GetPoolPauseStatus {
         // No parameters needed - reads from pool state account
     },
@@ -211,6 +222,9 @@ pub enum PoolInstruction {
     /// - 4: Routine maintenance and debugging
     /// - 5: Emergency halt due to unexpected behavior
     /// - 255: Custom reason (see external documentation)
+    /// 
+    /// `PauseReason::code()` gives the code for each named reason. The pausing
+    /// authority and timestamp are recorded and reported by `GetSystemStatus`.
     PauseSystem {
         /// Standardized pause reason code for efficient storage
         reason_code: u8,
@@ -234,9 +248,18 @@ pub enum PoolInstruction {
     /// - Caller must be the system authority (same as system pause authority)
     /// - Main treasury must have sufficient balance above rent-exempt minimum
     /// - Amount must not exceed available balance
+    /// - At most `TREASURY_MAX_WITHDRAWAL_PER_WINDOW` may be withdrawn per
+    ///   `TREASURY_WITHDRAWAL_WINDOW_SECONDS` (`WithdrawalRateExceeded` otherwise)
+    /// 
+    /// - The destination account (account [3]) is chosen per call and may be any system
+    ///   account, including a wallet the authority does not control
     /// 
     /// # Arguments:
-    /// - `amount`: Amount of SOL to withdraw in lamports (0 = withdraw all available)
+    /// - `amount`: Amount of SOL to withdraw in lamports (0 = withdraw all available,
+    ///   `TREASURY_WITHDRAW_MAX_ALLOWED` (u64::MAX) = as much as the rent reserve and rate limits allow)
+    /// 
+    /// # Returns (via return data):
+    /// - Lamports actually withdrawn as a little-endian u64
     WithdrawTreasuryFees {
         amount: u64,
     },
@@ -304,7 +327,8 @@ pub enum PoolInstruction {
     /// - Estimated consolidation cost vs. benefit analysis
     /// 
     /// # Arguments:
-    /// - `pool_count`: Number of pools to check (1-20)
+    /// - `pool_count`: Number of pools to check (1-20); must equal the number of
+    ///   accounts passed, otherwise the instruction fails with `PoolCountMismatch`
     /// 
     /// # Account Order:
     /// - [0..pool_count] Pool State PDAs (pools to check)
@@ -319,6 +343,8 @@ pub enum PoolInstruction {
     /// - PAUSE_FLAG_LIQUIDITY (1): Pause deposits/withdrawals
     /// - PAUSE_FLAG_SWAPS (2): Pause swaps
     /// - PAUSE_FLAG_ALL (3): Pause both (required for consolidation eligibility)
+    /// - PAUSE_SWAP_A_TO_B (4) / PAUSE_SWAP_B_TO_A (8): Pause one swap direction
+    /// - PAUSE_DEPOSITS (16) / PAUSE_WITHDRAWALS (32): Pause one side of liquidity
     /// 
     /// **Idempotent**: Pausing already paused operations does not cause an error.
     /// 
@@ -346,6 +372,8 @@ pub enum PoolInstruction {
     /// - PAUSE_FLAG_LIQUIDITY (1): Unpause deposits/withdrawals
     /// - PAUSE_FLAG_SWAPS (2): Unpause swaps
     /// - PAUSE_FLAG_ALL (3): Unpause both operations
+    /// - PAUSE_SWAP_A_TO_B (4) / PAUSE_SWAP_B_TO_A (8): Unpause one swap direction
+    /// - PAUSE_DEPOSITS (16) / PAUSE_WITHDRAWALS (32): Unpause one side of liquidity
     /// 
     /// **Idempotent**: Unpausing already unpaused operations does not cause an error.
     /// 
@@ -393,6 +421,7 @@ pub enum PoolInstruction {
     /// # Arguments:
     /// - `enable_restriction`: True to enable owner-only mode, false to disable
     /// - `designated_owner`: The pubkey that will have swap control when restrictions are enabled
+    ///   (must not be the default pubkey when enabling, or `InvalidDesignatedOwner` is returned)
     /// - `pool_id`: Expected Pool ID (PDA address) for security validation
     /// 
     /// # Security:
@@ -462,7 +491,8 @@ pub enum PoolInstruction {
     /// 
     /// # Arguments:
     /// - `amount`: Amount of SOL to donate in lamports
-    /// - `message`: Optional message (logged but not stored on-chain)
+    /// - `message`: Optional message (logged in full; the first 32 bytes are kept with the
+    ///   donation in the recent donations buffer, see `GetRecentDonations`)
     /// 
     /// # Account Order:
     /// - [0] Donor Account (signer, writable) - Account donating SOL
@@ -498,4 +528,1240 @@ pub enum PoolInstruction {
         new_admin: Pubkey,
     },
     
-} 
\ No newline at end of file
+    /// **VIEW INSTRUCTION**: Estimate the SOL a user needs to complete a swap
+    /// 
+    /// Returns the minimum lamport balance a user should hold before submitting a
+    /// swap against this pool. The estimate covers:
+    /// - The base transaction signature fee
+    /// - The pool's swap contract fee
+    /// - Rent for creating the output token account if it does not exist yet
+    /// 
+    /// The total is returned as a little-endian `u64` via `set_return_data` and
+    /// is also logged for clients that read program logs.
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable) - Pool the swap will execute against
+    /// - [1] User Output Token Account (readable) - May be uninitialized or missing
+    GetSwapSolCost {},
+    
+    /// **VIEW INSTRUCTION**: Cost of swapping into the other token and back
+    /// 
+    /// Computes the swap from `start_token_mint` into the other pool token and then
+    /// swaps the full result back. Reports the token lost to rounding and the SOL
+    /// contract fees paid across both legs.
+    /// 
+    /// # Arguments:
+    /// - `amount_in`: Amount of the start token in basis points
+    /// - `start_token_mint`: Token the round trip begins and ends with
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Four little-endian `u64` values: `[first_leg_out, final_amount, token_loss, sol_fees]`
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
+    GetRoundTripCost {
+        amount_in: u64,
+        start_token_mint: Pubkey,
+        pool_id: Pubkey,
+    },
+    
+    /// **VIEW INSTRUCTION**: Get the rounding surplus accumulated by a pool
+    /// 
+    /// Swap outputs are truncated in the pool's favor. The pool tracks the whole
+    /// input-token units it kept this way, per token.
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Two little-endian `u64` values: `[rounding_surplus_token_a, rounding_surplus_token_b]`
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
+    GetRoundingSurplus {
+        // No fields needed - reads from pool state
+    },
+    
+    /// **POOL LP SUPPLY CAP**: Set the maximum supply of each LP token mint (admin authority only)
+    /// 
+    /// Bounds a pool's size at the token level. Deposits that would mint LP tokens
+    /// past the cap are rejected with `LpSupplyCapReached`. A cap of 0 removes the limit.
+    /// 
+    /// # Arguments:
+    /// - `max_lp_supply`: New LP supply cap per LP mint (0 = no limit)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (for pause and admin validation)
+    /// - [2] Pool State PDA (writable, to update the cap)
+    /// - [3] Program Data Account (for upgrade authority validation)
+    SetMaxLpSupply {
+        max_lp_supply: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// **VIEW INSTRUCTION**: Get lifetime protocol statistics
+    /// 
+    /// Single monitoring call covering pools, treasury and consolidation activity.
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Three little-endian `u64` values: `[total_pools, treasury_balance, total_consolidations]`
+    /// 
+    /// # Account Order:
+    /// - [0] Main Treasury PDA (readable)
+    GetProgramStats {
+        // No fields needed - reads from main treasury state
+    },
+    
+    /// **PROTOCOL SWAP FEE**: Set the global SOL fee paid to the treasury per swap (admin authority only)
+    /// 
+    /// Once set, every swap must pass the Main Treasury PDA as a 12th account and pays
+    /// this fee in addition to the pool's swap contract fee. A fee of 0 disables it.
+    /// 
+    /// # Arguments:
+    /// - `swap_sol_fee`: Fee in lamports (0 to `MAX_SWAP_FEE`)
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (writable, to store the fee)
+    /// - [2] Program Data Account (for upgrade authority validation)
+    SetSwapSolFee {
+        swap_sol_fee: u64,
+    },
+    
+    /// **MULTI-HOP SWAP**: Swap through up to `MAX_SWAP_ROUTE_HOPS` pools in one transaction
+    /// 
+    /// Each hop is executed as a regular swap against its pool, with the output of
+    /// one hop becoming the input of the next. Each hop's input mint must be the
+    /// previous hop's output mint. Each leg pays its pool's swap contract fee.
+    /// Any failing leg reverts the whole route.
+    /// 
+    /// # Arguments:
+    /// - `hops`: Pool, input mint and vault PDAs of each leg, in execution order
+    ///   (more than `MAX_SWAP_ROUTE_HOPS` fails with `RouteTooLong`)
+    /// - `amount_in`: Amount of the first hop's input token to swap
+    /// - `minimum_final_out`: Minimum output of the last leg, otherwise the route fails
+    /// 
+    /// # Account Order:
+    /// - [0] User Authority Signer (writable, pays SOL fees)
+    /// - [1] System Program
+    /// - [2] System State PDA
+    /// - [3] SPL Token Program
+    /// - [4] Main Treasury PDA (writable, pays the protocol swap SOL fee when set)
+    /// - Then 7 accounts per hop, in hop order:
+    ///   - [+0] Pool State PDA (writable, `hops[i].pool_id`)
+    ///   - [+1] Token A Vault PDA (writable, `hops[i].token_a_vault`)
+    ///   - [+2] Token B Vault PDA (writable, `hops[i].token_b_vault`)
+    ///   - [+3] User Input Token Account (writable)
+    ///   - [+4] User Output Token Account (writable, must be the next hop's input account)
+    ///   - [+5] Input Token Mint
+    ///   - [+6] Output Token Mint
+    SwapRoute {
+        hops: Vec<RouteHop>,
+        amount_in: u64,
+        minimum_final_out: u64,
+    },
+    
+    /// **VIEW INSTRUCTION**: Check whether a swap of the given size would currently succeed
+    /// 
+    /// Combines the system/pool pause, owner-only, amount, liquidity and SOL fee checks
+    /// performed by `Swap` into a single go/no-go answer for wallets.
+    /// 
+    /// # Arguments:
+    /// - `input_token_mint`: Mint the user would swap from
+    /// - `amount_in`: Amount the user would swap
+    /// - `pool_id`: Pool State PDA to check against
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
+    /// - [1] System State PDA (readable)
+    /// - [2] User Authority (readable, checked for owner-only access and SOL fees)
+    /// 
+    /// # Returns (via return data):
+    /// - `[can_swap, reason_code]` as two little-endian u64 values; `reason_code` is
+    ///   the `PoolError` code of the first blocking check, or 0 when the swap can proceed
+    CanSwap {
+        input_token_mint: Pubkey,
+        amount_in: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// **VIEW INSTRUCTION**: Quote a swap without transferring anything
+    /// 
+    /// Runs the same fixed-ratio math and rounding as `Swap`, so the quoted output
+    /// always equals what an executed swap of the same size would pay out.
+    /// No signatures or user token accounts are required.
+    /// 
+    /// # Arguments:
+    /// - `input_token_mint`: Mint the user would swap from
+    /// - `amount_in`: Amount the user would swap
+    /// - `pool_id`: Pool State PDA to quote against
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
+    /// 
+    /// # Returns (via return data):
+    /// - `[amount_out, swap_fee_lamports, has_liquidity]` as three little-endian u64 values,
+    ///   where `has_liquidity` is 1 when the pool can currently pay `amount_out`
+    GetSwapQuote {
+        input_token_mint: Pubkey,
+        amount_in: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// Deposit both Token A and Token B into the pool in one instruction
+    /// 
+    /// Runs a regular single-sided `Deposit` for each side, so each side mints its
+    /// LP tokens 1:1 and pays the pool's liquidity fee. If either side fails the
+    /// whole instruction is rolled back.
+    /// 
+    /// # Arguments:
+    /// - `amount_a`: Amount of Token A to deposit
+    /// - `amount_b`: Amount of Token B to deposit
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] User Authority Signer (writable, pays SOL fees)
+    /// - [1] System Program
+    /// - [2] System State PDA
+    /// - [3] Pool State PDA (writable)
+    /// - [4] SPL Token Program
+    /// - [5] Token A Vault PDA (writable)
+    /// - [6] Token B Vault PDA (writable)
+    /// - [7] User Token A Account (writable)
+    /// - [8] User Token B Account (writable)
+    /// - [9] User LP Token A Account (writable)
+    /// - [10] User LP Token B Account (writable)
+    /// - [11] LP Token A Mint PDA (writable)
+    /// - [12] LP Token B Mint PDA (writable)
+    /// 
+    /// # Returns (via return data):
+    /// - `[lp_a_minted, lp_b_minted]` as two little-endian u64 values
+    DepositBoth {
+        amount_a: u64,
+        amount_b: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// **RESERVE SAFETY FLOOR**: Set the minimum liquidity swaps must leave in the pool (admin authority only)
+    /// 
+    /// Swaps whose output would take a token's liquidity below its floor are rejected
+    /// with `ReserveFloorBreached`. A floor of 0 disables the check for that token.
+    /// 
+    /// # Arguments:
+    /// - `reserve_safety_floor_a`: Minimum Token A liquidity after a swap (0 = no floor)
+    /// - `reserve_safety_floor_b`: Minimum Token B liquidity after a swap (0 = no floor)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (for pause and admin validation)
+    /// - [2] Pool State PDA (writable, to update the floors)
+    /// - [3] Program Data Account (for upgrade authority validation)
+    SetReserveSafetyFloor {
+        reserve_safety_floor_a: u64,
+        reserve_safety_floor_b: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// **VIEW INSTRUCTION**: Show how a swap's SOL fee divides between the protocol and the pool
+    /// 
+    /// Every swap pays the pool's swap contract fee, which stays with the pool until
+    /// consolidated, plus the global protocol swap SOL fee paid straight to the main
+    /// treasury. Both are flat lamport amounts, the same for every swap size, so there is
+    /// no percentage split and no swap amount to pass.
+    /// 
+    /// # Arguments:
+    /// - `pool_id`: Pool State PDA to report on
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
+    /// - [1] System State PDA (readable, for the protocol swap SOL fee)
+    /// 
+    /// # Returns (via return data):
+    /// - `[total_fee, protocol_portion, pool_portion]` as three little-endian u64 values (lamports)
+    GetSwapFeeBreakdown {
+        pool_id: Pubkey,
+    },
+    
+    /// Swap tokens at fixed ratio, optionally filling only what the pool can pay
+    /// 
+    /// When the output for `amount_in` exceeds the pool's output liquidity (above its
+    /// reserve safety floor) and `allow_partial` is set, swaps the largest input whose
+    /// output fits instead of failing. Only the filled input is taken from the user, so
+    /// the unused input never leaves their account. The filled input is rounded in the
+    /// pool's favor: it is the largest input paying out the filled output.
+    /// 
+    /// # Arguments:
+    /// - `input_token_mint`: Token mint being swapped from
+    /// - `amount_in`: Maximum amount of input tokens to swap
+    /// - `minimum_amount_out`: Minimum acceptable (filled) output
+    /// - `allow_partial`: Fill as much as possible when liquidity is short
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// Same 11 accounts (plus the optional Main Treasury PDA) as `Swap`
+    /// 
+    /// # Returns (via return data):
+    /// - `[amount_in_filled, amount_out_filled, amount_in_unfilled]` as three little-endian u64 values
+    SwapPartial {
+        input_token_mint: Pubkey,
+        amount_in: u64,
+        minimum_amount_out: u64,
+        allow_partial: bool,
+        pool_id: Pubkey,
+    },
+    
+    /// Pool initialization with a custom starting swap contract fee
+    /// 
+    /// Same as `InitializePool`, which keeps its original layout for existing clients,
+    /// plus an optional starting swap fee so pools with different economics do not
+    /// need a follow-up `UpdatePoolFees`.
+    /// 
+    /// # Arguments:
+    /// - `ratio_a_numerator`, `ratio_b_denominator`, `flags`: As in `InitializePool`
+    /// - `initial_swap_contract_fee`: Starting swap fee in lamports (`MIN_SWAP_FEE` to
+    ///   `MAX_SWAP_FEE`); `None` uses the default `SWAP_CONTRACT_FEE`
+    /// 
+    /// # Account Order:
+    /// Same 13 accounts as `InitializePool`
+    InitializePoolWithSwapFee {
+        ratio_a_numerator: u64,
+        ratio_b_denominator: u64,
+        flags: u8,
+        initial_swap_contract_fee: Option<u64>,
+    },
+    
+    /// **VIEW INSTRUCTION**: Export a pool's configuration as a `PortablePoolConfig`
+    /// 
+    /// Returns every setting needed to back up the pool or recreate it on another
+    /// network: mints, ratio, flags, owner, fees, amount limits, LP cap, reserve
+    /// floors, minimum reserve and swap mode. Runtime balances and counters are not
+    /// included. The snapshot fits in a single return data buffer.
+    /// 
+    /// # Arguments:
+    /// - `pool_id`: Pool State PDA to export
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
+    /// 
+    /// # Returns (via return data):
+    /// - Borsh-serialized `PortablePoolConfig`
+    GetPoolConfig {
+        pool_id: Pubkey,
+    },
+    
+    /// Withdraw one side of an LP position and swap it into the other token atomically
+    /// 
+    /// Lets an LP exit entirely in the pool's other token: burns `lp_amount` LP tokens
+    /// for the underlying token (1:1, liquidity fee applies), then swaps all of it at
+    /// the pool's fixed ratio (swap fees apply). Either both legs succeed or neither does.
+    /// 
+    /// # Arguments:
+    /// - `lp_amount`: Amount of LP tokens to burn
+    /// - `lp_token_mint`: LP mint being burned (LP Token A or LP Token B mint PDA)
+    /// - `output_token_mint`: Token to receive; must be the pool token the LP mint does not represent
+    /// - `min_out`: Minimum acceptable output from the swap leg
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] User Authority Signer (writable, pays SOL fees)
+    /// - [1] System Program
+    /// - [2] System State PDA
+    /// - [3] Pool State PDA (writable)
+    /// - [4] SPL Token Program
+    /// - [5] Token A Vault PDA (writable)
+    /// - [6] Token B Vault PDA (writable)
+    /// - [7] User Input LP Token Account (writable)
+    /// - [8] User Withdrawn Token Account (writable, receives the withdrawal and funds the swap)
+    /// - [9] LP Token A Mint PDA (writable)
+    /// - [10] LP Token B Mint PDA (writable)
+    /// - [11] User Output Token Account (writable)
+    /// - [12] Withdrawn Token Mint
+    /// - [13] Output Token Mint
+    /// - [14] Main Treasury PDA (writable, optional; required when the protocol swap SOL fee is set)
+    /// 
+    /// # Returns (via return data):
+    /// - `[amount_withdrawn, amount_out]` as two little-endian u64 values
+    WithdrawAndSwap {
+        lp_amount: u64,
+        lp_token_mint: Pubkey,
+        output_token_mint: Pubkey,
+        min_out: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// Swap for an exact output amount at the fixed ratio
+    /// 
+    /// Derives the smallest input that pays out at least `amount_out` (rounded up so
+    /// the vault is never short-changed) and fails if it exceeds `maximum_amount_in`.
+    /// Swap fees are flat SOL amounts, so they never change the token input.
+    /// 
+    /// # Arguments:
+    /// - `output_token_mint`: Token mint to receive
+    /// - `amount_out`: Output amount to receive
+    /// - `maximum_amount_in`: Most input tokens the user will spend
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// Same 11 accounts (plus the optional Main Treasury PDA) as `Swap`
+    /// 
+    /// # Returns (via return data):
+    /// - `[amount_in, amount_out]` as executed, two little-endian u64 values
+    SwapExactOut {
+        output_token_mint: Pubkey,
+        amount_out: u64,
+        maximum_amount_in: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// Rewrite a pool state account written with an older layout in the current layout
+    /// 
+    /// Reads the account with any supported schema version, maps it forward (fields
+    /// the old layout lacks start at their defaults), grows the account to the current
+    /// size with the signer paying the extra rent, and writes it back. Allowed while
+    /// the system is paused; a pool already at `target_version` is left unchanged.
+    /// 
+    /// # Arguments:
+    /// - `target_version`: Layout version to migrate to (must be `POOL_STATE_SCHEMA_VERSION`)
+    /// 
+    /// # Account Order:
+    /// - [0] Authority Signer (signer, writable; pool owner or admin authority, pays any extra rent)
+    /// - [1] System Program
+    /// - [2] System State PDA (for admin validation)
+    /// - [3] Pool State PDA (writable)
+    /// - [4] Program Data Account (for upgrade authority validation)
+    MigratePoolState {
+        target_version: u16,
+    },
+    
+    
+    /// **MINIMUM RESERVE**: Set the minimum liquidity withdrawals must leave in each vault (admin authority only)
+    /// 
+    /// Withdrawals that would take the withdrawn token's liquidity below the minimum are
+    /// rejected with `BelowMinimumReserve`. The minimum applies to both vaults, each in its
+    /// own token's base units; 0 disables the check.
+    /// 
+    /// # Arguments:
+    /// - `minimum_reserve`: Minimum liquidity left in a vault after a withdrawal (0 = no minimum)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (for pause and admin validation)
+    /// - [2] Pool State PDA (writable, to update the minimum)
+    /// - [3] Program Data Account (for upgrade authority validation)
+    SetMinimumReserve {
+        minimum_reserve: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// **VIEW INSTRUCTION**: Get a pool account's lifecycle state
+    /// 
+    /// Tells an uninitialized pool account apart from a live or paused pool. An
+    /// account with no lamports or no data is `Uninitialized`; otherwise the pool
+    /// is `Paused` while an owner pause of liquidity or swaps is in effect, and
+    /// `Active` otherwise (an elapsed auto-unpause timer counts as `Active`).
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable, may not exist yet)
+    /// 
+    /// # Returns (via return data):
+    /// - Borsh-serialized `PoolInitState` (1 byte)
+    GetInitState {},
+    
+    /// **SWAP MODE**: Restrict a pool to exact-in or exact-out swaps (admin authority only)
+    /// 
+    /// With `ExactOutOnly`, `Swap`, `SwapPartial`, `SwapRoute` and `WithdrawAndSwap` are
+    /// rejected and only `SwapExactOut` (a given output for at most a maximum input) is
+    /// accepted; `ExactInOnly` is the reverse. Disallowed swaps fail with `SwapModeNotAllowed`.
+    /// New pools accept both.
+    /// 
+    /// # Arguments:
+    /// - `swap_mode`: Swaps the pool accepts (`Both`, `ExactInOnly` or `ExactOutOnly`)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (for pause and admin validation)
+    /// - [2] Pool State PDA (writable, to update the mode)
+    /// - [3] Program Data Account (for upgrade authority validation)
+    SetSwapMode {
+        swap_mode: SwapMode,
+        pool_id: Pubkey,
+    },
+    
+    /// **VIEW INSTRUCTION**: Dry run of `ConsolidatePoolFees`
+    /// 
+    /// Reports how many lamports a consolidation with the same accounts would sweep,
+    /// applying the same pause eligibility, rent-exempt and partial consolidation
+    /// rules, without writing any account or moving funds. The admin authority
+    /// does not need to sign.
+    /// 
+    /// # Arguments:
+    /// - `pool_count`: Number of pools to simulate (1-20)
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Four little-endian `u64` values:
+    /// `[total_lamports, liquidity_fees, regular_swap_fees, pools_consolidated]`
+    /// 
+    /// # Account Order:
+    /// Identical to `ConsolidatePoolFees`:
+    /// - [0] Admin Authority (signature not required)
+    /// - [1] System State PDA (for consolidation mode)
+    /// - [2] Main Treasury PDA
+    /// - [3] Program Data Account
+    /// - [4..4+pool_count] Pool State PDAs
+    SimulateConsolidation {
+        pool_count: u8,
+    },
+    
+    /// **VIEW INSTRUCTION**: Get a pool's age, swap activity, operation count and lifetime totals
+    /// 
+    /// Surfaces inactive pools for cleanup. A pool is dormant when it has not swapped
+    /// within `POOL_DORMANCY_WINDOW_SECONDS` (30 days). Pools created before schema
+    /// version 5 report an age of 0 and are measured from their first recorded swap.
+    /// `total_operations` divided by the age gives the pool's operation rate; the u128
+    /// lifetime volume and fee totals saturate instead of overflowing.
+    /// 
+    /// # Arguments:
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Borsh-serialized `PoolActivity`: `age_seconds: u64`, `seconds_since_last_swap: u64`, `dormant: bool`,
+    /// `total_operations: u64`, `cumulative_volume_token_a: u128`, `cumulative_volume_token_b: u128`,
+    /// `lifetime_fees: u128` (u128 values are 16 bytes little-endian)
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
+    GetPoolActivity {
+        pool_id: Pubkey,
+    },
+    
+    /// **VIEW INSTRUCTION**: Get the collected token fees of several pools at once
+    /// 
+    /// Batch form of `GetFeeInfo`. Accounts that are not valid pool states are
+    /// skipped rather than failing the call, so the result may hold fewer entries
+    /// than accounts passed.
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Borsh `Vec<PoolFeeSummary>`: a little-endian `u32` count, then per pool
+    /// `pool: Pubkey`, `collected_fees_token_a: u64`, `collected_fees_token_b: u64`
+    /// 
+    /// # Account Order:
+    /// - [0..n] Pool State PDAs (readable, at most `MAX_POOLS_PER_FEE_QUERY` = 21)
+    GetAllPoolFees {},
+    
+    /// **TREASURY AUTO-COMPOUNDING**: Configure the pool idle treasury SOL is compounded into (admin authority only)
+    /// 
+    /// The pool must hold wrapped SOL (the native mint) on one side. `CompoundTreasury`
+    /// then deposits everything above the treasury's rent-exempt minimum plus
+    /// `reserve_lamports` into that side. Pass `Pubkey::default()` as `pool_id` to disable.
+    /// 
+    /// # Arguments:
+    /// - `pool_id`: Pool to compound into (`Pubkey::default()` disables auto-compounding)
+    /// - `reserve_lamports`: Lamports always kept in the treasury above its rent-exempt minimum
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (for pause and admin validation)
+    /// - [2] Main Treasury PDA (writable, stores the configuration)
+    /// - [3] Program Data Account (for upgrade authority validation)
+    /// - [4] Pool State PDA (readable, ignored when disabling)
+    SetTreasuryAutocompound {
+        pool_id: Pubkey,
+        reserve_lamports: u64,
+    },
+    
+    /// **TREASURY AUTO-COMPOUNDING**: Deposit idle treasury SOL into the auto-compound pool (admin authority only)
+    /// 
+    /// Wraps the treasury balance above its rent-exempt minimum and configured reserve,
+    /// deposits it on the pool's wrapped SOL side and mints LP tokens 1:1 to the treasury's
+    /// LP token account. No liquidity fee is charged. Fails with `NothingToCompound`
+    /// when nothing lies above the reserve.
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Lamports compounded as a little-endian `u64`
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (for pause and admin validation)
+    /// - [2] Main Treasury PDA (writable)
+    /// - [3] Program Data Account (for upgrade authority validation)
+    /// - [4] Pool State PDA (writable, the configured pool)
+    /// - [5] Treasury Wrapped SOL Account (writable, native mint, owned by the treasury PDA)
+    /// - [6] Pool Vault PDA (writable, wrapped SOL side)
+    /// - [7] LP Token Mint PDA (writable, wrapped SOL side)
+    /// - [8] Treasury LP Token Account (writable, owned by the treasury PDA)
+    /// - [9] SPL Token Program
+    CompoundTreasury {},
+    
+    /// **VIEW INSTRUCTION**: Get ratio, fees and liquidity of several pools at once
+    /// 
+    /// Batch form of `GetPoolInfo`. Accounts that are not valid initialized pool
+    /// states are skipped rather than failing the call and their indices reported.
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Borsh `MultiplePoolInfo`: `pools: Vec<PoolSummary>` (u32 count, then per pool
+    /// `pool: Pubkey`, `ratio_a_numerator`, `ratio_b_denominator`, `contract_liquidity_fee`,
+    /// `swap_contract_fee`, `total_token_a_liquidity`, `total_token_b_liquidity` as `u64`),
+    /// then `skipped: Vec<u8>` (u32 count, then one account index per skipped account)
+    /// 
+    /// # Account Order:
+    /// - [0..n] Pool State PDAs (readable, at most `MAX_POOLS_PER_INFO_QUERY` = 12)
+    GetMultiplePoolInfo {},
+    
+    /// **MINIMUM SWAP SIZE**: Set the smallest input a pool accepts for a swap (admin authority only)
+    /// 
+    /// Swaps whose input amount is below the minimum fail with `SwapAmountBelowMinimum`,
+    /// which keeps dust swaps from churning the pool for a fraction of a token. Applies to
+    /// every swap instruction. New pools have no minimum (0). The minimum may not exceed a
+    /// nonzero `max_swap_amount`, and its output at the pool ratio must fit in a `u64`.
+    /// 
+    /// # Arguments:
+    /// - `min_swap_amount`: Smallest accepted swap input in basis points (0 disables the check)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (for pause and admin validation)
+    /// - [2] Pool State PDA (writable, to update the minimum)
+    /// - [3] Program Data Account (for upgrade authority validation)
+    SetMinSwapAmount {
+        min_swap_amount: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// **CONSOLIDATION SKIP WINDOW**: Skip recently consolidated pools in `ConsolidatePoolFees` (admin authority only)
+    /// 
+    /// Pools whose `last_consolidation_timestamp` is less than `window_seconds` old are
+    /// skipped instead of processed, so a failed or partial batch can be resubmitted with
+    /// the same account list. `SimulateConsolidation` applies the same window. 0 disables it.
+    /// 
+    /// # Arguments:
+    /// - `window_seconds`: Skip window in seconds (0 to `MAX_CONSOLIDATION_SKIP_WINDOW`)
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (writable, to store the window)
+    /// - [2] Program Data Account (for upgrade authority validation)
+    SetConsolidationSkipWindow {
+        window_seconds: u64,
+    },
+    
+    /// **VIEW INSTRUCTION**: Get which of two mints would be Token A and which Token B
+    /// 
+    /// Pools store the lexicographically smaller mint as Token A. Lets clients order a
+    /// pair correctly (vaults, LP mints, ratio sides) before the pool exists. Both
+    /// argument orders return the same ordering. Identical mints fail with `InvalidArgument`.
+    /// 
+    /// # Arguments:
+    /// - `mint_x`: One mint of the pair
+    /// - `mint_y`: The other mint of the pair
+    /// 
+    /// # Account Order:
+    /// - None
+    /// 
+    /// # Returns (via return data):
+    /// - Borsh-serialized `CanonicalOrdering`: `token_a_mint`, `token_b_mint`, `mint_x_is_token_a` (65 bytes)
+    GetCanonicalOrdering {
+        mint_x: Pubkey,
+        mint_y: Pubkey,
+    },
+    
+    /// Withdraw an entire LP position without knowing its size in advance
+    /// 
+    /// Reads the balance of the user's LP token account and withdraws all of it, exactly
+    /// like `Withdraw` with `lp_amount_to_burn` set to that balance. LP tokens are burned
+    /// 1:1 for the underlying token, so no LP dust is left behind. An empty LP token
+    /// account fails with `NoLpTokensToWithdraw`.
+    /// 
+    /// # Arguments:
+    /// - `withdraw_token_mint`: Token to withdraw (Token A or Token B of the pool)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// Identical to `Withdraw` (11 accounts), with [7] the LP token account to empty
+    /// 
+    /// # Returns (via return data):
+    /// - LP tokens burned as a little-endian u64
+    WithdrawAll {
+        withdraw_token_mint: Pubkey,
+        pool_id: Pubkey,
+    },
+    
+    /// **POOL CREATION DURING PAUSE**: Allow or block `InitializePool` while the system is paused (admin authority only)
+    /// 
+    /// Pool creation is blocked during a system pause by default (`PoolCreationPausedBySystem`).
+    /// Setting `allowed` to true keeps it available, e.g. for planned maintenance pauses.
+    /// The setting can be changed while the system is paused.
+    /// 
+    /// # Arguments:
+    /// - `allowed`: Whether pools can be created while the system is paused
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (writable, to store the flag)
+    /// - [2] Program Data Account (for upgrade authority validation)
+    SetAllowPoolCreationWhilePaused {
+        allowed: bool,
+    },
+    
+    /// **SWAP FEE BAND**: Bound the swap fee `UpdatePoolFees` may set (pool owner only)
+    /// 
+    /// Fee changes outside `[min_allowed_fee, max_allowed_fee]` fail with `SwapFeeOutsideBand`.
+    /// A bound of 0 leaves that side open; `(0, 0)` removes the band. The current fee is not changed.
+    /// 
+    /// # Arguments:
+    /// - `min_allowed_fee`: Lowest allowed swap fee in lamports (0 = no lower bound)
+    /// - `max_allowed_fee`: Highest allowed swap fee in lamports (0 = no upper bound)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] Pool Owner Signer
+    /// - [1] System State PDA (for pause validation)
+    /// - [2] Pool State PDA (writable, to store the band)
+    SetSwapFeeBand {
+        min_allowed_fee: u64,
+        max_allowed_fee: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// **STRANDED TOKEN RESCUE**: Recover unrelated tokens sent to a pool-owned token account (pool owner only)
+    /// 
+    /// Transfers `amount` out of a token account whose authority is the pool state PDA,
+    /// with the PDA signing via its seeds. Token A and Token B are never rescuable and fail
+    /// with `CannotRescuePoolToken`. Allowed while the system or pool is paused.
+    /// 
+    /// # Arguments:
+    /// - `mint`: Mint of the stranded tokens
+    /// - `amount`: Amount to transfer out
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] Pool Owner Signer
+    /// - [1] Pool State PDA
+    /// - [2] Stranded Token Account (writable, owned by the pool state PDA)
+    /// - [3] Destination Token Account (writable)
+    /// - [4] SPL Token Program
+    RescueStrandedTokens {
+        mint: Pubkey,
+        amount: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// **VIEW INSTRUCTION**: Dump a pool's complete state for debugging
+    /// 
+    /// Returns every `PoolState` field in one call instead of assembling them from
+    /// `GetPoolInfo`, `GetLiquidityInfo` and `GetFeeInfo`.
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// - Byte 0: layout version of the bytes that follow (`POOL_STATE_SCHEMA_VERSION`);
+    ///   decode the rest with the `PoolState` layout of that version
+    /// - Bytes 1..: Borsh-serialized `PoolState`, truncated if the dump would exceed
+    ///   the 1024-byte return data limit
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
+    GetPoolStateRaw {},
+    
+    /// **VIEW INSTRUCTION**: Get the exact instruction data of a `Swap`
+    /// 
+    /// For hardware wallet integrations: returns the canonical bytes of the swap
+    /// instruction with these arguments so clients can compare them with what they
+    /// built before signing. No accounts are read and nothing is validated.
+    /// 
+    /// # Arguments:
+    /// - Same as `Swap`, plus the `SwapWithExpiry` deadline and slot expiry; `expected_amount_out`
+    ///   is the exact output the swap must produce
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Borsh-serialized `PoolInstruction::Swap { .. }`, or `PoolInstruction::SwapWithExpiry { .. }`
+    /// when a deadline or slot expiry is given, identical to `try_to_vec()` on the client
+    /// 
+    /// # Account Order:
+    /// - None
+    EncodeSwap {
+        input_token_mint: Pubkey,
+        amount_in: u64,
+        expected_amount_out: u64,
+        pool_id: Pubkey,
+        deadline: Option<i64>,
+        not_valid_after_slot: Option<u64>,
+    },
+    
+    /// **VIEW INSTRUCTION**: Get the system pause status and system message
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Borsh-serialized `SystemStatus`: `is_paused: bool`, `pause_reason: PauseReason`,
+    /// `pause_timestamp: i64`, `paused_by: Pubkey`, `system_message: String`. Reason,
+    /// timestamp and authority are cleared by `UnpauseSystem`; the message stays until
+    /// the admin changes it with `SetSystemMessage`.
+    /// 
+    /// # Account Order:
+    /// - [0] System State PDA (readable)
+    GetSystemStatus {},
+    
+    /// **VIEW INSTRUCTION**: Get a pool's swap contract fees per UTC day
+    /// 
+    /// Covers the last `FEE_HISTORY_DAYS` (7) days, kept in a fixed ring in the
+    /// pool state that swaps update as they collect their fee.
+    /// 
+    /// # Arguments:
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Borsh-serialized `FeeHistory`: `day_start: i64` (UTC midnight of today), then
+    /// `fees_token_a: [u64; 7]` and `fees_token_b: [u64; 7]` in lamports, today first,
+    /// split by the token the swaps paid in
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
+    GetFeeHistory {
+        pool_id: Pubkey,
+    },
+    
+    /// **EVENT EMISSION**: Turn a pool's structured events on or off (admin authority only)
+    /// 
+    /// With `emit_events` false, swaps, deposits and withdrawals on the pool skip their
+    /// `SWAP|..` / `DEPOSIT|..` / `WITHDRAW|..` log lines and `sol_log_data` event blobs,
+    /// saving compute and log space on high-frequency pools. The operations are otherwise
+    /// unchanged. New pools emit events.
+    /// 
+    /// # Arguments:
+    /// - `emit_events`: Whether the pool emits structured events
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (for pause and admin validation)
+    /// - [2] Pool State PDA (writable, to update the toggle)
+    /// - [3] Program Data Account (for upgrade authority validation)
+    SetEventEmission {
+        emit_events: bool,
+        pool_id: Pubkey,
+    },
+    
+    /// **OWNER TRANSFER (STEP 1)**: Nominate a new pool owner (pool owner only)
+    /// 
+    /// Records `new_owner` as the pending owner. Ownership does not move until the
+    /// nominee signs `AcceptOwnerTransfer` at least `POOL_OWNER_TRANSFER_DELAY` seconds
+    /// later, so a mistyped address never receives the pool. Initiating again replaces
+    /// the nominee and restarts the delay.
+    /// 
+    /// # Arguments:
+    /// - `new_owner`: Proposed new pool owner (must differ from the current owner)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] Pool Owner Signer
+    /// - [1] System State PDA (for pause validation)
+    /// - [2] Pool State PDA (writable, to store the pending owner)
+    InitiateOwnerTransfer {
+        new_owner: Pubkey,
+        pool_id: Pubkey,
+    },
+    
+    /// **OWNER TRANSFER (STEP 2)**: Accept a pending pool ownership transfer (pending owner only)
+    /// 
+    /// Fails with `OwnerTransferTimelockActive` until `POOL_OWNER_TRANSFER_DELAY` seconds
+    /// have passed since `InitiateOwnerTransfer`.
+    /// 
+    /// # Arguments:
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] Pending Owner Signer
+    /// - [1] System State PDA (for pause validation)
+    /// - [2] Pool State PDA (writable, to record the new owner)
+    AcceptOwnerTransfer {
+        pool_id: Pubkey,
+    },
+    
+    /// **OWNER TRANSFER (CANCEL)**: Withdraw a pending pool ownership transfer (pool owner only)
+    /// 
+    /// Allowed while the system is paused, so a pause never leaves the owner unable to
+    /// stop a transfer that becomes acceptable when the system resumes.
+    /// 
+    /// # Arguments:
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Account Order:
+    /// - [0] Pool Owner Signer
+    /// - [1] Pool State PDA (writable, to clear the pending owner)
+    CancelOwnerTransfer {
+        pool_id: Pubkey,
+    },
+    
+    /// **LIQUIDITY MIGRATION**: Move all liquidity into a fresh pool of the same token pair (pool owner only)
+    /// 
+    /// Re-launches a pool at a corrected ratio. Both source vaults are emptied into the
+    /// destination vaults, the source liquidity is zeroed and the destination is credited
+    /// with what its vaults received. The destination mints LP Token A and LP Token B 1:1
+    /// for the moved amounts into the source pool's migration escrow accounts (created
+    /// here, controlled by the source pool PDA). Each source LP holder then swaps their own
+    /// source LP tokens for them with `ClaimMigratedLiquidity`; the owner receives no LP.
+    /// 
+    /// Both pools must be owned by the signer and share Token A and Token B
+    /// (`MigrationTokenPairMismatch` otherwise), and the destination must hold no
+    /// liquidity or LP tokens (`MigrationDestinationNotEmpty`). A pool can only be
+    /// migrated from once (`AccountAlreadyInitialized`). Allowed while either pool is paused.
+    /// 
+    /// # Arguments:
+    /// - `source_pool_id`: Expected Pool ID (PDA address) of the pool being emptied
+    /// - `dest_pool_id`: Expected Pool ID (PDA address) of the pool receiving the liquidity
+    /// 
+    /// # Account Order:
+    /// - [0] Pool Owner Signer (owner of both pools, writable; pays the escrow rent)
+    /// - [1] System State PDA (for pause validation)
+    /// - [2] Source Pool State PDA (writable)
+    /// - [3] Source Token A Vault PDA (writable)
+    /// - [4] Source Token B Vault PDA (writable)
+    /// - [5] Destination Pool State PDA (writable)
+    /// - [6] Destination Token A Vault PDA (writable)
+    /// - [7] Destination Token B Vault PDA (writable)
+    /// - [8] Destination LP Token A Mint PDA (writable)
+    /// - [9] Destination LP Token B Mint PDA (writable)
+    /// - [10] Migration Escrow LP Token A PDA (writable, seeds `migration_escrow_lp_a` + source pool)
+    /// - [11] Migration Escrow LP Token B PDA (writable, seeds `migration_escrow_lp_b` + source pool)
+    /// - [12] SPL Token Program
+    /// - [13] System Program
+    /// - [14] Rent Sysvar
+    /// 
+    /// # Returns (via return data):
+    /// - `[token_a_moved, token_b_moved]` as two little-endian u64 values
+    MigrateLiquidity {
+        source_pool_id: Pubkey,
+        dest_pool_id: Pubkey,
+    },
+    
+    /// **TREASURY TOKEN WITHDRAWAL**: Withdraw SPL tokens held by the treasury (admin authority only)
+    /// 
+    /// The SPL counterpart of `WithdrawTreasuryFees` for tokens the treasury accrues, such as
+    /// the LP tokens minted by `CompoundTreasury`. Transfers from a token account owned by the
+    /// main treasury PDA, with the PDA signing. Withdrawing auto-compound LP tokens lowers
+    /// `autocompound_lp_tokens` accordingly.
+    /// 
+    /// # Arguments:
+    /// - `token_mint`: Mint of the tokens to withdraw
+    /// - `amount`: Amount to withdraw (0 = the whole balance of the treasury token account)
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (for pause and admin validation)
+    /// - [2] Main Treasury PDA (writable)
+    /// - [3] Program Data Account (for upgrade authority validation)
+    /// - [4] Treasury Token Account (writable, `token_mint`, owned by the treasury PDA)
+    /// - [5] Destination Token Account (writable, `token_mint`)
+    /// - [6] SPL Token Program
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Tokens withdrawn as a little-endian `u64`
+    WithdrawTreasuryTokens {
+        token_mint: Pubkey,
+        amount: u64,
+    },
+    
+    /// **SYSTEM MESSAGE**: Broadcast an on-chain message to users (admin authority only)
+    /// 
+    /// Lets the admin explain an incident, e.g. "paused for audit, funds safe, see URL".
+    /// The message is stored in the system state and reported by `GetSystemStatus`; it
+    /// is independent of the pause state and can be set while the system is paused.
+    /// 
+    /// # Arguments:
+    /// - `message`: UTF-8 bytes, at most `SYSTEM_MESSAGE_MAX_LEN` (128) and without zero
+    ///   bytes; empty clears the message
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (writable, to store the message)
+    /// - [2] Program Data Account (for upgrade authority validation)
+    SetSystemMessage {
+        message: Vec<u8>,
+    },
+    
+    /// **POOL PARAMETER DRY RUN**: Check whether `InitializePool` would accept a configuration
+    /// 
+    /// Runs the same checks as pool creation (system pause, mint decimals and ownership,
+    /// same-mint rejection, non-zero ratio, pool PDA derivation, existing pool, ratio type)
+    /// without paying the registration fee or creating accounts. The pair is given in the
+    /// user's terms and normalized on-chain (Token A < Token B, ratios swapped with the
+    /// mints). The registration fee balance of the creator is not checked.
+    /// 
+    /// # Arguments:
+    /// - `multiple_token_mint`: Mint of the abundant token
+    /// - `base_token_mint`: Mint of the base token
+    /// - `ratio_a_numerator`: Multiple token amount in basis points
+    /// - `ratio_b_denominator`: Base token amount in basis points
+    /// 
+    /// # Account Order:
+    /// - [0] System State PDA (for pause validation)
+    /// - [1] Multiple Token Mint (`multiple_token_mint`)
+    /// - [2] Base Token Mint (`base_token_mint`)
+    /// - [3] Pool State PDA (derived from the normalized configuration)
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Little-endian `u64`: `0` if the pool would pass validation, otherwise the error code
+    /// creation would fail with (`PoolError` code, or the builtin `ProgramError` encoding)
+    ValidatePoolParams {
+        multiple_token_mint: Pubkey,
+        base_token_mint: Pubkey,
+        ratio_a_numerator: u64,
+        ratio_b_denominator: u64,
+    },
+    
+    /// **VIEW INSTRUCTION**: Verify a pool matches an expected configuration hash
+    /// 
+    /// For deployment verification: the SHA-256 of the pool's immutable configuration
+    /// (`PoolState::config_hash`: Token A/B mints, the ratio as requested at creation as
+    /// little-endian u64s, Token A/B vaults, LP Token A/B mints) is compared with
+    /// `expected_hash`. Mutable settings such as the owner, flags and fees are not part of
+    /// the hash.
+    /// 
+    /// # Arguments:
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// - `expected_hash`: SHA-256 the pool's configuration should hash to
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Borsh `bool`: true when the pool's hash equals `expected_hash`
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
+    VerifyPoolConfig {
+        pool_id: Pubkey,
+        expected_hash: [u8; 32],
+    },
+    
+    /// **VIEW INSTRUCTION**: Get the most recent donations to the treasury
+    /// 
+    /// The treasury keeps the last `RECENT_DONATIONS_CAPACITY` (12) donations in a ring
+    /// buffer; older entries are overwritten. Lifetime totals are reported by
+    /// `GetTreasuryInfo` (`donation_count`, `total_donations`).
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Borsh-serialized `Vec<DonationRecord>`, newest first: `donor: Pubkey`, `amount: u64`,
+    /// `message: [u8; 32]` (first `DONATION_MESSAGE_PREVIEW_LEN` bytes, zero-padded),
+    /// `timestamp: i64`
+    /// 
+    /// # Account Order:
+    /// - [0] Main Treasury PDA (readable)
+    GetRecentDonations {},
+    
+    /// **VIEW INSTRUCTION**: Get the fees a liquidity deposit would be charged
+    /// 
+    /// Uses the same fee as `Deposit`: LP tokens are minted 1:1 with the deposited
+    /// tokens, so the token-unit fee is always zero, and the pool's flat
+    /// `contract_liquidity_fee` is charged in SOL whatever the amount.
+    /// 
+    /// # Arguments:
+    /// - `deposit_token_mint`: Token to deposit (must be the pool's Token A or Token B)
+    /// - `amount`: Amount of tokens to deposit
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Two little-endian u64 values: `[token_fee, sol_fee]` (token units, lamports)
+    /// 
+    /// # Account Order:
+    /// - [0] Pool State PDA (readable)
+    GetDepositFee {
+        deposit_token_mint: Pubkey,
+        amount: u64,
+        pool_id: Pubkey,
+    },
+    
+    /// **ROUNDING SURPLUS SWEEP**: Move a token's accumulated rounding surplus to the owner (pool owner only)
+    /// 
+    /// Transfers the surplus reported by `GetRoundingSurplus` for `sweep_token_mint` out
+    /// of its vault, capped at both the surplus counter and the token's tracked liquidity.
+    /// The amount is removed from the liquidity and from the counter. Nothing to sweep is
+    /// not an error; the swept amount is 0.
+    /// 
+    /// # Arguments:
+    /// - `sweep_token_mint`: Token whose surplus is swept (Token A or Token B)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Amount swept as a little-endian u64
+    /// 
+    /// # Account Order:
+    /// - [0] Pool Owner Signer
+    /// - [1] System State PDA (for pause validation)
+    /// - [2] Pool State PDA (writable)
+    /// - [3] Token Vault PDA for `sweep_token_mint` (writable)
+    /// - [4] Destination Token Account (writable)
+    /// - [5] SPL Token Program
+    SweepRoundingSurplus {
+        sweep_token_mint: Pubkey,
+        pool_id: Pubkey,
+    },
+    
+    /// **FEE RECIPIENT CHANGE**: Pay out pending fees to the current recipient, then switch recipients (admin only)
+    /// 
+    /// Consolidates the pool's pending SOL fees to its current fee recipient (the
+    /// MainTreasury unless a previous call set one) under the usual rent-exempt and
+    /// partial consolidation rules, then records `new_recipient` so later fees accrue
+    /// for it. Both steps happen in this one instruction, so no fee is ever credited
+    /// to the wrong recipient and no pause is required. `ConsolidatePoolFees` skips
+    /// pools with a custom recipient; passing the current recipient again collects
+    /// their fees.
+    /// 
+    /// # Arguments:
+    /// - `new_recipient`: Account that receives future fees (`Pubkey::default()` or the
+    ///   Main Treasury PDA restores the MainTreasury)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// 
+    /// # Returns (via `set_return_data`):
+    /// Lamports paid to the old recipient as a little-endian u64
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer
+    /// - [1] System State PDA (for admin validation)
+    /// - [2] Main Treasury PDA (writable)
+    /// - [3] Program Data Account (for upgrade authority validation)
+    /// - [4] Pool State PDA (writable)
+    /// - [5] Current Fee Recipient (writable; the Main Treasury PDA when the pool has no custom recipient)
+    ChangeFeeRecipientAndConsolidate {
+        new_recipient: Pubkey,
+        pool_id: Pubkey,
+    },
+    
+    /// Swap tokens at fixed ratio, refusing to execute after a deadline or slot
+    /// 
+    /// Same swap as `Swap`; the expiry lives in its own variant so `Swap` instruction
+    /// data keeps the layout existing clients already build. Either expiry, both or
+    /// neither may be given.
+    /// 
+    /// # Arguments:
+    /// - `input_token_mint`: Token mint being swapped from
+    /// - `amount_in`: Amount of input tokens to swap
+    /// - `expected_amount_out`: Expected output amount (for validation)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// - `deadline`: Optional Unix timestamp after which the swap must not execute
+    /// - `not_valid_after_slot`: Optional last slot in which the swap may execute,
+    ///   independent of blockhash age
+    /// 
+    /// # Account Order:
+    /// Same 11 accounts (plus the optional Main Treasury PDA) as `Swap`
+    SwapWithExpiry {
+        input_token_mint: Pubkey,
+        amount_in: u64,
+        expected_amount_out: u64,
+        pool_id: Pubkey,
+        deadline: Option<i64>,
+        not_valid_after_slot: Option<u64>,
+    },
+    
+    /// Pause pool operations until a given time (admin only)
+    /// 
+    /// Same as `PausePool`, but the pause stops blocking operations once the clock
+    /// passes `auto_unpause_at`, with no follow-up `UnpausePool` needed. A later
+    /// `PausePool` or `UnpausePool` clears the timer.
+    /// 
+    /// # Arguments:
+    /// - `pause_flags`: Bitwise flags indicating which operations to pause
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// - `auto_unpause_at`: Future Unix timestamp after which the pause no longer applies
+    /// 
+    /// # Account Order:
+    /// Same 4 accounts as `PausePool`
+    PausePoolUntil {
+        pause_flags: u8,
+        pool_id: Pubkey,
+        auto_unpause_at: i64,
+    },
+    
+    /// Rewrite a system state account created with an older, shorter layout in the
+    /// current layout (admin only)
+    /// 
+    /// Older accounts can still be read (missing trailing fields read as zero), but
+    /// setters cannot write the larger layout back into them. This grows the account
+    /// to `SystemState::LEN` with the signer paying the extra rent and writes it back.
+    /// Allowed while the system is paused; an account already at full size is left
+    /// unchanged.
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer (signer, writable; pays any extra rent)
+    /// - [1] System Program
+    /// - [2] System State PDA (writable)
+    /// - [3] Program Data Account (for upgrade authority validation)
+    MigrateSystemState {},
+    
+    /// Deposit liquidity, failing unless at least `minimum_lp_out` LP tokens are minted
+    /// 
+    /// Same deposit as `Deposit`; the floor lives in its own variant so `Deposit`
+    /// instruction data keeps the layout existing clients already build. LP tokens are
+    /// minted 1:1, so the check runs before any transfer and only fails when
+    /// `minimum_lp_out` is above `amount`.
+    /// 
+    /// # Arguments:
+    /// - `deposit_token_mint`: Token mint being deposited (must match pool's Token A or Token B)
+    /// - `amount`: Amount of tokens to deposit (will receive exactly this many LP tokens)
+    /// - `pool_id`: Expected Pool ID (PDA address) for security validation
+    /// - `minimum_lp_out`: Fewest LP tokens the caller accepts; fails with `SlippageExceeded` below it
+    /// 
+    /// # Account Order:
+    /// Same 11 accounts as `Deposit`
+    DepositWithMinimumLp {
+        deposit_token_mint: Pubkey,
+        amount: u64,
+        pool_id: Pubkey,
+        minimum_lp_out: u64,
+    },
+    
+    /// Rewrite a main treasury account created with an older, shorter layout in the
+    /// current layout (admin only)
+    /// 
+    /// Older accounts can still be read (missing trailing fields read as zero: no
+    /// auto-compounding, an empty withdrawal window, no recent donations), but nothing
+    /// can write the larger layout back into them. This grows the account to
+    /// `MainTreasuryState::LEN` with the signer paying the extra rent and writes it back.
+    /// Allowed while the system is paused; an account already at full size is left
+    /// unchanged.
+    /// 
+    /// # Account Order:
+    /// - [0] Admin Authority Signer (signer, writable; pays any extra rent)
+    /// - [1] System Program
+    /// - [2] System State PDA (for admin validation)
+    /// - [3] Main Treasury PDA (writable)
+    /// - [4] Program Data Account (for upgrade authority validation)
+    MigrateTreasuryState {},
+    
+    /// Exchange source LP tokens of a migrated pool 1:1 for the escrowed destination LP tokens
+    /// 
+    /// After `MigrateLiquidity`, the holder burns `lp_amount` source LP tokens of one side
+    /// (chosen by the source LP mint passed) and receives the same amount of that side's
+    /// destination LP token from the source pool's migration escrow. Each side is paid only
+    /// from its own escrow, like `Withdraw` pays each LP token only from its own vault;
+    /// a claim above what is left fails with `InsufficientFunds`. Allowed while either pool
+    /// is paused.
+    /// 
+    /// # Arguments:
+    /// - `source_pool_id`: Expected Pool ID (PDA address) of the migrated pool
+    /// - `dest_pool_id`: Expected Pool ID (PDA address) of the pool that received the liquidity
+    /// - `lp_amount`: Source LP tokens to burn, and destination LP tokens to receive
+    /// 
+    /// # Account Order:
+    /// - [0] Holder Signer (owner of the source LP tokens)
+    /// - [1] System State PDA (for pause validation)
+    /// - [2] Source Pool State PDA
+    /// - [3] Source LP Token A or B Mint PDA (writable, selects the side)
+    /// - [4] Holder Source LP Token Account (writable)
+    /// - [5] Destination Pool State PDA
+    /// - [6] Destination LP Token Mint PDA of the same side
+    /// - [7] Migration Escrow LP Token PDA of the same side (writable)
+    /// - [8] Holder Destination LP Token Account (writable)
+    /// - [9] SPL Token Program
+    ClaimMigratedLiquidity {
+        source_pool_id: Pubkey,
+        dest_pool_id: Pubkey,
+        lp_amount: u64,
+    },
+} 
+
+/// A single leg of a `SwapRoute`: the pool to swap through and what goes in
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouteHop {
+    pub pool_id: Pubkey,
+    pub input_token_mint: Pubkey,
+    pub token_a_vault: Pubkey,
+    pub token_b_vault: Pubkey,
+}
\ No newline at end of file
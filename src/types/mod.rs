@@ -5,8 +5,12 @@
 pub mod instructions;
 pub mod errors;
 pub mod ratio;
+pub mod pool_config;
+pub mod pool_status;
 
 // Re-export all types for easy access
 pub use instructions::*;
 pub use errors::*;
-pub use ratio::*; 
\ No newline at end of file
+pub use ratio::*;
+pub use pool_config::*;
+pub use pool_status::*;
\ No newline at end of file
@@ -0,0 +1,74 @@
+//! Portable Pool Configuration
+//!
+//! This module contains the network-independent snapshot of a pool's settings
+//! returned by `GetPoolConfig`, used to back up a pool or recreate it elsewhere.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::state::{PoolState, SwapMode};
+
+/// Everything needed to recreate a pool's configuration, without runtime balances.
+/// 
+/// Liquidity, collected fees, consolidation counters, rounding surplus, PDA bumps
+/// and the auto-unpause timer are left out; vault and LP mint addresses are derived
+/// from the mints and ratio, so they are left out as well.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PortablePoolConfig {
+    /// Normalized Token A mint (lexicographically first)
+    pub token_a_mint: Pubkey,
+    /// Normalized Token B mint (lexicographically second)
+    pub token_b_mint: Pubkey,
+    /// Token A ratio in basis points as requested at creation (the pool PDA seed)
+    pub ratio_a_numerator: u64,
+    /// Token B ratio in basis points as requested at creation (the pool PDA seed)
+    pub ratio_b_denominator: u64,
+    /// Pool flags (ratio type, pause state, owner-only, exact exchange)
+    pub flags: u8,
+    /// Designated owner for owner-only swaps
+    pub owner: Pubkey,
+    /// Deposit/withdrawal contract fee in lamports
+    pub contract_liquidity_fee: u64,
+    /// Swap contract fee in lamports
+    pub swap_contract_fee: u64,
+    pub max_swap_amount: u64,
+    pub min_swap_amount: u64,
+    pub max_deposit_amount: u64,
+    pub min_deposit_amount: u64,
+    pub max_withdrawal_amount: u64,
+    pub min_withdrawal_amount: u64,
+    /// LP supply cap per LP mint (0 = uncapped)
+    pub max_lp_supply: u64,
+    pub reserve_safety_floor_a: u64,
+    pub reserve_safety_floor_b: u64,
+    /// Liquidity a withdrawal must leave in the vault it draws from (0 = no minimum)
+    pub minimum_reserve: u64,
+    /// Swaps the pool accepts (exact-in, exact-out or both)
+    pub swap_mode: SwapMode,
+}
+
+impl From<&PoolState> for PortablePoolConfig {
+    fn from(pool_state: &PoolState) -> Self {
+        Self {
+            token_a_mint: pool_state.token_a_mint,
+            token_b_mint: pool_state.token_b_mint,
+            ratio_a_numerator: pool_state.original_ratio_a_numerator,
+            ratio_b_denominator: pool_state.original_ratio_b_denominator,
+            flags: pool_state.flags,
+            owner: pool_state.owner,
+            contract_liquidity_fee: pool_state.contract_liquidity_fee,
+            swap_contract_fee: pool_state.swap_contract_fee,
+            max_swap_amount: pool_state.max_swap_amount,
+            min_swap_amount: pool_state.min_swap_amount,
+            max_deposit_amount: pool_state.max_deposit_amount,
+            min_deposit_amount: pool_state.min_deposit_amount,
+            max_withdrawal_amount: pool_state.max_withdrawal_amount,
+            min_withdrawal_amount: pool_state.min_withdrawal_amount,
+            max_lp_supply: pool_state.max_lp_supply,
+            reserve_safety_floor_a: pool_state.reserve_safety_floor_a,
+            reserve_safety_floor_b: pool_state.reserve_safety_floor_b,
+            minimum_reserve: pool_state.minimum_reserve,
+            swap_mode: pool_state.swap_mode,
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Pool Initialization State, Activity and Fees
+//!
+//! This module contains the coarse lifecycle state returned by `GetInitState`, so
+//! clients can tell an uninitialized pool account from a live or paused one, the
+//! activity summary returned by `GetPoolActivity`, the daily swap fees returned
+//! by `GetFeeHistory`, and the per-pool entries returned by `GetAllPoolFees`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::constants::{FEE_HISTORY_DAYS, POOL_DORMANCY_WINDOW_SECONDS, SECONDS_PER_DAY};
+use crate::state::PoolState;
+
+/// Lifecycle state of a pool account, derived from its `PoolState` flags.
+/// 
+/// Variants are only ever appended; the Borsh variant index is the wire format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum PoolInitState {
+    /// The account has no lamports or no data - the pool has not been created
+    Uninitialized,
+    /// The pool exists and neither liquidity nor swaps are paused
+    Active,
+    /// The pool exists and at least one operation (a swap direction, deposits or withdrawals) is paused
+    Paused,
+}
+
+impl PoolInitState {
+    /// State of an initialized pool at `current_timestamp`.
+    /// 
+    /// A pause whose auto-unpause time has passed no longer blocks operations,
+    /// so it reports `Active` even though the pause flags are still set.
+    pub fn from_pool_state(pool_state: &PoolState, current_timestamp: i64) -> Self {
+        if pool_state.operation_paused(u8::MAX, current_timestamp) {
+            PoolInitState::Paused
+        } else {
+            PoolInitState::Active
+        }
+    }
+}
+
+/// Age, swap activity, operation count and lifetime totals of a pool, returned by `GetPoolActivity`.
+/// 
+/// Pools created before schema version 5 have no recorded creation time; they
+/// report an age of 0 and are measured from their first recorded swap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PoolActivity {
+    /// Seconds since the pool was created (0 if unknown)
+    pub age_seconds: u64,
+    /// Seconds since the last swap, or since creation if the pool never swapped (0 if unknown)
+    pub seconds_since_last_swap: u64,
+    /// No swap within `POOL_DORMANCY_WINDOW_SECONDS`
+    pub dormant: bool,
+    /// State-changing instructions applied to the pool (counted since schema version 12)
+    pub total_operations: u64,
+    /// Total Token A swapped into the pool (counted since schema version 13)
+    pub cumulative_volume_token_a: u128,
+    /// Total Token B swapped into the pool (counted since schema version 13)
+    pub cumulative_volume_token_b: u128,
+    /// Total SOL fees (lamports) charged by the pool (counted since schema version 13)
+    pub lifetime_fees: u128,
+}
+
+impl PoolActivity {
+    /// Activity of a pool at `current_timestamp`.
+    /// 
+    /// A pool with neither timestamp recorded is never reported as dormant.
+    pub fn from_pool_state(pool_state: &PoolState, current_timestamp: i64) -> Self {
+        let elapsed_since = |timestamp: i64| {
+            if timestamp == 0 {
+                0
+            } else {
+                current_timestamp.saturating_sub(timestamp).max(0) as u64
+            }
+        };
+        let last_activity = if pool_state.last_swap_at != 0 {
+            pool_state.last_swap_at
+        } else {
+            pool_state.created_at
+        };
+        let seconds_since_last_swap = elapsed_since(last_activity);
+        
+        PoolActivity {
+            age_seconds: elapsed_since(pool_state.created_at),
+            seconds_since_last_swap,
+            dormant: last_activity != 0 && seconds_since_last_swap > POOL_DORMANCY_WINDOW_SECONDS as u64,
+            total_operations: pool_state.total_operations,
+            cumulative_volume_token_a: pool_state.cumulative_volume_token_a,
+            cumulative_volume_token_b: pool_state.cumulative_volume_token_b,
+            lifetime_fees: pool_state.lifetime_fees,
+        }
+    }
+}
+
+/// Swap contract fees of a pool per UTC day, returned by `GetFeeHistory`.
+/// 
+/// Entry 0 is the current day, entry 1 the day before, and so on; days without a
+/// swap (or older than the pool's history) read as 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FeeHistory {
+    /// Start (UTC midnight) of the current day, the day of entry 0
+    pub day_start: i64,
+    /// Lamports paid by swaps with Token A input, newest day first
+    pub fees_token_a: [u64; FEE_HISTORY_DAYS],
+    /// Lamports paid by swaps with Token B input, newest day first
+    pub fees_token_b: [u64; FEE_HISTORY_DAYS],
+}
+
+impl FeeHistory {
+    /// Fee history of a pool at `current_timestamp`.
+    /// 
+    /// Days after the pool's newest recorded day had no swaps, so the stored ring is
+    /// aged forward to the current day rather than reported as of its last swap.
+    pub fn from_pool_state(pool_state: &PoolState, current_timestamp: i64) -> Self {
+        let today = current_timestamp.div_euclid(SECONDS_PER_DAY);
+        let newest_day = pool_state.snapshot_timestamp.div_euclid(SECONDS_PER_DAY);
+        let mut history = FeeHistory {
+            day_start: today * SECONDS_PER_DAY,
+            fees_token_a: [0; FEE_HISTORY_DAYS],
+            fees_token_b: [0; FEE_HISTORY_DAYS],
+        };
+        if pool_state.snapshot_timestamp == 0 {
+            return history;
+        }
+        
+        for age in 0..FEE_HISTORY_DAYS {
+            let day = today - age as i64;
+            if day > newest_day || day <= newest_day - FEE_HISTORY_DAYS as i64 {
+                continue;
+            }
+            let slot = day.rem_euclid(FEE_HISTORY_DAYS as i64) as usize;
+            history.fees_token_a[age] = pool_state.daily_fee_snapshot_token_a[slot];
+            history.fees_token_b[age] = pool_state.daily_fee_snapshot_token_b[slot];
+        }
+        history
+    }
+}
+
+/// Collected token fees of one pool, as reported by `GetAllPoolFees`.
+/// 
+/// The view returns a Borsh `Vec<PoolFeeSummary>` (u32 count, then 48 bytes per pool).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PoolFeeSummary {
+    pub pool: Pubkey,
+    pub collected_fees_token_a: u64,
+    pub collected_fees_token_b: u64,
+}
+
+impl PoolFeeSummary {
+    /// Fee entry for the pool stored at `pool`
+    pub fn from_pool_state(pool: Pubkey, pool_state: &PoolState) -> Self {
+        PoolFeeSummary {
+            pool,
+            collected_fees_token_a: pool_state.collected_fees_token_a,
+            collected_fees_token_b: pool_state.collected_fees_token_b,
+        }
+    }
+}
+
+/// Ratio, fees and liquidity of one pool, as reported by `GetMultiplePoolInfo` (80 bytes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PoolSummary {
+    pub pool: Pubkey,
+    pub ratio_a_numerator: u64,
+    pub ratio_b_denominator: u64,
+    /// Liquidity contract fee in lamports
+    pub contract_liquidity_fee: u64,
+    /// Swap contract fee in lamports
+    pub swap_contract_fee: u64,
+    pub total_token_a_liquidity: u64,
+    pub total_token_b_liquidity: u64,
+}
+
+impl PoolSummary {
+    /// Summary of the pool stored at `pool`
+    pub fn from_pool_state(pool: Pubkey, pool_state: &PoolState) -> Self {
+        PoolSummary {
+            pool,
+            ratio_a_numerator: pool_state.ratio_a_numerator,
+            ratio_b_denominator: pool_state.ratio_b_denominator,
+            contract_liquidity_fee: pool_state.contract_liquidity_fee,
+            swap_contract_fee: pool_state.swap_contract_fee,
+            total_token_a_liquidity: pool_state.total_token_a_liquidity,
+            total_token_b_liquidity: pool_state.total_token_b_liquidity,
+        }
+    }
+}
+
+/// Result of `GetMultiplePoolInfo`: the valid pools plus the account indices that were skipped.
+#[derive(Debug, Clone, PartialEq, Eq, Default, BorshSerialize, BorshDeserialize)]
+pub struct MultiplePoolInfo {
+    /// One entry per valid pool, in account order
+    pub pools: Vec<PoolSummary>,
+    /// Indices of the accounts that are not valid initialized pools
+    pub skipped: Vec<u8>,
+}
+
+/// Which of two mints a pool stores as Token A and which as Token B, as reported by
+/// `GetCanonicalOrdering` (65 bytes).
+/// 
+/// Pools always store the lexicographically smaller mint as Token A, whichever order
+/// the mints were passed in when the pool was created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CanonicalOrdering {
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    /// True if the first mint passed (`mint_x`) is Token A
+    pub mint_x_is_token_a: bool,
+}
+
+impl CanonicalOrdering {
+    /// Canonical ordering of `mint_x` and `mint_y` (the mints must differ)
+    pub fn from_mints(mint_x: Pubkey, mint_y: Pubkey) -> Self {
+        let mint_x_is_token_a = mint_x < mint_y;
+        let (token_a_mint, token_b_mint) = if mint_x_is_token_a { (mint_x, mint_y) } else { (mint_y, mint_x) };
+        CanonicalOrdering {
+            token_a_mint,
+            token_b_mint,
+            mint_x_is_token_a,
+        }
+    }
+}
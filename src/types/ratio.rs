@@ -1,6 +1,7 @@
 //! Ratio Type Definitions
 //!
-//! This module contains types related to pool ratio classifications.
+//! This module contains types related to pool ratio classifications and the
+//! conversion of human-readable ratios into the base-unit ratios pools store.
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
@@ -53,3 +54,25 @@ impl std::fmt::Display for RatioType {
         write!(f, "{} ({})", self.short_name(), self.description())
     }
 }
+
+/// Converts a human-readable ratio into the base-unit ratio `InitializePool` expects.
+/// 
+/// Pools store `ratio_a_numerator`/`ratio_b_denominator` in each token's smallest
+/// units, so a display ratio of `display_a` whole Token A for `display_b` whole Token B
+/// becomes `display_a * 10^decimals_a : display_b * 10^decimals_b`. With 6-decimal
+/// Token A and 9-decimal Token B, 1:1 becomes 1_000_000 : 1_000_000_000.
+/// 
+/// # Arguments
+/// * `display_a` - Whole Token A units in the ratio
+/// * `display_b` - Whole Token B units in the ratio
+/// * `decimals_a` - Token A mint decimals
+/// * `decimals_b` - Token B mint decimals
+/// 
+/// # Returns
+/// * `Option<(u64, u64)>` - `(ratio_a_numerator, ratio_b_denominator)`, or `None` if
+///   either side does not fit in a `u64`
+pub fn scale_display_ratio(display_a: u64, display_b: u64, decimals_a: u8, decimals_b: u8) -> Option<(u64, u64)> {
+    let ratio_a = 10u64.checked_pow(decimals_a as u32)?.checked_mul(display_a)?;
+    let ratio_b = 10u64.checked_pow(decimals_b as u32)?.checked_mul(display_b)?;
+    Some((ratio_a, ratio_b))
+}
@@ -275,28 +275,16 @@ pub fn collect_fee_to_pool_state<'a>(
         FeeType::Liquidity => {
             msg!("🔍 Updating liquidity fees...");
             
-            // 🔒 SECURITY FIX: Handle overflow protection from checked arithmetic
-            pool_state.add_liquidity_fee(fee_amount, current_timestamp)
-                .map_err(|e| {
-                    msg!("❌ OVERFLOW ERROR: Failed to add liquidity fee: {:?}", e);
-                    PoolError::FeeValidationFailed {
-                        reason: format!("Liquidity fee counter overflow: {:?}", e),
-                    }
-                })?;
+            // Fee counters saturate rather than fail, so a full counter never blocks the operation
+            pool_state.add_liquidity_fee(fee_amount, current_timestamp);
 
             msg!("   After update - collected_liquidity_fees: {}", pool_state.collected_liquidity_fees);
         },
         FeeType::RegularSwap => {
             msg!("🔍 Updating swap contract fees...");
             
-            // 🔒 SECURITY FIX: Handle overflow protection from checked arithmetic
-            pool_state.add_swap_contract_fee(fee_amount, current_timestamp)
-                .map_err(|e| {
-                    msg!("❌ OVERFLOW ERROR: Failed to add swap contract fee: {:?}", e);
-                    PoolError::FeeValidationFailed {
-                        reason: format!("Swap contract fee counter overflow: {:?}", e),
-                    }
-                })?;
+            // Fee counters saturate rather than fail, so a full counter never blocks the swap
+            pool_state.add_swap_contract_fee(fee_amount, current_timestamp);
 
             msg!("   After update - collected_swap_contract_fees: {}", pool_state.collected_swap_contract_fees);
         },
@@ -346,4 +334,60 @@ pub fn collect_fee_to_pool_state<'a>(
     // msg!("🔍 FEE COLLECTION DEBUG: Completed successfully");
     
     Ok(())
-} 
\ No newline at end of file
+} 
+/// Collects the protocol swap SOL fee directly into the main treasury
+/// 
+/// Unlike the pool's swap contract fee, this fee skips pool state and
+/// consolidation: it is transferred to the Main Treasury PDA immediately and
+/// the treasury balance is synced in the same instruction.
+pub fn collect_swap_sol_fee_to_treasury<'a>(
+    payer_account: &AccountInfo<'a>,
+    main_treasury_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    program_id: &Pubkey,
+    fee_amount: u64,
+) -> ProgramResult {
+    use solana_program::{program::invoke, system_instruction};
+    use crate::state::MainTreasuryState;
+    
+    let validation_result = validate_fee_payment(payer_account, fee_amount, VALIDATION_CONTEXT_SWAP);
+    if !validation_result.is_valid {
+        return Err(PoolError::InsufficientFeeBalance {
+            required: fee_amount,
+            available: validation_result.available_balance,
+            account: *payer_account.key,
+        }.into());
+    }
+    
+    let (expected_main_treasury, _) = Pubkey::find_program_address(
+        &[MAIN_TREASURY_SEED_PREFIX],
+        program_id,
+    );
+    validate_treasury_account(main_treasury_account, &expected_main_treasury, TREASURY_TYPE_MAIN)?;
+    
+    invoke(
+        &system_instruction::transfer(
+            payer_account.key,
+            main_treasury_account.key,
+            fee_amount,
+        ),
+        &[
+            payer_account.clone(),
+            main_treasury_account.clone(),
+            system_program.clone(),
+        ],
+    )?;
+    
+    let mut treasury_state = MainTreasuryState::from_versioned_data(&main_treasury_account.data.borrow())?;
+    treasury_state.sync_balance_with_account(main_treasury_account.lamports());
+    
+    let serialized_data = treasury_state.try_to_vec()?;
+    if main_treasury_account.data_len() < serialized_data.len() {
+        msg!("🚨 Critical Error: Treasury serialized data too large for account");
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    main_treasury_account.data.borrow_mut()[..serialized_data.len()].copy_from_slice(&serialized_data);
+    
+    msg!("✅ Protocol swap SOL fee collected: {} lamports", fee_amount);
+    Ok(())
+}
@@ -15,10 +15,11 @@ pub const INITIALIZE_PROGRAM_ACCOUNTS: usize = 6;
 pub const INITIALIZE_POOL_ACCOUNTS: usize = 13;
 pub const DEPOSIT_ACCOUNTS: usize = 11;
 pub const WITHDRAW_ACCOUNTS: usize = 11;
-pub const SWAP_ACCOUNTS: usize = 11;  // 9 base + 2 mint accounts
+pub const SWAP_ACCOUNTS: usize = 11;  // 9 base + 2 mint accounts (+ main treasury when a swap SOL fee is set)
 pub const DONATE_SOL_ACCOUNTS: usize = 4;  // donor, treasury, system state, system program
 pub const SET_SWAP_OWNER_ONLY_ACCOUNTS: usize = 4;
 pub const UPDATE_POOL_FEES_ACCOUNTS: usize = 4;
+pub const SET_MAX_LP_SUPPLY_ACCOUNTS: usize = 4;  // admin, system state, pool state, program data
 
 // Admin authority management accounts
 pub const PROCESS_ADMIN_CHANGE_ACCOUNTS: usize = 3;  // current admin, system state, program data
@@ -31,6 +32,53 @@ pub const CONSOLIDATE_POOL_FEES_MIN_ACCOUNTS: usize = 4; // Admin authority, sys
 pub const GET_POOL_INFO_ACCOUNTS: usize = 4;
 pub const GET_TREASURY_INFO_ACCOUNTS: usize = 1;
 pub const GET_VERSION_ACCOUNTS: usize = 0;
+pub const GET_SWAP_SOL_COST_ACCOUNTS: usize = 2;  // pool state, user output token account
+pub const GET_ROUND_TRIP_COST_ACCOUNTS: usize = 1;  // pool state
+pub const GET_ROUNDING_SURPLUS_ACCOUNTS: usize = 1;  // pool state
+pub const GET_PROGRAM_STATS_ACCOUNTS: usize = 1;  // main treasury
+pub const SET_SWAP_SOL_FEE_ACCOUNTS: usize = 3;  // admin, system state, program data
+pub const SWAP_ROUTE_BASE_ACCOUNTS: usize = 5;  // user, system program, system state, token program, main treasury
+pub const SWAP_ROUTE_HOP_ACCOUNTS: usize = 7;  // pool state, 2 vaults, user input/output, input/output mint
+pub const CAN_SWAP_ACCOUNTS: usize = 3;  // pool state, system state, user authority
+pub const GET_SWAP_QUOTE_ACCOUNTS: usize = 1;  // pool state
+pub const DEPOSIT_BOTH_ACCOUNTS: usize = 13;  // deposit accounts plus a user token and LP account per side
+pub const SET_RESERVE_SAFETY_FLOOR_ACCOUNTS: usize = 4;  // admin, system state, pool state, program data
+pub const GET_SWAP_FEE_BREAKDOWN_ACCOUNTS: usize = 2;  // pool state, system state
+pub const GET_POOL_CONFIG_ACCOUNTS: usize = 1;  // pool state
+pub const WITHDRAW_AND_SWAP_ACCOUNTS: usize = 14;  // withdrawal accounts plus user output account and both token mints
+pub const MIGRATE_POOL_STATE_ACCOUNTS: usize = 5;  // authority, system program, system state, pool state, program data
+pub const SET_MINIMUM_RESERVE_ACCOUNTS: usize = 4;  // admin, system state, pool state, program data
+pub const GET_INIT_STATE_ACCOUNTS: usize = 1;  // pool state (may not exist yet)
+pub const SET_SWAP_MODE_ACCOUNTS: usize = 4;  // admin, system state, pool state, program data
+pub const GET_POOL_ACTIVITY_ACCOUNTS: usize = 1;  // pool state
+pub const SET_TREASURY_AUTOCOMPOUND_ACCOUNTS: usize = 5;  // admin, system state, main treasury, program data, pool state
+pub const SET_MIN_SWAP_AMOUNT_ACCOUNTS: usize = 4;  // admin, system state, pool state, program data
+pub const SET_CONSOLIDATION_SKIP_WINDOW_ACCOUNTS: usize = 3;  // admin, system state, program data
+pub const GET_CANONICAL_ORDERING_ACCOUNTS: usize = 0;
+pub const SET_ALLOW_POOL_CREATION_WHILE_PAUSED_ACCOUNTS: usize = 3;  // admin, system state, program data
+pub const SET_SWAP_FEE_BAND_ACCOUNTS: usize = 3;  // pool owner, system state, pool state
+pub const RESCUE_STRANDED_TOKENS_ACCOUNTS: usize = 5;  // pool owner, pool state, stranded account, destination, token program
+pub const GET_POOL_STATE_RAW_ACCOUNTS: usize = 1;  // pool state
+pub const ENCODE_SWAP_ACCOUNTS: usize = 0;
+pub const GET_SYSTEM_STATUS_ACCOUNTS: usize = 1;  // system state
+pub const GET_FEE_HISTORY_ACCOUNTS: usize = 1;  // pool state
+pub const SET_EVENT_EMISSION_ACCOUNTS: usize = 4;  // admin, system state, pool state, program data
+pub const INITIATE_OWNER_TRANSFER_ACCOUNTS: usize = 3;  // pool owner, system state, pool state
+pub const ACCEPT_OWNER_TRANSFER_ACCOUNTS: usize = 3;  // pending owner, system state, pool state
+pub const CANCEL_OWNER_TRANSFER_ACCOUNTS: usize = 2;  // pool owner, pool state
+pub const MIGRATE_LIQUIDITY_ACCOUNTS: usize = 15;  // pool owner, system state, source pool + 2 vaults, dest pool + 2 vaults + 2 LP mints, escrow LP A/B, token program, system program, rent sysvar
+pub const COMPOUND_TREASURY_ACCOUNTS: usize = 10;  // admin, system state, main treasury, program data, pool state, treasury wSOL, vault, LP mint, treasury LP, token program
+pub const WITHDRAW_TREASURY_TOKENS_ACCOUNTS: usize = 7;  // admin, system state, main treasury, program data, treasury token account, destination, token program
+pub const SET_SYSTEM_MESSAGE_ACCOUNTS: usize = 3;  // admin, system state, program data
+pub const VALIDATE_POOL_PARAMS_ACCOUNTS: usize = 4;  // system state, multiple mint, base mint, pool state
+pub const VERIFY_POOL_CONFIG_ACCOUNTS: usize = 1;  // pool state
+pub const GET_RECENT_DONATIONS_ACCOUNTS: usize = 1;  // main treasury
+pub const GET_DEPOSIT_FEE_ACCOUNTS: usize = 1;  // pool state
+pub const SWEEP_ROUNDING_SURPLUS_ACCOUNTS: usize = 6;  // pool owner, system state, pool state, vault, destination, token program
+pub const CHANGE_FEE_RECIPIENT_ACCOUNTS: usize = 6;  // admin authority, system state, treasury, program data, pool state, current recipient
+pub const MIGRATE_SYSTEM_STATE_ACCOUNTS: usize = 4;  // admin, system program, system state, program data
+pub const MIGRATE_TREASURY_STATE_ACCOUNTS: usize = 5;  // admin, system program, system state, main treasury, program data
+pub const CLAIM_MIGRATED_LIQUIDITY_ACCOUNTS: usize = 10;  // holder, system state, source pool + LP mint, holder source LP, dest pool + LP mint, escrow, holder dest LP, token program
 
 /// Minimum instruction data sizes (in bytes) for each instruction type
 /// These are conservative estimates based on Borsh serialization
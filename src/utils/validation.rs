@@ -11,6 +11,8 @@ use solana_program::{
     program_error::ProgramError,
     program_pack::Pack,
     pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
 };
 
 use crate::{
@@ -106,6 +108,87 @@ pub fn validate_vault_owner(
     Ok(())
 }
 
+/// Validates that the two vault accounts hold the pool's Token A and Token B mints.
+///
+/// Defense in depth against a misconfigured or swapped vault: the Token A vault must
+/// hold `token_a_mint` and the Token B vault `token_b_mint`, which also means the two
+/// vaults hold different mints. Runs before any vault address checks so a swapped
+/// pair is reported as such.
+///
+/// # Arguments
+/// * `pool_state` - The pool whose mints the vaults must hold
+/// * `token_a_vault` - The account passed as the Token A vault
+/// * `token_b_vault` - The account passed as the Token B vault
+///
+/// # Returns
+/// * `ProgramResult` - `VaultMintMismatch` naming the first vault with the wrong mint
+pub fn validate_vault_mints<'a>(
+    pool_state: &PoolState,
+    token_a_vault: &AccountInfo<'a>,
+    token_b_vault: &AccountInfo<'a>,
+) -> ProgramResult {
+    for (vault, expected_mint) in [(token_a_vault, pool_state.token_a_mint), (token_b_vault, pool_state.token_b_mint)] {
+        let actual_mint = TokenAccount::unpack_from_slice(&vault.data.borrow())
+            .map_err(|_| ProgramError::InvalidAccountData)?
+            .mint;
+        if actual_mint != expected_mint {
+            msg!("❌ Vault {} holds mint {}, expected {}", vault.key, actual_mint, expected_mint);
+            return Err(PoolError::VaultMintMismatch {
+                vault: *vault.key,
+                expected_mint,
+                actual_mint,
+            }.into());
+        }
+    }
+    Ok(())
+}
+
+/// Validates that an LP token mint is initialized and can still mint.
+///
+/// Runs before deposits so a broken LP mint fails with a specific error instead of an
+/// opaque token program failure during `mint_to`. A mint whose authority has been
+/// removed is treated as frozen, matching `validate_lp_mint_authority`.
+///
+/// # Arguments
+/// * `mint_account` - The LP mint account to check
+/// * `account_name` - Context string for error messages
+///
+/// # Returns
+/// * `ProgramResult` - Success if the mint is usable, `LpMintNotInitialized` or
+///   `LpMintFrozen` otherwise
+pub fn validate_lp_mint_ready(
+    mint_account: &AccountInfo,
+    account_name: &str,
+) -> ProgramResult {
+    if mint_account.owner != &spl_token::id() {
+        msg!("❌ {}: Mint account not owned by SPL Token program", account_name);
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if mint_account.data_len() == 0 {
+        msg!("❌ {}: LP mint account has no data", account_name);
+        return Err(PoolError::LpMintNotInitialized { mint: *mint_account.key }.into());
+    }
+
+    let mint_data = Mint::unpack_unchecked(&mint_account.data.borrow())
+        .map_err(|_| {
+            msg!("❌ {}: Failed to unpack mint data", account_name);
+            ProgramError::InvalidAccountData
+        })?;
+
+    if !mint_data.is_initialized {
+        msg!("❌ {}: LP mint is not initialized", account_name);
+        return Err(PoolError::LpMintNotInitialized { mint: *mint_account.key }.into());
+    }
+
+    if mint_data.mint_authority.is_none() {
+        msg!("❌ {}: LP mint is frozen (no mint authority)", account_name);
+        return Err(PoolError::LpMintFrozen { mint: *mint_account.key }.into());
+    }
+
+    Ok(())
+}
+
 /// 🔒 CRITICAL SECURITY: Validates that an LP token mint has the expected mint authority
 ///
 /// This prevents attackers from providing fake LP mints with unauthorized authorities
@@ -177,6 +260,9 @@ pub fn validate_lp_mint_authority(
 /// 2. Validating the provided account matches the expected Pool ID BEFORE deserialization
 /// 3. Only then deserializing the PoolState data (safe since we know it's the right account)
 /// 
+/// Empty, closed or non-rent-exempt accounts and accounts that were never written by
+/// pool creation are rejected with `PoolNotInitialized` instead of being deserialized.
+/// 
 /// # Arguments
 /// * `pool_state_account` - The pool state account to validate and deserialize
 /// * `expected_pool_id` - The expected Pool ID (PDA address) that the client must provide
@@ -189,6 +275,12 @@ pub fn validate_and_deserialize_pool_state_secure(
     expected_pool_id: &Pubkey,
     program_id: &Pubkey,
 ) -> Result<PoolState, ProgramError> {
+    // 🔒 STEP 0: Reject empty or closed accounts before looking at owner or data
+    if pool_state_account.lamports() == 0 || pool_state_account.data_is_empty() {
+        msg!("❌ POOL NOT INITIALIZED: Pool state account {} has no lamports or data", pool_state_account.key);
+        return Err(PoolError::PoolNotInitialized { pool: *pool_state_account.key }.into());
+    }
+    
     // 🔒 STEP 1: Validate account ownership
     if pool_state_account.owner != program_id {
         msg!("❌ SECURITY VIOLATION: Pool state account not owned by program");
@@ -212,6 +304,12 @@ pub fn validate_and_deserialize_pool_state_secure(
         }.into());
     }
     
+    // 🔒 STEP 2b: A live pool state account is always rent-exempt
+    if !Rent::get()?.is_exempt(pool_state_account.lamports(), pool_state_account.data_len()) {
+        msg!("❌ POOL NOT INITIALIZED: Pool state account {} is not rent-exempt", pool_state_account.key);
+        return Err(PoolError::PoolNotInitialized { pool: *pool_state_account.key }.into());
+    }
+    
     // 🔒 STEP 3: Safe to deserialize now (we know it's the right account)
     let pool_state_data = PoolState::deserialize(&mut &pool_state_account.data.borrow()[..])
         .map_err(|e| {
@@ -223,13 +321,14 @@ pub fn validate_and_deserialize_pool_state_secure(
         })?;
     
     // 🔒 STEP 4: Additional data integrity validation
+    // Pool creation always writes both mints, so default mints mean the account was never initialized
     if pool_state_data.token_a_mint == Pubkey::default() ||
        pool_state_data.token_b_mint == Pubkey::default() {
-        msg!("❌ DATA INTEGRITY ERROR: Pool contains invalid token mints");
+        msg!("❌ POOL NOT INITIALIZED: Pool contains no token mints");
         msg!("   Pool ID: {}", expected_pool_id);
         msg!("   Token A: {}", pool_state_data.token_a_mint);
         msg!("   Token B: {}", pool_state_data.token_b_mint);
-        return Err(ProgramError::InvalidAccountData);
+        return Err(PoolError::PoolNotInitialized { pool: *expected_pool_id }.into());
     }
     
     if pool_state_data.ratio_a_numerator == 0 || pool_state_data.ratio_b_denominator == 0 {
@@ -276,8 +375,8 @@ pub fn validate_and_deserialize_pool_state_legacy(
             POOL_STATE_SEED_PREFIX,
             pool_state_data.token_a_mint.as_ref(),
             pool_state_data.token_b_mint.as_ref(),
-            &pool_state_data.ratio_a_numerator.to_le_bytes(),
-            &pool_state_data.ratio_b_denominator.to_le_bytes(),
+            &pool_state_data.original_ratio_a_numerator.to_le_bytes(),
+            &pool_state_data.original_ratio_b_denominator.to_le_bytes(),
         ],
         program_id,
     );
@@ -373,6 +472,32 @@ pub fn validate_system_not_paused_secure(
     }
 }
 
+/// Loads the SystemState and rejects the operation when the system is paused.
+///
+/// Same check as `validate_system_not_paused_secure`, but returns the loaded state so
+/// hot paths that also need system settings (e.g. the swap SOL fee) derive the
+/// SystemState PDA once instead of once per read.
+///
+/// # Arguments
+/// * `system_state_account` - The system state account to check
+/// * `program_id` - The program ID for PDA derivation
+///
+/// # Returns
+/// * `Result<SystemState, ProgramError>` - The loaded state, or `SystemPaused` when paused
+pub fn load_unpaused_system_state(
+    system_state_account: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<SystemState, ProgramError> {
+    let system_state = SystemState::load_from_account(system_state_account, program_id)?;
+    if system_state.is_paused {
+        msg!("🛑 SYSTEM PAUSED: All operations blocked");
+        msg!("Pause code: {}", system_state.pause_reason_code);
+        msg!("Paused at: {}", system_state.pause_timestamp);
+        return Err(PoolError::SystemPaused.into());
+    }
+    Ok(system_state)
+}
+
 
 
 /// Validates ratio values and returns pool ID string for PDA derivation.
@@ -404,6 +529,28 @@ pub fn validate_ratio_values(ratio_a_numerator: u64, ratio_b_denominator: u64) -
     Ok(())
 }
 
+/// Reduces a pool ratio by the greatest common divisor of its two sides.
+///
+/// 4:2, 6:3 and 100:50 all become 2:1. Swap outputs are unchanged, since
+/// `amount * b / a` truncates to the same value for any common factor of `a` and `b`.
+///
+/// # Arguments
+/// * `ratio_a_numerator` - Token A base units (non-zero)
+/// * `ratio_b_denominator` - Token B base units (non-zero)
+///
+/// # Returns
+/// * `(u64, u64)` - The ratio in lowest terms
+pub fn reduce_ratio(ratio_a_numerator: u64, ratio_b_denominator: u64) -> (u64, u64) {
+    let (mut a, mut b) = (ratio_a_numerator, ratio_b_denominator);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    if a == 0 {
+        return (ratio_a_numerator, ratio_b_denominator);
+    }
+    (ratio_a_numerator / a, ratio_b_denominator / a)
+}
+
 /// **BASIS POINTS REFACTOR: Ratio Type Classification**
 /// 
 /// Classifies pool ratios into three categories based on their numeric characteristics:
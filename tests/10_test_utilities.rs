@@ -308,8 +308,70 @@ fn test_pool_state_get_packed_len() {
         8 +  // max_withdrawal_amount
         8 +  // min_withdrawal_amount
         
-        // **RESERVED SPACE**
-        32;  // _reserved: [u64; 4] = 4 * 8 bytes
+        // **ROUNDING SURPLUS TRACKING**
+        8 +  // rounding_surplus_token_a
+        8 +  // rounding_surplus_token_b
+        
+        // **LP SUPPLY CAP**
+        8 +  // max_lp_supply
+        
+        // **AUTO-UNPAUSE TIMER**
+        8 +  // auto_unpause_at
+        
+        // **RESERVE SAFETY FLOORS**
+        8 +  // reserve_safety_floor_a
+        8 +  // reserve_safety_floor_b
+        
+        // **SCHEMA VERSION**
+        2 +  // schema_version
+        
+        // **MINIMUM RESERVE**
+        8 +  // minimum_reserve
+        
+        // **SWAP MODE**
+        1 +  // swap_mode
+        
+        // **ACTIVITY TIMESTAMPS**
+        8 +  // created_at
+        8 +  // last_swap_at
+        
+        // **SWAP FEE BAND**
+        8 +  // min_allowed_fee
+        8 +  // max_allowed_fee
+        
+        // **LP TOKEN DECIMALS**
+        1 +  // lp_token_a_decimals
+        1 +  // lp_token_b_decimals
+        
+        // **PARTIAL PAUSES**
+        1 +  // partial_pause_flags
+        
+        // **DAILY FEE HISTORY**
+        8 * 7 + // daily_fee_snapshot_token_a
+        8 * 7 + // daily_fee_snapshot_token_b
+        8 +  // snapshot_timestamp
+        
+        // **EVENT TOGGLE**
+        1 +  // emit_events
+        
+        // **OWNER TRANSFER**
+        32 + // pending_owner
+        8 +  // owner_transfer_requested_at
+        
+        // **OPERATION COUNTER**
+        8 +  // total_operations
+        
+        // **LIFETIME COUNTERS**
+        16 + // cumulative_volume_token_a
+        16 + // cumulative_volume_token_b
+        16 + // lifetime_fees
+        
+        // **REQUESTED RATIO**
+        8 +  // original_ratio_a_numerator
+        8 +  // original_ratio_b_denominator
+        
+        // **FEE RECIPIENT**
+        32;  // fee_recipient
         
         // **REMOVED FIELDS** (these are no longer in PoolState):
         // - is_initialized: bool (1 byte) - Pool existence = initialization
@@ -1370,6 +1432,211 @@ async fn test_get_pool_info() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// GetPoolInfo on a closed or never-initialized pool account fails cleanly with PoolNotInitialized
+#[tokio::test]
+async fn test_get_pool_info_rejects_uninitialized_pool() -> Result<(), Box<dyn std::error::Error>> {
+    use fixed_ratio_trading::{PoolInstruction, PoolState};
+    use solana_sdk::{
+        account::AccountSharedData,
+        instruction::{AccountMeta, InstructionError},
+        signature::Signer,
+        transaction::TransactionError,
+    };
+
+    let mut context = create_program_test().start_with_context().await;
+    let payer = context.payer.insecure_clone();
+
+    // A zeroed, rent-exempt account owned by the program that pool creation never wrote
+    let never_initialized_pool = Pubkey::new_unique();
+    let rent = context.banks_client.get_rent().await?;
+    let pool_state_len = PoolState::get_packed_len();
+    context.set_account(
+        &never_initialized_pool,
+        &AccountSharedData::new(rent.minimum_balance(pool_state_len), pool_state_len, &PROGRAM_ID),
+    );
+    // A closed pool has no lamports left, so it no longer exists on chain
+    let closed_pool = Pubkey::new_unique();
+
+    for pool_state_pda in [closed_pool, never_initialized_pool] {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(payer.pubkey(), false),
+                AccountMeta::new_readonly(solana_program::system_program::id(), false),
+                AccountMeta::new_readonly(pool_state_pda, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+            ],
+            data: PoolInstruction::GetPoolInfo {}.try_to_vec()?,
+        };
+        let blockhash = context.banks_client.get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(&[instruction], Some(&payer.pubkey()), &[&payer], blockhash);
+        let result = context.banks_client.process_transaction(transaction).await;
+        assert_eq!(
+            result.expect_err("GetPoolInfo must fail for an uninitialized pool").unwrap(),
+            TransactionError::InstructionError(0, InstructionError::Custom(
+                fixed_ratio_trading::error::PoolError::PoolNotInitialized { pool: pool_state_pda }.error_code()
+            )),
+        );
+    }
+
+    println!("✅ GetPoolInfo rejects closed and never-initialized pool accounts with PoolNotInitialized");
+    Ok(())
+}
+
+/// GetMultiplePoolInfo summarizes every valid pool and reports the indices it skipped
+#[tokio::test]
+async fn test_get_multiple_pool_info_skips_invalid_accounts() -> Result<(), Box<dyn std::error::Error>> {
+    use fixed_ratio_trading::{types::MultiplePoolInfo, PoolInstruction, PoolState};
+    use solana_sdk::instruction::AccountMeta;
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+
+    // Two more pools in the same environment
+    let mut pools = vec![foundation.pool_config.pool_state_pda];
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    for ratio in [3, 5] {
+        let multiple_mint = Keypair::new();
+        let base_mint = Keypair::new();
+        create_mint(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &multiple_mint, None).await?;
+        create_mint(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &base_mint, None).await?;
+        let config = create_pool_new_pattern(
+            &mut foundation.env.banks_client, &foundation.env.payer, blockhash, &multiple_mint, &base_mint, Some(ratio),
+        ).await?;
+        pools.push(config.pool_state_pda);
+    }
+
+    // A token mint is an existing account that is not a pool
+    let garbage = foundation.pool_config.token_a_mint;
+    let accounts = [pools[0], garbage, pools[1], pools[2]];
+    let instruction = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts.iter().map(|account| AccountMeta::new_readonly(*account, false)).collect(),
+        data: PoolInstruction::GetMultiplePoolInfo {}.try_to_vec()?,
+    };
+    let return_data = simulate_and_get_return_data(
+        &mut foundation.env.banks_client,
+        &foundation.env.payer,
+        instruction,
+    ).await?;
+    let info = MultiplePoolInfo::try_from_slice(&return_data)?;
+
+    assert_eq!(info.skipped, vec![1], "The garbage account must be skipped, not fail the call");
+    assert_eq!(info.pools.len(), 3);
+    for (summary, pool) in info.pools.iter().zip(&pools) {
+        let pool_account = foundation.env.banks_client.get_account(*pool).await?.unwrap();
+        let pool_state = PoolState::try_from_slice(&pool_account.data)?;
+        assert_eq!(summary.pool, *pool);
+        assert_eq!(summary.ratio_a_numerator, pool_state.ratio_a_numerator);
+        assert_eq!(summary.ratio_b_denominator, pool_state.ratio_b_denominator);
+        assert_eq!(summary.contract_liquidity_fee, pool_state.contract_liquidity_fee);
+        assert_eq!(summary.swap_contract_fee, pool_state.swap_contract_fee);
+        assert_eq!(summary.total_token_a_liquidity, pool_state.total_token_a_liquidity);
+        assert_eq!(summary.total_token_b_liquidity, pool_state.total_token_b_liquidity);
+    }
+
+    println!("✅ GetMultiplePoolInfo reported {} pools and skipped {:?}", info.pools.len(), info.skipped);
+    Ok(())
+}
+
+/// GetCanonicalOrdering reports the same Token A / Token B split for both argument
+/// orders, matching how pool creation stores the mints
+#[tokio::test]
+async fn test_get_canonical_ordering_is_order_independent() -> Result<(), Box<dyn std::error::Error>> {
+    use fixed_ratio_trading::{types::CanonicalOrdering, PoolInstruction};
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let env = &mut foundation.env;
+    let pool_state = get_pool_state(&mut env.banks_client, &foundation.pool_config.pool_state_pda).await.expect("Pool state should exist");
+    let (mint_x, mint_y) = (pool_state.token_b_mint, pool_state.token_a_mint);
+
+    let mut orderings = Vec::new();
+    for (first, second) in [(mint_x, mint_y), (mint_y, mint_x)] {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![],
+            data: PoolInstruction::GetCanonicalOrdering { mint_x: first, mint_y: second }.try_to_vec()?,
+        };
+        let return_data = simulate_and_get_return_data(&mut env.banks_client, &env.payer, instruction).await?;
+        orderings.push(CanonicalOrdering::try_from_slice(&return_data)?);
+    }
+
+    assert_eq!(orderings[0].token_a_mint, orderings[1].token_a_mint);
+    assert_eq!(orderings[0].token_b_mint, orderings[1].token_b_mint);
+    assert_ne!(orderings[0].mint_x_is_token_a, orderings[1].mint_x_is_token_a);
+    assert!(orderings[0].token_a_mint < orderings[0].token_b_mint);
+
+    // The existing pool over the pair stores the mints in the reported order
+    assert!(!orderings[0].mint_x_is_token_a);
+    assert_eq!(pool_state.token_a_mint, orderings[0].token_a_mint);
+    assert_eq!(pool_state.token_b_mint, orderings[0].token_b_mint);
+
+    // Identical mints have no ordering
+    let instruction = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![],
+        data: PoolInstruction::GetCanonicalOrdering { mint_x, mint_y: mint_x }.try_to_vec()?,
+    };
+    assert!(simulate_and_get_return_data(&mut env.banks_client, &env.payer, instruction).await.is_err());
+
+    println!("✅ GetCanonicalOrdering: Token A {}, Token B {}", orderings[0].token_a_mint, orderings[0].token_b_mint);
+    Ok(())
+}
+
+/// EncodeSwap returns exactly the bytes of the original 81-byte Swap instruction without
+/// an expiry, and of SwapWithExpiry when a deadline and/or slot expiry is given
+#[tokio::test]
+async fn test_encode_swap_matches_client_serialization() -> Result<(), Box<dyn std::error::Error>> {
+    use fixed_ratio_trading::PoolInstruction;
+
+    let mut env = common::setup::start_test_environment().await;
+    let (input_token_mint, pool_id) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    for (deadline, not_valid_after_slot) in [
+        (None, None),
+        (None, Some(42u64)),
+        (Some(1_700_000_000i64), None),
+        (Some(1_700_000_000i64), Some(42u64)),
+    ] {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![],
+            data: PoolInstruction::EncodeSwap {
+                input_token_mint,
+                amount_in: 1_000,
+                expected_amount_out: 3_000,
+                pool_id,
+                deadline,
+                not_valid_after_slot,
+            }.try_to_vec()?,
+        };
+        let return_data = simulate_and_get_return_data(&mut env.banks_client, &env.payer, instruction).await?;
+
+        let expected = match (deadline, not_valid_after_slot) {
+            (None, None) => PoolInstruction::Swap {
+                input_token_mint,
+                amount_in: 1_000,
+                expected_amount_out: 3_000,
+                pool_id,
+            },
+            _ => PoolInstruction::SwapWithExpiry {
+                input_token_mint,
+                amount_in: 1_000,
+                expected_amount_out: 3_000,
+                pool_id,
+                deadline,
+                not_valid_after_slot,
+            },
+        }.try_to_vec()?;
+        if deadline.is_none() && not_valid_after_slot.is_none() {
+            assert_eq!(expected.len(), 81, "Swap must keep its original instruction layout");
+        }
+        assert_eq!(return_data, expected, "EncodeSwap must return the client's Swap bytes unchanged");
+    }
+
+    println!("✅ EncodeSwap matches PoolInstruction::Swap serialization");
+    Ok(())
+}
+
 // ================================================================================================
 // CONSTANTS TESTS
 // ================================================================================================
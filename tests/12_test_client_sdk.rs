@@ -427,3 +427,397 @@ fn test_utils_create_test_pool_config() {
     assert_eq!(test_config.ratio_b_denominator, 1);
     assert_ne!(test_config.multiple_token_mint, test_config.base_token_mint);
 }
+
+/// Test exporting a pool's configuration and recreating it from the snapshot (SDK-006)
+#[tokio::test]
+#[serial_test::serial]
+async fn test_pool_config_snapshot_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    use common::liquidity_helpers::create_liquidity_test_foundation;
+    use common::pool_helpers::get_pool_state;
+    use common::setup::simulate_and_get_return_data;
+    use fixed_ratio_trading::client_sdk::PortablePoolConfig;
+    use solana_program::instruction::{AccountMeta, Instruction};
+
+    println!("Running SDK-006: test_pool_config_snapshot_round_trip - GetPoolConfig export and recreation");
+
+    let mut foundation = create_liquidity_test_foundation(Some(3)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let pool_client = PoolClient::new(PROGRAM_ID);
+
+    // Export the live pool's configuration
+    let get_config_ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![AccountMeta::new_readonly(pool_state_pda, false)],
+        data: PoolInstruction::GetPoolConfig { pool_id: pool_state_pda }.try_to_vec()?,
+    };
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, get_config_ix).await?;
+    let snapshot = pool_client.decode_pool_config(&return_data)?;
+
+    // The snapshot carries the pool's settings and survives serialization unchanged
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &pool_state_pda).await.expect("Pool state should exist");
+    assert_eq!(snapshot, PortablePoolConfig::from(&pool_state));
+    assert_eq!(PortablePoolConfig::try_from_slice(&snapshot.try_to_vec()?)?, snapshot);
+
+    // Recreating from the snapshot targets the same pool address and settings
+    let config = pool_client.pool_config_from_snapshot(&snapshot)?;
+    assert_eq!(pool_client.derive_pool_id(&config), pool_state_pda);
+    match pool_client.recreate_pool_instruction(&snapshot) {
+        PoolInstruction::InitializePoolWithSwapFee { ratio_a_numerator, ratio_b_denominator, flags, initial_swap_contract_fee } => {
+            assert_eq!((ratio_a_numerator, ratio_b_denominator), (pool_state.ratio_a_numerator, pool_state.ratio_b_denominator));
+            assert_eq!(flags, pool_state.flags);
+            assert_eq!(initial_swap_contract_fee, Some(pool_state.swap_contract_fee));
+        }
+        other => panic!("Expected InitializePoolWithSwapFee, got {:?}", other),
+    }
+
+    println!("✅ SDK-006 test completed successfully");
+    Ok(())
+}
+
+/// Test the typed Deposit, Swap and Withdraw builders against a live pool (SDK-007)
+#[tokio::test]
+#[serial_test::serial]
+async fn test_typed_instruction_builders() -> Result<(), Box<dyn std::error::Error>> {
+    use common::liquidity_helpers::create_liquidity_test_foundation;
+    use common::tokens::{get_token_balance, mint_tokens};
+    use solana_sdk::{signature::Signer, transaction::Transaction};
+    use spl_associated_token_account::{get_associated_token_address, instruction::create_associated_token_account};
+
+    println!("Running SDK-007: test_typed_instruction_builders - Deposit, Swap and Withdraw builders");
+
+    let mut foundation = create_liquidity_test_foundation(Some(1)).await?;
+    let pool_client = PoolClient::new(PROGRAM_ID);
+
+    // Derive the pool from its mints and ratio alone
+    let config = PoolConfig::new(
+        foundation.pool_config.token_a_mint,
+        foundation.pool_config.token_b_mint,
+        foundation.pool_config.ratio_a_numerator,
+        foundation.pool_config.ratio_b_denominator,
+    )?;
+    let pool = pool_client.derive_pool_addresses(&config);
+    assert_eq!(pool.pool_state, foundation.pool_config.pool_state_pda);
+    assert_eq!(pool.lp_token_a_mint, foundation.lp_token_a_mint_pda);
+    assert_eq!(pool.lp_token_b_mint, foundation.lp_token_b_mint_pda);
+
+    // A mint outside the pool is rejected before any instruction is built
+    let stranger_mint = Pubkey::new_unique();
+    assert!(matches!(
+        pool_client.build_swap_instruction(&pool, &Pubkey::new_unique(), &stranger_mint, 1, 1),
+        Err(PoolClientError::InvalidTokenMint)
+    ));
+
+    // Fund user2's associated token accounts
+    let user = foundation.user2.pubkey();
+    let payer = foundation.env.payer.insecure_clone();
+    let create_atas: Vec<_> = [pool.token_a_mint, pool.token_b_mint, pool.lp_token_b_mint]
+        .iter()
+        .map(|mint| create_associated_token_account(&payer.pubkey(), &user, mint, &spl_token::id()))
+        .collect();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let tx = Transaction::new_signed_with_payer(&create_atas, Some(&payer.pubkey()), &[&payer], blockhash);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    let user_token_a = get_associated_token_address(&user, &pool.token_a_mint);
+    let user_token_b = get_associated_token_address(&user, &pool.token_b_mint);
+    let user_lp_b = get_associated_token_address(&user, &pool.lp_token_b_mint);
+    let (ratio_a, ratio_b) = (config.ratio_a_numerator, config.ratio_b_denominator);
+    let deposit_b = ratio_b * 10_000;
+    let swap_in = ratio_a * 1_000;
+    let swap_out = ratio_b * 1_000;
+    mint_tokens(&mut foundation.env.banks_client, &payer, blockhash, &pool.token_a_mint, &user_token_a, &payer, swap_in).await?;
+    mint_tokens(&mut foundation.env.banks_client, &payer, blockhash, &pool.token_b_mint, &user_token_b, &payer, deposit_b).await?;
+
+    let user_keypair = foundation.user2.insecure_clone();
+    let instructions = [
+        pool_client.build_deposit_instruction(&pool, &user, &pool.token_b_mint, deposit_b)?,
+        pool_client.build_swap_instruction(&pool, &user, &pool.token_a_mint, swap_in, swap_out)?,
+        pool_client.build_withdraw_instruction(&pool, &user, &pool.token_b_mint, swap_out)?,
+    ];
+    for ix in instructions {
+        let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(&[ix], Some(&user), &[&user_keypair], blockhash);
+        foundation.env.banks_client.process_transaction(tx).await?;
+    }
+
+    // Deposit minted LP 1:1, the swap paid out at the pool ratio, the withdrawal burned LP 1:1
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_b).await, deposit_b - swap_out);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_a).await, 0);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_b).await, swap_out * 2);
+
+    println!("✅ SDK-007 test completed successfully");
+    Ok(())
+}
+
+/// Test the free instruction builders against the hand-assembled test layouts (SDK-008)
+#[test]
+fn test_free_instruction_builders_match_manual_layout() {
+    use common::liquidity_helpers::{
+        create_deposit_instruction_standardized, create_swap_instruction_standardized,
+        create_withdrawal_instruction_standardized,
+    };
+    use fixed_ratio_trading::client_sdk::{build_deposit_instruction, build_swap_instruction, build_withdraw_instruction};
+    use fixed_ratio_trading::constants::MAIN_TREASURY_SEED_PREFIX;
+    use solana_program::instruction::AccountMeta;
+    use spl_associated_token_account::get_associated_token_address;
+
+    println!("Running SDK-008: test_free_instruction_builders_match_manual_layout - byte-for-byte account layouts");
+
+    let config = PoolConfig::new(Pubkey::new_unique(), Pubkey::new_unique(), 3, 1).unwrap();
+    let pool = PoolClient::new(PROGRAM_ID).derive_pool_addresses(&config);
+    let manual_config = common::pool_helpers::normalize_pool_config(&config.multiple_token_mint, &config.base_token_mint, 3, 1);
+    let user = Pubkey::new_unique();
+    let ata = |mint: &Pubkey| get_associated_token_address(&user, mint);
+
+    // Swap: the standard 11 accounts plus the Main Treasury PDA
+    let data = PoolInstruction::Swap {
+        input_token_mint: pool.token_a_mint,
+        amount_in: 3_000,
+        expected_amount_out: 1_000,
+        pool_id: pool.pool_state,
+    };
+    let mut expected = create_swap_instruction_standardized(&user, &ata(&pool.token_a_mint), &ata(&pool.token_b_mint), &manual_config, &data).unwrap();
+    let (main_treasury, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &PROGRAM_ID);
+    expected.accounts.push(AccountMeta::new(main_treasury, false));
+    let built = build_swap_instruction(&PROGRAM_ID, &config, &user, &pool.token_a_mint, 3_000, 1_000).unwrap();
+    assert_eq!(built, expected);
+
+    // Deposit Token B: user's Token B account in, LP B account out
+    let data = PoolInstruction::Deposit { deposit_token_mint: pool.token_b_mint, amount: 5_000, pool_id: pool.pool_state };
+    let expected = create_deposit_instruction_standardized(
+        &user, &ata(&pool.token_b_mint), &ata(&pool.lp_token_b_mint), &manual_config, &pool.lp_token_a_mint, &pool.lp_token_b_mint, &data,
+    ).unwrap();
+    assert_eq!(build_deposit_instruction(&PROGRAM_ID, &config, &user, &pool.token_b_mint, 5_000).unwrap(), expected);
+
+    // Withdraw Token A: LP A account in, user's Token A account out
+    let data = PoolInstruction::Withdraw { withdraw_token_mint: pool.token_a_mint, lp_amount_to_burn: 2_000, pool_id: pool.pool_state };
+    let expected = create_withdrawal_instruction_standardized(
+        &user, &ata(&pool.lp_token_a_mint), &ata(&pool.token_a_mint), &manual_config, &pool.lp_token_a_mint, &pool.lp_token_b_mint, &data,
+    ).unwrap();
+    assert_eq!(build_withdraw_instruction(&PROGRAM_ID, &config, &user, &pool.token_a_mint, 2_000).unwrap(), expected);
+
+    println!("✅ SDK-008 test completed successfully");
+}
+
+/// Test that tunable parameters set by the admin all appear in the GetPoolConfig dump (SDK-009)
+#[tokio::test]
+#[serial_test::serial]
+async fn test_pool_config_includes_tunable_parameters() -> Result<(), Box<dyn std::error::Error>> {
+    use common::liquidity_helpers::create_liquidity_test_foundation;
+    use common::setup::{get_test_program_data_address, simulate_and_get_return_data};
+    use fixed_ratio_trading::{constants::SYSTEM_STATE_SEED_PREFIX, state::SwapMode};
+    use solana_program::instruction::{AccountMeta, Instruction};
+    use solana_sdk::{signature::Signer, transaction::Transaction};
+
+    println!("Running SDK-009: test_pool_config_includes_tunable_parameters - GetPoolConfig after admin updates");
+
+    let mut foundation = create_liquidity_test_foundation(Some(3)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let pool_client = PoolClient::new(PROGRAM_ID);
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+
+    // Update several settings in one admin transaction
+    let admin_ix = |data: PoolInstruction| Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(foundation.system_authority.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(pool_state_pda, false),
+            AccountMeta::new_readonly(get_test_program_data_address(&PROGRAM_ID), false),
+        ],
+        data: data.try_to_vec().unwrap(),
+    };
+    let updates = [
+        admin_ix(PoolInstruction::SetMinSwapAmount { min_swap_amount: 2_500, pool_id: pool_state_pda }),
+        admin_ix(PoolInstruction::SetSwapMode { swap_mode: SwapMode::ExactInOnly, pool_id: pool_state_pda }),
+        admin_ix(PoolInstruction::SetMinimumReserve { minimum_reserve: 7_000, pool_id: pool_state_pda }),
+    ];
+    let mut tx = Transaction::new_with_payer(&updates, Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.system_authority], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    // One GetPoolConfig call returns every updated setting
+    let get_config_ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![AccountMeta::new_readonly(pool_state_pda, false)],
+        data: PoolInstruction::GetPoolConfig { pool_id: pool_state_pda }.try_to_vec()?,
+    };
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, get_config_ix).await?;
+    let snapshot = pool_client.decode_pool_config(&return_data)?;
+    assert_eq!(snapshot.min_swap_amount, 2_500);
+    assert_eq!(snapshot.swap_mode, SwapMode::ExactInOnly);
+    assert_eq!(snapshot.minimum_reserve, 7_000);
+
+    println!("✅ SDK-009 test completed successfully");
+    Ok(())
+}
+
+/// Test converting human ratios to base-unit ratios across mint decimals (SDK-010)
+#[test]
+fn test_normalize_ratio_scales_by_decimals() {
+    use fixed_ratio_trading::client_sdk::normalize_ratio;
+
+    println!("Running SDK-010: test_normalize_ratio_scales_by_decimals - decimal-aware ratio conversion");
+
+    // 6-decimal Token A against 9-decimal Token B: a 1:1 ratio scales B by 1000 more than A
+    let (ratio_a, ratio_b) = normalize_ratio((1, 1), 6, 9).unwrap();
+    assert_eq!((ratio_a, ratio_b), (1_000_000, 1_000_000_000));
+    assert_eq!(ratio_b / ratio_a, 1_000);
+
+    // 1 SOL (9 decimals) = 160 USDT (6 decimals)
+    assert_eq!(normalize_ratio((1, 160), 9, 6).unwrap(), (1_000_000_000, 160_000_000));
+
+    // Equal decimals leave the human ratio's proportion unchanged
+    assert_eq!(normalize_ratio((3, 7), 0, 0).unwrap(), (3, 7));
+
+    // The scaled ratio is accepted by PoolConfig
+    let (ratio_a, ratio_b) = normalize_ratio((2, 5), 6, 9).unwrap();
+    assert!(PoolConfig::new(Pubkey::new_unique(), Pubkey::new_unique(), ratio_a, ratio_b).is_ok());
+
+    // Zero sides are rejected before scaling
+    assert!(matches!(normalize_ratio((0, 1), 6, 9), Err(PoolClientError::InvalidRatio)));
+    assert!(matches!(normalize_ratio((1, 0), 6, 9), Err(PoolClientError::InvalidRatio)));
+
+    println!("✅ SDK-010 test completed successfully");
+}
+
+/// Test that extreme decimal differences overflow cleanly instead of wrapping (SDK-011)
+#[test]
+fn test_normalize_ratio_overflow_at_extreme_decimals() {
+    use fixed_ratio_trading::client_sdk::normalize_ratio;
+
+    println!("Running SDK-011: test_normalize_ratio_overflow_at_extreme_decimals - u64 overflow handling");
+
+    // 10^19 is the largest power of ten that fits in a u64
+    assert_eq!(normalize_ratio((1, 1), 0, 19).unwrap(), (1, 10_000_000_000_000_000_000));
+    assert!(matches!(normalize_ratio((1, 1), 0, 20), Err(PoolClientError::RatioOverflow)));
+    assert!(matches!(normalize_ratio((1, 1), u8::MAX, 0), Err(PoolClientError::RatioOverflow)));
+
+    // The power fits but the multiplication by the display amount does not
+    assert!(matches!(normalize_ratio((2, 1), 19, 0), Err(PoolClientError::RatioOverflow)));
+    assert!(matches!(normalize_ratio((1, u64::MAX), 0, 1), Err(PoolClientError::RatioOverflow)));
+
+    println!("✅ SDK-011 test completed successfully");
+}
+
+/// Test setup_pool end to end: new mints, InitializePool and seed liquidity, then a swap (SDK-012)
+#[tokio::test]
+#[serial_test::serial]
+async fn test_setup_pool_creates_swappable_pool() -> Result<(), Box<dyn std::error::Error>> {
+    use common::setup::{create_program_test, initialize_treasury_system};
+    use common::tokens::get_token_balance;
+    use fixed_ratio_trading::client_sdk::{setup_pool, PoolSetupParams};
+    use fixed_ratio_trading::state::PoolState;
+    use solana_sdk::{signature::{Keypair, Signer}, transaction::Transaction};
+    use spl_associated_token_account::get_associated_token_address;
+
+    println!("Running SDK-012: test_setup_pool_creates_swappable_pool - setup_pool then swap");
+
+    let (mut banks_client, payer, blockhash) = create_program_test().start().await;
+    initialize_treasury_system(&mut banks_client, &payer, blockhash, &Keypair::new()).await?;
+
+    let multiple_mint = Keypair::new();
+    let base_mint = Keypair::new();
+    let setup = setup_pool(&PROGRAM_ID, &PoolSetupParams {
+        payer: payer.pubkey(),
+        multiple_token_mint: multiple_mint.pubkey(),
+        base_token_mint: base_mint.pubkey(),
+        decimals: 6,
+        ratio_a_numerator: 2,
+        ratio_b_denominator: 1,
+        initial_balance: 1_000_000,
+        seed_amount_a: 200_000,
+        seed_amount_b: 100_000,
+    })?;
+
+    let [create_mints, initialize_pool, seed_liquidity] = setup.transactions();
+    for (instructions, extra_signers) in [
+        (create_mints, vec![&multiple_mint, &base_mint]),
+        (initialize_pool, vec![]),
+        (seed_liquidity, vec![]),
+    ] {
+        let mut signers = vec![&payer];
+        signers.extend(extra_signers);
+        let blockhash = banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(instructions, Some(&payer.pubkey()), &signers, blockhash);
+        banks_client.process_transaction(tx).await?;
+    }
+
+    // The pool exists at the derived address with the seed liquidity in place
+    let pool = &setup.addresses;
+    let pool_account = banks_client.get_account(pool.pool_state).await?.expect("Pool state should exist");
+    let pool_state = PoolState::try_from_slice(&pool_account.data)?;
+    assert_eq!((pool_state.token_a_mint, pool_state.token_b_mint), (pool.token_a_mint, pool.token_b_mint));
+    assert_eq!((pool_state.total_token_a_liquidity, pool_state.total_token_b_liquidity), (200_000, 100_000));
+    let user_lp_a = get_associated_token_address(&payer.pubkey(), &pool.lp_token_a_mint);
+    assert_eq!(get_token_balance(&mut banks_client, &user_lp_a).await, 200_000);
+
+    // The pool is immediately usable: swap Token A for Token B at the pool ratio
+    let user_token_b = get_associated_token_address(&payer.pubkey(), &pool.token_b_mint);
+    let token_b_before = get_token_balance(&mut banks_client, &user_token_b).await;
+    let (swap_in, swap_out) = (pool.ratio_a_numerator * 1_000, pool.ratio_b_denominator * 1_000);
+    let swap_ix = PoolClient::new(PROGRAM_ID).build_swap_instruction(pool, &payer.pubkey(), &pool.token_a_mint, swap_in, swap_out)?;
+    let blockhash = banks_client.get_latest_blockhash().await?;
+    let tx = Transaction::new_signed_with_payer(&[swap_ix], Some(&payer.pubkey()), &[&payer], blockhash);
+    banks_client.process_transaction(tx).await?;
+    assert_eq!(get_token_balance(&mut banks_client, &user_token_b).await, token_b_before + swap_out);
+
+    println!("✅ SDK-012 test completed successfully");
+    Ok(())
+}
+
+/// Test verifying a deployed pool against a configuration hash computed off-chain (SDK-013)
+#[tokio::test]
+#[serial_test::serial]
+async fn test_verify_pool_config_hash() -> Result<(), Box<dyn std::error::Error>> {
+    use common::liquidity_helpers::create_liquidity_test_foundation;
+    use common::setup::simulate_and_get_return_data;
+    use fixed_ratio_trading::constants::{LP_TOKEN_A_MINT_SEED_PREFIX, LP_TOKEN_B_MINT_SEED_PREFIX};
+    use solana_program::hash::hashv;
+    use solana_program::instruction::{AccountMeta, Instruction};
+
+    println!("Running SDK-013: test_verify_pool_config_hash - VerifyPoolConfig match and mismatch");
+
+    let mut foundation = create_liquidity_test_foundation(Some(3)).await?;
+    let config = foundation.pool_config.clone();
+    let (lp_token_a_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_A_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()], &PROGRAM_ID);
+    let (lp_token_b_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_B_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()], &PROGRAM_ID);
+
+    // The hash CI would compute from the intended deployment
+    let intended_hash = |ratio_a: u64, ratio_b: u64| hashv(&[
+        config.token_a_mint.as_ref(),
+        config.token_b_mint.as_ref(),
+        &ratio_a.to_le_bytes(),
+        &ratio_b.to_le_bytes(),
+        config.token_a_vault_pda.as_ref(),
+        config.token_b_vault_pda.as_ref(),
+        lp_token_a_mint.as_ref(),
+        lp_token_b_mint.as_ref(),
+    ]).to_bytes();
+
+    // The ratio is hashed as requested at creation (the PDA seeds), not GCD-reduced
+    let matching = intended_hash(config.ratio_a_numerator, config.ratio_b_denominator);
+    let altered_ratio = intended_hash(config.ratio_a_numerator + 1, config.ratio_b_denominator);
+    for (expected_hash, expected_match) in [(matching, true), (altered_ratio, false), ([0u8; 32], false)] {
+        let verify_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![AccountMeta::new_readonly(config.pool_state_pda, false)],
+            data: PoolInstruction::VerifyPoolConfig { pool_id: config.pool_state_pda, expected_hash }.try_to_vec()?,
+        };
+        let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, verify_ix).await?;
+        assert_eq!(bool::try_from_slice(&return_data)?, expected_match);
+    }
+
+    // The on-chain helper agrees with the off-chain computation
+    let mut pool_state = common::pool_helpers::get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.expect("Pool state should exist");
+    assert_eq!(pool_state.config_hash(), matching);
+
+    // A pool requested as a multiple of the same ratio stores the same reduced ratio but
+    // is a different pool with a different hash
+    pool_state.original_ratio_a_numerator *= 2;
+    pool_state.original_ratio_b_denominator *= 2;
+    assert_ne!(pool_state.config_hash(), matching, "4:2 and 2:1 pools must not share a config hash");
+
+    println!("✅ SDK-013 test completed successfully");
+    Ok(())
+}
@@ -1338,4 +1338,289 @@ async fn test_security_guards_comprehensive_validation() {
     println!("   • Total pools in enhanced foundation: {}", enhanced.pool_count());
     
     println!("✅ COMPREHENSIVE SECURITY GUARD TEST PASSED: Valid pool creation works correctly");
-} 
\ No newline at end of file
+} 
+/// **SECURITY GUARD TEST 5**: LP mint collision rejection
+/// 
+/// This test validates that pool creation refuses to reuse an account that already
+/// exists at one of the pool's derived LP token mint addresses.
+#[tokio::test]
+async fn test_security_guard_lp_mint_collision() {
+    use common::pool_helpers::*;
+    use solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        transaction::TransactionError,
+    };
+    use fixed_ratio_trading::{
+        constants::LP_TOKEN_A_MINT_SEED_PREFIX,
+        error::PoolError,
+        id,
+    };
+
+    println!("🔒 SECURITY GUARD TEST 5: LP mint collision rejection");
+
+    let mut context = create_program_test().start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let blockhash = context.last_blockhash;
+
+    let multiple_mint = Keypair::new();
+    let base_mint = Keypair::new();
+    create_mint(&mut context.banks_client, &payer, blockhash, &multiple_mint, None).await.unwrap();
+    create_mint(&mut context.banks_client, &payer, blockhash, &base_mint, None).await.unwrap();
+    initialize_treasury_system(&mut context.banks_client, &payer, blockhash, &Keypair::new()).await.unwrap();
+
+    // Existing pool whose LP mint will be copied into the colliding address
+    let existing_pool = create_pool_new_pattern(&mut context.banks_client, &payer, blockhash, &multiple_mint, &base_mint, Some(2))
+        .await
+        .expect("First pool should be created");
+    let (existing_lp_a_mint, _) = Pubkey::find_program_address(
+        &[LP_TOKEN_A_MINT_SEED_PREFIX, existing_pool.pool_state_pda.as_ref()],
+        &id(),
+    );
+    let existing_lp_mint_account = context.banks_client.get_account(existing_lp_a_mint).await.unwrap()
+        .expect("Existing LP mint should exist");
+
+    // Occupy the new pool's LP Token A mint address with the existing pool's LP mint
+    let new_config = normalize_pool_config(&multiple_mint.pubkey(), &base_mint.pubkey(), 3, 1);
+    let (colliding_lp_a_mint, _) = Pubkey::find_program_address(
+        &[LP_TOKEN_A_MINT_SEED_PREFIX, new_config.pool_state_pda.as_ref()],
+        &id(),
+    );
+    context.set_account(&colliding_lp_a_mint, &AccountSharedData::from(existing_lp_mint_account));
+
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let result = create_pool_new_pattern(&mut context.banks_client, &payer, blockhash, &multiple_mint, &base_mint, Some(3)).await;
+
+    let expected_code = PoolError::LpMintCollision { mint: colliding_lp_a_mint }.error_code();
+    assert_eq!(
+        result.expect_err("Pool creation should fail on LP mint collision").unwrap(),
+        TransactionError::InstructionError(1, InstructionError::Custom(expected_code)),
+    );
+
+    println!("✅ SECURITY GUARD TEST 5 PASSED: LP mint collision rejected with LpMintCollision");
+}
+
+/// Builds an InitializePoolWithSwapFee instruction for the given normalized pool configuration
+fn build_initialize_pool_with_swap_fee_ix(
+    payer: &Pubkey,
+    config: &PoolConfig,
+    initial_swap_contract_fee: Option<u64>,
+) -> Instruction {
+    use fixed_ratio_trading::constants::{LP_TOKEN_A_MINT_SEED_PREFIX, LP_TOKEN_B_MINT_SEED_PREFIX, MAIN_TREASURY_SEED_PREFIX, SYSTEM_STATE_SEED_PREFIX};
+
+    let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &PROGRAM_ID);
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+    let (lp_token_a_mint_pda, _) = Pubkey::find_program_address(&[LP_TOKEN_A_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()], &PROGRAM_ID);
+    let (lp_token_b_mint_pda, _) = Pubkey::find_program_address(&[LP_TOKEN_B_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()], &PROGRAM_ID);
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(config.token_a_mint, false),
+            AccountMeta::new_readonly(config.token_b_mint, false),
+            AccountMeta::new(config.token_a_vault_pda, false),
+            AccountMeta::new(config.token_b_vault_pda, false),
+            AccountMeta::new(lp_token_a_mint_pda, false),
+            AccountMeta::new(lp_token_b_mint_pda, false),
+        ],
+        data: PoolInstruction::InitializePoolWithSwapFee {
+            ratio_a_numerator: config.ratio_a_numerator,
+            ratio_b_denominator: config.ratio_b_denominator,
+            flags: 0,
+            initial_swap_contract_fee,
+        }.try_to_vec().unwrap(),
+    }
+}
+
+/// Pools can start with their own swap fee; out-of-range fees are rejected and `None` keeps the default
+#[tokio::test]
+#[serial]
+async fn test_initialize_pool_with_custom_swap_fee() -> Result<(), Box<dyn std::error::Error>> {
+    use fixed_ratio_trading::constants::{MAX_SWAP_FEE, SWAP_CONTRACT_FEE};
+    use fixed_ratio_trading::error::PoolError;
+    use solana_sdk::{compute_budget::ComputeBudgetInstruction, instruction::InstructionError, transaction::TransactionError};
+
+    // The foundation initializes the system and a 2:1 pool; new pools reuse its mints at other ratios
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let multiple_mint = foundation.primary_mint.pubkey();
+    let base_mint = foundation.base_mint.pubkey();
+    let payer_pubkey = foundation.env.payer.pubkey();
+
+    // Existing clients keep serializing InitializePool without the new field
+    let legacy = PoolInstruction::InitializePool { ratio_a_numerator: 2, ratio_b_denominator: 1, flags: 0 };
+    assert!(matches!(
+        PoolInstruction::try_from_slice(&legacy.try_to_vec()?)?,
+        PoolInstruction::InitializePool { ratio_a_numerator: 2, ratio_b_denominator: 1, flags: 0 }
+    ));
+
+    for (ratio, initial_swap_contract_fee, expected) in [
+        (3u64, Some(50_000u64), Ok(50_000u64)),
+        (4, None, Ok(SWAP_CONTRACT_FEE)),
+        (5, Some(MAX_SWAP_FEE + 1), Err(PoolError::InvalidSwapFee { fee: 0, min: 0, max: 0 }.error_code())),
+    ] {
+        let config = normalize_pool_config(&multiple_mint, &base_mint, ratio, 1);
+        let ix = build_initialize_pool_with_swap_fee_ix(&payer_pubkey, &config, initial_swap_contract_fee);
+        let mut tx = Transaction::new_with_payer(
+            &[ComputeBudgetInstruction::set_compute_unit_limit(500_000), ix],
+            Some(&payer_pubkey),
+        );
+        tx.sign(&[&foundation.env.payer], foundation.env.banks_client.get_latest_blockhash().await?);
+        let result = foundation.env.banks_client.process_transaction(tx).await;
+
+        match expected {
+            Ok(expected_fee) => {
+                result?;
+                let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.expect("Pool should exist");
+                assert_eq!(pool_state.swap_contract_fee, expected_fee);
+                println!("✅ {}:1 pool created with swap fee {} lamports", ratio, expected_fee);
+            }
+            Err(code) => {
+                assert_eq!(
+                    result.expect_err("Out-of-range starting swap fee must be rejected").unwrap(),
+                    TransactionError::InstructionError(1, InstructionError::Custom(code)),
+                );
+                assert!(get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.is_none());
+                println!("✅ {}:1 pool with swap fee {:?} rejected", ratio, initial_swap_contract_fee);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// `ValidatePoolParams` reports the result creation would have, without creating or charging anything
+#[tokio::test]
+#[serial]
+async fn test_validate_pool_params_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    use fixed_ratio_trading::constants::SYSTEM_STATE_SEED_PREFIX;
+    use solana_program::program_error::ProgramError;
+
+    // The foundation initializes the system and a 2:1 pool of primary (multiple) to base
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let multiple_mint = foundation.primary_mint.pubkey();
+    let base_mint = foundation.base_mint.pubkey();
+    let existing_pool = foundation.pool_config.pool_state_pda;
+    let payer = foundation.env.payer.insecure_clone();
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+
+    let validate_ix = |multiple: Pubkey, base: Pubkey, ratio_a: u64, ratio_b: u64, pool_state_pda: Pubkey| Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new_readonly(multiple, false),
+            AccountMeta::new_readonly(base, false),
+            AccountMeta::new_readonly(pool_state_pda, false),
+        ],
+        data: PoolInstruction::ValidatePoolParams {
+            multiple_token_mint: multiple,
+            base_token_mint: base,
+            ratio_a_numerator: ratio_a,
+            ratio_b_denominator: ratio_b,
+        }.try_to_vec().unwrap(),
+    };
+
+    let new_config = normalize_pool_config(&multiple_mint, &base_mint, 3, 1);
+    let already_exists = u64::from(ProgramError::AccountAlreadyInitialized);
+    for (description, ix, expected) in [
+        ("new 3:1 pool", validate_ix(multiple_mint, base_mint, 3, 1, new_config.pool_state_pda), 0),
+        ("existing 2:1 pool", validate_ix(multiple_mint, base_mint, 2, 1, existing_pool), already_exists),
+        // The same pool stated the other way round normalizes to the same PDA
+        ("existing pool, base as multiple", validate_ix(base_mint, multiple_mint, 1, 2, existing_pool), already_exists),
+        ("zero ratio", validate_ix(multiple_mint, base_mint, 0, 1, new_config.pool_state_pda), u64::from(ProgramError::InvalidArgument)),
+        ("same mint", validate_ix(multiple_mint, multiple_mint, 3, 1, new_config.pool_state_pda), u64::from(ProgramError::InvalidArgument)),
+        ("wrong pool PDA", validate_ix(multiple_mint, base_mint, 4, 1, new_config.pool_state_pda), u64::from(ProgramError::InvalidAccountData)),
+    ] {
+        let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &payer, ix).await?;
+        let code = u64::from_le_bytes(return_data.as_slice().try_into()?);
+        assert_eq!(code, expected, "{}", description);
+        println!("✅ {}: result code {}", description, code);
+    }
+
+    // Nothing was created by the dry run, and creation agrees with it
+    assert!(get_pool_state(&mut foundation.env.banks_client, &new_config.pool_state_pda).await.is_none());
+    let create_ix = build_initialize_pool_with_swap_fee_ix(&payer.pubkey(), &new_config, None);
+    let mut tx = Transaction::new_with_payer(
+        &[solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(500_000), create_ix],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    let ix = validate_ix(multiple_mint, base_mint, 3, 1, new_config.pool_state_pda);
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &payer, ix).await?;
+    assert_eq!(u64::from_le_bytes(return_data.as_slice().try_into()?), already_exists);
+
+    Ok(())
+}
+
+/// Ratios are stored in lowest terms: 4:2, 6:3 and 100:50 all reduce to 2:1. On chain, pools
+/// requested with different encodings of 2:1 (raw 2:1, 2.0:1.0 and 1.0:0.5 in 6-decimal base
+/// units) all store 2:1, keep the requested values for auditing and quote identically
+#[tokio::test]
+#[serial]
+async fn test_pool_ratio_reduced_by_gcd() -> Result<(), Box<dyn std::error::Error>> {
+    use fixed_ratio_trading::utils::validation::reduce_ratio;
+
+    for (ratio_a, ratio_b) in [(4u64, 2u64), (6, 3), (100, 50), (2, 1)] {
+        assert_eq!(reduce_ratio(ratio_a, ratio_b), (2, 1), "{}:{} must reduce to 2:1", ratio_a, ratio_b);
+    }
+    assert_eq!(reduce_ratio(1_000_000_000, 160_000_000), (25, 4));
+    assert_eq!(reduce_ratio(7, 3), (7, 3));
+
+    // The foundation initializes the system; the pools below use their own 6-decimal mints
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let payer = foundation.env.payer.insecure_clone();
+    let multiple_mint = Keypair::new();
+    let base_mint = Keypair::new();
+    for mint in [&multiple_mint, &base_mint] {
+        let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+        create_mint(&mut foundation.env.banks_client, &payer, blockhash, mint, Some(6)).await?;
+    }
+
+    let quote = |pool_state_pda: Pubkey, input_token_mint: Pubkey, amount_in: u64| Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![AccountMeta::new_readonly(pool_state_pda, false)],
+        data: PoolInstruction::GetSwapQuote { input_token_mint, amount_in, pool_id: pool_state_pda }.try_to_vec().unwrap(),
+    };
+
+    let mut quotes = Vec::new();
+    for (ratio_a, ratio_b) in [(2u64, 1u64), (2_000_000, 1_000_000), (1_000_000, 500_000)] {
+        // Each requested encoding is its own pool, addressed by the requested values
+        let config = normalize_pool_config(&multiple_mint.pubkey(), &base_mint.pubkey(), ratio_a, ratio_b);
+        let ix = build_initialize_pool_with_swap_fee_ix(&payer.pubkey(), &config, None);
+        let mut tx = Transaction::new_with_payer(
+            &[solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(500_000), ix],
+            Some(&payer.pubkey()),
+        );
+        tx.sign(&[&payer], foundation.env.banks_client.get_latest_blockhash().await?);
+        foundation.env.banks_client.process_transaction(tx).await?;
+
+        let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.expect("Pool should exist");
+        assert_eq!(
+            (pool_state.ratio_a_numerator, pool_state.ratio_b_denominator),
+            reduce_ratio(config.ratio_a_numerator, config.ratio_b_denominator),
+        );
+        assert_eq!(pool_state.ratio_a_numerator.max(pool_state.ratio_b_denominator), 2, "{}:{} must be stored as 2:1", ratio_a, ratio_b);
+        assert_eq!(
+            (pool_state.original_ratio_a_numerator, pool_state.original_ratio_b_denominator),
+            (config.ratio_a_numerator, config.ratio_b_denominator),
+        );
+
+        let mut pool_quotes = Vec::new();
+        for (input_token_mint, amount_in) in [(config.token_a_mint, 4u64), (config.token_a_mint, 1_001), (config.token_b_mint, 4), (config.token_b_mint, 999)] {
+            let data = simulate_and_get_return_data(&mut foundation.env.banks_client, &payer, quote(config.pool_state_pda, input_token_mint, amount_in)).await?;
+            pool_quotes.push(u64::from_le_bytes(data[..8].try_into()?));
+        }
+        println!("✅ {}:{} pool stored as {}:{}, quotes {:?}", ratio_a, ratio_b, pool_state.ratio_a_numerator, pool_state.ratio_b_denominator, pool_quotes);
+        quotes.push(pool_quotes);
+    }
+    assert!(quotes.windows(2).all(|pair| pair[0] == pair[1]), "Every encoding of 2:1 must quote the same: {:?}", quotes);
+
+    Ok(())
+}
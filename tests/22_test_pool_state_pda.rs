@@ -795,11 +795,11 @@ async fn test_pool_flag_persistence_immediate_verification() -> Result<(), Box<d
                 // **VERIFY BASIS POINTS CONVERSION**
                 println!("\n🔢 BASIS POINTS VERIFICATION:");
                 println!("   Expected Token A: {} basis points", normalized_token_a_basis_points);
-                println!("   Actual Token A: {} basis points", pool_state.ratio_a_numerator);
+                println!("   Actual Token A: {} basis points", pool_state.original_ratio_a_numerator);
                 println!("   Expected Token B: {} basis points", normalized_token_b_basis_points);
-                println!("   Actual Token B: {} basis points", pool_state.ratio_b_denominator);
-                println!("   Token A match: {}", pool_state.ratio_a_numerator == normalized_token_a_basis_points);
-                println!("   Token B match: {}", pool_state.ratio_b_denominator == normalized_token_b_basis_points);
+                println!("   Actual Token B: {} basis points", pool_state.original_ratio_b_denominator);
+                println!("   Token A match: {}", pool_state.original_ratio_a_numerator == normalized_token_a_basis_points);
+                println!("   Token B match: {}", pool_state.original_ratio_b_denominator == normalized_token_b_basis_points);
                 
                 // **CRITICAL CHECK: Verify the flag is set correctly**
                 let flag_is_set = (pool_state.flags & POOL_FLAG_SIMPLE_RATIO) != 0;
@@ -810,12 +810,12 @@ async fn test_pool_flag_persistence_immediate_verification() -> Result<(), Box<d
                 println!("   POOL_FLAG_SIMPLE_RATIO constant: 0b{:08b} ({})", POOL_FLAG_SIMPLE_RATIO, POOL_FLAG_SIMPLE_RATIO);
                 
                 // Verify basis points conversion first
-                assert_eq!(pool_state.ratio_a_numerator, normalized_token_a_basis_points, 
+                assert_eq!(pool_state.original_ratio_a_numerator, normalized_token_a_basis_points, 
                     "❌ BUG: Token A basis points conversion incorrect! Expected: {}, Got: {}", 
-                    normalized_token_a_basis_points, pool_state.ratio_a_numerator);
-                assert_eq!(pool_state.ratio_b_denominator, normalized_token_b_basis_points, 
+                    normalized_token_a_basis_points, pool_state.original_ratio_a_numerator);
+                assert_eq!(pool_state.original_ratio_b_denominator, normalized_token_b_basis_points, 
                     "❌ BUG: Token B basis points conversion incorrect! Expected: {}, Got: {}", 
-                    normalized_token_b_basis_points, pool_state.ratio_b_denominator);
+                    normalized_token_b_basis_points, pool_state.original_ratio_b_denominator);
                 
                 // Then verify flag setting
                 if EXPECT_FLAG_TO_BE_SET {
@@ -896,7 +896,35 @@ async fn test_serialization_method_comparison() -> Result<(), Box<dyn std::error
         min_deposit_amount: 0,
         max_withdrawal_amount: 0,
         min_withdrawal_amount: 0,
-        _reserved: [0; 4],
+        rounding_surplus_token_a: 0,
+        rounding_surplus_token_b: 0,
+        max_lp_supply: 0,
+        auto_unpause_at: 0,
+        reserve_safety_floor_a: 0,
+        reserve_safety_floor_b: 0,
+        schema_version: fixed_ratio_trading::constants::POOL_STATE_SCHEMA_VERSION,
+        minimum_reserve: 0,
+        swap_mode: fixed_ratio_trading::state::SwapMode::Both,
+        created_at: 0,
+        last_swap_at: 0,
+        min_allowed_fee: 0,
+        max_allowed_fee: 0,
+        lp_token_a_decimals: 0,
+        lp_token_b_decimals: 0,
+        partial_pause_flags: 0,
+        daily_fee_snapshot_token_a: [0; 7],
+        daily_fee_snapshot_token_b: [0; 7],
+        snapshot_timestamp: 0,
+        emit_events: true,
+        pending_owner: Pubkey::default(),
+        owner_transfer_requested_at: 0,
+        total_operations: 0,
+        cumulative_volume_token_a: 0,
+        cumulative_volume_token_b: 0,
+        lifetime_fees: 0,
+        original_ratio_a_numerator: 1_000_000_000,
+        original_ratio_b_denominator: 160_000_000,
+        fee_recipient: Pubkey::default(),
     };
     
     println!("📊 Original PoolState:");
@@ -1030,4 +1058,274 @@ async fn test_flag_bit_manipulation_standalone() -> Result<(), Box<dyn std::erro
     println!("✅ FLAG MANIPULATION TEST COMPLETED SUCCESSFULLY!");
     
     Ok(())
-} 
\ No newline at end of file
+} 
+/// A pool state account written with the version 1 layout (no `schema_version` field)
+/// is rewritten at the current version by MigratePoolState with balances, fees and owner-only
+/// delegation intact, and only the pool owner or admin authority may migrate it
+#[tokio::test]
+async fn test_migrate_pool_state_v1_to_current() -> Result<(), Box<dyn std::error::Error>> {
+    use fixed_ratio_trading::constants::{POOL_FLAG_SWAP_FOR_OWNERS_ONLY, POOL_STATE_SCHEMA_VERSION, POOL_STATE_SEED_PREFIX, SYSTEM_STATE_SEED_PREFIX};
+    use fixed_ratio_trading::{PoolInstruction, PoolState};
+    use solana_sdk::{account::AccountSharedData, instruction::AccountMeta, signature::Signer};
+
+    let mut context = create_program_test().start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let rent = context.banks_client.get_rent().await?;
+
+    // The payer owns the pool and has delegated swaps to a separate owner-only signer
+    let (token_a_mint, token_b_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (ratio_a_numerator, ratio_b_denominator) = (3_000_000_000u64, 1_000_000u64);
+    let (pool_state_pda, pool_authority_bump_seed) = Pubkey::find_program_address(
+        &[
+            POOL_STATE_SEED_PREFIX,
+            token_a_mint.as_ref(),
+            token_b_mint.as_ref(),
+            &ratio_a_numerator.to_le_bytes(),
+            &ratio_b_denominator.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+    let v1_state = PoolState {
+        owner: payer.pubkey(),
+        token_a_mint,
+        token_b_mint,
+        token_a_vault: Pubkey::new_unique(),
+        token_b_vault: Pubkey::new_unique(),
+        lp_token_a_mint: Pubkey::new_unique(),
+        lp_token_b_mint: Pubkey::new_unique(),
+        ratio_a_numerator,
+        ratio_b_denominator,
+        total_token_a_liquidity: 7_500_000_000,
+        total_token_b_liquidity: 2_500_000,
+        pool_authority_bump_seed,
+        flags: POOL_FLAG_SWAP_FOR_OWNERS_ONLY,
+        contract_liquidity_fee: 1_300_000,
+        swap_contract_fee: 27_150,
+        collected_liquidity_fees: 2_600_000,
+        collected_swap_contract_fees: 81_450,
+        total_sol_fees_collected: 2_681_450,
+        max_lp_supply: 10_000_000_000,
+        reserve_safety_floor_a: 1_000,
+        reserve_safety_floor_b: 500,
+        ..PoolState::default()
+    };
+    let mut v1_bytes = v1_state.try_to_vec()?;
+    v1_bytes.truncate(PoolState::get_v1_packed_len());
+    let mut v1_account = AccountSharedData::new(rent.minimum_balance(v1_bytes.len()), v1_bytes.len(), &PROGRAM_ID);
+    v1_account.set_data_from_slice(&v1_bytes);
+    context.set_account(&pool_state_pda, &v1_account);
+
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+    let migrate_ix = |authority: Pubkey| Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(pool_state_pda, false),
+            AccountMeta::new_readonly(get_test_program_data_address(&PROGRAM_ID), false),
+        ],
+        data: PoolInstruction::MigratePoolState { target_version: POOL_STATE_SCHEMA_VERSION }.try_to_vec().unwrap(),
+    };
+
+    // Neither the pool owner nor the admin authority: rejected, account untouched
+    let stranger = Keypair::new();
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(&[migrate_ix(stranger.pubkey())], Some(&payer.pubkey()), &[&payer, &stranger], blockhash);
+    assert!(context.banks_client.process_transaction(transaction).await.is_err(), "Only the pool owner or admin may migrate");
+    let account = context.banks_client.get_account(pool_state_pda).await?.expect("Pool state should exist");
+    assert_eq!(account.data.len(), PoolState::get_v1_packed_len());
+
+    // Pool owner migrates: the account grows by the version field and stays rent-exempt
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(&[migrate_ix(payer.pubkey())], Some(&payer.pubkey()), &[&payer], blockhash);
+    context.banks_client.process_transaction(transaction).await?;
+
+    let account = context.banks_client.get_account(pool_state_pda).await?.expect("Pool state should exist");
+    assert_eq!(account.data.len(), PoolState::get_packed_len());
+    assert!(rent.is_exempt(account.lamports, account.data.len()), "Migrated account must stay rent-exempt");
+    assert_eq!(&account.data[..v1_bytes.len()], &v1_bytes[..], "Version 1 fields must be preserved byte for byte");
+
+    let migrated = PoolState::try_from_slice(&account.data)?;
+    assert_eq!(migrated.schema_version, POOL_STATE_SCHEMA_VERSION);
+    assert_eq!(migrated.owner, v1_state.owner);
+    assert!(migrated.swap_for_owners_only(), "Owner-only delegation must survive migration");
+    assert_eq!(migrated.total_token_a_liquidity, v1_state.total_token_a_liquidity);
+    assert_eq!(migrated.total_token_b_liquidity, v1_state.total_token_b_liquidity);
+    assert_eq!(migrated.contract_liquidity_fee, v1_state.contract_liquidity_fee);
+    assert_eq!(migrated.swap_contract_fee, v1_state.swap_contract_fee);
+    assert_eq!(migrated.collected_liquidity_fees, v1_state.collected_liquidity_fees);
+    assert_eq!(migrated.collected_swap_contract_fees, v1_state.collected_swap_contract_fees);
+    assert_eq!(migrated.total_sol_fees_collected, v1_state.total_sol_fees_collected);
+    assert_eq!(migrated.max_lp_supply, v1_state.max_lp_supply);
+    assert_eq!((migrated.reserve_safety_floor_a, migrated.reserve_safety_floor_b), (1_000, 500));
+    assert_eq!(migrated.minimum_reserve, 0, "Fields the old layout lacks start at their defaults");
+
+    // Version 2 accounts keep their stored version and read the missing minimum reserve as 0
+    let mut v2_bytes = PoolState { total_token_a_liquidity: v1_state.total_token_a_liquidity, schema_version: 2, minimum_reserve: 123, ..PoolState::default() }.try_to_vec()?;
+    v2_bytes.truncate(PoolState::get_v2_packed_len());
+    let v2_state = PoolState::from_versioned_data(&v2_bytes)?;
+    assert_eq!((v2_state.schema_version, v2_state.minimum_reserve), (2, 0));
+    assert_eq!(v2_state.total_token_a_liquidity, v1_state.total_token_a_liquidity);
+
+    // Version 3 accounts keep their minimum reserve and read the missing swap mode as Both
+    let mut v3_bytes = PoolState { schema_version: 3, minimum_reserve: 123, swap_mode: fixed_ratio_trading::state::SwapMode::ExactOutOnly, ..PoolState::default() }.try_to_vec()?;
+    v3_bytes.truncate(PoolState::get_v3_packed_len());
+    let v3_state = PoolState::from_versioned_data(&v3_bytes)?;
+    assert_eq!((v3_state.schema_version, v3_state.minimum_reserve), (3, 123));
+    assert_eq!(v3_state.swap_mode, fixed_ratio_trading::state::SwapMode::Both);
+
+    // Version 4 accounts keep their swap mode and read the missing activity timestamps as 0
+    let mut v4_bytes = PoolState { schema_version: 4, swap_mode: fixed_ratio_trading::state::SwapMode::ExactOutOnly, created_at: 77, last_swap_at: 88, ..PoolState::default() }.try_to_vec()?;
+    v4_bytes.truncate(PoolState::get_v4_packed_len());
+    let v4_state = PoolState::from_versioned_data(&v4_bytes)?;
+    assert_eq!((v4_state.schema_version, v4_state.swap_mode), (4, fixed_ratio_trading::state::SwapMode::ExactOutOnly));
+    assert_eq!((v4_state.created_at, v4_state.last_swap_at), (0, 0));
+
+    // Version 5 accounts keep their activity timestamps and read the missing fee band as open
+    let mut v5_bytes = PoolState { schema_version: 5, created_at: 77, last_swap_at: 88, min_allowed_fee: 1, max_allowed_fee: 2, ..PoolState::default() }.try_to_vec()?;
+    v5_bytes.truncate(PoolState::get_v5_packed_len());
+    let v5_state = PoolState::from_versioned_data(&v5_bytes)?;
+    assert_eq!((v5_state.schema_version, v5_state.created_at, v5_state.last_swap_at), (5, 77, 88));
+    assert_eq!((v5_state.min_allowed_fee, v5_state.max_allowed_fee), (0, 0));
+
+    // Version 6 accounts keep their fee band and read the missing LP decimals as 0
+    let mut v6_bytes = PoolState { schema_version: 6, min_allowed_fee: 1, max_allowed_fee: 2, lp_token_a_decimals: 6, lp_token_b_decimals: 9, ..PoolState::default() }.try_to_vec()?;
+    v6_bytes.truncate(PoolState::get_v6_packed_len());
+    let v6_state = PoolState::from_versioned_data(&v6_bytes)?;
+    assert_eq!((v6_state.schema_version, v6_state.min_allowed_fee, v6_state.max_allowed_fee), (6, 1, 2));
+    assert_eq!((v6_state.lp_token_a_decimals, v6_state.lp_token_b_decimals), (0, 0));
+
+    // Version 7 accounts keep their LP decimals and read the missing partial pauses as none
+    let mut v7_bytes = PoolState { schema_version: 7, lp_token_a_decimals: 6, lp_token_b_decimals: 9, partial_pause_flags: 4, ..PoolState::default() }.try_to_vec()?;
+    v7_bytes.truncate(PoolState::get_v7_packed_len());
+    let v7_state = PoolState::from_versioned_data(&v7_bytes)?;
+    assert_eq!((v7_state.schema_version, v7_state.lp_token_a_decimals, v7_state.lp_token_b_decimals), (7, 6, 9));
+    assert_eq!(v7_state.partial_pause_flags, 0);
+
+    // Version 8 accounts keep their partial pauses and start with an empty fee history
+    let mut v8_bytes = PoolState { schema_version: 8, partial_pause_flags: 4, daily_fee_snapshot_token_a: [5; 7], snapshot_timestamp: 86_400, ..PoolState::default() }.try_to_vec()?;
+    v8_bytes.truncate(PoolState::get_v8_packed_len());
+    let v8_state = PoolState::from_versioned_data(&v8_bytes)?;
+    assert_eq!((v8_state.schema_version, v8_state.partial_pause_flags), (8, 4));
+    assert_eq!((v8_state.daily_fee_snapshot_token_a, v8_state.snapshot_timestamp), ([0; 7], 0));
+
+    // Version 9 accounts keep their fee history and keep emitting events
+    let mut v9_bytes = PoolState { schema_version: 9, daily_fee_snapshot_token_a: [5; 7], snapshot_timestamp: 86_400, emit_events: false, ..PoolState::default() }.try_to_vec()?;
+    v9_bytes.truncate(PoolState::get_v9_packed_len());
+    let v9_state = PoolState::from_versioned_data(&v9_bytes)?;
+    assert_eq!((v9_state.schema_version, v9_state.daily_fee_snapshot_token_a, v9_state.snapshot_timestamp), (9, [5; 7], 86_400));
+    assert!(v9_state.emit_events, "Pools that predate the toggle must keep emitting events");
+
+    // Version 10 accounts keep their own toggle and start with no owner transfer pending
+    let mut v10_bytes = PoolState { schema_version: 10, emit_events: false, ..PoolState::default() }.try_to_vec()?;
+    v10_bytes.truncate(PoolState::get_v10_packed_len());
+    let v10_state = PoolState::from_versioned_data(&v10_bytes)?;
+    assert_eq!((v10_state.schema_version, v10_state.emit_events), (10, false));
+    assert!(!v10_state.owner_transfer_pending());
+
+    // Version 11 accounts keep their pending transfer and start counting operations at 0
+    let pending_owner = Pubkey::new_unique();
+    let mut v11_bytes = PoolState { schema_version: 11, pending_owner, owner_transfer_requested_at: 100, total_operations: 9, ..PoolState::default() }.try_to_vec()?;
+    v11_bytes.truncate(PoolState::get_v11_packed_len());
+    let v11_state = PoolState::from_versioned_data(&v11_bytes)?;
+    assert_eq!((v11_state.schema_version, v11_state.pending_owner, v11_state.owner_transfer_requested_at), (11, pending_owner, 100));
+    assert_eq!(v11_state.total_operations, 0);
+
+    // Version 12 accounts keep their operation count and start the lifetime counters at 0
+    let mut v12_bytes = PoolState { schema_version: 12, total_operations: 9, lifetime_fees: 7, ..PoolState::default() }.try_to_vec()?;
+    v12_bytes.truncate(PoolState::get_v12_packed_len());
+    let v12_state = PoolState::from_versioned_data(&v12_bytes)?;
+    assert_eq!((v12_state.schema_version, v12_state.total_operations), (12, 9));
+    assert_eq!((v12_state.cumulative_volume_token_a, v12_state.lifetime_fees), (0, 0));
+
+    // Version 13 accounts never reduced their ratio, so the stored ratio is the requested one
+    let mut v13_bytes = PoolState { schema_version: 13, ratio_a_numerator: 4, ratio_b_denominator: 2, lifetime_fees: 7, ..PoolState::default() }.try_to_vec()?;
+    v13_bytes.truncate(PoolState::get_v13_packed_len());
+    let v13_state = PoolState::from_versioned_data(&v13_bytes)?;
+    assert_eq!((v13_state.schema_version, v13_state.lifetime_fees), (13, 7));
+    assert_eq!((v13_state.original_ratio_a_numerator, v13_state.original_ratio_b_denominator), (4, 2));
+
+    // Version 14 accounts keep their requested ratio and consolidate to the MainTreasury
+    let mut v14_bytes = PoolState { schema_version: 14, ratio_a_numerator: 2, ratio_b_denominator: 1, original_ratio_a_numerator: 4, original_ratio_b_denominator: 2, ..PoolState::default() }.try_to_vec()?;
+    v14_bytes.truncate(PoolState::get_v14_packed_len());
+    let v14_state = PoolState::from_versioned_data(&v14_bytes)?;
+    assert_eq!((v14_state.schema_version, v14_state.original_ratio_a_numerator, v14_state.original_ratio_b_denominator), (14, 4, 2));
+    assert_eq!(v14_state.fee_recipient, Pubkey::default());
+
+    println!("✅ Pool state migrated from schema version 1 to {} with all fields preserved", POOL_STATE_SCHEMA_VERSION);
+    Ok(())
+}
+
+/// GetPoolStateRaw returns the layout version followed by the pool's full Borsh-serialized state
+#[tokio::test]
+#[serial]
+async fn test_get_pool_state_raw_dumps_full_state() -> Result<(), Box<dyn std::error::Error>> {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use common::liquidity_helpers::create_liquidity_test_foundation;
+    use common::setup::simulate_and_get_return_data;
+    use fixed_ratio_trading::{constants::POOL_STATE_SCHEMA_VERSION, PoolInstruction, PoolState};
+    use solana_program::instruction::{AccountMeta, Instruction};
+
+    let mut foundation = create_liquidity_test_foundation(Some(4)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+
+    let get_raw_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![AccountMeta::new_readonly(pool_state_pda, false)],
+        data: PoolInstruction::GetPoolStateRaw {}.try_to_vec()?,
+    };
+    let dump = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, get_raw_ix).await?;
+
+    assert_eq!(dump.len(), 1 + PoolState::get_packed_len());
+    assert_eq!(dump[0] as u16, POOL_STATE_SCHEMA_VERSION, "First byte must carry the layout version");
+
+    let account = foundation.env.banks_client.get_account(pool_state_pda).await?.expect("Pool state should exist");
+    let dumped_state = PoolState::try_from_slice(&dump[1..])?;
+    assert_eq!(dumped_state.try_to_vec()?, PoolState::try_from_slice(&account.data)?.try_to_vec()?);
+
+    println!("✅ GetPoolStateRaw returned {} bytes at layout version {}", dump.len(), dump[0]);
+    Ok(())
+}
+
+/// The daily fee ring keeps one slot per UTC day, clears slots as days roll over and
+/// never holds more than FEE_HISTORY_DAYS days
+#[tokio::test]
+async fn test_daily_fee_history_ring_rolls_over_days() -> Result<(), Box<dyn std::error::Error>> {
+    use fixed_ratio_trading::constants::{FEE_HISTORY_DAYS, SECONDS_PER_DAY};
+    use fixed_ratio_trading::types::FeeHistory;
+
+    let day = |n: i64| 20_000 * SECONDS_PER_DAY + n * SECONDS_PER_DAY;
+    let mut pool_state = PoolState::default();
+
+    // Two fees on day 0 (one each side), one on day 1
+    pool_state.record_daily_swap_fee(true, 100, day(0) + 10);
+    pool_state.record_daily_swap_fee(false, 40, day(0) + SECONDS_PER_DAY - 1);
+    pool_state.record_daily_swap_fee(true, 7, day(1) + 5);
+    assert_eq!(pool_state.snapshot_timestamp, day(1));
+
+    let history = FeeHistory::from_pool_state(&pool_state, day(1) + 60);
+    assert_eq!(history.day_start, day(1));
+    assert_eq!((history.fees_token_a[0], history.fees_token_a[1]), (7, 100));
+    assert_eq!((history.fees_token_b[0], history.fees_token_b[1]), (0, 40));
+
+    // Reading three idle days later ages the same entries forward
+    let history = FeeHistory::from_pool_state(&pool_state, day(4));
+    assert_eq!((history.fees_token_a[3], history.fees_token_a[4]), (7, 100));
+    assert_eq!(history.fees_token_a[..3], [0, 0, 0]);
+
+    // Day 0 falls out of the week on day 7, and its slot is reused for day 7's fees
+    pool_state.record_daily_swap_fee(true, 3, day(7));
+    let history = FeeHistory::from_pool_state(&pool_state, day(7));
+    assert_eq!(history.fees_token_a[0], 3, "The reused slot must start from zero");
+    assert_eq!(history.fees_token_a[6], 7, "Day 1 is still within the week");
+    assert_eq!(history.fees_token_b, [0; FEE_HISTORY_DAYS], "Day 0's Token B fee must be gone");
+
+    // A gap longer than the ring clears everything before the new day
+    pool_state.record_daily_swap_fee(false, 9, day(30));
+    assert_eq!(pool_state.daily_fee_snapshot_token_a, [0; FEE_HISTORY_DAYS]);
+    assert_eq!(pool_state.daily_fee_snapshot_token_b.iter().sum::<u64>(), 9);
+
+    println!("✅ Daily fee ring rolls over UTC days within {} fixed slots", FEE_HISTORY_DAYS);
+    Ok(())
+}
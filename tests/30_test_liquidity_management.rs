@@ -1822,4 +1822,710 @@ async fn test_liquidity_operations_basis_points_refactor() -> Result<(), Box<dyn
     println!("====================================================================");
 
     Ok(())
-}
\ No newline at end of file
+}
+/// Deposits may fill the LP supply cap exactly; the next deposit must fail
+#[tokio::test]
+#[serial]
+async fn test_deposit_rejected_past_lp_supply_cap() -> TestResult {
+    use fixed_ratio_trading::{constants::SYSTEM_STATE_SEED_PREFIX, error::PoolError};
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        transaction::TransactionError,
+    };
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let lp_supply_cap: u64 = 1_000_000;
+
+    // Admin sets the LP supply cap
+    let program_id = fixed_ratio_trading::id();
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &program_id);
+    let set_cap_ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(foundation.system_authority.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(pool_state_pda, false),
+            AccountMeta::new_readonly(common::setup::get_test_program_data_address(&program_id), false),
+        ],
+        data: PoolInstruction::SetMaxLpSupply {
+            max_lp_supply: lp_supply_cap,
+            pool_id: pool_state_pda,
+        }.try_to_vec()?,
+    };
+    let mut tx = Transaction::new_with_payer(&[set_cap_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.system_authority], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &pool_state_pda).await
+        .expect("Pool state should exist");
+    assert_eq!(pool_state.max_lp_supply, lp_supply_cap);
+
+    // Fill the cap exactly across two deposits
+    let user1_pubkey = foundation.user1.pubkey();
+    let user1_primary = foundation.user1_primary_account.pubkey();
+    let user1_lp_a = foundation.user1_lp_a_account.pubkey();
+    let token_a_mint = foundation.pool_config.token_a_mint;
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_primary, &user1_lp_a, &token_a_mint, 600_000).await?;
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_primary, &user1_lp_a, &token_a_mint, 400_000).await?;
+
+    // One more LP token would exceed the cap
+    let result = execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_primary, &user1_lp_a, &token_a_mint, 1).await;
+    let expected_code = PoolError::LpSupplyCapReached { cap: 0, current_supply: 0, requested: 0 }.error_code();
+    let error = result.expect_err("Deposit past the LP supply cap must fail");
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(expected_code)),
+    );
+
+    println!("✅ LP supply cap of {} enforced; deposit past the cap rejected", lp_supply_cap);
+    Ok(())
+}
+
+/// DepositBoth mints both LP tokens 1:1 and reports them; if either side fails, neither side lands
+#[tokio::test]
+#[serial]
+async fn test_deposit_both_tokens_atomically() -> TestResult {
+    use fixed_ratio_trading::constants::SYSTEM_STATE_SEED_PREFIX;
+    use solana_sdk::instruction::{AccountMeta, Instruction};
+
+    let mut foundation = create_liquidity_test_foundation(Some(1)).await?;
+    let config = foundation.pool_config.clone();
+    let (user_token_a, user_token_b) = if config.token_a_is_the_multiple {
+        (foundation.user1_primary_account.pubkey(), foundation.user1_base_account.pubkey())
+    } else {
+        (foundation.user1_base_account.pubkey(), foundation.user1_primary_account.pubkey())
+    };
+    let user_lp_a = foundation.user1_lp_a_account.pubkey();
+    let user_lp_b = foundation.user1_lp_b_account.pubkey();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &foundation.user1_lp_a_account, &foundation.lp_token_a_mint_pda, &foundation.user1.pubkey()).await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &foundation.user1_lp_b_account, &foundation.lp_token_b_mint_pda, &foundation.user1.pubkey()).await?;
+
+    let program_id = fixed_ratio_trading::id();
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &program_id);
+    let build_deposit_both_ix = |amount_a: u64, amount_b: u64| Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(foundation.user1.pubkey(), true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(config.token_a_vault_pda, false),
+            AccountMeta::new(config.token_b_vault_pda, false),
+            AccountMeta::new(user_token_a, false),
+            AccountMeta::new(user_token_b, false),
+            AccountMeta::new(user_lp_a, false),
+            AccountMeta::new(user_lp_b, false),
+            AccountMeta::new(foundation.lp_token_a_mint_pda, false),
+            AccountMeta::new(foundation.lp_token_b_mint_pda, false),
+        ],
+        data: PoolInstruction::DepositBoth { amount_a, amount_b, pool_id: config.pool_state_pda }.try_to_vec().unwrap(),
+    };
+
+    let token_a_before = get_token_balance(&mut foundation.env.banks_client, &user_token_a).await;
+    let token_b_before = get_token_balance(&mut foundation.env.banks_client, &user_token_b).await;
+
+    // Step 1: Token B side exceeds the user's balance, so the Token A side must roll back too
+    let mut tx = Transaction::new_with_payer(&[build_deposit_both_ix(100_000, token_b_before + 1)], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    assert!(foundation.env.banks_client.process_transaction(tx).await.is_err(), "DepositBoth must fail when one side fails");
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_a).await, token_a_before, "Token A side must be rolled back");
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_a).await, 0, "No LP Token A may be minted");
+
+    // Step 2: Both sides deposit and the minted LP amounts are returned
+    let (amount_a, amount_b) = (100_000u64, 50_000u64);
+    let mut tx = Transaction::new_with_payer(&[build_deposit_both_ix(amount_a, amount_b)], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    let simulation = foundation.env.banks_client.simulate_transaction(tx.clone()).await?;
+    let return_data = simulation.simulation_details.and_then(|details| details.return_data).map(|data| data.data).unwrap_or_default();
+    assert_eq!(return_data.len(), 16, "DepositBoth must return two u64 values");
+    assert_eq!(u64::from_le_bytes(return_data[0..8].try_into().unwrap()), amount_a);
+    assert_eq!(u64::from_le_bytes(return_data[8..16].try_into().unwrap()), amount_b);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_a).await, token_a_before - amount_a);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_b).await, token_b_before - amount_b);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_a).await, amount_a);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_b).await, amount_b);
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await
+        .expect("Pool state should exist");
+    assert_eq!(pool_state.total_token_a_liquidity, amount_a);
+    assert_eq!(pool_state.total_token_b_liquidity, amount_b);
+
+    println!("✅ DepositBoth minted {} LP Token A and {} LP Token B atomically", amount_a, amount_b);
+    Ok(())
+}
+
+/// DepositBoth needs both sides to contribute, but any split of the amounts is deposited in full
+#[tokio::test]
+#[serial]
+async fn test_deposit_both_zero_side_and_skewed_amounts() -> TestResult {
+    use fixed_ratio_trading::{constants::SYSTEM_STATE_SEED_PREFIX, error::PoolError};
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        transaction::TransactionError,
+    };
+
+    let mut foundation = create_liquidity_test_foundation(Some(1)).await?;
+    let config = foundation.pool_config.clone();
+    let (user_token_a, user_token_b) = if config.token_a_is_the_multiple {
+        (foundation.user1_primary_account.pubkey(), foundation.user1_base_account.pubkey())
+    } else {
+        (foundation.user1_base_account.pubkey(), foundation.user1_primary_account.pubkey())
+    };
+    let user_lp_a = foundation.user1_lp_a_account.pubkey();
+    let user_lp_b = foundation.user1_lp_b_account.pubkey();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &foundation.user1_lp_a_account, &foundation.lp_token_a_mint_pda, &foundation.user1.pubkey()).await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &foundation.user1_lp_b_account, &foundation.lp_token_b_mint_pda, &foundation.user1.pubkey()).await?;
+
+    let program_id = fixed_ratio_trading::id();
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &program_id);
+    let build_deposit_both_ix = |amount_a: u64, amount_b: u64| Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(foundation.user1.pubkey(), true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(config.token_a_vault_pda, false),
+            AccountMeta::new(config.token_b_vault_pda, false),
+            AccountMeta::new(user_token_a, false),
+            AccountMeta::new(user_token_b, false),
+            AccountMeta::new(user_lp_a, false),
+            AccountMeta::new(user_lp_b, false),
+            AccountMeta::new(foundation.lp_token_a_mint_pda, false),
+            AccountMeta::new(foundation.lp_token_b_mint_pda, false),
+        ],
+        data: PoolInstruction::DepositBoth { amount_a, amount_b, pool_id: config.pool_state_pda }.try_to_vec().unwrap(),
+    };
+
+    // Step 1: A zero on either side is rejected before anything moves
+    let expected_code = PoolError::DepositRatioMismatch { amount_a: 0, amount_b: 0 }.error_code();
+    for (amount_a, amount_b) in [(100_000u64, 0u64), (0, 100_000)] {
+        let mut tx = Transaction::new_with_payer(&[build_deposit_both_ix(amount_a, amount_b)], Some(&foundation.env.payer.pubkey()));
+        tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+        assert_eq!(
+            foundation.env.banks_client.process_transaction(tx).await.expect_err("A zero side must be rejected").unwrap(),
+            TransactionError::InstructionError(0, InstructionError::Custom(expected_code)),
+        );
+    }
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_a).await, 0);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_b).await, 0);
+
+    // Step 2: A heavily skewed split is deposited in full on both sides, nothing refunded
+    let (amount_a, amount_b) = (1u64, 500_000u64);
+    let token_a_before = get_token_balance(&mut foundation.env.banks_client, &user_token_a).await;
+    let token_b_before = get_token_balance(&mut foundation.env.banks_client, &user_token_b).await;
+    assert!(token_b_before >= amount_b, "User needs enough Token B for the skewed deposit");
+    let mut tx = Transaction::new_with_payer(&[build_deposit_both_ix(amount_a, amount_b)], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_a).await, token_a_before - amount_a);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_b).await, token_b_before - amount_b);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_a).await, amount_a);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_b).await, amount_b);
+
+    println!("✅ DepositBoth rejected zero sides and deposited a {}:{} split in full", amount_a, amount_b);
+    Ok(())
+}
+
+/// WithdrawAndSwap exits an LP Token A position entirely into Token B; the slippage check covers the swap leg
+#[tokio::test]
+#[serial]
+async fn test_withdraw_and_swap_exits_into_other_token() -> TestResult {
+    use fixed_ratio_trading::constants::SYSTEM_STATE_SEED_PREFIX;
+    use solana_sdk::instruction::{AccountMeta, Instruction};
+
+    let mut foundation = create_liquidity_test_foundation(Some(1)).await?;
+    let config = foundation.pool_config.clone();
+    let (user_token_a, user_token_b) = if config.token_a_is_the_multiple {
+        (foundation.user1_primary_account.pubkey(), foundation.user1_base_account.pubkey())
+    } else {
+        (foundation.user1_base_account.pubkey(), foundation.user1_primary_account.pubkey())
+    };
+    let user_lp_a = foundation.user1_lp_a_account.pubkey();
+    let user_lp_b = foundation.user1_lp_b_account.pubkey();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &foundation.user1_lp_a_account, &foundation.lp_token_a_mint_pda, &foundation.user1.pubkey()).await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &foundation.user1_lp_b_account, &foundation.lp_token_b_mint_pda, &foundation.user1.pubkey()).await?;
+
+    let program_id = fixed_ratio_trading::id();
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &program_id);
+
+    // Step 1: Seed both sides so the pool holds Token B to pay the swap leg
+    let (amount_a, amount_b) = (100_000u64, 200_000u64);
+    let deposit_both_ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(foundation.user1.pubkey(), true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(config.token_a_vault_pda, false),
+            AccountMeta::new(config.token_b_vault_pda, false),
+            AccountMeta::new(user_token_a, false),
+            AccountMeta::new(user_token_b, false),
+            AccountMeta::new(user_lp_a, false),
+            AccountMeta::new(user_lp_b, false),
+            AccountMeta::new(foundation.lp_token_a_mint_pda, false),
+            AccountMeta::new(foundation.lp_token_b_mint_pda, false),
+        ],
+        data: PoolInstruction::DepositBoth { amount_a, amount_b, pool_id: config.pool_state_pda }.try_to_vec().unwrap(),
+    };
+    let mut tx = Transaction::new_with_payer(&[deposit_both_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    let build_withdraw_and_swap_ix = |min_out: u64| Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(foundation.user1.pubkey(), true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(config.token_a_vault_pda, false),
+            AccountMeta::new(config.token_b_vault_pda, false),
+            AccountMeta::new(user_lp_a, false),
+            AccountMeta::new(user_token_a, false),
+            AccountMeta::new(foundation.lp_token_a_mint_pda, false),
+            AccountMeta::new(foundation.lp_token_b_mint_pda, false),
+            AccountMeta::new(user_token_b, false),
+            AccountMeta::new_readonly(config.token_a_mint, false),
+            AccountMeta::new_readonly(config.token_b_mint, false),
+        ],
+        data: PoolInstruction::WithdrawAndSwap {
+            lp_amount: amount_a,
+            lp_token_mint: foundation.lp_token_a_mint_pda,
+            output_token_mint: config.token_b_mint,
+            min_out,
+            pool_id: config.pool_state_pda,
+        }.try_to_vec().unwrap(),
+    };
+
+    let token_a_before = get_token_balance(&mut foundation.env.banks_client, &user_token_a).await;
+    let token_b_before = get_token_balance(&mut foundation.env.banks_client, &user_token_b).await;
+
+    // Step 2: A minimum above the 1:1 swap output fails the whole instruction
+    let mut tx = Transaction::new_with_payer(&[build_withdraw_and_swap_ix(amount_a + 1)], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    let error = foundation.env.banks_client.process_transaction(tx).await.expect_err("Output below min_out must be rejected");
+    assert!(
+        matches!(error.unwrap(), solana_sdk::transaction::TransactionError::InstructionError(0, solana_sdk::instruction::InstructionError::Custom(1061))),
+        "Expected SwapOutputBelowMinimum (1061)"
+    );
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_a).await, amount_a, "No LP Token A may be burned");
+
+    // Step 3: Exit the whole LP Token A position into Token B
+    let mut tx = Transaction::new_with_payer(&[build_withdraw_and_swap_ix(amount_a)], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    let simulation = foundation.env.banks_client.simulate_transaction(tx.clone()).await?;
+    let return_data = simulation.simulation_details.and_then(|details| details.return_data).map(|data| data.data).unwrap_or_default();
+    assert_eq!(return_data.len(), 16, "WithdrawAndSwap must return two u64 values");
+    assert_eq!(u64::from_le_bytes(return_data[0..8].try_into().unwrap()), amount_a);
+    assert_eq!(u64::from_le_bytes(return_data[8..16].try_into().unwrap()), amount_a);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_a).await, 0, "LP Token A position must be fully exited");
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_a).await, token_a_before, "Withdrawn Token A must all be swapped");
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_b).await, token_b_before + amount_a);
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await
+        .expect("Pool state should exist");
+    assert_eq!(pool_state.total_token_a_liquidity, amount_a, "Swapped Token A returns to the pool");
+    assert_eq!(pool_state.total_token_b_liquidity, amount_b - amount_a);
+
+    println!("✅ WithdrawAndSwap exited {} LP Token A into {} Token B", amount_a, amount_a);
+    Ok(())
+}
+
+/// WithdrawAll burns the user's entire LP balance and rejects an empty LP account
+#[tokio::test]
+#[serial]
+async fn test_withdraw_all_burns_entire_lp_balance() -> TestResult {
+    use common::liquidity_helpers::{create_deposit_instruction_standardized, create_withdrawal_instruction_standardized};
+
+    let mut foundation = create_liquidity_test_foundation(Some(1)).await?;
+    let config = foundation.pool_config.clone();
+    let user_token_a = if config.token_a_is_the_multiple {
+        foundation.user1_primary_account.pubkey()
+    } else {
+        foundation.user1_base_account.pubkey()
+    };
+    let user_lp_a = foundation.user1_lp_a_account.pubkey();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &foundation.user1_lp_a_account, &foundation.lp_token_a_mint_pda, &foundation.user1.pubkey()).await?;
+
+    // Step 1: Build an LP position from two deposits of odd sizes
+    for amount in [12_345u64, 6_789u64] {
+        let deposit_ix = create_deposit_instruction_standardized(
+            &foundation.user1.pubkey(),
+            &user_token_a,
+            &user_lp_a,
+            &config,
+            &foundation.lp_token_a_mint_pda,
+            &foundation.lp_token_b_mint_pda,
+            &PoolInstruction::Deposit { deposit_token_mint: config.token_a_mint, amount, pool_id: config.pool_state_pda },
+        ).unwrap();
+        let mut tx = Transaction::new_with_payer(&[deposit_ix], Some(&foundation.env.payer.pubkey()));
+        tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+        foundation.env.banks_client.process_transaction(tx).await?;
+    }
+    let lp_balance = get_token_balance(&mut foundation.env.banks_client, &user_lp_a).await;
+    assert_eq!(lp_balance, 12_345 + 6_789);
+    let token_a_before = get_token_balance(&mut foundation.env.banks_client, &user_token_a).await;
+
+    let withdraw_all_ix = create_withdrawal_instruction_standardized(
+        &foundation.user1.pubkey(),
+        &user_lp_a,
+        &user_token_a,
+        &config,
+        &foundation.lp_token_a_mint_pda,
+        &foundation.lp_token_b_mint_pda,
+        &PoolInstruction::WithdrawAll { withdraw_token_mint: config.token_a_mint, pool_id: config.pool_state_pda },
+    ).unwrap();
+
+    // Step 2: WithdrawAll burns the whole balance and reports it via return data
+    let mut tx = Transaction::new_with_payer(&[withdraw_all_ix.clone()], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    let simulation = foundation.env.banks_client.simulate_transaction(tx.clone()).await?;
+    let return_data = simulation.simulation_details.and_then(|details| details.return_data).map(|data| data.data).unwrap_or_default();
+    assert_eq!(return_data, lp_balance.to_le_bytes().to_vec(), "WithdrawAll must return the LP amount burned");
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_a).await, 0, "No LP dust may remain");
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_a).await, token_a_before + lp_balance);
+
+    // Step 3: A second WithdrawAll on the now-empty LP account fails clearly
+    let mut tx = Transaction::new_with_payer(&[withdraw_all_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    let error = foundation.env.banks_client.process_transaction(tx).await.expect_err("Empty LP account must be rejected");
+    assert!(
+        matches!(error.unwrap(), solana_sdk::transaction::TransactionError::InstructionError(0, solana_sdk::instruction::InstructionError::Custom(1071))),
+        "Expected NoLpTokensToWithdraw (1071)"
+    );
+
+    println!("✅ WithdrawAll burned {} LP Token A with no dust left", lp_balance);
+    Ok(())
+}
+
+/// Pools created with POOL_FLAG_LP_TRANSFERS_FROZEN keep LP accounts frozen: direct LP transfers
+/// fail while deposits and withdrawals still work
+#[tokio::test]
+#[serial]
+async fn test_lp_transfers_frozen_pool_blocks_direct_lp_transfers() -> TestResult {
+    use fixed_ratio_trading::constants::{
+        LP_TOKEN_A_MINT_SEED_PREFIX, LP_TOKEN_B_MINT_SEED_PREFIX, MAIN_TREASURY_SEED_PREFIX,
+        POOL_FLAG_LP_TRANSFERS_FROZEN, SYSTEM_STATE_SEED_PREFIX,
+    };
+    use solana_program::program_pack::Pack;
+    use solana_sdk::{compute_budget::ComputeBudgetInstruction, instruction::{AccountMeta, Instruction}};
+    use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+    use common::liquidity_helpers::{create_deposit_instruction_standardized, create_withdrawal_instruction_standardized};
+
+    // The foundation initializes the system; the frozen-LP pool reuses its mints at 3:1
+    let mut foundation = create_liquidity_test_foundation(Some(1)).await?;
+    let program_id = fixed_ratio_trading::id();
+    let config = normalize_pool_config(&foundation.primary_mint.pubkey(), &foundation.base_mint.pubkey(), 3, 1);
+    let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &program_id);
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &program_id);
+    let (lp_token_a_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_A_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()], &program_id);
+    let (lp_token_b_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_B_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()], &program_id);
+
+    let initialize_pool_ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(foundation.env.payer.pubkey(), true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(config.token_a_mint, false),
+            AccountMeta::new_readonly(config.token_b_mint, false),
+            AccountMeta::new(config.token_a_vault_pda, false),
+            AccountMeta::new(config.token_b_vault_pda, false),
+            AccountMeta::new(lp_token_a_mint, false),
+            AccountMeta::new(lp_token_b_mint, false),
+        ],
+        data: PoolInstruction::InitializePool {
+            ratio_a_numerator: config.ratio_a_numerator,
+            ratio_b_denominator: config.ratio_b_denominator,
+            flags: POOL_FLAG_LP_TRANSFERS_FROZEN,
+        }.try_to_vec().unwrap(),
+    };
+    let mut tx = Transaction::new_with_payer(
+        &[ComputeBudgetInstruction::set_compute_unit_limit(500_000), initialize_pool_ix],
+        Some(&foundation.env.payer.pubkey()),
+    );
+    tx.sign(&[&foundation.env.payer], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    // Step 1: The flag is stored and the pool state PDA is the LP mints' freeze authority
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await
+        .expect("Pool state should exist");
+    assert!(pool_state.lp_transfers_frozen());
+    for lp_mint in [lp_token_a_mint, lp_token_b_mint] {
+        let mint_account = foundation.env.banks_client.get_account(lp_mint).await?.expect("LP mint should exist");
+        let mint = Mint::unpack_from_slice(&mint_account.data).unwrap();
+        assert_eq!(mint.freeze_authority, Some(config.pool_state_pda).into());
+    }
+
+    let user_token_a = if config.token_a_is_the_multiple {
+        foundation.user1_primary_account.pubkey()
+    } else {
+        foundation.user1_base_account.pubkey()
+    };
+    let user1_lp_a = Keypair::new();
+    let user2_lp_a = Keypair::new();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &user1_lp_a, &lp_token_a_mint, &foundation.user1.pubkey()).await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &user2_lp_a, &lp_token_a_mint, &foundation.user2.pubkey()).await?;
+    let lp_account_state = |data: &[u8]| TokenAccount::unpack_from_slice(data).unwrap().state;
+
+    // Step 2: Deposit mints LP Token A and leaves the LP account frozen
+    let deposit_amount = 10_000u64;
+    let deposit_ix = create_deposit_instruction_standardized(
+        &foundation.user1.pubkey(),
+        &user_token_a,
+        &user1_lp_a.pubkey(),
+        &config,
+        &lp_token_a_mint,
+        &lp_token_b_mint,
+        &PoolInstruction::Deposit { deposit_token_mint: config.token_a_mint, amount: deposit_amount, pool_id: config.pool_state_pda },
+    ).unwrap();
+    let mut tx = Transaction::new_with_payer(&[deposit_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user1_lp_a.pubkey()).await, deposit_amount);
+    let lp_account = foundation.env.banks_client.get_account(user1_lp_a.pubkey()).await?.unwrap();
+    assert_eq!(lp_account_state(&lp_account.data), AccountState::Frozen, "LP account must be frozen after deposit");
+
+    // Step 3: A direct LP transfer to another wallet fails
+    let transfer_ix = spl_token::instruction::transfer(
+        &spl_token::id(),
+        &user1_lp_a.pubkey(),
+        &user2_lp_a.pubkey(),
+        &foundation.user1.pubkey(),
+        &[],
+        1_000,
+    ).unwrap();
+    let mut tx = Transaction::new_with_payer(&[transfer_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    assert!(foundation.env.banks_client.process_transaction(tx).await.is_err(), "Direct LP transfer must fail");
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user1_lp_a.pubkey()).await, deposit_amount);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user2_lp_a.pubkey()).await, 0);
+
+    // Step 4: Withdrawal still burns LP and returns the underlying; the account is refrozen
+    let token_a_before = get_token_balance(&mut foundation.env.banks_client, &user_token_a).await;
+    let withdraw_amount = 4_000u64;
+    let withdraw_ix = create_withdrawal_instruction_standardized(
+        &foundation.user1.pubkey(),
+        &user1_lp_a.pubkey(),
+        &user_token_a,
+        &config,
+        &lp_token_a_mint,
+        &lp_token_b_mint,
+        &PoolInstruction::Withdraw { withdraw_token_mint: config.token_a_mint, lp_amount_to_burn: withdraw_amount, pool_id: config.pool_state_pda },
+    ).unwrap();
+    let mut tx = Transaction::new_with_payer(&[withdraw_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user1_lp_a.pubkey()).await, deposit_amount - withdraw_amount);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_a).await, token_a_before + withdraw_amount);
+    let lp_account = foundation.env.banks_client.get_account(user1_lp_a.pubkey()).await?.unwrap();
+    assert_eq!(lp_account_state(&lp_account.data), AccountState::Frozen, "LP account must be refrozen after withdrawal");
+
+    println!("✅ Frozen-LP pool: deposit and withdrawal work, direct LP transfer rejected");
+    Ok(())
+}
+
+/// Withdrawals may take a vault down to the pool's minimum reserve but not below it
+#[tokio::test]
+#[serial]
+async fn test_withdraw_respects_minimum_reserve() -> TestResult {
+    use fixed_ratio_trading::{constants::SYSTEM_STATE_SEED_PREFIX, error::PoolError};
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        transaction::TransactionError,
+    };
+    use common::liquidity_helpers::execute_withdrawal_operation;
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let user1_pubkey = foundation.user1.pubkey();
+    let user1_primary = foundation.user1_primary_account.pubkey();
+    let user1_lp_a = foundation.user1_lp_a_account.pubkey();
+    let token_a_mint = foundation.pool_config.token_a_mint;
+    let deposit_amount = 100_000u64;
+    let minimum_reserve = 40_000u64;
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_primary, &user1_lp_a, &token_a_mint, deposit_amount).await?;
+
+    // Admin sets the minimum reserve
+    let program_id = fixed_ratio_trading::id();
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &program_id);
+    let set_minimum_reserve_ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(foundation.system_authority.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(pool_state_pda, false),
+            AccountMeta::new_readonly(common::setup::get_test_program_data_address(&program_id), false),
+        ],
+        data: PoolInstruction::SetMinimumReserve {
+            minimum_reserve,
+            pool_id: pool_state_pda,
+        }.try_to_vec()?,
+    };
+    let mut tx = Transaction::new_with_payer(&[set_minimum_reserve_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.system_authority], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &pool_state_pda).await
+        .expect("Pool state should exist");
+    assert_eq!(pool_state.minimum_reserve, minimum_reserve);
+
+    // Leaves 50,000 above the 40,000 minimum
+    execute_withdrawal_operation(&mut foundation, &user1_pubkey, &user1_lp_a, &user1_primary, &token_a_mint, 50_000).await?;
+
+    // Would leave 39,999: one below the minimum
+    let result = execute_withdrawal_operation(&mut foundation, &user1_pubkey, &user1_lp_a, &user1_primary, &token_a_mint, 10_001).await;
+    let expected_code = PoolError::BelowMinimumReserve { minimum_reserve: 0, remaining: 0 }.error_code();
+    assert_eq!(
+        result.expect_err("Withdrawal below the minimum reserve must fail").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(expected_code)),
+    );
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &pool_state_pda).await.unwrap();
+    assert_eq!(pool_state.total_token_a_liquidity, 50_000, "Rejected withdrawal must not move liquidity");
+
+    // Leaves exactly the minimum
+    execute_withdrawal_operation(&mut foundation, &user1_pubkey, &user1_lp_a, &user1_primary, &token_a_mint, 10_000).await?;
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &pool_state_pda).await.unwrap();
+    assert_eq!(pool_state.total_token_a_liquidity, minimum_reserve);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user1_lp_a).await, minimum_reserve);
+
+    println!("✅ Minimum reserve of {} enforced on withdrawals", minimum_reserve);
+    Ok(())
+}
+
+/// DepositWithMinimumLp mints when the floor is met and reverts with SlippageExceeded
+/// when the floor is above what the deposit would mint
+#[tokio::test]
+#[serial]
+async fn test_deposit_minimum_lp_out_enforces_slippage_floor() -> TestResult {
+    use common::liquidity_helpers::create_deposit_instruction_standardized;
+    use fixed_ratio_trading::error::PoolError;
+
+    let mut foundation = create_liquidity_test_foundation(Some(1)).await?;
+    let config = foundation.pool_config.clone();
+    let user_token_a = if config.token_a_is_the_multiple {
+        foundation.user1_primary_account.pubkey()
+    } else {
+        foundation.user1_base_account.pubkey()
+    };
+    let user_lp_a = foundation.user1_lp_a_account.pubkey();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &foundation.user1_lp_a_account, &foundation.lp_token_a_mint_pda, &foundation.user1.pubkey()).await?;
+
+    let amount = 10_000u64;
+    let deposit_ix = |minimum_lp_out: u64| create_deposit_instruction_standardized(
+        &foundation.user1.pubkey(),
+        &user_token_a,
+        &user_lp_a,
+        &config,
+        &foundation.lp_token_a_mint_pda,
+        &foundation.lp_token_b_mint_pda,
+        &PoolInstruction::DepositWithMinimumLp { deposit_token_mint: config.token_a_mint, amount, pool_id: config.pool_state_pda, minimum_lp_out },
+    ).unwrap();
+    let slipped_ix = deposit_ix(amount + 1);
+    let exact_ix = deposit_ix(amount);
+
+    // Step 1: A floor one above the 1:1 mint is rejected and nothing is minted
+    let mut tx = Transaction::new_with_payer(&[slipped_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    let error = foundation.env.banks_client.process_transaction(tx).await.expect_err("Deposit below minimum_lp_out must fail");
+    let expected_code = PoolError::SlippageExceeded { minimum_lp_out: 0, lp_minted: 0 }.error_code();
+    assert!(
+        matches!(error.unwrap(), solana_sdk::transaction::TransactionError::InstructionError(0, solana_sdk::instruction::InstructionError::Custom(code)) if code == expected_code),
+        "Expected SlippageExceeded ({})", expected_code
+    );
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_a).await, 0, "Reverted deposit must not mint LP");
+
+    // Step 2: A floor equal to the mint succeeds
+    let mut tx = Transaction::new_with_payer(&[exact_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_lp_a).await, amount);
+
+    println!("✅ minimum_lp_out rejected a {} floor and accepted {}", amount + 1, amount);
+    Ok(())
+}
+
+/// GetDepositFee reports the same fees a real deposit is charged: nothing in token units
+/// (LP is minted 1:1) and the pool's flat liquidity fee in SOL
+#[tokio::test]
+#[serial]
+async fn test_get_deposit_fee_matches_charged_fee() -> TestResult {
+    use common::{liquidity_helpers::create_deposit_instruction_standardized, setup::simulate_and_get_return_data};
+    use fixed_ratio_trading::state::PoolState;
+    use solana_sdk::instruction::{AccountMeta, Instruction};
+
+    let mut foundation = create_liquidity_test_foundation(Some(1)).await?;
+    let config = foundation.pool_config.clone();
+    let user_token_a = if config.token_a_is_the_multiple {
+        foundation.user1_primary_account.pubkey()
+    } else {
+        foundation.user1_base_account.pubkey()
+    };
+    let user_lp_a = foundation.user1_lp_a_account.pubkey();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &foundation.user1_lp_a_account, &foundation.lp_token_a_mint_pda, &foundation.user1.pubkey()).await?;
+
+    // Step 1: Ask the view what a deposit would cost
+    let amount = 7_531u64;
+    let view_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![AccountMeta::new_readonly(config.pool_state_pda, false)],
+        data: PoolInstruction::GetDepositFee { deposit_token_mint: config.token_a_mint, amount, pool_id: config.pool_state_pda }.try_to_vec()?,
+    };
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, view_ix).await?;
+    assert_eq!(return_data.len(), 16);
+    let token_fee = u64::from_le_bytes(return_data[0..8].try_into()?);
+    let sol_fee = u64::from_le_bytes(return_data[8..16].try_into()?);
+
+    // Step 2: Make the deposit and measure what was actually charged
+    let pool_before = foundation.env.banks_client.get_account(config.pool_state_pda).await?.unwrap();
+    let fees_before = PoolState::try_from_slice(&pool_before.data)?.collected_liquidity_fees;
+    let token_a_before = get_token_balance(&mut foundation.env.banks_client, &user_token_a).await;
+    let deposit_ix = create_deposit_instruction_standardized(
+        &foundation.user1.pubkey(),
+        &user_token_a,
+        &user_lp_a,
+        &config,
+        &foundation.lp_token_a_mint_pda,
+        &foundation.lp_token_b_mint_pda,
+        &PoolInstruction::Deposit { deposit_token_mint: config.token_a_mint, amount, pool_id: config.pool_state_pda },
+    ).unwrap();
+    let mut tx = Transaction::new_with_payer(&[deposit_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user1], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    let pool_after = foundation.env.banks_client.get_account(config.pool_state_pda).await?.unwrap();
+    let fees_after = PoolState::try_from_slice(&pool_after.data)?.collected_liquidity_fees;
+    let tokens_spent = token_a_before - get_token_balance(&mut foundation.env.banks_client, &user_token_a).await;
+    let lp_minted = get_token_balance(&mut foundation.env.banks_client, &user_lp_a).await;
+
+    assert!(sol_fee > 0, "Pool must charge a liquidity fee");
+    assert_eq!(pool_after.lamports - pool_before.lamports, sol_fee, "SOL fee must match the lamports collected");
+    assert_eq!(fees_after - fees_before, sol_fee, "SOL fee must match collected_liquidity_fees");
+    assert_eq!(tokens_spent - lp_minted, token_fee, "Token fee must match the tokens kept back");
+    assert_eq!(token_fee, 0);
+
+    println!("✅ GetDepositFee reported {} tokens + {} lamports, matching the deposit", token_fee, sol_fee);
+    Ok(())
+}
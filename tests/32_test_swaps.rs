@@ -2351,6 +2351,7 @@ async fn execute_real_swap_with_verification(
     println!("   • Total SOL fees collected: {} lamports", final_pool_state.total_sol_fees_collected);
     println!("   • Total fees consolidated: {} lamports", final_pool_state.total_fees_consolidated);
     println!("   • Pending SOL fees: {} lamports", final_pool_state.pending_sol_fees());
+    assert_ne!(final_pool_state.last_swap_at, 0, "Swap must record its timestamp for GetPoolActivity");
     
     // **STEP 4: Comprehensive verification**
     println!("🔍 VERIFICATION RESULTS:");
@@ -3403,8 +3404,8 @@ async fn test_dashboard_basis_points_math_fixed_token_a_first() -> TestResult {
     println!("   • Token B mint: {} (TS)", pool_state.token_b_mint);
     
     // Verify ratios match dashboard (1000 MST basis points : 10000 TS basis points)
-    assert_eq!(pool_state.ratio_a_numerator, expected_mst_basis_points, "MST ratio mismatch!");
-    assert_eq!(pool_state.ratio_b_denominator, expected_ts_basis_points, "TS ratio mismatch!");
+    assert_eq!(pool_state.original_ratio_a_numerator, expected_mst_basis_points, "MST ratio mismatch!");
+    assert_eq!(pool_state.original_ratio_b_denominator, expected_ts_basis_points, "TS ratio mismatch!");
     
     println!("   ✅ Pool configuration matches dashboard exactly");
 
@@ -3716,8 +3717,8 @@ async fn test_dashboard_basis_points_math_fixed_token_b_first() -> TestResult {
     println!("   • Token B mint: {} (TS)", pool_state.token_b_mint);
     
     // Verify ratios match dashboard (1000 MST basis points : 10000 TS basis points)
-    assert_eq!(pool_state.ratio_a_numerator, expected_mst_basis_points, "MST ratio mismatch!");
-    assert_eq!(pool_state.ratio_b_denominator, expected_ts_basis_points, "TS ratio mismatch!");
+    assert_eq!(pool_state.original_ratio_a_numerator, expected_mst_basis_points, "MST ratio mismatch!");
+    assert_eq!(pool_state.original_ratio_b_denominator, expected_ts_basis_points, "TS ratio mismatch!");
     
     println!("   ✅ Pool configuration matches dashboard exactly");
 
@@ -4055,8 +4056,8 @@ async fn test_fractional_swap_result() -> TestResult {
     println!("   • Token B mint: {} (TS)", pool_state.token_b_mint);
     
     // Verify ratios match dashboard (1000 MST basis points : 10000 TS basis points)
-    assert_eq!(pool_state.ratio_a_numerator, expected_mst_basis_points, "MST ratio mismatch!");
-    assert_eq!(pool_state.ratio_b_denominator, expected_ts_basis_points, "TS ratio mismatch!");
+    assert_eq!(pool_state.original_ratio_a_numerator, expected_mst_basis_points, "MST ratio mismatch!");
+    assert_eq!(pool_state.original_ratio_b_denominator, expected_ts_basis_points, "TS ratio mismatch!");
     
     println!("   ✅ Pool configuration matches dashboard exactly");
 
@@ -4265,3 +4266,1213 @@ async fn test_fractional_swap_result() -> TestResult {
     // Always return Ok to see the analysis above
     Ok(())
 }
+
+/// Builds a user2 Token A → Token B swap instruction with the given deadline
+fn build_user2_a_to_b_swap(
+    foundation: &LiquidityTestFoundation,
+    amount_in: u64,
+    deadline: Option<i64>,
+) -> Instruction {
+    build_user2_a_to_b_swap_with_expiry(foundation, amount_in, deadline, None)
+}
+
+/// Builds a user2 Token A → Token B swap instruction with the given deadline and last valid slot
+fn build_user2_a_to_b_swap_with_expiry(
+    foundation: &LiquidityTestFoundation,
+    amount_in: u64,
+    deadline: Option<i64>,
+    not_valid_after_slot: Option<u64>,
+) -> Instruction {
+    let config = &foundation.pool_config;
+    let expected_amount_out = amount_in * config.ratio_b_denominator / config.ratio_a_numerator;
+    let swap_instruction_data = if deadline.is_some() || not_valid_after_slot.is_some() {
+        PoolInstruction::SwapWithExpiry {
+            input_token_mint: config.token_a_mint,
+            amount_in,
+            expected_amount_out,
+            pool_id: config.pool_state_pda,
+            deadline,
+            not_valid_after_slot,
+        }
+    } else {
+        PoolInstruction::Swap {
+            input_token_mint: config.token_a_mint,
+            amount_in,
+            expected_amount_out,
+            pool_id: config.pool_state_pda,
+        }
+    };
+    let (input_account, output_account) = if config.token_a_is_the_multiple {
+        (foundation.user2_primary_account.pubkey(), foundation.user2_base_account.pubkey())
+    } else {
+        (foundation.user2_base_account.pubkey(), foundation.user2_primary_account.pubkey())
+    };
+    common::liquidity_helpers::create_swap_instruction_standardized(
+        &foundation.user2.pubkey(),
+        &input_account,
+        &output_account,
+        config,
+        &swap_instruction_data,
+    ).expect("Failed to create swap instruction")
+}
+
+/// Builds and submits a user2 Token A → Token B swap with the given deadline
+async fn submit_swap_with_deadline(
+    foundation: &mut LiquidityTestFoundation,
+    amount_in: u64,
+    deadline: Option<i64>,
+) -> Result<(), BanksClientError> {
+    let swap_ix = build_user2_a_to_b_swap(foundation, amount_in, deadline);
+    // A fresh blockhash keeps repeated identical swaps from being deduplicated as already processed
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let blockhash = foundation.env.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    let mut swap_tx = Transaction::new_with_payer(&[swap_ix], Some(&foundation.user2.pubkey()));
+    swap_tx.sign(&[&foundation.user2], blockhash);
+    foundation.env.banks_client.process_transaction(swap_tx).await
+}
+
+/// Swaps carrying a deadline earlier than the on-chain clock must be rejected
+#[tokio::test]
+#[serial]
+async fn test_swap_rejected_after_deadline() -> TestResult {
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+
+    // Provide Token B liquidity so the A → B swap can be filled
+    let token_b_mint = foundation.pool_config.token_b_mint;
+    let user1_pubkey = foundation.user1.pubkey();
+    let (user1_token_b, user1_lp_b) = if foundation.pool_config.token_a_is_the_multiple {
+        (foundation.user1_base_account.pubkey(), foundation.user1_lp_b_account.pubkey())
+    } else {
+        (foundation.user1_primary_account.pubkey(), foundation.user1_lp_b_account.pubkey())
+    };
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &token_b_mint, 1_000_000).await?;
+
+    let now = foundation.env.banks_client
+        .get_sysvar::<solana_sdk::clock::Clock>().await?
+        .unix_timestamp;
+
+    // Deadline already in the past
+    let result = submit_swap_with_deadline(&mut foundation, 1_000, Some(now - 1)).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        solana_sdk::transaction::TransactionError::InstructionError(
+            0,
+            solana_sdk::instruction::InstructionError::Custom(1054),
+        ),
+        "Swap past its deadline must fail with SwapDeadlineExpired"
+    );
+
+    // Same swap with a future deadline goes through
+    submit_swap_with_deadline(&mut foundation, 1_000, Some(now + 3_600)).await?;
+
+    println!("✅ Swap deadline enforced: expired deadline rejected, future deadline accepted");
+    Ok(())
+}
+
+/// Swaps carrying a `not_valid_after_slot` earlier than the current slot must be rejected
+#[tokio::test]
+#[serial]
+async fn test_swap_rejected_after_slot_expiry() -> TestResult {
+    use fixed_ratio_trading::error::PoolError;
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+
+    // Provide Token B liquidity so the A → B swap can be filled
+    let token_b_mint = foundation.pool_config.token_b_mint;
+    let user1_pubkey = foundation.user1.pubkey();
+    let (user1_token_b, user1_lp_b) = if foundation.pool_config.token_a_is_the_multiple {
+        (foundation.user1_base_account.pubkey(), foundation.user1_lp_b_account.pubkey())
+    } else {
+        (foundation.user1_primary_account.pubkey(), foundation.user1_lp_b_account.pubkey())
+    };
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &token_b_mint, 1_000_000).await?;
+
+    let current_slot = foundation.env.banks_client
+        .get_sysvar::<solana_sdk::clock::Clock>().await?
+        .slot;
+    assert!(current_slot > 0, "Test needs a slot in the past to expire against");
+
+    let submit = |not_valid_after_slot: u64| build_user2_a_to_b_swap_with_expiry(&foundation, 1_000, None, Some(not_valid_after_slot));
+    let expired_ix = submit(current_slot - 1);
+    let valid_ix = submit(current_slot + 1_000);
+
+    // Last valid slot already passed
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let mut expired_tx = Transaction::new_with_payer(&[expired_ix], Some(&foundation.user2.pubkey()));
+    expired_tx.sign(&[&foundation.user2], blockhash);
+    let result = foundation.env.banks_client.process_transaction(expired_tx).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        solana_sdk::transaction::TransactionError::InstructionError(
+            0,
+            solana_sdk::instruction::InstructionError::Custom(
+                PoolError::SwapSlotExpired { not_valid_after_slot: 0, current_slot: 0 }.error_code()
+            ),
+        ),
+        "Swap past its last valid slot must fail with SwapSlotExpired"
+    );
+
+    // Same swap with a future slot goes through
+    let mut valid_tx = Transaction::new_with_payer(&[valid_ix], Some(&foundation.user2.pubkey()));
+    valid_tx.sign(&[&foundation.user2], blockhash);
+    foundation.env.banks_client.process_transaction(valid_tx).await?;
+
+    println!("✅ Swap slot expiry enforced: past slot rejected, future slot accepted");
+    Ok(())
+}
+
+/// A protocol swap SOL fee set in SystemState is paid by the swapper into the treasury on every swap
+#[tokio::test]
+#[serial]
+async fn test_swap_sol_fee_paid_to_treasury() -> TestResult {
+    use fixed_ratio_trading::constants::{MAIN_TREASURY_SEED_PREFIX, SYSTEM_STATE_SEED_PREFIX};
+    use solana_sdk::{instruction::{AccountMeta, InstructionError}, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let program_id = PROGRAM_ID;
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &program_id);
+    let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &program_id);
+
+    // Provide Token B liquidity so the A → B swaps can be filled
+    let token_b_mint = foundation.pool_config.token_b_mint;
+    let user1_pubkey = foundation.user1.pubkey();
+    let user1_token_b = if foundation.pool_config.token_a_is_the_multiple {
+        foundation.user1_base_account.pubkey()
+    } else {
+        foundation.user1_primary_account.pubkey()
+    };
+    let user1_lp_b = foundation.user1_lp_b_account.pubkey();
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &token_b_mint, 1_000_000).await?;
+
+    // Admin sets the protocol swap SOL fee
+    let swap_sol_fee = 50_000u64;
+    let set_fee_ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(foundation.system_authority.pubkey(), true),
+            AccountMeta::new(system_state_pda, false),
+            AccountMeta::new_readonly(get_test_program_data_address(&program_id), false),
+        ],
+        data: PoolInstruction::SetSwapSolFee { swap_sol_fee }.try_to_vec()?,
+    };
+    let mut tx = Transaction::new_with_payer(&[set_fee_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.system_authority], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    let system_account = foundation.env.banks_client.get_account(system_state_pda).await?.unwrap();
+    assert_eq!(SystemState::from_account_data_unchecked(&system_account.data)?.swap_sol_fee, swap_sol_fee);
+
+    // Without the treasury account the swap cannot pay the fee
+    let swap_ix = build_user2_a_to_b_swap(&foundation, 1_000, None);
+    let mut tx = Transaction::new_with_payer(&[swap_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.user2], foundation.env.banks_client.get_latest_blockhash().await?);
+    let result = foundation.env.banks_client.process_transaction(tx).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys),
+        "Swap without the treasury account must fail while a swap SOL fee is set"
+    );
+
+    // Each swap moves exactly the fee from the swapper into the treasury
+    let swap_contract_fee = get_pool_state(&mut foundation.env.banks_client, &foundation.pool_config.pool_state_pda).await
+        .expect("Pool state should exist")
+        .swap_contract_fee;
+    for amount_in in [1_000u64, 2_000u64] {
+        let treasury_before = foundation.env.banks_client.get_balance(main_treasury_pda).await?;
+        let swapper_before = foundation.env.banks_client.get_balance(foundation.user2.pubkey()).await?;
+
+        let mut swap_ix = build_user2_a_to_b_swap(&foundation, amount_in, None);
+        swap_ix.accounts.push(AccountMeta::new(main_treasury_pda, false));
+        let mut tx = Transaction::new_with_payer(&[swap_ix], Some(&foundation.env.payer.pubkey()));
+        tx.sign(&[&foundation.env.payer, &foundation.user2], foundation.env.banks_client.get_latest_blockhash().await?);
+        foundation.env.banks_client.process_transaction(tx).await?;
+
+        let treasury_after = foundation.env.banks_client.get_balance(main_treasury_pda).await?;
+        let swapper_after = foundation.env.banks_client.get_balance(foundation.user2.pubkey()).await?;
+        assert_eq!(treasury_after - treasury_before, swap_sol_fee, "Treasury must receive the swap SOL fee");
+        assert_eq!(swapper_before - swapper_after, swap_contract_fee + swap_sol_fee, "Swapper pays the pool fee plus the swap SOL fee");
+    }
+
+    println!("✅ Swap SOL fee of {} lamports paid to the treasury on every swap", swap_sol_fee);
+    Ok(())
+}
+
+/// Creates the provider's token and LP accounts for one side of a pool and deposits into it
+async fn deposit_route_liquidity(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    provider: &Keypair,
+    config: &PoolConfig,
+    deposit_mint: &Pubkey,
+    amount: u64,
+) -> TestResult {
+    use fixed_ratio_trading::constants::{LP_TOKEN_A_MINT_SEED_PREFIX, LP_TOKEN_B_MINT_SEED_PREFIX};
+
+    let (lp_token_a_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_A_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()], &PROGRAM_ID);
+    let (lp_token_b_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_B_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()], &PROGRAM_ID);
+    let lp_mint = if *deposit_mint == config.token_a_mint { lp_token_a_mint } else { lp_token_b_mint };
+
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let provider_token = Keypair::new();
+    let provider_lp = Keypair::new();
+    create_token_account(&mut context.banks_client, payer, blockhash, &provider_token, deposit_mint, &provider.pubkey()).await?;
+    create_token_account(&mut context.banks_client, payer, blockhash, &provider_lp, &lp_mint, &provider.pubkey()).await?;
+    mint_tokens(&mut context.banks_client, payer, blockhash, deposit_mint, &provider_token.pubkey(), payer, amount).await?;
+
+    let deposit_ix = common::liquidity_helpers::create_deposit_instruction_standardized(
+        &provider.pubkey(),
+        &provider_token.pubkey(),
+        &provider_lp.pubkey(),
+        config,
+        &lp_token_a_mint,
+        &lp_token_b_mint,
+        &PoolInstruction::Deposit {
+            deposit_token_mint: *deposit_mint,
+            amount,
+            pool_id: config.pool_state_pda,
+        },
+    )?;
+    let mut tx = Transaction::new_with_payer(&[deposit_ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, provider], blockhash);
+    context.banks_client.process_transaction(tx).await?;
+    Ok(())
+}
+
+/// Builds a SwapRoute instruction; each leg is (pool, input mint, output mint, user input account, user output account)
+fn build_swap_route_instruction(
+    user: &Pubkey,
+    legs: &[(&PoolConfig, Pubkey, Pubkey, Pubkey, Pubkey)],
+    amount_in: u64,
+    minimum_final_out: u64,
+) -> Instruction {
+    use fixed_ratio_trading::{
+        constants::{MAIN_TREASURY_SEED_PREFIX, SYSTEM_STATE_SEED_PREFIX},
+        RouteHop,
+    };
+    use solana_sdk::instruction::AccountMeta;
+
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+    let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &PROGRAM_ID);
+    let mut accounts = vec![
+        AccountMeta::new(*user, true),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(system_state_pda, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new(main_treasury_pda, false),
+    ];
+    let mut hops = Vec::with_capacity(legs.len());
+    for (config, input_mint, output_mint, input_account, output_account) in legs {
+        accounts.extend([
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new(config.token_a_vault_pda, false),
+            AccountMeta::new(config.token_b_vault_pda, false),
+            AccountMeta::new(*input_account, false),
+            AccountMeta::new(*output_account, false),
+            AccountMeta::new_readonly(*input_mint, false),
+            AccountMeta::new_readonly(*output_mint, false),
+        ]);
+        hops.push(RouteHop {
+            pool_id: config.pool_state_pda,
+            input_token_mint: *input_mint,
+            token_a_vault: config.token_a_vault_pda,
+            token_b_vault: config.token_b_vault_pda,
+        });
+    }
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: PoolInstruction::SwapRoute { hops, amount_in, minimum_final_out }.try_to_vec().unwrap(),
+    }
+}
+
+/// SwapRoute chains X → Y → Z through two pools sharing Y, rolls back fully when a
+/// middle hop cannot be filled, and enforces the minimum only on the final output
+#[tokio::test]
+#[serial]
+async fn test_swap_route_two_hops() -> TestResult {
+    use fixed_ratio_trading::{
+        constants::{MAIN_TREASURY_SEED_PREFIX, SYSTEM_STATE_SEED_PREFIX},
+        error::PoolError,
+        PoolState,
+    };
+    use solana_sdk::{
+        compute_budget::ComputeBudgetInstruction,
+        instruction::InstructionError,
+        transaction::TransactionError,
+    };
+
+    let mut context = create_program_test().start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let blockhash = context.last_blockhash;
+
+    // Pools X/Y and Z/Y share mint Y but there is no X/Z pool
+    let mint_x = Keypair::new();
+    let mint_y = Keypair::new();
+    let mint_z = Keypair::new();
+    for mint in [&mint_x, &mint_y, &mint_z] {
+        create_mint(&mut context.banks_client, &payer, blockhash, mint, None).await?;
+    }
+    initialize_treasury_system(&mut context.banks_client, &payer, blockhash, &Keypair::new()).await?;
+    let pool_xy = create_pool_new_pattern(&mut context.banks_client, &payer, blockhash, &mint_x, &mint_y, Some(2)).await?;
+    let pool_zy = create_pool_new_pattern(&mut context.banks_client, &payer, blockhash, &mint_z, &mint_y, Some(3)).await?;
+
+    // Only the first hop has output liquidity so far
+    let provider = Keypair::new();
+    transfer_sol(&mut context.banks_client, &payer, blockhash, &payer, &provider.pubkey(), 1_000_000_000).await?;
+    deposit_route_liquidity(&mut context, &payer, &provider, &pool_xy, &mint_y.pubkey(), 1_000_000).await?;
+
+    let user = Keypair::new();
+    let user_x = Keypair::new();
+    let user_y = Keypair::new();
+    let user_z = Keypair::new();
+    transfer_sol(&mut context.banks_client, &payer, blockhash, &payer, &user.pubkey(), 1_000_000_000).await?;
+    for (account, mint) in [(&user_x, &mint_x), (&user_y, &mint_y), (&user_z, &mint_z)] {
+        create_token_account(&mut context.banks_client, &payer, blockhash, account, &mint.pubkey(), &user.pubkey()).await?;
+    }
+    let amount_in = 10_000u64;
+    mint_tokens(&mut context.banks_client, &payer, blockhash, &mint_x.pubkey(), &user_x.pubkey(), &payer, amount_in).await?;
+
+    // Expected outputs from the stored pool ratios
+    let quote = |pool: &PoolState, input_mint: &Pubkey, amount: u64| -> u64 {
+        if *input_mint == pool.token_a_mint {
+            amount * pool.ratio_b_denominator / pool.ratio_a_numerator
+        } else {
+            amount * pool.ratio_a_numerator / pool.ratio_b_denominator
+        }
+    };
+    let pool_xy_state = get_pool_state(&mut context.banks_client, &pool_xy.pool_state_pda).await.expect("Pool X/Y should exist");
+    let pool_zy_state = get_pool_state(&mut context.banks_client, &pool_zy.pool_state_pda).await.expect("Pool Z/Y should exist");
+    let expected_y = quote(&pool_xy_state, &mint_x.pubkey(), amount_in);
+    let expected_z = quote(&pool_zy_state, &mint_y.pubkey(), expected_y);
+    assert!(expected_y > 0 && expected_z > 0, "Route amounts must be non-zero");
+
+    let build_route_ix = |minimum_final_out: u64| -> Instruction {
+        build_swap_route_instruction(
+            &user.pubkey(),
+            &[
+                (&pool_xy, mint_x.pubkey(), mint_y.pubkey(), user_x.pubkey(), user_y.pubkey()),
+                (&pool_zy, mint_y.pubkey(), mint_z.pubkey(), user_y.pubkey(), user_z.pubkey()),
+            ],
+            amount_in,
+            minimum_final_out,
+        )
+    };
+    let send_route = |route_ix: Instruction, blockhash: solana_sdk::hash::Hash| {
+        let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_000_000);
+        let mut tx = Transaction::new_with_payer(&[compute_budget_ix, route_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &user], blockhash);
+        tx
+    };
+
+    // Step 1: The second pool has no Z liquidity, so the whole route must roll back
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let result = context.banks_client.process_transaction(send_route(build_route_ix(0), blockhash)).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(1, InstructionError::InsufficientFunds),
+        "Route must fail when a later hop lacks liquidity"
+    );
+    assert_eq!(get_token_balance(&mut context.banks_client, &user_x.pubkey()).await, amount_in, "First hop input must be restored");
+    assert_eq!(get_token_balance(&mut context.banks_client, &user_y.pubkey()).await, 0, "First hop output must be reverted");
+    let pool_xy_after = get_pool_state(&mut context.banks_client, &pool_xy.pool_state_pda).await.unwrap();
+    assert_eq!(pool_xy_after.total_token_a_liquidity, pool_xy_state.total_token_a_liquidity);
+    assert_eq!(pool_xy_after.total_token_b_liquidity, pool_xy_state.total_token_b_liquidity);
+    println!("✅ Route rolled back when the middle pool could not fill the second hop");
+
+    // Step 2: Fund the second hop; a minimum above the final output still fails
+    deposit_route_liquidity(&mut context, &payer, &provider, &pool_zy, &mint_z.pubkey(), 1_000_000).await?;
+    let blockhash = context.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    let result = context.banks_client.process_transaction(send_route(build_route_ix(expected_z + 1), blockhash)).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(1, InstructionError::Custom(
+            PoolError::RouteOutputBelowMinimum { minimum_final_out: 0, final_amount_out: 0 }.error_code()
+        )),
+        "Route must fail when the final output is below the minimum"
+    );
+
+    // Step 3: The route succeeds and each pool collects its own swap fee
+    let pool_xy_fees_before = pool_xy_state.collected_swap_contract_fees;
+    let pool_zy_fees_before = pool_zy_state.collected_swap_contract_fees;
+    let blockhash = context.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    context.banks_client.process_transaction(send_route(build_route_ix(expected_z), blockhash)).await?;
+
+    assert_eq!(get_token_balance(&mut context.banks_client, &user_x.pubkey()).await, 0);
+    assert_eq!(get_token_balance(&mut context.banks_client, &user_y.pubkey()).await, 0, "Intermediate token must be fully forwarded");
+    assert_eq!(get_token_balance(&mut context.banks_client, &user_z.pubkey()).await, expected_z);
+
+    let pool_xy_after = get_pool_state(&mut context.banks_client, &pool_xy.pool_state_pda).await.unwrap();
+    let pool_zy_after = get_pool_state(&mut context.banks_client, &pool_zy.pool_state_pda).await.unwrap();
+    assert_eq!(pool_xy_after.collected_swap_contract_fees - pool_xy_fees_before, pool_xy_state.swap_contract_fee);
+    assert_eq!(pool_zy_after.collected_swap_contract_fees - pool_zy_fees_before, pool_zy_state.swap_contract_fee);
+
+    println!("✅ Swap route X → Y → Z: {} → {} → {}", amount_in, expected_y, expected_z);
+    Ok(())
+}
+
+/// Two 2:1 pools sharing a middle mint compose to a 4:1 route rate, and routes longer
+/// than MAX_SWAP_ROUTE_HOPS are rejected with RouteTooLong
+#[tokio::test]
+#[serial]
+async fn test_swap_route_composed_rate() -> TestResult {
+    use fixed_ratio_trading::{constants::MAX_SWAP_ROUTE_HOPS, error::PoolError};
+    use solana_sdk::{
+        compute_budget::ComputeBudgetInstruction,
+        instruction::InstructionError,
+        transaction::TransactionError,
+    };
+
+    let mut context = create_program_test().start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let blockhash = context.last_blockhash;
+
+    let mint_x = Keypair::new();
+    let mint_y = Keypair::new();
+    let mint_z = Keypair::new();
+    for mint in [&mint_x, &mint_y, &mint_z] {
+        create_mint(&mut context.banks_client, &payer, blockhash, mint, None).await?;
+    }
+    initialize_treasury_system(&mut context.banks_client, &payer, blockhash, &Keypair::new()).await?;
+    // 2 X buy 1 Y, and 2 Y buy 1 Z
+    let pool_xy = create_pool_new_pattern(&mut context.banks_client, &payer, blockhash, &mint_x, &mint_y, Some(2)).await?;
+    let pool_yz = create_pool_new_pattern(&mut context.banks_client, &payer, blockhash, &mint_y, &mint_z, Some(2)).await?;
+
+    let provider = Keypair::new();
+    transfer_sol(&mut context.banks_client, &payer, blockhash, &payer, &provider.pubkey(), 1_000_000_000).await?;
+    deposit_route_liquidity(&mut context, &payer, &provider, &pool_xy, &mint_y.pubkey(), 1_000_000).await?;
+    deposit_route_liquidity(&mut context, &payer, &provider, &pool_yz, &mint_z.pubkey(), 1_000_000).await?;
+
+    let user = Keypair::new();
+    let user_x = Keypair::new();
+    let user_y = Keypair::new();
+    let user_z = Keypair::new();
+    transfer_sol(&mut context.banks_client, &payer, blockhash, &payer, &user.pubkey(), 1_000_000_000).await?;
+    for (account, mint) in [(&user_x, &mint_x), (&user_y, &mint_y), (&user_z, &mint_z)] {
+        create_token_account(&mut context.banks_client, &payer, blockhash, account, &mint.pubkey(), &user.pubkey()).await?;
+    }
+    let amount_in = 10_000u64;
+    mint_tokens(&mut context.banks_client, &payer, blockhash, &mint_x.pubkey(), &user_x.pubkey(), &payer, amount_in).await?;
+
+    let leg_xy = (&pool_xy, mint_x.pubkey(), mint_y.pubkey(), user_x.pubkey(), user_y.pubkey());
+    let leg_yz = (&pool_yz, mint_y.pubkey(), mint_z.pubkey(), user_y.pubkey(), user_z.pubkey());
+    let send_route = |route_ix: Instruction, blockhash: solana_sdk::hash::Hash| {
+        let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_000_000);
+        let mut tx = Transaction::new_with_payer(&[compute_budget_ix, route_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &user], blockhash);
+        tx
+    };
+
+    // Step 1: One hop more than the cap is rejected before any leg executes
+    let too_long: Vec<_> = [leg_xy, leg_yz].into_iter().cycle().take(MAX_SWAP_ROUTE_HOPS + 1).collect();
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let result = context.banks_client
+        .process_transaction(send_route(build_swap_route_instruction(&user.pubkey(), &too_long, amount_in, 0), blockhash))
+        .await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(1, InstructionError::Custom(
+            PoolError::RouteTooLong { hops: 0, max_hops: 0 }.error_code()
+        )),
+        "Routes longer than the hop cap must be rejected"
+    );
+
+    // Step 2: X → Y → Z at 2:1 then 2:1 yields a quarter of the input
+    let expected_z = amount_in / 4;
+    let blockhash = context.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    let route_ix = build_swap_route_instruction(&user.pubkey(), &[leg_xy, leg_yz], amount_in, expected_z);
+    context.banks_client.process_transaction(send_route(route_ix, blockhash)).await?;
+
+    assert_eq!(get_token_balance(&mut context.banks_client, &user_x.pubkey()).await, 0);
+    assert_eq!(get_token_balance(&mut context.banks_client, &user_y.pubkey()).await, 0, "Intermediate token must be fully forwarded");
+    assert_eq!(get_token_balance(&mut context.banks_client, &user_z.pubkey()).await, expected_z, "Route must apply the composed 4:1 rate");
+
+    println!("✅ Composed route rate: {} X → {} Z", amount_in, expected_z);
+    Ok(())
+}
+
+/// Swaps may take the output reserve down to its safety floor but not below it; a floor of 0 disables the check
+#[tokio::test]
+#[serial]
+async fn test_swap_respects_reserve_safety_floor() -> TestResult {
+    use fixed_ratio_trading::{constants::SYSTEM_STATE_SEED_PREFIX, error::PoolError};
+    use solana_sdk::{instruction::{AccountMeta, InstructionError}, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let config = foundation.pool_config.clone();
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+
+    // Provide Token B liquidity so the A → B swaps can be filled
+    let token_b_liquidity = 1_000_000u64;
+    let user1_pubkey = foundation.user1.pubkey();
+    let user1_token_b = if config.token_a_is_the_multiple {
+        foundation.user1_base_account.pubkey()
+    } else {
+        foundation.user1_primary_account.pubkey()
+    };
+    let user1_lp_b = foundation.user1_lp_b_account.pubkey();
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &config.token_b_mint, token_b_liquidity).await?;
+
+    // Each swap pays out `amount_out` Token B; the floor allows one swap but not two
+    let amount_in = 10_000u64;
+    let amount_out = amount_in * config.ratio_b_denominator / config.ratio_a_numerator;
+    let reserve_safety_floor_b = token_b_liquidity - amount_out - amount_out / 2;
+    let admin = foundation.system_authority.pubkey();
+    let set_floor = |reserve_safety_floor_b: u64| Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new_readonly(get_test_program_data_address(&PROGRAM_ID), false),
+        ],
+        data: PoolInstruction::SetReserveSafetyFloor {
+            reserve_safety_floor_a: 0,
+            reserve_safety_floor_b,
+            pool_id: config.pool_state_pda,
+        }.try_to_vec().unwrap(),
+    };
+    let mut tx = Transaction::new_with_payer(&[set_floor(reserve_safety_floor_b)], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.system_authority], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.expect("Pool state should exist");
+    assert_eq!(pool_state.reserve_safety_floor_b, reserve_safety_floor_b);
+
+    // Stays above the floor
+    submit_swap_with_deadline(&mut foundation, amount_in, None).await?;
+
+    // Would dip below the floor
+    let result = submit_swap_with_deadline(&mut foundation, amount_in, None).await;
+    assert_eq!(
+        result.expect_err("Swap below the reserve floor must fail").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(
+            PoolError::ReserveFloorBreached { floor: 0, remaining: 0 }.error_code()
+        )),
+    );
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.unwrap();
+    assert_eq!(pool_state.total_token_b_liquidity, token_b_liquidity - amount_out, "Rejected swap must not move liquidity");
+
+    // A floor of 0 disables the check
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let blockhash = foundation.env.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    let mut tx = Transaction::new_with_payer(&[set_floor(0)], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.system_authority], blockhash);
+    foundation.env.banks_client.process_transaction(tx).await?;
+    submit_swap_with_deadline(&mut foundation, amount_in, None).await?;
+
+    println!("✅ Reserve safety floor of {} Token B enforced on swaps", reserve_safety_floor_b);
+    Ok(())
+}
+
+/// Builds a user2 Token A → Token B SwapPartial instruction
+fn build_user2_a_to_b_swap_partial(
+    foundation: &LiquidityTestFoundation,
+    amount_in: u64,
+    minimum_amount_out: u64,
+    allow_partial: bool,
+) -> Instruction {
+    let config = &foundation.pool_config;
+    let mut swap_ix = build_user2_a_to_b_swap(foundation, amount_in, None);
+    swap_ix.data = PoolInstruction::SwapPartial {
+        input_token_mint: config.token_a_mint,
+        amount_in,
+        minimum_amount_out,
+        allow_partial,
+        pool_id: config.pool_state_pda,
+    }.try_to_vec().unwrap();
+    swap_ix
+}
+
+/// Simulates a SwapPartial for its `[amount_in_filled, amount_out_filled]`, then executes it
+async fn submit_swap_partial(
+    foundation: &mut LiquidityTestFoundation,
+    amount_in: u64,
+    minimum_amount_out: u64,
+    allow_partial: bool,
+) -> Result<(u64, u64), BanksClientError> {
+    let swap_ix = build_user2_a_to_b_swap_partial(foundation, amount_in, minimum_amount_out, allow_partial);
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let blockhash = foundation.env.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    let mut swap_tx = Transaction::new_with_payer(&[swap_ix], Some(&foundation.user2.pubkey()));
+    swap_tx.sign(&[&foundation.user2], blockhash);
+
+    let simulation = foundation.env.banks_client.simulate_transaction(swap_tx.clone()).await?;
+    let return_data = simulation.simulation_details.and_then(|details| details.return_data).map(|r| r.data).unwrap_or_default();
+    foundation.env.banks_client.process_transaction(swap_tx).await?;
+    assert_eq!(return_data.len(), 24, "SwapPartial must return three u64 values");
+    let filled_in = u64::from_le_bytes(return_data[0..8].try_into().unwrap());
+    let unfilled_in = u64::from_le_bytes(return_data[16..24].try_into().unwrap());
+    assert_eq!(filled_in + unfilled_in, amount_in, "Filled and unfilled input must add up to amount_in");
+    Ok((filled_in, u64::from_le_bytes(return_data[8..16].try_into().unwrap())))
+}
+
+/// Partial fills take what the pool can pay: a swap exactly at the liquidity boundary
+/// fills fully, a swap well over it fills up to the boundary and leaves the rest of the
+/// input with the user, and an empty pool still rejects the swap
+#[tokio::test]
+#[serial]
+async fn test_swap_partial_fill_at_and_over_liquidity() -> TestResult {
+    use fixed_ratio_trading::error::PoolError;
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let config = foundation.pool_config.clone();
+    let token_b_mint = config.token_b_mint;
+    let user1_pubkey = foundation.user1.pubkey();
+    let (user1_token_b, user1_lp_b) = if config.token_a_is_the_multiple {
+        (foundation.user1_base_account.pubkey(), foundation.user1_lp_b_account.pubkey())
+    } else {
+        (foundation.user1_primary_account.pubkey(), foundation.user1_lp_b_account.pubkey())
+    };
+    let user2_token_a = if config.token_a_is_the_multiple {
+        foundation.user2_primary_account.pubkey()
+    } else {
+        foundation.user2_base_account.pubkey()
+    };
+
+    // Exactly at the boundary: the whole Token B liquidity is paid out in a regular fill
+    let token_b_liquidity = 1_000u64;
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &token_b_mint, token_b_liquidity).await?;
+    let boundary_in = token_b_liquidity * config.ratio_a_numerator / config.ratio_b_denominator;
+    let (filled_in, filled_out) = submit_swap_partial(&mut foundation, boundary_in, token_b_liquidity, true).await?;
+    assert_eq!((filled_in, filled_out), (boundary_in, token_b_liquidity), "Swap at the boundary fills completely");
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.unwrap();
+    assert_eq!(pool_state.total_token_b_liquidity, 0);
+
+    // Empty pool: nothing to fill, even with partial fills allowed
+    let result = submit_swap_partial(&mut foundation, boundary_in, 0, true).await;
+    assert_eq!(
+        result.expect_err("Swap against an empty pool must fail").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InsufficientFunds),
+    );
+
+    // Well over the boundary: fills up to the liquidity, only the filled input leaves the user
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &token_b_mint, token_b_liquidity).await?;
+    let over_in = boundary_in * 3;
+
+    let result = submit_swap_partial(&mut foundation, over_in, 0, false).await;
+    assert_eq!(
+        result.expect_err("Oversized swap without partial fills must fail").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InsufficientFunds),
+    );
+    let result = submit_swap_partial(&mut foundation, over_in, token_b_liquidity + 1, true).await;
+    assert_eq!(
+        result.expect_err("Partial fill below the minimum output must fail").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(
+            PoolError::SwapOutputBelowMinimum { minimum_amount_out: 0, amount_out: 0 }.error_code()
+        )),
+    );
+
+    let input_before = get_token_balance(&mut foundation.env.banks_client, &user2_token_a).await;
+    let (filled_in, filled_out) = submit_swap_partial(&mut foundation, over_in, token_b_liquidity, true).await?;
+    let input_after = get_token_balance(&mut foundation.env.banks_client, &user2_token_a).await;
+    // Filled input rounds in the pool's favor: the largest input that still pays out only the liquidity
+    let max_fill_in = ((token_b_liquidity + 1) * config.ratio_a_numerator - 1) / config.ratio_b_denominator;
+    assert_eq!((filled_in, filled_out), (max_fill_in, token_b_liquidity), "Oversized swap fills up to the liquidity");
+    assert!(filled_in >= boundary_in && filled_in < over_in);
+    assert_eq!(input_before - input_after, filled_in, "Unfilled input must stay with the user");
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.unwrap();
+    assert_eq!(pool_state.total_token_b_liquidity, 0);
+
+    println!("✅ Partial fill: {} of {} input filled for {} out", filled_in, over_in, filled_out);
+    Ok(())
+}
+
+/// Builds a user2 SwapExactOut instruction in either direction
+fn build_user2_swap_exact_out(
+    foundation: &LiquidityTestFoundation,
+    a_to_b: bool,
+    amount_out: u64,
+    maximum_amount_in: u64,
+) -> Instruction {
+    let config = &foundation.pool_config;
+    let (user2_token_a, user2_token_b) = if config.token_a_is_the_multiple {
+        (foundation.user2_primary_account.pubkey(), foundation.user2_base_account.pubkey())
+    } else {
+        (foundation.user2_base_account.pubkey(), foundation.user2_primary_account.pubkey())
+    };
+    let (input_token_mint, output_token_mint, input_account, output_account) = if a_to_b {
+        (config.token_a_mint, config.token_b_mint, user2_token_a, user2_token_b)
+    } else {
+        (config.token_b_mint, config.token_a_mint, user2_token_b, user2_token_a)
+    };
+    let swap_instruction_data = PoolInstruction::Swap {
+        input_token_mint,
+        amount_in: maximum_amount_in,
+        expected_amount_out: amount_out,
+        pool_id: config.pool_state_pda,
+    };
+    let mut swap_ix = common::liquidity_helpers::create_swap_instruction_standardized(
+        &foundation.user2.pubkey(),
+        &input_account,
+        &output_account,
+        config,
+        &swap_instruction_data,
+    ).expect("Failed to create swap instruction");
+    swap_ix.data = PoolInstruction::SwapExactOut {
+        output_token_mint,
+        amount_out,
+        maximum_amount_in,
+        pool_id: config.pool_state_pda,
+    }.try_to_vec().unwrap();
+    swap_ix
+}
+
+/// Simulates a SwapExactOut for its executed `[amount_in, amount_out]`, then executes it
+async fn submit_swap_exact_out(
+    foundation: &mut LiquidityTestFoundation,
+    a_to_b: bool,
+    amount_out: u64,
+    maximum_amount_in: u64,
+) -> Result<(u64, u64), BanksClientError> {
+    let swap_ix = build_user2_swap_exact_out(foundation, a_to_b, amount_out, maximum_amount_in);
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let blockhash = foundation.env.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    let mut swap_tx = Transaction::new_with_payer(&[swap_ix], Some(&foundation.user2.pubkey()));
+    swap_tx.sign(&[&foundation.user2], blockhash);
+
+    let simulation = foundation.env.banks_client.simulate_transaction(swap_tx.clone()).await?;
+    let return_data = simulation.simulation_details.and_then(|details| details.return_data).map(|r| r.data).unwrap_or_default();
+    foundation.env.banks_client.process_transaction(swap_tx).await?;
+    assert_eq!(return_data.len(), 16, "SwapExactOut must return two u64 values");
+    Ok((
+        u64::from_le_bytes(return_data[0..8].try_into().unwrap()),
+        u64::from_le_bytes(return_data[8..16].try_into().unwrap()),
+    ))
+}
+
+/// Exact-output swaps derive the smallest input paying out at least the requested
+/// amount, rounding up when the ratio does not divide evenly, and reject inputs above
+/// the caller's maximum
+#[tokio::test]
+#[serial]
+async fn test_swap_exact_out_rounds_required_input_up() -> TestResult {
+    use fixed_ratio_trading::error::PoolError;
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(3)).await?;
+    let config = foundation.pool_config.clone();
+    let (a, b) = (config.ratio_a_numerator, config.ratio_b_denominator);
+    let user1_pubkey = foundation.user1.pubkey();
+    let (user1_token_a, user1_token_b) = if config.token_a_is_the_multiple {
+        (foundation.user1_primary_account.pubkey(), foundation.user1_base_account.pubkey())
+    } else {
+        (foundation.user1_base_account.pubkey(), foundation.user1_primary_account.pubkey())
+    };
+    let user2_token_a = if config.token_a_is_the_multiple {
+        foundation.user2_primary_account.pubkey()
+    } else {
+        foundation.user2_base_account.pubkey()
+    };
+    let (user1_lp_a, user1_lp_b) = (foundation.user1_lp_a_account.pubkey(), foundation.user1_lp_b_account.pubkey());
+    let (token_a_mint, token_b_mint) = (config.token_a_mint, config.token_b_mint);
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_a, &user1_lp_a, &token_a_mint, 1_000_000).await?;
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &token_b_mint, 1_000_000).await?;
+
+    // Both directions, with an odd output so one of them cannot divide evenly
+    for a_to_b in [true, false] {
+        let amount_out = 1_001u64;
+        let (in_ratio, out_ratio) = if a_to_b { (a, b) } else { (b, a) };
+        let required_in = (amount_out * in_ratio).div_ceil(out_ratio);
+        assert!((required_in - 1) * out_ratio / in_ratio < amount_out, "One unit less must not cover the output");
+
+        let result = submit_swap_exact_out(&mut foundation, a_to_b, amount_out, required_in - 1).await;
+        assert_eq!(
+            result.expect_err("Required input above the maximum must fail").unwrap(),
+            TransactionError::InstructionError(0, InstructionError::Custom(
+                PoolError::SwapInputAboveMaximum { maximum_amount_in: 0, amount_in: 0 }.error_code()
+            )),
+        );
+
+        let input_before = get_token_balance(&mut foundation.env.banks_client, &user2_token_a).await;
+        let (amount_in, executed_out) = submit_swap_exact_out(&mut foundation, a_to_b, amount_out, required_in).await?;
+        let input_after = get_token_balance(&mut foundation.env.banks_client, &user2_token_a).await;
+        assert_eq!(amount_in, required_in, "Required input rounds up");
+        assert_eq!(executed_out, required_in * out_ratio / in_ratio);
+        assert!(executed_out >= amount_out, "Output must cover the requested amount");
+        if a_to_b {
+            assert_eq!(input_before - input_after, required_in, "Only the required Token A leaves the user");
+        } else {
+            assert_eq!(input_after - input_before, executed_out, "User receives the executed Token A output");
+        }
+        println!("✅ SwapExactOut {}: {} in for {} out (requested {})", if a_to_b { "A→B" } else { "B→A" }, amount_in, executed_out, amount_out);
+    }
+
+    Ok(())
+}
+
+/// A pool's swap mode restricts it to exact-in or exact-out swaps, rejecting the
+/// other kind with SwapModeNotAllowed, and `Both` accepts either
+#[tokio::test]
+#[serial]
+async fn test_swap_mode_restricts_swaps() -> TestResult {
+    use fixed_ratio_trading::{constants::SYSTEM_STATE_SEED_PREFIX, error::PoolError, state::SwapMode};
+    use solana_sdk::{instruction::{AccountMeta, InstructionError}, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let config = foundation.pool_config.clone();
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+
+    // Provide Token B liquidity so the A → B swaps can be filled
+    let user1_pubkey = foundation.user1.pubkey();
+    let user1_token_b = if config.token_a_is_the_multiple {
+        foundation.user1_base_account.pubkey()
+    } else {
+        foundation.user1_primary_account.pubkey()
+    };
+    let user1_lp_b = foundation.user1_lp_b_account.pubkey();
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &config.token_b_mint, 1_000_000).await?;
+
+    let admin = foundation.system_authority.pubkey();
+    let set_swap_mode = |swap_mode: SwapMode| Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new_readonly(get_test_program_data_address(&PROGRAM_ID), false),
+        ],
+        data: PoolInstruction::SetSwapMode {
+            swap_mode,
+            pool_id: config.pool_state_pda,
+        }.try_to_vec().unwrap(),
+    };
+    let mode_not_allowed = TransactionError::InstructionError(0, InstructionError::Custom(
+        PoolError::SwapModeNotAllowed { allowed: String::new() }.error_code()
+    ));
+
+    // New pools accept both kinds
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.expect("Pool state should exist");
+    assert_eq!(pool_state.swap_mode, SwapMode::Both);
+
+    for swap_mode in [SwapMode::ExactOutOnly, SwapMode::ExactInOnly, SwapMode::Both] {
+        let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+        let blockhash = foundation.env.banks_client.get_new_latest_blockhash(&blockhash).await?;
+        let mut tx = Transaction::new_with_payer(&[set_swap_mode(swap_mode)], Some(&foundation.env.payer.pubkey()));
+        tx.sign(&[&foundation.env.payer, &foundation.system_authority], blockhash);
+        foundation.env.banks_client.process_transaction(tx).await?;
+        let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.unwrap();
+        assert_eq!(pool_state.swap_mode, swap_mode);
+
+        let exact_in = submit_swap_with_deadline(&mut foundation, 10_000, None).await;
+        let exact_out = submit_swap_exact_out(&mut foundation, true, 1_000, 1_000_000).await;
+        match swap_mode {
+            SwapMode::ExactOutOnly => {
+                assert_eq!(exact_in.expect_err("Exact-in swap must be rejected").unwrap(), mode_not_allowed);
+                exact_out?;
+            }
+            SwapMode::ExactInOnly => {
+                exact_in?;
+                assert_eq!(exact_out.expect_err("Exact-out swap must be rejected").unwrap(), mode_not_allowed);
+            }
+            SwapMode::Both => {
+                exact_in?;
+                exact_out?;
+            }
+        }
+        println!("✅ Swap mode {:?} enforced", swap_mode);
+    }
+
+    Ok(())
+}
+
+/// With event emission turned off, a swap still succeeds but emits no `SWAP|..` event;
+/// turning it back on restores the event
+#[tokio::test]
+#[serial]
+async fn test_event_emission_toggle_silences_swap_events() -> TestResult {
+    use fixed_ratio_trading::constants::SYSTEM_STATE_SEED_PREFIX;
+    use solana_sdk::instruction::AccountMeta;
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let config = foundation.pool_config.clone();
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+
+    // Provide Token B liquidity so the A → B swaps can be filled
+    let user1_pubkey = foundation.user1.pubkey();
+    let user1_token_b = if config.token_a_is_the_multiple {
+        foundation.user1_base_account.pubkey()
+    } else {
+        foundation.user1_primary_account.pubkey()
+    };
+    let user1_lp_b = foundation.user1_lp_b_account.pubkey();
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &config.token_b_mint, 1_000_000).await?;
+
+    // New pools emit events
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.expect("Pool state should exist");
+    assert!(pool_state.emit_events);
+
+    let admin = foundation.system_authority.pubkey();
+    let set_event_emission = |emit_events: bool| Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new_readonly(get_test_program_data_address(&PROGRAM_ID), false),
+        ],
+        data: PoolInstruction::SetEventEmission {
+            emit_events,
+            pool_id: config.pool_state_pda,
+        }.try_to_vec().unwrap(),
+    };
+
+    for emit_events in [false, true] {
+        let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+        let blockhash = foundation.env.banks_client.get_new_latest_blockhash(&blockhash).await?;
+        let mut tx = Transaction::new_with_payer(&[set_event_emission(emit_events)], Some(&foundation.env.payer.pubkey()));
+        tx.sign(&[&foundation.env.payer, &foundation.system_authority], blockhash);
+        foundation.env.banks_client.process_transaction(tx).await?;
+        let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.unwrap();
+        assert_eq!(pool_state.emit_events, emit_events);
+
+        let swap_ix = build_user2_a_to_b_swap(&foundation, 10_000, None);
+        let blockhash = foundation.env.banks_client.get_new_latest_blockhash(&blockhash).await?;
+        let mut swap_tx = Transaction::new_with_payer(&[swap_ix], Some(&foundation.user2.pubkey()));
+        swap_tx.sign(&[&foundation.user2], blockhash);
+        let result = foundation.env.banks_client.process_transaction_with_metadata(swap_tx).await?;
+        result.result.clone()?;
+        let logs = result.metadata.expect("Processed transactions carry metadata").log_messages;
+
+        // The native test runtime does not capture `sol_log_data`, so the text line
+        // (logged by the same `emit` call as the data blob) stands in for both
+        let has_event_line = logs.iter().any(|line| line.contains("SWAP|pool="));
+        assert_eq!(has_event_line, emit_events, "SWAP| event must follow the toggle");
+        println!("✅ Swap succeeded with emit_events = {} (event logged: {})", emit_events, has_event_line);
+    }
+
+    Ok(())
+}
+
+/// Swaps below a pool's minimum swap amount fail with SwapAmountBelowMinimum, swaps at the
+/// minimum go through, and a minimum whose output would overflow u64 is refused
+#[tokio::test]
+#[serial]
+async fn test_min_swap_amount_rejects_dust_swaps() -> TestResult {
+    use fixed_ratio_trading::{constants::SYSTEM_STATE_SEED_PREFIX, error::PoolError};
+    use solana_sdk::{instruction::{AccountMeta, InstructionError}, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let config = foundation.pool_config.clone();
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+
+    // Provide Token B liquidity so the A → B swaps can be filled
+    let user1_pubkey = foundation.user1.pubkey();
+    let user1_token_b = if config.token_a_is_the_multiple {
+        foundation.user1_base_account.pubkey()
+    } else {
+        foundation.user1_primary_account.pubkey()
+    };
+    let user1_lp_b = foundation.user1_lp_b_account.pubkey();
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &config.token_b_mint, 1_000_000).await?;
+
+    let admin = foundation.system_authority.pubkey();
+    let set_min_swap_amount = |min_swap_amount: u64| Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new_readonly(get_test_program_data_address(&PROGRAM_ID), false),
+        ],
+        data: PoolInstruction::SetMinSwapAmount {
+            min_swap_amount,
+            pool_id: config.pool_state_pda,
+        }.try_to_vec().unwrap(),
+    };
+
+    // New pools have no minimum
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.expect("Pool state should exist");
+    assert_eq!(pool_state.min_swap_amount, 0);
+
+    // A minimum whose swap output cannot fit in a u64 is refused
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let mut tx = Transaction::new_with_payer(&[set_min_swap_amount(u64::MAX)], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.system_authority], blockhash);
+    assert_eq!(
+        foundation.env.banks_client.process_transaction(tx).await.expect_err("Unrepresentable minimum must be refused").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+
+    let blockhash = foundation.env.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    let mut tx = Transaction::new_with_payer(&[set_min_swap_amount(5_000)], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.system_authority], blockhash);
+    foundation.env.banks_client.process_transaction(tx).await?;
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.unwrap();
+    assert_eq!(pool_state.min_swap_amount, 5_000);
+
+    let dust = submit_swap_with_deadline(&mut foundation, 4_999, None).await;
+    assert_eq!(
+        dust.expect_err("Swap below the minimum must be rejected").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(
+            PoolError::SwapAmountBelowMinimum { min_swap_amount: 0, amount_in: 0 }.error_code()
+        )),
+    );
+    submit_swap_with_deadline(&mut foundation, 5_000, None).await?;
+
+    println!("✅ Minimum swap amount of 5000 enforced");
+    Ok(())
+}
+
+/// Swap outputs round down; a 1-unit input of the multiple token in a 100:1 pool rounds to
+/// zero output and fails with AmountTooSmall instead of transferring the input for nothing
+#[tokio::test]
+#[serial]
+async fn test_swap_zero_output_rounding_is_amount_too_small() -> TestResult {
+    use fixed_ratio_trading::error::PoolError;
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(100)).await?;
+    let config = foundation.pool_config.clone();
+
+    // Swapping the multiple (abundant) token in divides the amount by the ratio
+    let (input_mint, input_ratio, output_ratio) = if config.token_a_is_the_multiple {
+        (config.token_a_mint, config.ratio_a_numerator, config.ratio_b_denominator)
+    } else {
+        (config.token_b_mint, config.ratio_b_denominator, config.ratio_a_numerator)
+    };
+    assert_eq!(output_ratio / input_ratio, 0, "1 unit in must truncate to zero out");
+
+    let user_input = foundation.user2_primary_account.pubkey();
+    let user_output = foundation.user2_base_account.pubkey();
+    let input_before = get_token_balance(&mut foundation.env.banks_client, &user_input).await;
+    let output_before = get_token_balance(&mut foundation.env.banks_client, &user_output).await;
+
+    let swap_ix = common::liquidity_helpers::create_swap_instruction_standardized(
+        &foundation.user2.pubkey(),
+        &user_input,
+        &user_output,
+        &config,
+        &PoolInstruction::Swap {
+            input_token_mint: input_mint,
+            amount_in: 1,
+            expected_amount_out: 0,
+            pool_id: config.pool_state_pda,
+        },
+    )?;
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let mut swap_tx = Transaction::new_with_payer(&[swap_ix], Some(&foundation.user2.pubkey()));
+    swap_tx.sign(&[&foundation.user2], blockhash);
+    let result = foundation.env.banks_client.process_transaction(swap_tx).await;
+    assert_eq!(
+        result.expect_err("Zero-output swap must fail").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(
+            PoolError::AmountTooSmall { amount_in: 0 }.error_code()
+        )),
+    );
+
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_input).await, input_before, "Input must not be taken");
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_output).await, output_before);
+
+    println!("✅ 1 in at {}:{} rejected with AmountTooSmall", input_ratio, output_ratio);
+    Ok(())
+}
+
+/// A swap whose output would not fit in a u64 fails up front with CalculationOverflow,
+/// before any balance checks or transfers, in a 100:1 pool
+#[tokio::test]
+#[serial]
+async fn test_swap_large_ratio_overflow_is_calculation_overflow() -> TestResult {
+    use fixed_ratio_trading::error::PoolError;
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(100)).await?;
+    let config = foundation.pool_config.clone();
+
+    // Swapping the base (valuable) token in multiplies the amount by the ratio
+    let (input_mint, input_ratio, output_ratio) = if config.token_a_is_the_multiple {
+        (config.token_b_mint, config.ratio_b_denominator, config.ratio_a_numerator)
+    } else {
+        (config.token_a_mint, config.ratio_a_numerator, config.ratio_b_denominator)
+    };
+    let amount_in = u64::MAX / 2;
+    assert!(amount_in as u128 * output_ratio as u128 / input_ratio as u128 > u64::MAX as u128);
+
+    let swap_ix = common::liquidity_helpers::create_swap_instruction_standardized(
+        &foundation.user2.pubkey(),
+        &foundation.user2_base_account.pubkey(),
+        &foundation.user2_primary_account.pubkey(),
+        &config,
+        &PoolInstruction::Swap {
+            input_token_mint: input_mint,
+            amount_in,
+            expected_amount_out: u64::MAX,
+            pool_id: config.pool_state_pda,
+        },
+    )?;
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let mut swap_tx = Transaction::new_with_payer(&[swap_ix], Some(&foundation.user2.pubkey()));
+    swap_tx.sign(&[&foundation.user2], blockhash);
+    let result = foundation.env.banks_client.process_transaction(swap_tx).await;
+    assert_eq!(
+        result.expect_err("Overflowing swap must fail").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(
+            PoolError::CalculationOverflow { amount: 0, multiplier: 0, divisor: 0 }.error_code()
+        )),
+    );
+
+    println!("✅ {} in at {}:{} rejected with CalculationOverflow", amount_in, input_ratio, output_ratio);
+    Ok(())
+}
+
+/// Swaps trust the PDA bumps and vault addresses cached in the pool state instead of
+/// re-deriving them: the cached values must match a fresh derivation, and a vault
+/// that is not the pool's own must still be rejected
+#[tokio::test]
+#[serial]
+async fn test_swap_uses_cached_bumps_and_rejects_forged_vaults() -> TestResult {
+    use fixed_ratio_trading::constants::{
+        LP_TOKEN_A_MINT_SEED_PREFIX, LP_TOKEN_B_MINT_SEED_PREFIX, POOL_STATE_SEED_PREFIX,
+        TOKEN_A_VAULT_SEED_PREFIX, TOKEN_B_VAULT_SEED_PREFIX,
+    };
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let config = foundation.pool_config.clone();
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await.expect("Pool state should exist");
+
+    // Step 1: Every cached bump and address matches a fresh derivation
+    let (pool_pda, pool_bump) = Pubkey::find_program_address(
+        &[
+            POOL_STATE_SEED_PREFIX,
+            pool_state.token_a_mint.as_ref(),
+            pool_state.token_b_mint.as_ref(),
+            &pool_state.original_ratio_a_numerator.to_le_bytes(),
+            &pool_state.original_ratio_b_denominator.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    );
+    assert_eq!((pool_pda, pool_bump), (config.pool_state_pda, pool_state.pool_authority_bump_seed));
+    let derive = |prefix: &[u8]| Pubkey::find_program_address(&[prefix, config.pool_state_pda.as_ref()], &PROGRAM_ID);
+    assert_eq!(derive(TOKEN_A_VAULT_SEED_PREFIX), (pool_state.token_a_vault, pool_state.token_a_vault_bump_seed));
+    assert_eq!(derive(TOKEN_B_VAULT_SEED_PREFIX), (pool_state.token_b_vault, pool_state.token_b_vault_bump_seed));
+    assert_eq!(derive(LP_TOKEN_A_MINT_SEED_PREFIX), (pool_state.lp_token_a_mint, pool_state.lp_token_a_mint_bump_seed));
+    assert_eq!(derive(LP_TOKEN_B_MINT_SEED_PREFIX), (pool_state.lp_token_b_mint, pool_state.lp_token_b_mint_bump_seed));
+
+    // Provide Token B liquidity so the A → B swap can be filled
+    let user1_pubkey = foundation.user1.pubkey();
+    let user1_token_b = if config.token_a_is_the_multiple {
+        foundation.user1_base_account.pubkey()
+    } else {
+        foundation.user1_primary_account.pubkey()
+    };
+    let user1_lp_b = foundation.user1_lp_b_account.pubkey();
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &config.token_b_mint, 1_000_000).await?;
+
+    // Step 2: A pool-owned token account of the right mint is not the pool's vault
+    let forged_vault = Keypair::new();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    create_token_account(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &forged_vault, &config.token_b_mint, &config.pool_state_pda).await?;
+    let mut forged_ix = build_user2_a_to_b_swap(&foundation, 1_000, None);
+    forged_ix.accounts[6].pubkey = forged_vault.pubkey();
+    let mut tx = Transaction::new_with_payer(&[forged_ix], Some(&foundation.user2.pubkey()));
+    tx.sign(&[&foundation.user2], foundation.env.banks_client.get_latest_blockhash().await?);
+    let error = foundation.env.banks_client.process_transaction(tx).await.expect_err("Forged vault must be rejected");
+    assert_eq!(error.unwrap(), TransactionError::InstructionError(0, InstructionError::InvalidAccountData));
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &config.token_b_vault_pda).await, 1_000_000, "Rejected swap must not move tokens");
+
+    // Step 3: The same swap through the real vaults goes through
+    submit_swap_with_deadline(&mut foundation, 1_000, None).await?;
+
+    println!("✅ Cached bumps match derived PDAs and forged vaults are rejected");
+    Ok(())
+}
@@ -601,6 +601,57 @@ async fn test_flexible_ownership_delegation() -> Result<(), Box<dyn std::error::
     Ok(())
 }
 
+/// SWAP-OWNER-006: Enabling owner-only swaps with the default pubkey as owner is rejected
+///
+/// A default designated owner would leave nobody able to swap, bricking the pool.
+#[tokio::test]
+#[serial]
+async fn test_set_swap_owner_only_rejects_default_owner() -> TestResult {
+    use fixed_ratio_trading::error::PoolError;
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let admin = foundation.system_authority.pubkey();
+    let set_owner_only = |designated_owner: Pubkey| Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new_readonly(get_system_state_pda(), false),
+            AccountMeta::new(pool_state_pda, false),
+            AccountMeta::new_readonly(get_test_program_data_address(&fixed_ratio_trading::id()), false),
+        ],
+        data: PoolInstruction::SetSwapOwnerOnly {
+            enable_restriction: true,
+            designated_owner,
+            pool_id: pool_state_pda,
+        }.try_to_vec().unwrap(),
+    };
+
+    // Default owner is rejected and the pool stays open
+    let mut tx = Transaction::new_with_payer(&[set_owner_only(Pubkey::default())], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.system_authority], foundation.env.banks_client.get_latest_blockhash().await?);
+    let result = foundation.env.banks_client.process_transaction(tx).await;
+    assert_eq!(
+        result.expect_err("Default designated owner must be rejected").unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(PoolError::InvalidDesignatedOwner.error_code())),
+    );
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &pool_state_pda).await.expect("Pool state should exist");
+    assert!(!pool_state.swap_for_owners_only(), "Rejected call must not restrict swaps");
+
+    // A real owner is accepted
+    let designated_owner = foundation.user1.pubkey();
+    let mut tx = Transaction::new_with_payer(&[set_owner_only(designated_owner)], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.system_authority], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+    let pool_state = get_pool_state(&mut foundation.env.banks_client, &pool_state_pda).await.expect("Pool state should exist");
+    assert!(pool_state.swap_for_owners_only(), "Pool should now restrict swaps to the owner");
+    assert_eq!(pool_state.owner, designated_owner);
+
+    println!("✅ SWAP-OWNER-006: Default designated owner rejected, valid owner accepted");
+    Ok(())
+}
+
 /// Helper function to create foundation with timeout
 async fn create_foundation_with_timeout(
     pool_ratio: Option<u64>,
@@ -700,6 +700,62 @@ async fn test_get_consolidation_status() -> TestResult {
     Ok(())
 }
 
+/// CONSOLIDATION-005b: GetConsolidationStatus rejects a pool_count that does not match the accounts
+/// 
+/// A count larger or smaller than the account list fails with `PoolCountMismatch`
+/// instead of panicking on the slice, and a non-pool account is rejected.
+#[tokio::test]
+#[serial]
+async fn test_get_consolidation_status_pool_count_mismatch() -> TestResult {
+    use fixed_ratio_trading::error::PoolError;
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(3)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let (system_state_pda, _) = Pubkey::find_program_address(
+        &[SYSTEM_STATE_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let mismatch_code = PoolError::PoolCountMismatch { pool_count: 0, pool_accounts: 0 }.error_code();
+
+    let cases = [
+        (5u8, vec![pool_state_pda], Some(mismatch_code)),
+        (1u8, vec![pool_state_pda, pool_state_pda], Some(mismatch_code)),
+        (1u8, vec![system_state_pda], None),
+    ];
+    let mut blockhash = foundation.env.recent_blockhash;
+    for (pool_count, pools, expected_code) in cases {
+        let instruction = Instruction {
+            program_id: fixed_ratio_trading::id(),
+            accounts: pools.iter().map(|pool| AccountMeta::new_readonly(*pool, false)).collect(),
+            data: PoolInstruction::GetConsolidationStatus { pool_count }.try_to_vec()?,
+        };
+        blockhash = foundation.env.banks_client.get_new_latest_blockhash(&blockhash).await?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&foundation.env.payer.pubkey()),
+            &[&foundation.env.payer],
+            blockhash,
+        );
+        let error = foundation.env.banks_client.process_transaction(transaction).await
+            .expect_err("Mismatched or invalid pool accounts must be rejected")
+            .unwrap();
+        match expected_code {
+            Some(code) => assert_eq!(
+                error,
+                TransactionError::InstructionError(0, InstructionError::Custom(code)),
+                "pool_count {} with {} accounts must fail with PoolCountMismatch", pool_count, pools.len()
+            ),
+            None => assert!(
+                matches!(error, TransactionError::InstructionError(0, _)),
+                "A non-pool account must fail cleanly, got {:?}", error
+            ),
+        }
+    }
+
+    Ok(())
+}
+
 /// CONSOLIDATION-006: Test consolidation with mixed pool pause states
 /// 
 /// This test verifies that consolidation respects individual pool pause states
@@ -2192,4 +2248,524 @@ async fn test_consolidation_maximum_20_pools_with_fees() -> TestResult {
     }
     
     Ok(())
-} 
\ No newline at end of file
+} 
+/// Reads `[total_pools, treasury_balance, total_consolidations]` via GetProgramStats
+async fn get_program_stats(foundation: &mut LiquidityTestFoundation) -> Result<[u64; 3], Box<dyn std::error::Error>> {
+    let (main_treasury_pda, _) = Pubkey::find_program_address(
+        &[MAIN_TREASURY_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let instruction = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![AccountMeta::new_readonly(main_treasury_pda, false)],
+        data: PoolInstruction::GetProgramStats {}.try_to_vec()?,
+    };
+    let return_data = common::setup::simulate_and_get_return_data(
+        &mut foundation.env.banks_client,
+        &foundation.env.payer,
+        instruction,
+    ).await?;
+    assert_eq!(return_data.len(), 24, "GetProgramStats must return three u64 values");
+
+    let mut stats = [0u64; 3];
+    for (i, chunk) in return_data.chunks_exact(8).enumerate() {
+        stats[i] = u64::from_le_bytes(chunk.try_into()?);
+    }
+    Ok(stats)
+}
+
+/// CONSOLIDATION-STATS: GetProgramStats reflects pool creation and consolidation activity
+#[tokio::test]
+#[serial]
+async fn test_get_program_stats_reflects_activity() -> TestResult {
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let (main_treasury_pda, _) = Pubkey::find_program_address(
+        &[MAIN_TREASURY_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let (system_state_pda, _) = Pubkey::find_program_address(
+        &[SYSTEM_STATE_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let program_data_pda = fixed_ratio_trading::utils::program_authority::get_program_data_address(
+        &fixed_ratio_trading::id()
+    );
+
+    let [pools_before, _, consolidations_before] = get_program_stats(&mut foundation).await?;
+    assert_eq!(pools_before, 1, "Foundation creates exactly one pool");
+
+    // Pause the pool and consolidate it
+    let admin_authority = common::setup::create_test_program_authority_keypair()
+        .expect("Should create test admin authority");
+    let pause_instruction = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new(foundation.env.payer.pubkey(), true),
+            AccountMeta::new(system_state_pda, false),
+            AccountMeta::new(foundation.pool_config.pool_state_pda, false),
+            AccountMeta::new(program_data_pda, false),
+        ],
+        data: PoolInstruction::PausePool {
+            pause_flags: PAUSE_FLAG_ALL,
+            pool_id: foundation.pool_config.pool_state_pda,
+        }.try_to_vec()?,
+    };
+    let consolidate_instruction = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(admin_authority.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(program_data_pda, false),
+            AccountMeta::new(foundation.pool_config.pool_state_pda, false),
+        ],
+        data: PoolInstruction::ConsolidatePoolFees { pool_count: 1 }.try_to_vec()?,
+    };
+    let transaction = Transaction::new_signed_with_payer(
+        &[pause_instruction, consolidate_instruction],
+        Some(&foundation.env.payer.pubkey()),
+        &[&foundation.env.payer, &admin_authority],
+        foundation.env.recent_blockhash,
+    );
+    foundation.env.banks_client.process_transaction(transaction).await?;
+
+    let [pools_after, treasury_balance, consolidations_after] = get_program_stats(&mut foundation).await?;
+    let treasury_account = foundation.env.banks_client.get_account(main_treasury_pda).await?.unwrap();
+    let treasury_state = fixed_ratio_trading::state::MainTreasuryState::try_from_slice(&treasury_account.data)?;
+
+    assert_eq!(pools_after, pools_before, "Consolidation must not change the pool count");
+    assert_eq!(consolidations_after, consolidations_before + 1, "Consolidation must be counted");
+    assert_eq!(treasury_balance, treasury_state.total_balance, "Balance must match treasury state");
+    assert_eq!(treasury_balance, treasury_account.lamports, "Balance is synced with the treasury account after consolidation");
+
+    println!("✅ Program stats: {} pools, {} lamports, {} consolidations", pools_after, treasury_balance, consolidations_after);
+    Ok(())
+}
+
+/// CONSOLIDATION-SIMULATE: SimulateConsolidation reports exactly what a real consolidation moves
+#[tokio::test]
+#[serial]
+async fn test_simulate_consolidation_matches_real_consolidation() -> TestResult {
+    let mut foundation = create_liquidity_test_foundation_with_fees(Some(2), true).await?;
+    let (main_treasury_pda, _) = Pubkey::find_program_address(
+        &[MAIN_TREASURY_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let (system_state_pda, _) = Pubkey::find_program_address(
+        &[SYSTEM_STATE_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let program_data_pda = fixed_ratio_trading::utils::program_authority::get_program_data_address(
+        &fixed_ratio_trading::id()
+    );
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let admin_authority = common::setup::create_test_program_authority_keypair()
+        .expect("Should create test admin authority");
+
+    let pause_instruction = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new(foundation.env.payer.pubkey(), true),
+            AccountMeta::new(system_state_pda, false),
+            AccountMeta::new(pool_state_pda, false),
+            AccountMeta::new(program_data_pda, false),
+        ],
+        data: PoolInstruction::PausePool {
+            pause_flags: PAUSE_FLAG_ALL,
+            pool_id: pool_state_pda,
+        }.try_to_vec()?,
+    };
+    let transaction = Transaction::new_signed_with_payer(
+        &[pause_instruction],
+        Some(&foundation.env.payer.pubkey()),
+        &[&foundation.env.payer],
+        foundation.env.recent_blockhash,
+    );
+    foundation.env.banks_client.process_transaction(transaction).await?;
+
+    // Same account list as the real consolidation; the admin does not sign the dry run
+    let simulate_instruction = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(admin_authority.pubkey(), false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new_readonly(main_treasury_pda, false),
+            AccountMeta::new_readonly(program_data_pda, false),
+            AccountMeta::new_readonly(pool_state_pda, false),
+        ],
+        data: PoolInstruction::SimulateConsolidation { pool_count: 1 }.try_to_vec()?,
+    };
+    let return_data = common::setup::simulate_and_get_return_data(
+        &mut foundation.env.banks_client,
+        &foundation.env.payer,
+        simulate_instruction,
+    ).await?;
+    assert_eq!(return_data.len(), 32, "SimulateConsolidation must return four u64 values");
+    let values: Vec<u64> = return_data
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+    let (simulated_total, simulated_liquidity, simulated_swap, simulated_pools) =
+        (values[0], values[1], values[2], values[3]);
+
+    assert!(simulated_total > 0, "Fee-generating foundation must leave fees to consolidate");
+    assert_eq!(simulated_total, simulated_liquidity + simulated_swap, "Fee split must add up to the total");
+    assert_eq!(simulated_pools, 1);
+
+    let pool_before = foundation.env.banks_client.get_account(pool_state_pda).await?.unwrap();
+    let treasury_before = get_sol_balance(&mut foundation.env.banks_client, &main_treasury_pda).await;
+
+    let consolidate_instruction = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(admin_authority.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(program_data_pda, false),
+            AccountMeta::new(pool_state_pda, false),
+        ],
+        data: PoolInstruction::ConsolidatePoolFees { pool_count: 1 }.try_to_vec()?,
+    };
+    let transaction = Transaction::new_signed_with_payer(
+        &[consolidate_instruction],
+        Some(&foundation.env.payer.pubkey()),
+        &[&foundation.env.payer, &admin_authority],
+        foundation.env.recent_blockhash,
+    );
+    foundation.env.banks_client.process_transaction(transaction).await?;
+
+    let pool_after = foundation.env.banks_client.get_account(pool_state_pda).await?.unwrap();
+    let treasury_after = get_sol_balance(&mut foundation.env.banks_client, &main_treasury_pda).await;
+
+    assert_eq!(pool_before.lamports - pool_after.lamports, simulated_total, "Pool must lose exactly the simulated amount");
+    assert_eq!(treasury_after - treasury_before, simulated_total, "Treasury must gain exactly the simulated amount");
+
+    println!("✅ Simulated {} lamports ({} liquidity, {} swap) matched the real consolidation",
+             simulated_total, simulated_liquidity, simulated_swap);
+    Ok(())
+}
+
+/// CONSOLIDATION-FEES: GetAllPoolFees reports every valid pool and skips other accounts
+#[tokio::test]
+#[serial]
+async fn test_get_all_pool_fees_skips_invalid_accounts() -> TestResult {
+    use common::{pool_helpers::create_pool_new_pattern, tokens::create_mint};
+    use fixed_ratio_trading::types::PoolFeeSummary;
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let (system_state_pda, _) = Pubkey::find_program_address(
+        &[SYSTEM_STATE_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+
+    // Second pool in the same environment
+    let multiple_mint = Keypair::new();
+    let base_mint = Keypair::new();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    create_mint(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &multiple_mint, None).await?;
+    create_mint(&mut foundation.env.banks_client, &foundation.env.payer, blockhash, &base_mint, None).await?;
+    let second_pool = create_pool_new_pattern(
+        &mut foundation.env.banks_client, &foundation.env.payer, blockhash, &multiple_mint, &base_mint, Some(5),
+    ).await?;
+
+    let pools = [foundation.pool_config.pool_state_pda, system_state_pda, second_pool.pool_state_pda];
+    let instruction = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: pools.iter().map(|pool| AccountMeta::new_readonly(*pool, false)).collect(),
+        data: PoolInstruction::GetAllPoolFees {}.try_to_vec()?,
+    };
+    let return_data = common::setup::simulate_and_get_return_data(
+        &mut foundation.env.banks_client,
+        &foundation.env.payer,
+        instruction,
+    ).await?;
+    let fees = Vec::<PoolFeeSummary>::try_from_slice(&return_data)?;
+
+    assert_eq!(fees.len(), 2, "The system state account must be skipped, not fail the call");
+    for (summary, pool) in fees.iter().zip([pools[0], pools[2]]) {
+        let pool_account = foundation.env.banks_client.get_account(pool).await?.unwrap();
+        let pool_state = PoolState::try_from_slice(&pool_account.data)?;
+        assert_eq!(summary.pool, pool);
+        assert_eq!(summary.collected_fees_token_a, pool_state.collected_fees_token_a);
+        assert_eq!(summary.collected_fees_token_b, pool_state.collected_fees_token_b);
+    }
+
+    println!("✅ GetAllPoolFees reported {} pools from {} accounts", fees.len(), pools.len());
+    Ok(())
+}
+
+/// CONSOLIDATION-SKIP: With a skip window set, a batch mixing a pool consolidated moments
+/// ago and a fresh pool processes the fresh one, skips the other, and reports both counts
+#[tokio::test]
+#[serial]
+async fn test_consolidation_skips_recently_consolidated_pools() -> TestResult {
+    use common::{liquidity_helpers::create_deposit_instruction_standardized, pool_helpers::create_pool_with_mint_keys, tokens::create_token_account};
+
+    let mut foundation = create_liquidity_test_foundation_with_fees(Some(2), true).await?;
+    let program_id = fixed_ratio_trading::id();
+    let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &program_id);
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &program_id);
+    let program_data_pda = fixed_ratio_trading::utils::program_authority::get_program_data_address(&program_id);
+    let admin_authority = common::setup::create_test_program_authority_keypair()
+        .expect("Should create test admin authority");
+    let recent_pool = foundation.pool_config.pool_state_pda;
+    let pool_owner = foundation.env.payer.pubkey();
+    let user1 = foundation.user1.pubkey();
+
+    // Second pool over the same mints, with fees from one deposit
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let fresh_config = create_pool_with_mint_keys(
+        &mut foundation.env.banks_client, &foundation.env.payer, blockhash,
+        &foundation.pool_config.token_a_mint, &foundation.pool_config.token_b_mint, Some(5),
+    ).await?;
+    let fresh_pool = fresh_config.pool_state_pda;
+    let (fresh_lp_a_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_A_MINT_SEED_PREFIX, fresh_pool.as_ref()], &program_id);
+    let (fresh_lp_b_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_B_MINT_SEED_PREFIX, fresh_pool.as_ref()], &program_id);
+    let (deposit_mint, user1_input, recent_lp_account, fresh_lp_mint) = if foundation.pool_config.token_a_is_the_multiple {
+        (foundation.pool_config.token_a_mint, foundation.user1_primary_account.pubkey(), foundation.user1_lp_a_account.pubkey(), fresh_lp_a_mint)
+    } else {
+        (foundation.pool_config.token_b_mint, foundation.user1_base_account.pubkey(), foundation.user1_lp_b_account.pubkey(), fresh_lp_b_mint)
+    };
+    let fresh_lp_account = Keypair::new();
+    create_token_account(
+        &mut foundation.env.banks_client, &foundation.env.payer, blockhash,
+        &fresh_lp_account, &fresh_lp_mint, &foundation.user1.pubkey(),
+    ).await?;
+    let deposit_ix = create_deposit_instruction_standardized(
+        &foundation.user1.pubkey(), &user1_input, &fresh_lp_account.pubkey(), &fresh_config, &fresh_lp_a_mint, &fresh_lp_b_mint,
+        &PoolInstruction::Deposit { deposit_token_mint: deposit_mint, amount: 100_000, pool_id: fresh_pool },
+    )?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[deposit_ix], Some(&foundation.user1.pubkey()), &[&foundation.user1], blockhash,
+    );
+    foundation.env.banks_client.process_transaction(transaction).await?;
+
+    let set_pause = |pool: Pubkey, pause: bool| Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(pool_owner, true),
+            AccountMeta::new(system_state_pda, false),
+            AccountMeta::new(pool, false),
+            AccountMeta::new_readonly(program_data_pda, false),
+        ],
+        data: if pause {
+            PoolInstruction::PausePool { pause_flags: PAUSE_FLAG_ALL, pool_id: pool }
+        } else {
+            PoolInstruction::UnpausePool { unpause_flags: PAUSE_FLAG_ALL, pool_id: pool }
+        }.try_to_vec().unwrap(),
+    };
+    let consolidate = |pools: &[Pubkey]| {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(admin_authority.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(program_data_pda, false),
+        ];
+        accounts.extend(pools.iter().map(|pool| AccountMeta::new(*pool, false)));
+        Instruction {
+            program_id,
+            accounts,
+            data: PoolInstruction::ConsolidatePoolFees { pool_count: pools.len() as u8 }.try_to_vec().unwrap(),
+        }
+    };
+
+    // Consolidate the first pool, then give it new fees
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[set_pause(recent_pool, true), consolidate(&[recent_pool]), set_pause(recent_pool, false)],
+        Some(&foundation.env.payer.pubkey()),
+        &[&foundation.env.payer, &admin_authority],
+        blockhash,
+    );
+    foundation.env.banks_client.process_transaction(transaction).await?;
+    execute_deposit_operation(&mut foundation, &user1, &user1_input, &recent_lp_account, &deposit_mint, 50_000).await?;
+    let recent_before = foundation.env.banks_client.get_account(recent_pool).await?.unwrap();
+    let recent_state_before = PoolState::try_from_slice(&recent_before.data)?;
+    assert!(recent_state_before.last_consolidation_timestamp > 0);
+    assert!(recent_state_before.pending_sol_fees() > 0, "Recently consolidated pool must have new fees");
+
+    // Skip pools consolidated within the last hour
+    let set_window = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(admin_authority.pubkey(), true),
+            AccountMeta::new(system_state_pda, false),
+            AccountMeta::new_readonly(program_data_pda, false),
+        ],
+        data: PoolInstruction::SetConsolidationSkipWindow { window_seconds: 3_600 }.try_to_vec()?,
+    };
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[set_window, set_pause(recent_pool, true), set_pause(fresh_pool, true)],
+        Some(&foundation.env.payer.pubkey()),
+        &[&foundation.env.payer, &admin_authority],
+        blockhash,
+    );
+    foundation.env.banks_client.process_transaction(transaction).await?;
+
+    // The mixed batch succeeds, sweeping only the fresh pool
+    let fresh_before = foundation.env.banks_client.get_account(fresh_pool).await?.unwrap();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[consolidate(&[recent_pool, fresh_pool])],
+        Some(&foundation.env.payer.pubkey()),
+        &[&foundation.env.payer, &admin_authority],
+        blockhash,
+    );
+    let result = foundation.env.banks_client.process_transaction_with_metadata(transaction).await?;
+    result.result?;
+    let return_data = result.metadata.and_then(|metadata| metadata.return_data).map(|return_data| return_data.data).unwrap_or_default();
+    let summary: Vec<u64> = return_data.chunks_exact(8).map(|c| u64::from_le_bytes(c.try_into().unwrap())).collect();
+    let fresh_after = foundation.env.banks_client.get_account(fresh_pool).await?.unwrap();
+    let fresh_swept = fresh_before.lamports - fresh_after.lamports;
+    assert!(fresh_swept > 0, "Fresh pool must be consolidated");
+    assert_eq!(summary, vec![1, 1, fresh_swept], "One pool processed, one skipped");
+
+    let recent_after = foundation.env.banks_client.get_account(recent_pool).await?.unwrap();
+    assert_eq!(recent_after.lamports, recent_before.lamports, "Skipped pool keeps its fees");
+    assert_eq!(PoolState::try_from_slice(&recent_after.data)?.pending_sol_fees(), recent_state_before.pending_sol_fees());
+
+    println!("✅ Mixed batch: fresh pool consolidated {} lamports, recently consolidated pool skipped", fresh_swept);
+    Ok(())
+}
+
+/// CONSOLIDATION-RECIPIENT: Changing the fee recipient pays old fees to the old recipient
+#[tokio::test]
+#[serial]
+async fn test_change_fee_recipient_and_consolidate() -> TestResult {
+    use solana_sdk::system_instruction;
+
+    let mut foundation = create_liquidity_test_foundation_with_fees(Some(2), true).await?;
+    let program_id = fixed_ratio_trading::id();
+    let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &program_id);
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &program_id);
+    let program_data_pda = fixed_ratio_trading::utils::program_authority::get_program_data_address(&program_id);
+    let admin_authority = common::setup::create_test_program_authority_keypair()
+        .expect("Should create test admin authority");
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let pool_owner = foundation.env.payer.pubkey();
+    let user1 = foundation.user1.pubkey();
+    let (deposit_mint, user1_input, user1_lp_account) = if foundation.pool_config.token_a_is_the_multiple {
+        (foundation.pool_config.token_a_mint, foundation.user1_primary_account.pubkey(), foundation.user1_lp_a_account.pubkey())
+    } else {
+        (foundation.pool_config.token_b_mint, foundation.user1_base_account.pubkey(), foundation.user1_lp_b_account.pubkey())
+    };
+
+    // Funded so the credited account stays rent exempt
+    let new_recipient = Keypair::new().pubkey();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(&pool_owner, &new_recipient, 1_000_000_000)],
+        Some(&pool_owner),
+        &[&foundation.env.payer],
+        blockhash,
+    );
+    foundation.env.banks_client.process_transaction(transaction).await?;
+
+    let change_recipient = |new_recipient: Pubkey, current_recipient: Pubkey| Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(admin_authority.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(program_data_pda, false),
+            AccountMeta::new(pool_state_pda, false),
+            AccountMeta::new(current_recipient, false),
+        ],
+        data: PoolInstruction::ChangeFeeRecipientAndConsolidate { new_recipient, pool_id: pool_state_pda }.try_to_vec().unwrap(),
+    };
+
+    // Fees collected so far go to the MainTreasury, the old recipient
+    let pool_before = foundation.env.banks_client.get_account(pool_state_pda).await?.unwrap();
+    let pending_before = PoolState::try_from_slice(&pool_before.data)?.pending_sol_fees();
+    assert!(pending_before > 0, "Fee-generating foundation must leave fees to consolidate");
+    let treasury_before = get_sol_balance(&mut foundation.env.banks_client, &main_treasury_pda).await;
+    let recipient_before = get_sol_balance(&mut foundation.env.banks_client, &new_recipient).await;
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[change_recipient(new_recipient, main_treasury_pda)],
+        Some(&pool_owner),
+        &[&foundation.env.payer, &admin_authority],
+        blockhash,
+    );
+    let result = foundation.env.banks_client.process_transaction_with_metadata(transaction).await?;
+    result.result?;
+    let paid = u64::from_le_bytes(result.metadata.and_then(|metadata| metadata.return_data).unwrap().data[..8].try_into()?);
+    let pool_after = foundation.env.banks_client.get_account(pool_state_pda).await?.unwrap();
+    let pool_state = PoolState::try_from_slice(&pool_after.data)?;
+    // Rent-exempt protection may hold part of the fees back, as in a batch consolidation
+    assert!(paid > 0 && paid <= pending_before, "Pending fees must be paid out");
+    assert_eq!(pool_before.lamports - pool_after.lamports, paid);
+    assert_eq!(get_sol_balance(&mut foundation.env.banks_client, &main_treasury_pda).await - treasury_before, paid);
+    assert_eq!(get_sol_balance(&mut foundation.env.banks_client, &new_recipient).await, recipient_before);
+    assert_eq!((pool_state.fee_recipient, pool_state.pending_sol_fees()), (new_recipient, pending_before - paid));
+    let pending_after_change = pool_state.pending_sol_fees();
+
+    // Later fees accrue for the new recipient: batch consolidation leaves them alone
+    execute_deposit_operation(&mut foundation, &user1, &user1_input, &user1_lp_account, &deposit_mint, 50_000).await?;
+    let pool_before = foundation.env.banks_client.get_account(pool_state_pda).await?.unwrap();
+    let new_fees = PoolState::try_from_slice(&pool_before.data)?.pending_sol_fees() - pending_after_change;
+    assert!(new_fees > 0, "Deposit must charge a fee");
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            Instruction {
+                program_id,
+                accounts: vec![
+                    AccountMeta::new(pool_owner, true),
+                    AccountMeta::new(system_state_pda, false),
+                    AccountMeta::new(pool_state_pda, false),
+                    AccountMeta::new_readonly(program_data_pda, false),
+                ],
+                data: PoolInstruction::PausePool { pause_flags: PAUSE_FLAG_ALL, pool_id: pool_state_pda }.try_to_vec()?,
+            },
+            Instruction {
+                program_id,
+                accounts: vec![
+                    AccountMeta::new_readonly(admin_authority.pubkey(), true),
+                    AccountMeta::new_readonly(system_state_pda, false),
+                    AccountMeta::new(main_treasury_pda, false),
+                    AccountMeta::new_readonly(program_data_pda, false),
+                    AccountMeta::new(pool_state_pda, false),
+                ],
+                data: PoolInstruction::ConsolidatePoolFees { pool_count: 1 }.try_to_vec()?,
+            },
+        ],
+        Some(&pool_owner),
+        &[&foundation.env.payer, &admin_authority],
+        blockhash,
+    );
+    foundation.env.banks_client.process_transaction(transaction).await?;
+    let pool_after = foundation.env.banks_client.get_account(pool_state_pda).await?.unwrap();
+    assert_eq!(pool_after.lamports, pool_before.lamports, "Batch consolidation must skip a pool with its own recipient");
+
+    // The MainTreasury is no longer the recipient to pay out
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[change_recipient(Pubkey::default(), main_treasury_pda)],
+        Some(&pool_owner),
+        &[&foundation.env.payer, &admin_authority],
+        blockhash,
+    );
+    assert!(foundation.env.banks_client.process_transaction(transaction).await.is_err());
+
+    // Switching back pays the new fees to the custom recipient
+    let treasury_before = get_sol_balance(&mut foundation.env.banks_client, &main_treasury_pda).await;
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[change_recipient(Pubkey::default(), new_recipient)],
+        Some(&pool_owner),
+        &[&foundation.env.payer, &admin_authority],
+        blockhash,
+    );
+    foundation.env.banks_client.process_transaction(transaction).await?;
+    let pool_after = foundation.env.banks_client.get_account(pool_state_pda).await?.unwrap();
+    let pool_state = PoolState::try_from_slice(&pool_after.data)?;
+    assert_eq!(get_sol_balance(&mut foundation.env.banks_client, &new_recipient).await - recipient_before, new_fees);
+    assert_eq!(pool_before.lamports - pool_after.lamports, new_fees);
+    assert_eq!(get_sol_balance(&mut foundation.env.banks_client, &main_treasury_pda).await, treasury_before);
+    assert_eq!(pool_state.fee_recipient, Pubkey::default());
+
+    println!("✅ Old fees went to the MainTreasury and later fees to {}", new_recipient);
+    Ok(())
+}
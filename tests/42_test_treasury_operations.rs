@@ -730,7 +730,8 @@ async fn test_treasury_withdrawal_comprehensive() -> TestResult {
     
     use fixed_ratio_trading::{
         processors::treasury::process_treasury_withdraw_fees,
-        state::{MainTreasuryState, SystemState},
+        state::{DonationRecord, MainTreasuryState, SystemState},
+        constants::RECENT_DONATIONS_CAPACITY,
         error::PoolError,
         utils::program_authority::get_program_data_address,
     };
@@ -776,6 +777,13 @@ async fn test_treasury_withdrawal_comprehensive() -> TestResult {
         last_withdrawal_timestamp: 1640995100,
         donation_count: 0,
         total_donations: 0,
+        autocompound_pool: Pubkey::default(),
+        autocompound_reserve: 0,
+        autocompound_lp_tokens: 0,
+        withdrawal_window_start: 0,
+        withdrawn_in_window: 0,
+        recent_donations: [DonationRecord::default(); RECENT_DONATIONS_CAPACITY],
+        next_donation_slot: 0,
     };
     
     let system_state = SystemState::new(Pubkey::new_unique()); // Create with test admin authority
@@ -1007,6 +1015,746 @@ async fn test_treasury_withdrawal_integration() -> Result<(), Box<dyn std::error
     Ok(())
 } 
 
+/// TREASURY-004B: Withdrawing the full treasury balance must leave the rent reserve intact
+/// 
+/// A request for every lamport in the treasury is rejected with `WouldBreachRentReserve`,
+/// while withdraw-all (amount 0) stops exactly at the rent-exempt minimum.
+#[tokio::test]
+#[serial]
+async fn test_treasury_withdrawal_preserves_rent_reserve() -> Result<(), Box<dyn std::error::Error>> {
+    use solana_program_test::ProgramTest;
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::Keypair,
+        system_instruction,
+        sysvar,
+        transaction::TransactionError,
+    };
+    use fixed_ratio_trading::utils::program_authority::get_program_data_address;
+    use crate::common::setup::initialize_treasury_system;
+
+    let program_test = ProgramTest::new(
+        "fixed_ratio_trading",
+        fixed_ratio_trading::id(),
+        processor!(test_adapter),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    let system_authority = Keypair::new();
+    initialize_treasury_system(&mut banks_client, &payer, recent_blockhash, &system_authority).await?;
+
+    let (main_treasury_pda, _) = Pubkey::find_program_address(
+        &[MAIN_TREASURY_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let (system_state_pda, _) = Pubkey::find_program_address(
+        &[SYSTEM_STATE_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let program_data_address = get_program_data_address(&fixed_ratio_trading::id());
+    let destination_account = Keypair::new();
+
+    // Fund the treasury so the withdrawal stays within the hourly rate limit
+    let mut fund_tx = Transaction::new_with_payer(
+        &[system_instruction::transfer(&payer.pubkey(), &main_treasury_pda, 5_000_000_000)],
+        Some(&payer.pubkey()),
+    );
+    fund_tx.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(fund_tx).await?;
+
+    let withdraw_ix = |amount: u64| -> Result<Instruction, std::io::Error> {
+        Ok(Instruction {
+            program_id: fixed_ratio_trading::id(),
+            accounts: vec![
+                AccountMeta::new(system_authority.pubkey(), true),
+                AccountMeta::new(main_treasury_pda, false),
+                AccountMeta::new_readonly(sysvar::rent::id(), false),
+                AccountMeta::new(destination_account.pubkey(), false),
+                AccountMeta::new_readonly(system_state_pda, false),
+                AccountMeta::new_readonly(program_data_address, false),
+            ],
+            data: PoolInstruction::WithdrawTreasuryFees { amount }.try_to_vec()?,
+        })
+    };
+
+    // Withdrawing every lamport must be rejected
+    let full_balance = banks_client.get_balance(main_treasury_pda).await?;
+    let mut full_tx = Transaction::new_with_payer(&[withdraw_ix(full_balance)?], Some(&payer.pubkey()));
+    full_tx.sign(&[&payer, &system_authority], recent_blockhash);
+    let result = banks_client.process_transaction(full_tx).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(1055)),
+        "Full-balance withdrawal must fail with WouldBreachRentReserve"
+    );
+    assert_eq!(banks_client.get_balance(main_treasury_pda).await?, full_balance, "Rejected withdrawal must not move funds");
+
+    // Withdraw-all takes only what sits above the reserve
+    let mut all_tx = Transaction::new_with_payer(&[withdraw_ix(0)?], Some(&payer.pubkey()));
+    all_tx.sign(&[&payer, &system_authority], recent_blockhash);
+    banks_client.process_transaction(all_tx).await?;
+
+    let treasury_account = banks_client.get_account(main_treasury_pda).await?.expect("Treasury must still exist");
+    let rent = banks_client.get_rent().await?;
+    assert_eq!(
+        treasury_account.lamports,
+        rent.minimum_balance(treasury_account.data.len()),
+        "Treasury must keep exactly its rent-exempt minimum after withdraw-all"
+    );
+
+    println!("✅ TREASURY-004B: Rent reserve of {} lamports preserved", treasury_account.lamports);
+    Ok(())
+}
+
+/// TREASURY-004D: Requesting `TREASURY_WITHDRAW_MAX_ALLOWED` withdraws as much as is allowed
+/// 
+/// With 5 SOL funded the whole balance above the rent reserve is withdrawn; with 25 SOL the
+/// withdrawal is capped at the 10 SOL/hour rate limit. Either way the amount actually
+/// withdrawn is reported via return data, and a second max request within the cooldown fails.
+#[tokio::test]
+#[serial]
+async fn test_treasury_withdraw_max_allowed_reports_amount() -> Result<(), Box<dyn std::error::Error>> {
+    use solana_program_test::ProgramTest;
+    use solana_sdk::{signature::Keypair, system_instruction, sysvar};
+    use fixed_ratio_trading::utils::program_authority::get_program_data_address;
+    use crate::common::setup::initialize_treasury_system;
+
+    for funding in [5_000_000_000u64, 25_000_000_000u64] {
+        let program_test = ProgramTest::new(
+            "fixed_ratio_trading",
+            fixed_ratio_trading::id(),
+            processor!(test_adapter),
+        );
+        let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+        let system_authority = Keypair::new();
+        initialize_treasury_system(&mut banks_client, &payer, recent_blockhash, &system_authority).await?;
+
+        let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &fixed_ratio_trading::id());
+        let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &fixed_ratio_trading::id());
+        let destination_account = Keypair::new();
+
+        let mut fund_tx = Transaction::new_with_payer(
+            &[system_instruction::transfer(&payer.pubkey(), &main_treasury_pda, funding)],
+            Some(&payer.pubkey()),
+        );
+        fund_tx.sign(&[&payer], recent_blockhash);
+        banks_client.process_transaction(fund_tx).await?;
+
+        let withdraw_max_ix = Instruction {
+            program_id: fixed_ratio_trading::id(),
+            accounts: vec![
+                AccountMeta::new(system_authority.pubkey(), true),
+                AccountMeta::new(main_treasury_pda, false),
+                AccountMeta::new_readonly(sysvar::rent::id(), false),
+                AccountMeta::new(destination_account.pubkey(), false),
+                AccountMeta::new_readonly(system_state_pda, false),
+                AccountMeta::new_readonly(get_program_data_address(&fixed_ratio_trading::id()), false),
+            ],
+            data: PoolInstruction::WithdrawTreasuryFees { amount: TREASURY_WITHDRAW_MAX_ALLOWED }.try_to_vec()?,
+        };
+
+        let treasury_account = banks_client.get_account(main_treasury_pda).await?.expect("Treasury must exist");
+        let reserve = banks_client.get_rent().await?.minimum_balance(treasury_account.data.len());
+        let expected = (treasury_account.lamports - reserve).min(TREASURY_BASE_HOURLY_RATE);
+
+        let mut max_tx = Transaction::new_with_payer(&[withdraw_max_ix.clone()], Some(&payer.pubkey()));
+        max_tx.sign(&[&payer, &system_authority], recent_blockhash);
+        let result = banks_client.process_transaction_with_metadata(max_tx).await?;
+        result.result?;
+        let return_data = result.metadata.and_then(|metadata| metadata.return_data).map(|return_data| return_data.data).unwrap_or_default();
+        assert_eq!(return_data, expected.to_le_bytes().to_vec(), "Reported amount must be the amount withdrawn");
+        assert_eq!(banks_client.get_balance(destination_account.pubkey()).await?, expected);
+        assert!(banks_client.get_balance(main_treasury_pda).await? >= reserve, "Rent reserve must be preserved");
+
+        // The cooldown leaves nothing to withdraw within the hour
+        let blockhash = banks_client.get_new_latest_blockhash(&recent_blockhash).await?;
+        let mut again_tx = Transaction::new_with_payer(&[withdraw_max_ix], Some(&payer.pubkey()));
+        again_tx.sign(&[&payer, &system_authority], blockhash);
+        assert!(banks_client.process_transaction(again_tx).await.is_err(), "Second withdrawal within the cooldown must fail");
+
+        println!("✅ TREASURY-004D: Max-allowed withdrawal took {} of {} lamports funded", expected, funding);
+    }
+    Ok(())
+}
+
+/// TREASURY-004C: Treasury auto-compounding into a wrapped SOL pool
+/// 
+/// Configures a native-mint pool as the auto-compound pool, funds the treasury and
+/// compounds: the treasury's LP account receives LP tokens 1:1 for everything above
+/// the rent-exempt minimum plus the reserve, which stays behind. A second compound
+/// with nothing above the reserve fails with `NothingToCompound`.
+#[tokio::test]
+#[serial]
+async fn test_treasury_autocompound_keeps_reserve() -> Result<(), Box<dyn std::error::Error>> {
+    use solana_program_test::ProgramTest;
+    use solana_sdk::{
+        instruction::InstructionError,
+        program_pack::Pack,
+        signature::Keypair,
+        system_instruction,
+        transaction::TransactionError,
+    };
+    use fixed_ratio_trading::{error::PoolError, state::MainTreasuryState, PoolState};
+    use fixed_ratio_trading::utils::program_authority::get_program_data_address;
+    use crate::common::{
+        pool_helpers::create_pool_with_mint_keys,
+        setup::initialize_treasury_system,
+        tokens::{create_mint, create_token_account},
+    };
+
+    let program_test = ProgramTest::new(
+        "fixed_ratio_trading",
+        fixed_ratio_trading::id(),
+        processor!(test_adapter),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    let system_authority = Keypair::new();
+    initialize_treasury_system(&mut banks_client, &payer, recent_blockhash, &system_authority).await?;
+
+    let (main_treasury_pda, _) = Pubkey::find_program_address(
+        &[MAIN_TREASURY_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let (system_state_pda, _) = Pubkey::find_program_address(
+        &[SYSTEM_STATE_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let program_data_address = get_program_data_address(&fixed_ratio_trading::id());
+
+    // Pool with wrapped SOL on one side
+    let native_mint = spl_token::native_mint::id();
+    let other_mint = Keypair::new();
+    create_mint(&mut banks_client, &payer, recent_blockhash, &other_mint, None).await?;
+    let config = create_pool_with_mint_keys(
+        &mut banks_client, &payer, recent_blockhash, &native_mint, &other_mint.pubkey(), Some(2),
+    ).await?;
+    let native_is_a = config.token_a_mint == native_mint;
+    let (vault, lp_mint_seed) = if native_is_a {
+        (config.token_a_vault_pda, LP_TOKEN_A_MINT_SEED_PREFIX)
+    } else {
+        (config.token_b_vault_pda, LP_TOKEN_B_MINT_SEED_PREFIX)
+    };
+    let (lp_mint, _) = Pubkey::find_program_address(
+        &[lp_mint_seed, config.pool_state_pda.as_ref()],
+        &fixed_ratio_trading::id(),
+    );
+
+    // Treasury-owned wrapped SOL and LP token accounts
+    let treasury_wsol = Keypair::new();
+    let treasury_lp = Keypair::new();
+    create_token_account(&mut banks_client, &payer, recent_blockhash, &treasury_wsol, &native_mint, &main_treasury_pda).await?;
+    create_token_account(&mut banks_client, &payer, recent_blockhash, &treasury_lp, &lp_mint, &main_treasury_pda).await?;
+
+    let reserve = 1_000_000_000;
+    let set_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(system_authority.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(program_data_address, false),
+            AccountMeta::new_readonly(config.pool_state_pda, false),
+        ],
+        data: PoolInstruction::SetTreasuryAutocompound {
+            pool_id: config.pool_state_pda,
+            reserve_lamports: reserve,
+        }.try_to_vec()?,
+    };
+    let fund_ix = system_instruction::transfer(&payer.pubkey(), &main_treasury_pda, 3_000_000_000);
+    let mut setup_tx = Transaction::new_with_payer(&[set_ix, fund_ix], Some(&payer.pubkey()));
+    setup_tx.sign(&[&payer, &system_authority], recent_blockhash);
+    banks_client.process_transaction(setup_tx).await?;
+
+    let compound_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(system_authority.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(program_data_address, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new(treasury_wsol.pubkey(), false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(lp_mint, false),
+            AccountMeta::new(treasury_lp.pubkey(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: PoolInstruction::CompoundTreasury {}.try_to_vec()?,
+    };
+
+    let treasury_before = banks_client.get_account(main_treasury_pda).await?.expect("Treasury must exist");
+    let rent_minimum = banks_client.get_rent().await?.minimum_balance(treasury_before.data.len());
+    let expected_amount = treasury_before.lamports - rent_minimum - reserve;
+
+    let mut compound_tx = Transaction::new_with_payer(&[compound_ix.clone()], Some(&payer.pubkey()));
+    compound_tx.sign(&[&payer, &system_authority], recent_blockhash);
+    banks_client.process_transaction(compound_tx).await?;
+
+    let lp_account = banks_client.get_account(treasury_lp.pubkey()).await?.expect("Treasury LP account must exist");
+    let lp_balance = spl_token::state::Account::unpack(&lp_account.data)?.amount;
+    assert_eq!(lp_balance, expected_amount, "Treasury must receive LP tokens 1:1 for the compounded SOL");
+
+    let treasury_after = banks_client.get_account(main_treasury_pda).await?.expect("Treasury must exist");
+    assert_eq!(treasury_after.lamports, rent_minimum + reserve, "Treasury must keep its rent minimum plus the reserve");
+    let treasury_state = MainTreasuryState::try_from_slice(&treasury_after.data)?;
+    assert_eq!(treasury_state.autocompound_lp_tokens, expected_amount);
+    assert_eq!(treasury_state.total_balance, treasury_after.lamports);
+
+    let pool_account = banks_client.get_account(config.pool_state_pda).await?.expect("Pool must exist");
+    let pool_state = PoolState::try_from_slice(&pool_account.data)?;
+    let native_liquidity = if native_is_a { pool_state.total_token_a_liquidity } else { pool_state.total_token_b_liquidity };
+    assert_eq!(native_liquidity, expected_amount, "Compounded SOL must be added to the pool's wrapped SOL liquidity");
+
+    // Nothing is left above the reserve
+    let blockhash = banks_client.get_new_latest_blockhash(&recent_blockhash).await?;
+    let mut again_tx = Transaction::new_with_payer(&[compound_ix], Some(&payer.pubkey()));
+    again_tx.sign(&[&payer, &system_authority], blockhash);
+    let result = banks_client.process_transaction(again_tx).await;
+    let nothing_code = PoolError::NothingToCompound { balance: 0, retained: 0 }.error_code();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(nothing_code)),
+        "Compounding with nothing above the reserve must fail with NothingToCompound"
+    );
+
+    println!("✅ TREASURY-004C: Compounded {} lamports, {} kept in treasury", expected_amount, treasury_after.lamports);
+    Ok(())
+}
+
+/// TREASURY-004E: Withdrawing SPL tokens accrued by the treasury
+/// 
+/// Compounds treasury SOL into a wrapped SOL pool so the treasury holds LP tokens, then
+/// withdraws part of them and finally the rest (amount 0) to an admin-chosen token
+/// account. Amounts above the balance are rejected, and the auto-compound LP tally
+/// follows the withdrawals.
+#[tokio::test]
+#[serial]
+async fn test_withdraw_treasury_tokens() -> Result<(), Box<dyn std::error::Error>> {
+    use solana_program_test::ProgramTest;
+    use solana_sdk::{
+        instruction::InstructionError,
+        program_pack::Pack,
+        signature::Keypair,
+        system_instruction,
+        transaction::TransactionError,
+    };
+    use fixed_ratio_trading::{error::PoolError, state::MainTreasuryState};
+    use fixed_ratio_trading::utils::program_authority::get_program_data_address;
+    use crate::common::{
+        pool_helpers::create_pool_with_mint_keys,
+        setup::initialize_treasury_system,
+        tokens::{create_mint, create_token_account, get_token_balance},
+    };
+
+    let program_test = ProgramTest::new(
+        "fixed_ratio_trading",
+        fixed_ratio_trading::id(),
+        processor!(test_adapter),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    let system_authority = Keypair::new();
+    initialize_treasury_system(&mut banks_client, &payer, recent_blockhash, &system_authority).await?;
+
+    let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &fixed_ratio_trading::id());
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &fixed_ratio_trading::id());
+    let program_data_address = get_program_data_address(&fixed_ratio_trading::id());
+
+    // Compound treasury SOL into a wrapped SOL pool so the treasury holds LP tokens
+    let native_mint = spl_token::native_mint::id();
+    let other_mint = Keypair::new();
+    create_mint(&mut banks_client, &payer, recent_blockhash, &other_mint, None).await?;
+    let config = create_pool_with_mint_keys(
+        &mut banks_client, &payer, recent_blockhash, &native_mint, &other_mint.pubkey(), Some(2),
+    ).await?;
+    let native_is_a = config.token_a_mint == native_mint;
+    let (vault, lp_mint_seed) = if native_is_a {
+        (config.token_a_vault_pda, LP_TOKEN_A_MINT_SEED_PREFIX)
+    } else {
+        (config.token_b_vault_pda, LP_TOKEN_B_MINT_SEED_PREFIX)
+    };
+    let (lp_mint, _) = Pubkey::find_program_address(&[lp_mint_seed, config.pool_state_pda.as_ref()], &fixed_ratio_trading::id());
+
+    let treasury_wsol = Keypair::new();
+    let treasury_lp = Keypair::new();
+    let destination = Keypair::new();
+    create_token_account(&mut banks_client, &payer, recent_blockhash, &treasury_wsol, &native_mint, &main_treasury_pda).await?;
+    create_token_account(&mut banks_client, &payer, recent_blockhash, &treasury_lp, &lp_mint, &main_treasury_pda).await?;
+    create_token_account(&mut banks_client, &payer, recent_blockhash, &destination, &lp_mint, &payer.pubkey()).await?;
+
+    let set_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(system_authority.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(program_data_address, false),
+            AccountMeta::new_readonly(config.pool_state_pda, false),
+        ],
+        data: PoolInstruction::SetTreasuryAutocompound { pool_id: config.pool_state_pda, reserve_lamports: 0 }.try_to_vec()?,
+    };
+    let fund_ix = system_instruction::transfer(&payer.pubkey(), &main_treasury_pda, 1_000_000_000);
+    let compound_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(system_authority.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(program_data_address, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new(treasury_wsol.pubkey(), false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(lp_mint, false),
+            AccountMeta::new(treasury_lp.pubkey(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: PoolInstruction::CompoundTreasury {}.try_to_vec()?,
+    };
+    let mut setup_tx = Transaction::new_with_payer(&[set_ix, fund_ix, compound_ix], Some(&payer.pubkey()));
+    setup_tx.sign(&[&payer, &system_authority], recent_blockhash);
+    banks_client.process_transaction(setup_tx).await?;
+    let accrued = get_token_balance(&mut banks_client, &treasury_lp.pubkey()).await;
+    assert!(accrued > 0, "Compounding must leave LP tokens in the treasury");
+
+    let withdraw_ix = |signer: &Pubkey, amount: u64| Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(program_data_address, false),
+            AccountMeta::new(treasury_lp.pubkey(), false),
+            AccountMeta::new(destination.pubkey(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: PoolInstruction::WithdrawTreasuryTokens { token_mint: lp_mint, amount }.try_to_vec().unwrap(),
+    };
+
+    // More than the treasury holds is rejected
+    let blockhash = banks_client.get_latest_blockhash().await?;
+    let mut tx = Transaction::new_with_payer(&[withdraw_ix(&system_authority.pubkey(), accrued + 1)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &system_authority], blockhash);
+    let insufficient_code = PoolError::InsufficientFunds { required: 0, available: 0, account: Pubkey::default() }.error_code();
+    assert_eq!(
+        banks_client.process_transaction(tx).await.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(insufficient_code)),
+    );
+
+    // Part of the balance, then the rest with amount 0
+    let part = accrued / 4;
+    for (amount, expected_destination) in [(part, part), (0, accrued)] {
+        let blockhash = banks_client.get_new_latest_blockhash(&blockhash).await?;
+        let mut tx = Transaction::new_with_payer(&[withdraw_ix(&system_authority.pubkey(), amount)], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &system_authority], blockhash);
+        banks_client.process_transaction(tx).await?;
+
+        assert_eq!(get_token_balance(&mut banks_client, &destination.pubkey()).await, expected_destination);
+        assert_eq!(get_token_balance(&mut banks_client, &treasury_lp.pubkey()).await, accrued - expected_destination);
+        let treasury_account = banks_client.get_account(main_treasury_pda).await?.expect("Treasury must exist");
+        let treasury_state = MainTreasuryState::try_from_slice(&treasury_account.data)?;
+        assert_eq!(treasury_state.autocompound_lp_tokens, accrued - expected_destination, "LP tally must follow the withdrawal");
+    }
+
+    println!("✅ TREASURY-004E: Withdrew {} treasury LP tokens to an admin-chosen account", accrued);
+    Ok(())
+}
+
+/// TREASURY-004F: Treasury SOL withdrawals go to the destination account chosen per call
+/// 
+/// The authority may route fees to any wallet passed as the destination account,
+/// including one it does not control. The destination must be a system account;
+/// otherwise the withdrawal fails with `InvalidWithdrawalDestination`.
+#[tokio::test]
+#[serial]
+async fn test_treasury_withdrawal_to_chosen_destination() -> Result<(), Box<dyn std::error::Error>> {
+    use solana_program_test::ProgramTest;
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::Keypair,
+        system_instruction,
+        sysvar,
+        transaction::TransactionError,
+    };
+    use fixed_ratio_trading::error::PoolError;
+    use fixed_ratio_trading::utils::program_authority::get_program_data_address;
+    use crate::common::setup::initialize_treasury_system;
+
+    let program_test = ProgramTest::new(
+        "fixed_ratio_trading",
+        fixed_ratio_trading::id(),
+        processor!(test_adapter),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    let system_authority = Keypair::new();
+    initialize_treasury_system(&mut banks_client, &payer, recent_blockhash, &system_authority).await?;
+
+    let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &fixed_ratio_trading::id());
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &fixed_ratio_trading::id());
+    let program_data_address = get_program_data_address(&fixed_ratio_trading::id());
+
+    let mut fund_tx = Transaction::new_with_payer(
+        &[system_instruction::transfer(&payer.pubkey(), &main_treasury_pda, 5_000_000_000)],
+        Some(&payer.pubkey()),
+    );
+    fund_tx.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(fund_tx).await?;
+
+    let withdraw_ix = |destination_account: Pubkey| -> Result<Instruction, std::io::Error> {
+        Ok(Instruction {
+            program_id: fixed_ratio_trading::id(),
+            accounts: vec![
+                AccountMeta::new(system_authority.pubkey(), true),
+                AccountMeta::new(main_treasury_pda, false),
+                AccountMeta::new_readonly(sysvar::rent::id(), false),
+                AccountMeta::new(destination_account, false),
+                AccountMeta::new_readonly(system_state_pda, false),
+                AccountMeta::new_readonly(program_data_address, false),
+            ],
+            data: PoolInstruction::WithdrawTreasuryFees { amount: 1_000_000_000 }.try_to_vec()?,
+        })
+    };
+    let invalid_destination = |destination: Pubkey| TransactionError::InstructionError(
+        0,
+        InstructionError::Custom(PoolError::InvalidWithdrawalDestination { destination }.error_code()),
+    );
+
+    // An operational wallet the authority holds no key for
+    let operations_wallet = Keypair::new().pubkey();
+
+    // Program-owned accounts cannot receive treasury SOL
+    let mut program_owned_tx = Transaction::new_with_payer(&[withdraw_ix(system_state_pda)?], Some(&payer.pubkey()));
+    program_owned_tx.sign(&[&payer, &system_authority], recent_blockhash);
+    assert_eq!(banks_client.process_transaction(program_owned_tx).await.unwrap_err().unwrap(), invalid_destination(system_state_pda));
+
+    // The explicitly chosen wallet receives the withdrawal even though the authority does not control it
+    let treasury_before = banks_client.get_balance(main_treasury_pda).await?;
+    let mut tx = Transaction::new_with_payer(&[withdraw_ix(operations_wallet)?], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &system_authority], recent_blockhash);
+    banks_client.process_transaction(tx).await?;
+
+    assert_eq!(banks_client.get_balance(operations_wallet).await?, 1_000_000_000);
+    assert_eq!(banks_client.get_balance(main_treasury_pda).await?, treasury_before - 1_000_000_000);
+
+    println!("✅ TREASURY-004F: Withdrew 1 SOL to an operational wallet chosen by the authority");
+    Ok(())
+}
+
+/// TREASURY-004G: Treasury SOL withdrawals are capped per 24-hour window
+/// 
+/// With 6,000 SOL donated the hourly tier allows 1,000 SOL per withdrawal, so only the
+/// `TREASURY_MAX_WITHDRAWAL_PER_WINDOW` cap stops a second withdrawal an hour later.
+/// Once `TREASURY_WITHDRAWAL_WINDOW_SECONDS` have passed the allowance resets.
+#[tokio::test]
+#[serial]
+async fn test_treasury_withdrawal_window_cap() -> Result<(), Box<dyn std::error::Error>> {
+    use solana_program::clock::Clock;
+    use solana_program_test::ProgramTest;
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::Keypair,
+        system_instruction,
+        sysvar,
+        transaction::TransactionError,
+    };
+    use fixed_ratio_trading::error::PoolError;
+    use fixed_ratio_trading::state::MainTreasuryState;
+    use fixed_ratio_trading::utils::program_authority::get_program_data_address;
+    use crate::common::setup::initialize_treasury_system;
+
+    const SOL: u64 = 1_000_000_000;
+
+    let program_test = ProgramTest::new(
+        "fixed_ratio_trading",
+        fixed_ratio_trading::id(),
+        processor!(test_adapter),
+    );
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let system_authority = Keypair::new();
+    initialize_treasury_system(&mut context.banks_client, &payer, context.last_blockhash, &system_authority).await?;
+
+    let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &fixed_ratio_trading::id());
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &fixed_ratio_trading::id());
+    let program_data_address = get_program_data_address(&fixed_ratio_trading::id());
+    let destination = Keypair::new().pubkey();
+
+    // A donation (unlike a plain transfer) updates the balance the hourly tier is computed from
+    let mut fund_tx = Transaction::new_with_payer(
+        &[Instruction {
+            program_id: fixed_ratio_trading::id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(main_treasury_pda, false),
+                AccountMeta::new_readonly(system_state_pda, false),
+                AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            ],
+            data: PoolInstruction::DonateSol { amount: 6_000 * SOL, message: String::new() }.try_to_vec()?,
+        }],
+        Some(&payer.pubkey()),
+    );
+    fund_tx.sign(&[&payer], context.last_blockhash);
+    context.banks_client.process_transaction(fund_tx).await?;
+
+    let window_start = context.banks_client.get_sysvar::<Clock>().await?.unix_timestamp;
+    let withdraw_ix = |amount: u64| Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new(system_authority.pubkey(), true),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new_readonly(program_data_address, false),
+        ],
+        data: PoolInstruction::WithdrawTreasuryFees { amount }.try_to_vec().unwrap(),
+    };
+    // Moves the clock to `timestamp` and withdraws `amount`
+    async fn withdraw_at(
+        context: &mut solana_program_test::ProgramTestContext,
+        signers: [&Keypair; 2],
+        instruction: Instruction,
+        timestamp: i64,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let mut clock = context.banks_client.get_sysvar::<Clock>().await?;
+        clock.unix_timestamp = timestamp;
+        context.set_sysvar(&clock);
+        let blockhash = context.banks_client.get_new_latest_blockhash(&context.last_blockhash).await?;
+        context.last_blockhash = blockhash;
+        let mut tx = Transaction::new_with_payer(&[instruction], Some(&signers[0].pubkey()));
+        tx.sign(&signers, blockhash);
+        context.banks_client.process_transaction(tx).await
+    }
+    let signers = [&payer, &system_authority];
+    let rate_exceeded = |requested: u64, remaining: u64| TransactionError::InstructionError(
+        0,
+        InstructionError::Custom(PoolError::WithdrawalRateExceeded { requested, remaining }.error_code()),
+    );
+
+    // Within the cap: 600 SOL, then another 400 SOL after the hourly cooldown
+    withdraw_at(&mut context, signers, withdraw_ix(600 * SOL), window_start).await?;
+    withdraw_at(&mut context, signers, withdraw_ix(400 * SOL), window_start + 2 * 3600).await?;
+    assert_eq!(context.banks_client.get_balance(destination).await?, TREASURY_MAX_WITHDRAWAL_PER_WINDOW);
+
+    // The window's 1,000 SOL are used up although the hourly limit would allow more
+    let result = withdraw_at(&mut context, signers, withdraw_ix(10 * SOL), window_start + 4 * 3600).await;
+    assert_eq!(result.unwrap_err().unwrap(), rate_exceeded(10 * SOL, 0));
+
+    let treasury = context.banks_client.get_account(main_treasury_pda).await?.expect("Treasury must exist");
+    let treasury_state = MainTreasuryState::try_from_slice(&treasury.data)?;
+    assert_eq!(treasury_state.withdrawal_window_start, window_start);
+    assert_eq!(treasury_state.withdrawn_in_window, TREASURY_MAX_WITHDRAWAL_PER_WINDOW);
+
+    // A full window after it opened the allowance resets
+    withdraw_at(&mut context, signers, withdraw_ix(600 * SOL), window_start + TREASURY_WITHDRAWAL_WINDOW_SECONDS).await?;
+    assert_eq!(context.banks_client.get_balance(destination).await?, TREASURY_MAX_WITHDRAWAL_PER_WINDOW + 600 * SOL);
+
+    println!("✅ TREASURY-004G: 1,000 SOL window cap enforced and reset after 24 hours");
+    Ok(())
+}
+
+/// TREASURY-004H: A main treasury created with the original 136-byte layout (before
+/// auto-compounding, the withdrawal window and recent donations) stays readable, rejects
+/// writes until migrated, and is grown by MigrateTreasuryState with its counters intact
+#[tokio::test]
+#[serial]
+async fn test_migrate_treasury_state_from_original_layout() -> Result<(), Box<dyn std::error::Error>> {
+    use solana_program_test::ProgramTest;
+    use solana_sdk::{account::AccountSharedData, signature::Keypair};
+    use fixed_ratio_trading::state::MainTreasuryState;
+    use fixed_ratio_trading::utils::program_authority::get_program_data_address;
+    use crate::common::setup::initialize_treasury_system;
+
+    let program_test = ProgramTest::new(
+        "fixed_ratio_trading",
+        fixed_ratio_trading::id(),
+        processor!(test_adapter),
+    );
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let system_authority = Keypair::new();
+    initialize_treasury_system(&mut context.banks_client, &payer, context.last_blockhash, &system_authority).await?;
+
+    let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &fixed_ratio_trading::id());
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &fixed_ratio_trading::id());
+    let program_data_address = get_program_data_address(&fixed_ratio_trading::id());
+    let rent = context.banks_client.get_rent().await?;
+
+    // Rewrite the treasury as a deployed original-layout account with some history
+    let legacy_rent = rent.minimum_balance(MainTreasuryState::V1_LEN);
+    let mut legacy_state = MainTreasuryState::new_with_rent_exemption(legacy_rent);
+    legacy_state.pool_creation_count = 7;
+    legacy_state.donation_count = 3;
+    legacy_state.total_donations = 4_000_000_000;
+    let mut legacy_bytes = legacy_state.try_to_vec()?;
+    legacy_bytes.truncate(MainTreasuryState::V1_LEN);
+    let mut legacy_account = AccountSharedData::new(legacy_rent, MainTreasuryState::V1_LEN, &fixed_ratio_trading::id());
+    legacy_account.set_data_from_slice(&legacy_bytes);
+    context.set_account(&main_treasury_pda, &legacy_account);
+
+    let loaded = MainTreasuryState::from_versioned_data(&legacy_bytes)?;
+    assert_eq!((loaded.pool_creation_count, loaded.donation_count), (7, 3));
+    assert_eq!(loaded.autocompound_pool, Pubkey::default(), "Missing fields read as zero");
+    assert_eq!(loaded.next_donation_slot, 0);
+
+    let donate_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: PoolInstruction::DonateSol { amount: 1_000_000_000, message: "after migration".to_string() }.try_to_vec()?,
+    };
+    let migrate_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new(system_authority.pubkey(), true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(main_treasury_pda, false),
+            AccountMeta::new_readonly(program_data_address, false),
+        ],
+        data: PoolInstruction::MigrateTreasuryState {}.try_to_vec()?,
+    };
+    let fund_authority_ix = solana_sdk::system_instruction::transfer(&payer.pubkey(), &system_authority.pubkey(), 1_000_000_000);
+
+    // The larger layout cannot be written into the old account
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(&[donate_ix.clone()], Some(&payer.pubkey()), &[&payer], blockhash);
+    assert!(context.banks_client.process_transaction(transaction).await.is_err(), "Donation must fail before migration");
+
+    // Only the admin authority may migrate
+    let stranger = Keypair::new();
+    let mut stranger_ix = migrate_ix.clone();
+    stranger_ix.accounts[0] = AccountMeta::new(stranger.pubkey(), true);
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(&[stranger_ix], Some(&payer.pubkey()), &[&payer, &stranger], blockhash);
+    assert!(context.banks_client.process_transaction(transaction).await.is_err(), "Only the admin may migrate");
+
+    // Admin migrates, paying the extra rent
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(&[fund_authority_ix, migrate_ix], Some(&payer.pubkey()), &[&payer, &system_authority], blockhash);
+    context.banks_client.process_transaction(transaction).await?;
+
+    let account = context.banks_client.get_account(main_treasury_pda).await?.expect("Treasury should exist");
+    assert_eq!(account.data.len(), MainTreasuryState::LEN);
+    assert!(rent.is_exempt(account.lamports, account.data.len()), "Migrated treasury must stay rent-exempt");
+    let migrated = MainTreasuryState::from_versioned_data(&account.data)?;
+    assert_eq!((migrated.pool_creation_count, migrated.donation_count, migrated.total_donations), (7, 3, 4_000_000_000));
+    assert_eq!(migrated.rent_exempt_minimum, rent.minimum_balance(MainTreasuryState::LEN));
+
+    // Writes succeed once migrated; a fresh blockhash keeps the bank from replaying the cached failure
+    let blockhash = context.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    let transaction = Transaction::new_signed_with_payer(&[donate_ix], Some(&payer.pubkey()), &[&payer], blockhash);
+    context.banks_client.process_transaction(transaction).await?;
+    let account = context.banks_client.get_account(main_treasury_pda).await?.expect("Treasury should exist");
+    let donated = MainTreasuryState::from_versioned_data(&account.data)?;
+    assert_eq!(donated.donation_count, 4);
+    assert_eq!(donated.recent_donations[0].message(), "after migration");
+
+    println!("✅ TREASURY-004H: Original-layout treasury migrated to {} bytes", MainTreasuryState::LEN);
+    Ok(())
+}
+
 /// TREASURY-005: Specific test for GetTreasuryInfo instruction
 /// 
 /// This test isolates the GetTreasuryInfo instruction to verify it works correctly
@@ -1632,7 +2380,7 @@ async fn test_enhanced_counter_system_integration() -> Result<(), Box<dyn std::e
 async fn test_analytics_methods_unit_test() -> Result<(), Box<dyn std::error::Error>> {
     println!("🧪 Testing TREASURY-010: Analytics methods unit test...");
     
-    use fixed_ratio_trading::state::MainTreasuryState;
+    use fixed_ratio_trading::{constants::RECENT_DONATIONS_CAPACITY, state::{DonationRecord, MainTreasuryState}};
     
     // Create a treasury state with known values for testing analytics
     let treasury_state = MainTreasuryState {
@@ -1653,6 +2401,13 @@ async fn test_analytics_methods_unit_test() -> Result<(), Box<dyn std::error::Er
         last_withdrawal_timestamp: 1640995100,
         donation_count: 0,
         total_donations: 0,
+        autocompound_pool: Pubkey::default(),
+        autocompound_reserve: 0,
+        autocompound_lp_tokens: 0,
+        withdrawal_window_start: 0,
+        withdrawn_in_window: 0,
+        recent_donations: [DonationRecord::default(); RECENT_DONATIONS_CAPACITY],
+        next_donation_slot: 0,
     };
     
     println!("🔢 Testing analytics calculations with known data...");
@@ -1708,4 +1708,166 @@ async fn test_update_liquidity_fee_only_minimal() {
             panic!("🚨 ERROR: Liquidity fee update transaction failed: {:?}", e);
         }
     }
-} 
\ No newline at end of file
+} 
+/// Test the owner-set swap fee band bounds UpdatePoolFees: with a band of
+/// [100_000, 300_000] lamports, 50_000 and 400_000 are rejected and 200_000 is accepted
+#[tokio::test]
+async fn test_swap_fee_band_bounds_fee_updates() -> TestResult {
+    let program_id = fixed_ratio_trading::id();
+    let (program_data_account, _bump) = Pubkey::find_program_address(
+        &[program_id.as_ref()],
+        &solana_program::bpf_loader_upgradeable::id()
+    );
+    
+    let mut program_test = ProgramTest::new(
+        "fixed-ratio-trading",
+        program_id,
+        processor!(test_adapter),
+    );
+    
+    let upgrade_authority = Keypair::new();
+    let pool_owner = Keypair::new();
+    
+    let mut account_data = Vec::new();
+    account_data.extend_from_slice(&3u32.to_le_bytes()); // ProgramData type
+    account_data.push(1); // has upgrade authority
+    account_data.extend_from_slice(upgrade_authority.pubkey().as_ref());
+    account_data.extend_from_slice(&0u64.to_le_bytes());
+    account_data.extend_from_slice(&[0u8; 100]);
+    program_test.add_account(
+        program_data_account,
+        Account {
+            lamports: 1_000_000_000,
+            data: account_data,
+            owner: solana_program::bpf_loader_upgradeable::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    
+    let token_a_mint = Pubkey::new_unique();
+    let token_b_mint = Pubkey::new_unique();
+    let pool_state_pda = Pubkey::find_program_address(
+        &[
+            b"pool_state",
+            token_a_mint.as_ref(),
+            token_b_mint.as_ref(),
+            &[1u64.to_le_bytes(), 1u64.to_le_bytes()].concat(),
+        ],
+        &program_id,
+    ).0;
+    
+    let mut initial_pool_state = PoolState::default();
+    initial_pool_state.owner = pool_owner.pubkey();
+    initial_pool_state.token_a_mint = token_a_mint;
+    initial_pool_state.token_b_mint = token_b_mint;
+    initial_pool_state.ratio_a_numerator = 1;
+    initial_pool_state.ratio_b_denominator = 1;
+    initial_pool_state.contract_liquidity_fee = DEPOSIT_WITHDRAWAL_FEE;
+    initial_pool_state.swap_contract_fee = SWAP_CONTRACT_FEE;
+    
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &program_id);
+    program_test.add_account(
+        system_state_pda,
+        Account {
+            lamports: 1_000_000,
+            data: SystemState::new(upgrade_authority.pubkey()).try_to_vec().unwrap(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    program_test.add_account(
+        pool_state_pda,
+        Account {
+            lamports: 10_000_000,
+            data: initial_pool_state.try_to_vec().unwrap(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    for funded in [&upgrade_authority, &pool_owner] {
+        program_test.add_account(
+            funded.pubkey(),
+            Account { lamports: 1_000_000_000, ..Account::default() },
+        );
+    }
+    
+    let (mut banks_client, _payer, recent_blockhash) = program_test.start().await;
+    
+    let set_band = |signer: &Keypair, min_allowed_fee: u64, max_allowed_fee: u64| Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(signer.pubkey(), true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(pool_state_pda, false),
+        ],
+        data: PoolInstruction::SetSwapFeeBand { min_allowed_fee, max_allowed_fee, pool_id: pool_state_pda }.try_to_vec().unwrap(),
+    };
+    
+    // Only the pool owner may set the band
+    let transaction = Transaction::new_signed_with_payer(
+        &[set_band(&upgrade_authority, 100_000, 300_000)],
+        Some(&upgrade_authority.pubkey()),
+        &[&upgrade_authority],
+        recent_blockhash,
+    );
+    let error = banks_client.process_transaction(transaction).await.expect_err("Non-owner must not set the band");
+    assert_eq!(error.unwrap(), TransactionError::InstructionError(0, InstructionError::Custom(1012)));
+    
+    let transaction = Transaction::new_signed_with_payer(
+        &[set_band(&pool_owner, 100_000, 300_000)],
+        Some(&pool_owner.pubkey()),
+        &[&pool_owner],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(transaction).await?;
+    
+    // Swap fees below and above the band are rejected
+    for rejected_fee in [50_000u64, 400_000u64] {
+        let update_instruction = create_fee_update_instruction(
+            pool_state_pda,
+            &upgrade_authority,
+            FEE_UPDATE_FLAG_SWAP,
+            DEPOSIT_WITHDRAWAL_FEE,
+            rejected_fee,
+        )?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[update_instruction],
+            Some(&upgrade_authority.pubkey()),
+            &[&upgrade_authority],
+            recent_blockhash,
+        );
+        let error = banks_client.process_transaction(transaction).await.expect_err("Fee outside the band must be rejected");
+        assert_eq!(
+            error.unwrap(),
+            TransactionError::InstructionError(0, InstructionError::Custom(1074)), // SwapFeeOutsideBand
+            "Swap fee {} should be outside the band", rejected_fee
+        );
+    }
+    
+    // A swap fee within the band is accepted
+    let update_instruction = create_fee_update_instruction(
+        pool_state_pda,
+        &upgrade_authority,
+        FEE_UPDATE_FLAG_SWAP,
+        DEPOSIT_WITHDRAWAL_FEE,
+        200_000,
+    )?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[update_instruction],
+        Some(&upgrade_authority.pubkey()),
+        &[&upgrade_authority],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(transaction).await?;
+    
+    let pool_account = banks_client.get_account(pool_state_pda).await?.ok_or("Pool state account not found")?;
+    let pool_state = PoolState::try_from_slice(&pool_account.data)?;
+    assert_eq!((pool_state.min_allowed_fee, pool_state.max_allowed_fee), (100_000, 300_000));
+    assert_eq!(pool_state.swap_contract_fee, 200_000);
+    
+    println!("✅ Swap fee band [100000, 300000] enforced on fee updates");
+    Ok(())
+}
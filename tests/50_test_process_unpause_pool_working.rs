@@ -271,4 +271,481 @@ async fn test_process_pool_unpause_all_operations() -> TestResult {
     println!("🎉 process_pool_unpause ALL operations test passed!");
     
     Ok(())
-} 
\ No newline at end of file
+} 
+/// Test that a pool paused with an auto-unpause time reopens on its own
+/// 
+/// 1. Pause liquidity operations with `auto_unpause_at` one hour ahead
+/// 2. Verify a deposit is rejected with PoolLiquidityPaused before that time
+/// 3. Move the clock past `auto_unpause_at` and verify the same deposit succeeds
+#[tokio::test]
+#[serial]
+async fn test_pool_auto_unpause_after_timer() -> TestResult {
+    use common::{
+        setup::{create_program_test, initialize_treasury_system, simulate_and_get_return_data, transfer_sol},
+        tokens::{create_mint, create_token_account, mint_tokens},
+        pool_helpers::create_pool_new_pattern,
+        liquidity_helpers::create_deposit_instruction_standardized,
+    };
+    use fixed_ratio_trading::error::PoolError;
+    use solana_sdk::{clock::Clock, instruction::InstructionError, transaction::TransactionError};
+
+    let mut context = create_program_test().start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let blockhash = context.last_blockhash;
+
+    let multiple_mint = Keypair::new();
+    let base_mint = Keypair::new();
+    create_mint(&mut context.banks_client, &payer, blockhash, &multiple_mint, None).await?;
+    create_mint(&mut context.banks_client, &payer, blockhash, &base_mint, None).await?;
+    initialize_treasury_system(&mut context.banks_client, &payer, blockhash, &Keypair::new()).await?;
+    let config = create_pool_new_pattern(&mut context.banks_client, &payer, blockhash, &multiple_mint, &base_mint, Some(2)).await?;
+
+    let (lp_token_a_mint, _) = Pubkey::find_program_address(
+        &[LP_TOKEN_A_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()],
+        &fixed_ratio_trading::id(),
+    );
+    let (lp_token_b_mint, _) = Pubkey::find_program_address(
+        &[LP_TOKEN_B_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()],
+        &fixed_ratio_trading::id(),
+    );
+    let (system_state_pda, _) = Pubkey::find_program_address(
+        &[SYSTEM_STATE_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let program_data_account = fixed_ratio_trading::utils::program_authority::get_program_data_address(
+        &fixed_ratio_trading::id()
+    );
+
+    let user = Keypair::new();
+    let user_token_a = Keypair::new();
+    let user_lp_a = Keypair::new();
+    transfer_sol(&mut context.banks_client, &payer, blockhash, &payer, &user.pubkey(), 1_000_000_000).await?;
+    create_token_account(&mut context.banks_client, &payer, blockhash, &user_token_a, &config.token_a_mint, &user.pubkey()).await?;
+    create_token_account(&mut context.banks_client, &payer, blockhash, &user_lp_a, &lp_token_a_mint, &user.pubkey()).await?;
+    mint_tokens(&mut context.banks_client, &payer, blockhash, &config.token_a_mint, &user_token_a.pubkey(), &payer, 1_000_000).await?;
+
+    // Step 1: Pause liquidity with a one-hour timer
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await?;
+    let auto_unpause_at = clock.unix_timestamp + 3_600;
+    let pause_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(system_state_pda, false),
+            AccountMeta::new(config.pool_state_pda, false),
+            AccountMeta::new_readonly(program_data_account, false),
+        ],
+        data: PoolInstruction::PausePoolUntil {
+            pause_flags: PAUSE_FLAG_LIQUIDITY,
+            pool_id: config.pool_state_pda,
+            auto_unpause_at,
+        }.try_to_vec()?,
+    };
+    let pause_tx = Transaction::new_signed_with_payer(&[pause_ix], Some(&payer.pubkey()), &[&payer], blockhash);
+    context.banks_client.process_transaction(pause_tx).await?;
+
+    let pool_account = context.banks_client.get_account(config.pool_state_pda).await?.unwrap();
+    let pool_state = PoolState::try_from_slice(&pool_account.data)?;
+    assert!(pool_state.liquidity_paused(), "Pool liquidity should be paused");
+    assert_eq!(pool_state.auto_unpause_at, auto_unpause_at, "Auto-unpause time should be stored");
+
+    // GetPoolPauseStatus reports the effective pause and the timer
+    let pause_status_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![AccountMeta::new_readonly(config.pool_state_pda, false)],
+        data: PoolInstruction::GetPoolPauseStatus {}.try_to_vec()?,
+    };
+    let mut expected_status = vec![PAUSE_DEPOSITS | PAUSE_WITHDRAWALS];
+    expected_status.extend_from_slice(&auto_unpause_at.to_le_bytes());
+    let status = simulate_and_get_return_data(&mut context.banks_client, &payer, pause_status_ix.clone()).await?;
+    assert_eq!(status, expected_status, "Liquidity should read as paused before the timer elapses");
+
+    // Step 2: Deposits are blocked before the timer elapses
+    let deposit_ix = create_deposit_instruction_standardized(
+        &user.pubkey(),
+        &user_token_a.pubkey(),
+        &user_lp_a.pubkey(),
+        &config,
+        &lp_token_a_mint,
+        &lp_token_b_mint,
+        &PoolInstruction::Deposit {
+            deposit_token_mint: config.token_a_mint,
+            amount: 100_000,
+            pool_id: config.pool_state_pda,
+        },
+    )?;
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let mut deposit_tx = Transaction::new_with_payer(&[deposit_ix.clone()], Some(&payer.pubkey()));
+    deposit_tx.sign(&[&payer, &user], blockhash);
+    let result = context.banks_client.process_transaction(deposit_tx).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(PoolError::PoolLiquidityPaused.error_code())),
+        "Deposit before auto-unpause time must be rejected"
+    );
+    println!("✅ Deposit blocked while the pause timer is running");
+
+    // Step 3: Deposits resume once the clock passes the timer
+    clock.unix_timestamp = auto_unpause_at + 1;
+    context.set_sysvar(&clock);
+
+    let blockhash = context.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    let mut deposit_tx = Transaction::new_with_payer(&[deposit_ix], Some(&payer.pubkey()));
+    deposit_tx.sign(&[&payer, &user], blockhash);
+    context.banks_client.process_transaction(deposit_tx).await?;
+    println!("✅ Deposit allowed after the auto-unpause time");
+
+    // The pause flags are still stored, but the status no longer reports them
+    let pool_account = context.banks_client.get_account(config.pool_state_pda).await?.unwrap();
+    assert!(PoolState::try_from_slice(&pool_account.data)?.liquidity_paused(), "Pause flags stay set until the next pause or unpause");
+    let mut expected_status = vec![0];
+    expected_status.extend_from_slice(&auto_unpause_at.to_le_bytes());
+    let status = simulate_and_get_return_data(&mut context.banks_client, &payer, pause_status_ix).await?;
+    assert_eq!(status, expected_status, "Elapsed auto-unpause must report every operation enabled");
+
+    Ok(())
+}
+
+/// Test GetInitState across the pool lifecycle
+/// 
+/// A derived but never-created pool reads as Uninitialized, a fresh pool as Active,
+/// a swap-paused pool as Paused, and the pool is Active again once unpaused.
+#[tokio::test]
+#[serial]
+async fn test_get_init_state_across_pool_lifecycle() -> TestResult {
+    use common::setup::simulate_and_get_return_data;
+    use fixed_ratio_trading::types::PoolInitState;
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let (system_state_pda, _) = Pubkey::find_program_address(
+        &[SYSTEM_STATE_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let program_data_account = fixed_ratio_trading::utils::program_authority::get_program_data_address(
+        &fixed_ratio_trading::id()
+    );
+
+    let get_init_state_ix = |pool: Pubkey| Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![AccountMeta::new_readonly(pool, false)],
+        data: PoolInstruction::GetInitState {}.try_to_vec().unwrap(),
+    };
+
+    // Uninitialized: the same mints at a ratio nobody has created
+    let (missing_pool, _) = Pubkey::find_program_address(
+        &[
+            POOL_STATE_SEED_PREFIX,
+            foundation.pool_config.token_a_mint.as_ref(),
+            foundation.pool_config.token_b_mint.as_ref(),
+            &7u64.to_le_bytes(),
+            &3u64.to_le_bytes(),
+        ],
+        &fixed_ratio_trading::id(),
+    );
+    let data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, get_init_state_ix(missing_pool)).await?;
+    assert_eq!(PoolInitState::try_from_slice(&data)?, PoolInitState::Uninitialized);
+
+    // Active: freshly created pool
+    let data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, get_init_state_ix(pool_state_pda)).await?;
+    assert_eq!(PoolInitState::try_from_slice(&data)?, PoolInitState::Active);
+
+    // Paused: pausing swaps alone is enough
+    let owner_accounts = vec![
+        AccountMeta::new(foundation.env.payer.pubkey(), true),
+        AccountMeta::new(system_state_pda, false),
+        AccountMeta::new(pool_state_pda, false),
+        AccountMeta::new_readonly(program_data_account, false),
+    ];
+    let pause_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: owner_accounts.clone(),
+        data: PoolInstruction::PausePool {
+            pause_flags: PAUSE_FLAG_SWAPS,
+            pool_id: pool_state_pda,
+        }.try_to_vec()?,
+    };
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let pause_tx = Transaction::new_signed_with_payer(&[pause_ix], Some(&foundation.env.payer.pubkey()), &[&foundation.env.payer], blockhash);
+    foundation.env.banks_client.process_transaction(pause_tx).await?;
+
+    let data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, get_init_state_ix(pool_state_pda)).await?;
+    assert_eq!(PoolInitState::try_from_slice(&data)?, PoolInitState::Paused);
+
+    // Active again after unpausing
+    let unpause_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: owner_accounts,
+        data: PoolInstruction::UnpausePool {
+            unpause_flags: PAUSE_FLAG_SWAPS,
+            pool_id: pool_state_pda,
+        }.try_to_vec()?,
+    };
+    let blockhash = foundation.env.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    let unpause_tx = Transaction::new_signed_with_payer(&[unpause_ix], Some(&foundation.env.payer.pubkey()), &[&foundation.env.payer], blockhash);
+    foundation.env.banks_client.process_transaction(unpause_tx).await?;
+
+    let data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, get_init_state_ix(pool_state_pda)).await?;
+    assert_eq!(PoolInitState::try_from_slice(&data)?, PoolInitState::Active);
+
+    Ok(())
+}
+
+/// Test GetPoolActivity dormancy
+/// 
+/// A fresh pool is not dormant; once the clock moves past the dormancy window with
+/// no swaps, the same pool reports dormant and its age covers the elapsed time.
+#[tokio::test]
+#[serial]
+async fn test_get_pool_activity_dormant_after_window() -> TestResult {
+    use common::{
+        setup::{create_program_test, initialize_treasury_system, simulate_and_get_return_data},
+        tokens::create_mint,
+        pool_helpers::create_pool_new_pattern,
+    };
+    use fixed_ratio_trading::types::PoolActivity;
+    use solana_sdk::clock::Clock;
+
+    let mut context = create_program_test().start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let blockhash = context.last_blockhash;
+
+    let multiple_mint = Keypair::new();
+    let base_mint = Keypair::new();
+    create_mint(&mut context.banks_client, &payer, blockhash, &multiple_mint, None).await?;
+    create_mint(&mut context.banks_client, &payer, blockhash, &base_mint, None).await?;
+    initialize_treasury_system(&mut context.banks_client, &payer, blockhash, &Keypair::new()).await?;
+    let config = create_pool_new_pattern(&mut context.banks_client, &payer, blockhash, &multiple_mint, &base_mint, Some(2)).await?;
+
+    let pool_account = context.banks_client.get_account(config.pool_state_pda).await?.unwrap();
+    let pool_state = PoolState::try_from_slice(&pool_account.data)?;
+    assert_ne!(pool_state.created_at, 0, "Pool creation must record its timestamp");
+    assert_eq!(pool_state.last_swap_at, 0, "A new pool has not swapped");
+
+    let get_activity_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![AccountMeta::new_readonly(config.pool_state_pda, false)],
+        data: PoolInstruction::GetPoolActivity { pool_id: config.pool_state_pda }.try_to_vec()?,
+    };
+
+    // Fresh pool: measured from creation, well inside the window
+    let data = simulate_and_get_return_data(&mut context.banks_client, &payer, get_activity_ix.clone()).await?;
+    let activity = PoolActivity::try_from_slice(&data)?;
+    assert!(!activity.dormant, "A new pool must not be dormant");
+    assert!(activity.seconds_since_last_swap < POOL_DORMANCY_WINDOW_SECONDS as u64);
+
+    // Move the clock past the window with no swaps
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await?;
+    clock.unix_timestamp = pool_state.created_at + POOL_DORMANCY_WINDOW_SECONDS + 1;
+    context.set_sysvar(&clock);
+
+    let data = simulate_and_get_return_data(&mut context.banks_client, &payer, get_activity_ix).await?;
+    let activity = PoolActivity::try_from_slice(&data)?;
+    assert!(activity.dormant, "A pool with no swaps past the window must be dormant");
+    assert_eq!(activity.age_seconds, POOL_DORMANCY_WINDOW_SECONDS as u64 + 1);
+    assert_eq!(activity.seconds_since_last_swap, activity.age_seconds, "Never-swapped pools are measured from creation");
+
+    Ok(())
+}
+
+/// Sends a PausePool or UnpausePool instruction signed by the pool owner
+async fn send_pool_pause_instruction(
+    foundation: &mut common::liquidity_helpers::LiquidityTestFoundation,
+    instruction: PoolInstruction,
+) -> TestResult {
+    let (system_state_pda, _) = Pubkey::find_program_address(
+        &[SYSTEM_STATE_SEED_PREFIX],
+        &fixed_ratio_trading::id(),
+    );
+    let program_data_account = fixed_ratio_trading::utils::program_authority::get_program_data_address(
+        &fixed_ratio_trading::id()
+    );
+    let ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new(foundation.env.payer.pubkey(), true),
+            AccountMeta::new(system_state_pda, false),
+            AccountMeta::new(foundation.pool_config.pool_state_pda, false),
+            AccountMeta::new_readonly(program_data_account, false),
+        ],
+        data: instruction.try_to_vec()?,
+    };
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&foundation.env.payer.pubkey()), &[&foundation.env.payer], blockhash);
+    foundation.env.banks_client.process_transaction(tx).await?;
+    Ok(())
+}
+
+/// Test per-direction and per-operation pause flags
+/// 
+/// 1. Pause only A→B swaps: A→B fails with DirectionPaused while B→A still trades
+/// 2. Deposits keep working while one or both swap directions are paused
+/// 3. Pausing both directions is stored as the coarse swap pause, and unpausing one
+///    direction leaves the other paused
+#[tokio::test]
+#[serial]
+async fn test_pause_single_swap_direction() -> TestResult {
+    use common::liquidity_helpers::{create_swap_instruction_standardized, execute_deposit_operation};
+    use fixed_ratio_trading::error::PoolError;
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let config = foundation.pool_config.clone();
+    let user = foundation.user1.insecure_clone();
+    let (user_token_a, user_token_b) = if config.token_a_is_the_multiple {
+        (foundation.user1_primary_account.pubkey(), foundation.user1_base_account.pubkey())
+    } else {
+        (foundation.user1_base_account.pubkey(), foundation.user1_primary_account.pubkey())
+    };
+    let user_lp_a = foundation.user1_lp_a_account.pubkey();
+    let read_pool_state = |data: &[u8]| PoolState::try_from_slice(data);
+
+    // Step 1: Pause Token A → Token B swaps only
+    send_pool_pause_instruction(&mut foundation, PoolInstruction::PausePool {
+        pause_flags: PAUSE_SWAP_A_TO_B,
+        pool_id: config.pool_state_pda,
+    }).await?;
+    let pool_account = foundation.env.banks_client.get_account(config.pool_state_pda).await?.unwrap();
+    let pool_state = read_pool_state(&pool_account.data)?;
+    assert!(!pool_state.swaps_paused(), "A single paused direction must not set the coarse swap pause");
+    assert_eq!(pool_state.partial_pause_flags, PAUSE_SWAP_A_TO_B);
+    assert_eq!(pool_state.paused_operations(), PAUSE_SWAP_A_TO_B);
+
+    // Step 2: Deposits are unaffected by the swap pause (and fund the B → A swap below)
+    let swap = |input_token_mint: Pubkey, input_account: &Pubkey, output_account: &Pubkey, expected_amount_out: u64| create_swap_instruction_standardized(
+        &user.pubkey(),
+        input_account,
+        output_account,
+        &config,
+        &PoolInstruction::Swap {
+            input_token_mint,
+            amount_in: 10,
+            expected_amount_out,
+            pool_id: config.pool_state_pda,
+        },
+    );
+    // 2:1 pool: 10 base tokens buy 20 of the multiple, 10 of the multiple buy 5 base tokens
+    let (a_to_b_out, b_to_a_out) = if config.token_a_is_the_multiple { (5, 20) } else { (20, 5) };
+    let swap_a_to_b = swap(config.token_a_mint, &user_token_a, &user_token_b, a_to_b_out)?;
+    let swap_b_to_a = swap(config.token_b_mint, &user_token_b, &user_token_a, b_to_a_out)?;
+
+    execute_deposit_operation(&mut foundation, &user.pubkey(), &user_token_a, &user_lp_a, &config.token_a_mint, 1_000).await?;
+    println!("✅ Deposit allowed while A→B swaps are paused");
+
+    // Step 3: A → B is blocked, B → A still trades
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let mut tx = Transaction::new_with_payer(&[swap_a_to_b], Some(&user.pubkey()));
+    tx.sign(&[&user], blockhash);
+    let result = foundation.env.banks_client.process_transaction(tx).await.map_err(|e| e.unwrap());
+    let direction_paused = PoolError::DirectionPaused { input_mint: config.token_a_mint }.error_code();
+    assert_eq!(result, Err(TransactionError::InstructionError(0, InstructionError::Custom(direction_paused))));
+    println!("✅ A→B swap rejected with DirectionPaused");
+
+    let mut tx = Transaction::new_with_payer(&[swap_b_to_a], Some(&user.pubkey()));
+    tx.sign(&[&user], blockhash);
+    foundation.env.banks_client.process_transaction(tx).await?;
+    println!("✅ B→A swap still allowed");
+
+    // Step 4: Pausing the other direction too is the same as the coarse swap pause
+    send_pool_pause_instruction(&mut foundation, PoolInstruction::PausePool {
+        pause_flags: PAUSE_SWAP_B_TO_A,
+        pool_id: config.pool_state_pda,
+    }).await?;
+    let pool_account = foundation.env.banks_client.get_account(config.pool_state_pda).await?.unwrap();
+    let pool_state = read_pool_state(&pool_account.data)?;
+    assert!(pool_state.swaps_paused(), "Both directions paused must set the coarse swap pause");
+    assert!(!pool_state.liquidity_paused());
+    assert_eq!(pool_state.partial_pause_flags, 0);
+
+    execute_deposit_operation(&mut foundation, &user.pubkey(), &user_token_a, &user_lp_a, &config.token_a_mint, 500).await?;
+    println!("✅ Deposit allowed while all swaps are paused");
+
+    // Step 5: Unpausing one direction leaves the other paused
+    send_pool_pause_instruction(&mut foundation, PoolInstruction::UnpausePool {
+        unpause_flags: PAUSE_SWAP_A_TO_B,
+        pool_id: config.pool_state_pda,
+    }).await?;
+    let pool_account = foundation.env.banks_client.get_account(config.pool_state_pda).await?.unwrap();
+    let pool_state = read_pool_state(&pool_account.data)?;
+    assert!(!pool_state.swaps_paused());
+    assert_eq!(pool_state.paused_operations(), PAUSE_SWAP_B_TO_A);
+
+    Ok(())
+}
+
+/// Test the per-pool operation counter
+/// 
+/// Deposits, a swap, a pause, an unpause and a withdrawal each count once; the
+/// stored counter and the `GetPoolActivity` view both report the total.
+#[tokio::test]
+#[serial]
+async fn test_total_operations_counts_mixed_operations() -> TestResult {
+    use common::{
+        liquidity_helpers::{create_swap_instruction_standardized, execute_deposit_operation, execute_withdrawal_operation},
+        setup::simulate_and_get_return_data,
+    };
+    use fixed_ratio_trading::types::PoolActivity;
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let config = foundation.pool_config.clone();
+    let user = foundation.user1.insecure_clone();
+    let (user_token_a, user_token_b) = if config.token_a_is_the_multiple {
+        (foundation.user1_primary_account.pubkey(), foundation.user1_base_account.pubkey())
+    } else {
+        (foundation.user1_base_account.pubkey(), foundation.user1_primary_account.pubkey())
+    };
+    let user_lp_a = foundation.user1_lp_a_account.pubkey();
+    let user_lp_b = foundation.user1_lp_b_account.pubkey();
+
+    let pool_account = foundation.env.banks_client.get_account(config.pool_state_pda).await?.unwrap();
+    assert_eq!(PoolState::try_from_slice(&pool_account.data)?.total_operations, 0, "A new pool has no operations");
+
+    // Operations 1-2: one deposit on each side
+    execute_deposit_operation(&mut foundation, &user.pubkey(), &user_token_a, &user_lp_a, &config.token_a_mint, 1_000).await?;
+    execute_deposit_operation(&mut foundation, &user.pubkey(), &user_token_b, &user_lp_b, &config.token_b_mint, 1_000).await?;
+
+    // Operation 3: a B → A swap (2:1 pool, see test_pause_single_swap_direction)
+    let b_to_a_out = if config.token_a_is_the_multiple { 20 } else { 5 };
+    let swap_ix = create_swap_instruction_standardized(
+        &user.pubkey(),
+        &user_token_b,
+        &user_token_a,
+        &config,
+        &PoolInstruction::Swap {
+            input_token_mint: config.token_b_mint,
+            amount_in: 10,
+            expected_amount_out: b_to_a_out,
+            pool_id: config.pool_state_pda,
+        },
+    )?;
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let mut tx = Transaction::new_with_payer(&[swap_ix], Some(&user.pubkey()));
+    tx.sign(&[&user], blockhash);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    // Operations 4-5: pause and unpause swaps
+    send_pool_pause_instruction(&mut foundation, PoolInstruction::PausePool {
+        pause_flags: PAUSE_FLAG_SWAPS,
+        pool_id: config.pool_state_pda,
+    }).await?;
+    send_pool_pause_instruction(&mut foundation, PoolInstruction::UnpausePool {
+        unpause_flags: PAUSE_FLAG_SWAPS,
+        pool_id: config.pool_state_pda,
+    }).await?;
+
+    // Operation 6: a withdrawal
+    execute_withdrawal_operation(&mut foundation, &user.pubkey(), &user_lp_a, &user_token_a, &config.token_a_mint, 100).await?;
+
+    let pool_account = foundation.env.banks_client.get_account(config.pool_state_pda).await?.unwrap();
+    assert_eq!(PoolState::try_from_slice(&pool_account.data)?.total_operations, 6);
+
+    let get_activity_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![AccountMeta::new_readonly(config.pool_state_pda, false)],
+        data: PoolInstruction::GetPoolActivity { pool_id: config.pool_state_pda }.try_to_vec()?,
+    };
+    let data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, get_activity_ix).await?;
+    assert_eq!(PoolActivity::try_from_slice(&data)?.total_operations, 6, "GetPoolActivity must report the stored counter");
+
+    Ok(())
+}
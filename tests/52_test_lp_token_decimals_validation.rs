@@ -253,6 +253,15 @@ async fn test_lp_token_decimals_direct() -> TestResult {
         
         println!("✅ LP TOKEN AUTHORITIES VERIFIED: Both set to pool PDA");
         
+        // The pool state records the decimals its LP mints were created with
+        let pool_state_account = env.banks_client.get_account(pool_config.pool_state_pda).await?
+            .ok_or("Pool state account not found")?;
+        let pool_state = fixed_ratio_trading::PoolState::try_from_slice(&pool_state_account.data)?;
+        assert_eq!(pool_state.lp_token_a_decimals, lp_token_a_mint.decimals);
+        assert_eq!(pool_state.lp_token_b_decimals, lp_token_b_mint.decimals);
+        
+        println!("✅ POOL STATE DECIMALS VERIFIED: A={}, B={}", pool_state.lp_token_a_decimals, pool_state.lp_token_b_decimals);
+        
     } else {
         return Err("Could not fetch required token accounts".into());
     }
@@ -260,4 +269,39 @@ async fn test_lp_token_decimals_direct() -> TestResult {
     println!("✅ DIRECT LP TOKEN DECIMALS TEST PASSED!");
     
     Ok(())
-} 
\ No newline at end of file
+} 
+
+/// Pool creation rejects underlying mints with more than 9 decimals
+#[tokio::test]
+#[serial]
+async fn test_pool_creation_rejects_unsupported_token_decimals() -> TestResult {
+    use fixed_ratio_trading::{constants::MAX_TOKEN_DECIMALS, error::PoolError};
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+    
+    let mut env = start_test_environment().await;
+    let system_authority = solana_sdk::signature::Keypair::new();
+    initialize_treasury_system(&mut env.banks_client, &env.payer, env.recent_blockhash, &system_authority).await?;
+    
+    let token_a_keypair = solana_sdk::signature::Keypair::new();
+    let token_b_keypair = solana_sdk::signature::Keypair::new();
+    create_mint(&mut env.banks_client, &env.payer, env.recent_blockhash, &token_a_keypair, Some(MAX_TOKEN_DECIMALS + 1)).await?;
+    create_mint(&mut env.banks_client, &env.payer, env.recent_blockhash, &token_b_keypair, Some(6)).await?;
+    
+    let error = create_pool_new_pattern(
+        &mut env.banks_client,
+        &env.payer,
+        env.recent_blockhash,
+        &token_a_keypair,
+        &token_b_keypair,
+        Some(1000),
+    ).await.expect_err("Pool creation must reject a mint with more than 9 decimals");
+    
+    let expected_code = PoolError::UnsupportedTokenDecimals { mint: Pubkey::default(), decimals: 0, max_decimals: 0 }.error_code();
+    assert!(
+        matches!(error.unwrap(), TransactionError::InstructionError(_, InstructionError::Custom(code)) if code == expected_code),
+        "Expected UnsupportedTokenDecimals ({})", expected_code
+    );
+    
+    println!("✅ Pool creation rejected a {}-decimal mint with UnsupportedTokenDecimals", MAX_TOKEN_DECIMALS + 1);
+    Ok(())
+}
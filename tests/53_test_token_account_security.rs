@@ -12,13 +12,27 @@ mod test_token_account_security {
             signature::{Keypair, Signer},
         },
         fixed_ratio_trading::{
+            error::PoolError,
             types::instructions::PoolInstruction,
         },
         borsh::BorshSerialize,
+        solana_program::{
+            program_option::COption,
+            program_pack::Pack,
+        },
+        solana_sdk::{
+            account::{Account, AccountSharedData},
+            instruction::InstructionError,
+            transaction::{Transaction, TransactionError},
+        },
+        spl_token::state::Mint,
     };
 
     use crate::common::{
         setup::*,
+        tokens::{create_mint, create_token_account, mint_tokens},
+        pool_helpers::create_pool_new_pattern,
+        liquidity_helpers::create_deposit_instruction_standardized,
         PROGRAM_ID,
     };
 
@@ -145,4 +159,218 @@ mod test_token_account_security {
         // requires full infrastructure that's not available with current setup
         println!("✅ Wrong mint test compiled successfully");
     }
-}
\ No newline at end of file
+
+    /// Creates a pool, overwrites its LP Token A mint with `corrupt_mint`, and
+    /// returns the result of a Token A deposit against it
+    async fn deposit_with_corrupted_lp_mint(
+        corrupt_mint: impl FnOnce(&mut Mint),
+    ) -> Result<(), TransactionError> {
+        let mut context = create_program_test().start_with_context().await;
+        let payer = context.payer.insecure_clone();
+        let blockhash = context.last_blockhash;
+
+        let multiple_mint = Keypair::new();
+        let base_mint = Keypair::new();
+        create_mint(&mut context.banks_client, &payer, blockhash, &multiple_mint, None).await.unwrap();
+        create_mint(&mut context.banks_client, &payer, blockhash, &base_mint, None).await.unwrap();
+        initialize_treasury_system(&mut context.banks_client, &payer, blockhash, &Keypair::new()).await.unwrap();
+        let config = create_pool_new_pattern(&mut context.banks_client, &payer, blockhash, &multiple_mint, &base_mint, Some(2))
+            .await.unwrap();
+
+        let (lp_token_a_mint, _) = Pubkey::find_program_address(
+            &[fixed_ratio_trading::constants::LP_TOKEN_A_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()],
+            &PROGRAM_ID,
+        );
+        let (lp_token_b_mint, _) = Pubkey::find_program_address(
+            &[fixed_ratio_trading::constants::LP_TOKEN_B_MINT_SEED_PREFIX, config.pool_state_pda.as_ref()],
+            &PROGRAM_ID,
+        );
+
+        // User accounts are created while the LP mint is still valid
+        let user = Keypair::new();
+        transfer_sol(&mut context.banks_client, &payer, blockhash, &payer, &user.pubkey(), 1_000_000_000).await.unwrap();
+        let user_token_a = Keypair::new();
+        let user_lp_a = Keypair::new();
+        create_token_account(&mut context.banks_client, &payer, blockhash, &user_token_a, &config.token_a_mint, &user.pubkey()).await.unwrap();
+        create_token_account(&mut context.banks_client, &payer, blockhash, &user_lp_a, &lp_token_a_mint, &user.pubkey()).await.unwrap();
+        mint_tokens(&mut context.banks_client, &payer, blockhash, &config.token_a_mint, &user_token_a.pubkey(), &payer, 1_000_000)
+            .await.unwrap();
+
+        // Overwrite the LP mint data in place
+        let mut lp_mint_account: Account = context.banks_client.get_account(lp_token_a_mint).await.unwrap().unwrap();
+        let mut lp_mint = Mint::unpack_unchecked(&lp_mint_account.data).unwrap();
+        corrupt_mint(&mut lp_mint);
+        Mint::pack(lp_mint, &mut lp_mint_account.data).unwrap();
+        context.set_account(&lp_token_a_mint, &AccountSharedData::from(lp_mint_account));
+
+        let deposit_ix = create_deposit_instruction_standardized(
+            &user.pubkey(),
+            &user_token_a.pubkey(),
+            &user_lp_a.pubkey(),
+            &config,
+            &lp_token_a_mint,
+            &lp_token_b_mint,
+            &PoolInstruction::Deposit {
+                deposit_token_mint: config.token_a_mint,
+                amount: 100_000,
+                pool_id: config.pool_state_pda,
+            },
+        ).unwrap();
+
+        let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        let mut tx = Transaction::new_with_payer(&[deposit_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &user], blockhash);
+        context.banks_client.process_transaction(tx).await.map_err(|e| e.unwrap())
+    }
+
+    /// Test that deposits reject an LP mint that is not initialized
+    #[tokio::test]
+    async fn test_deposit_rejects_uninitialized_lp_mint() {
+        let result = deposit_with_corrupted_lp_mint(|mint| mint.is_initialized = false).await;
+        let expected_code = PoolError::LpMintNotInitialized { mint: Pubkey::default() }.error_code();
+        assert_eq!(
+            result,
+            Err(TransactionError::InstructionError(0, InstructionError::Custom(expected_code))),
+        );
+        println!("✅ Uninitialized LP mint rejected with LpMintNotInitialized");
+    }
+
+    /// Test that deposits reject an LP mint whose mint authority was removed
+    #[tokio::test]
+    async fn test_deposit_rejects_frozen_lp_mint() {
+        let result = deposit_with_corrupted_lp_mint(|mint| mint.mint_authority = COption::None).await;
+        let expected_code = PoolError::LpMintFrozen { mint: Pubkey::default() }.error_code();
+        assert_eq!(
+            result,
+            Err(TransactionError::InstructionError(0, InstructionError::Custom(expected_code))),
+        );
+        println!("✅ Frozen LP mint rejected with LpMintFrozen");
+    }
+
+    /// Test that the pool owner can rescue an unrelated mint sent to a pool-owned token
+    /// account, and that the pool's own Token A can never be rescued
+    #[tokio::test]
+    async fn test_rescue_stranded_tokens_only_for_non_pool_mints() {
+        use crate::common::{liquidity_helpers::create_liquidity_test_foundation, tokens::get_token_balance};
+
+        let mut foundation = create_liquidity_test_foundation(Some(2)).await.unwrap();
+        let config = foundation.pool_config.clone();
+        let pool_owner = foundation.env.payer.insecure_clone();
+
+        // A third mint accidentally sent to a token account owned by the pool state PDA
+        let stray_mint = Keypair::new();
+        let stranded_account = Keypair::new();
+        let rescue_destination = Keypair::new();
+        let blockhash = foundation.env.banks_client.get_latest_blockhash().await.unwrap();
+        create_mint(&mut foundation.env.banks_client, &pool_owner, blockhash, &stray_mint, Some(6)).await.unwrap();
+        create_token_account(&mut foundation.env.banks_client, &pool_owner, blockhash, &stranded_account, &stray_mint.pubkey(), &config.pool_state_pda).await.unwrap();
+        create_token_account(&mut foundation.env.banks_client, &pool_owner, blockhash, &rescue_destination, &stray_mint.pubkey(), &pool_owner.pubkey()).await.unwrap();
+        mint_tokens(&mut foundation.env.banks_client, &pool_owner, blockhash, &stray_mint.pubkey(), &stranded_account.pubkey(), &pool_owner, 5_000).await.unwrap();
+
+        let rescue_ix = |mint: Pubkey, source: Pubkey, destination: Pubkey, amount: u64| Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(pool_owner.pubkey(), true),
+                AccountMeta::new_readonly(config.pool_state_pda, false),
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+            ],
+            data: PoolInstruction::RescueStrandedTokens { mint, amount, pool_id: config.pool_state_pda }.try_to_vec().unwrap(),
+        };
+
+        // The owner rescues the stray tokens, with the pool PDA signing the transfer
+        let mut tx = Transaction::new_with_payer(
+            &[rescue_ix(stray_mint.pubkey(), stranded_account.pubkey(), rescue_destination.pubkey(), 5_000)],
+            Some(&pool_owner.pubkey()),
+        );
+        tx.sign(&[&pool_owner], blockhash);
+        foundation.env.banks_client.process_transaction(tx).await.unwrap();
+        assert_eq!(get_token_balance(&mut foundation.env.banks_client, &stranded_account.pubkey()).await, 0);
+        assert_eq!(get_token_balance(&mut foundation.env.banks_client, &rescue_destination.pubkey()).await, 5_000);
+
+        // Token A in the pool vault can never be rescued
+        let owner_token_a = if config.token_a_is_the_multiple {
+            foundation.user1_primary_account.pubkey()
+        } else {
+            foundation.user1_base_account.pubkey()
+        };
+        let blockhash = foundation.env.banks_client.get_new_latest_blockhash(&blockhash).await.unwrap();
+        let mut tx = Transaction::new_with_payer(
+            &[rescue_ix(config.token_a_mint, config.token_a_vault_pda, owner_token_a, 1)],
+            Some(&pool_owner.pubkey()),
+        );
+        tx.sign(&[&pool_owner], blockhash);
+        let result = foundation.env.banks_client.process_transaction(tx).await.map_err(|e| e.unwrap());
+        let expected_code = PoolError::CannotRescuePoolToken { mint: Pubkey::default() }.error_code();
+        assert_eq!(
+            result,
+            Err(TransactionError::InstructionError(0, InstructionError::Custom(expected_code))),
+        );
+        println!("✅ Stray mint rescued; pool Token A rejected with CannotRescuePoolToken");
+    }
+
+    /// Test that swaps and deposits passing the Token A and Token B vaults in each
+    /// other's positions are rejected with VaultMintMismatch
+    #[tokio::test]
+    async fn test_swapped_vaults_rejected_with_vault_mint_mismatch() {
+        use crate::common::liquidity_helpers::{create_liquidity_test_foundation, create_swap_instruction_standardized};
+
+        let mut foundation = create_liquidity_test_foundation(Some(2)).await.unwrap();
+        let config = foundation.pool_config.clone();
+        let user = foundation.user1.insecure_clone();
+        let (user_token_a, user_token_b) = if config.token_a_is_the_multiple {
+            (foundation.user1_primary_account.pubkey(), foundation.user1_base_account.pubkey())
+        } else {
+            (foundation.user1_base_account.pubkey(), foundation.user1_primary_account.pubkey())
+        };
+
+        let swap_ix = create_swap_instruction_standardized(
+            &user.pubkey(),
+            &user_token_a,
+            &user_token_b,
+            &config,
+            &PoolInstruction::Swap {
+                input_token_mint: config.token_a_mint,
+                amount_in: 1_000,
+                expected_amount_out: 0,
+                pool_id: config.pool_state_pda,
+            },
+        ).unwrap();
+        let deposit_ix = create_deposit_instruction_standardized(
+            &user.pubkey(),
+            &user_token_a,
+            &foundation.user1_lp_a_account.pubkey(),
+            &config,
+            &foundation.lp_token_a_mint_pda,
+            &foundation.lp_token_b_mint_pda,
+            &PoolInstruction::Deposit {
+                deposit_token_mint: config.token_a_mint,
+                amount: 1_000,
+                pool_id: config.pool_state_pda,
+            },
+        ).unwrap();
+
+        let expected_code = PoolError::VaultMintMismatch {
+            vault: Pubkey::default(),
+            expected_mint: Pubkey::default(),
+            actual_mint: Pubkey::default(),
+        }.error_code();
+        for mut instruction in [swap_ix, deposit_ix] {
+            // Indices 5 and 6 hold the Token A and Token B vaults in both layouts
+            assert_eq!(instruction.accounts[5].pubkey, config.token_a_vault_pda);
+            assert_eq!(instruction.accounts[6].pubkey, config.token_b_vault_pda);
+            instruction.accounts.swap(5, 6);
+
+            let blockhash = foundation.env.banks_client.get_latest_blockhash().await.unwrap();
+            let mut tx = Transaction::new_with_payer(&[instruction], Some(&user.pubkey()));
+            tx.sign(&[&user], blockhash);
+            let result = foundation.env.banks_client.process_transaction(tx).await.map_err(|e| e.unwrap());
+            assert_eq!(
+                result,
+                Err(TransactionError::InstructionError(0, InstructionError::Custom(expected_code))),
+            );
+        }
+        println!("✅ Swapped vaults rejected with VaultMintMismatch for swap and deposit");
+    }
+}
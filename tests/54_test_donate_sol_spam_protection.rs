@@ -12,14 +12,14 @@ use {
     fixed_ratio_trading::{
         constants::*,
         PoolInstruction,
-        state::MainTreasuryState,
+        state::{DonationRecord, MainTreasuryState},
     },
     std::error::Error,
 };
 
 mod common;
 use common::{
-    setup::{initialize_treasury_system, start_test_environment},
+    setup::{initialize_treasury_system, simulate_and_get_return_data, start_test_environment},
 };
 
 /// 🎯 TEST CONFIGURATION - MODIFY THESE VALUES TO CHANGE TEST BEHAVIOR
@@ -419,4 +419,95 @@ async fn test_donation_spam_economic_analysis() -> Result<(), Box<dyn Error>> {
     println!("🔐 Conclusion: Spam attacks are economically unfeasible due to 0.1 SOL minimum + transaction fees");
     
     Ok(())
-}
\ No newline at end of file
+}
+/// DONATE-003: Test the recent donations buffer and GetRecentDonations view
+///
+/// Donates from more donors than the buffer holds and verifies that:
+/// 1. The view returns the newest donations first
+/// 2. The oldest donations are overwritten once the buffer wraps
+/// 3. Messages are kept truncated to the stored preview length
+/// 4. Donation count and total keep accumulating past the buffer capacity
+#[tokio::test]
+#[serial]
+async fn test_recent_donations_buffer_wraps() -> Result<(), Box<dyn Error>> {
+    println!("🧪 Testing DONATE-003: Recent donations buffer...");
+
+    let mut env = start_test_environment().await;
+    let system_authority = Keypair::new();
+    initialize_treasury_system(
+        &mut env.banks_client,
+        &env.payer,
+        env.recent_blockhash,
+        &system_authority,
+    ).await?;
+
+    let (main_treasury_pda, _) = Pubkey::find_program_address(&[MAIN_TREASURY_SEED_PREFIX], &fixed_ratio_trading::ID);
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &fixed_ratio_trading::ID);
+    let get_recent_donations_ix = Instruction {
+        program_id: fixed_ratio_trading::ID,
+        accounts: vec![AccountMeta::new_readonly(main_treasury_pda, false)],
+        data: PoolInstruction::GetRecentDonations {}.try_to_vec()?,
+    };
+
+    // Nothing donated yet
+    let data = simulate_and_get_return_data(&mut env.banks_client, &env.payer, get_recent_donations_ix.clone()).await?;
+    assert!(Vec::<DonationRecord>::try_from_slice(&data)?.is_empty());
+
+    // Donate from more distinct donors than the buffer holds
+    let donation_total = RECENT_DONATIONS_CAPACITY + 3;
+    let mut donations = Vec::new();
+    for i in 0..donation_total {
+        let donor = Keypair::new();
+        env.recent_blockhash = env.banks_client.get_latest_blockhash().await?;
+        let mut fund_tx = Transaction::new_with_payer(
+            &[solana_sdk::system_instruction::transfer(&env.payer.pubkey(), &donor.pubkey(), 1_000_000_000)],
+            Some(&env.payer.pubkey()),
+        );
+        fund_tx.sign(&[&env.payer], env.recent_blockhash);
+        env.banks_client.process_transaction(fund_tx).await?;
+
+        let amount = MIN_DONATION_AMOUNT + i as u64 * 1_000_000;
+        let message = if i == donation_total - 1 {
+            "A very long thank-you message that does not fit the preview".to_string()
+        } else {
+            format!("Donation #{}", i)
+        };
+        let donate_ix = Instruction {
+            program_id: fixed_ratio_trading::ID,
+            accounts: vec![
+                AccountMeta::new(donor.pubkey(), true),
+                AccountMeta::new(main_treasury_pda, false),
+                AccountMeta::new_readonly(system_state_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: PoolInstruction::DonateSol { amount, message: message.clone() }.try_to_vec()?,
+        };
+        let mut donate_tx = Transaction::new_with_payer(&[donate_ix], Some(&donor.pubkey()));
+        donate_tx.sign(&[&donor], env.recent_blockhash);
+        env.banks_client.process_transaction(donate_tx).await?;
+        donations.push((donor.pubkey(), amount, message));
+    }
+
+    // Only the newest RECENT_DONATIONS_CAPACITY donations remain, newest first
+    let data = simulate_and_get_return_data(&mut env.banks_client, &env.payer, get_recent_donations_ix).await?;
+    let recent = Vec::<DonationRecord>::try_from_slice(&data)?;
+    assert_eq!(recent.len(), RECENT_DONATIONS_CAPACITY);
+    for (record, (donor, amount, message)) in recent.iter().zip(donations.iter().rev()) {
+        assert_eq!(record.donor, *donor);
+        assert_eq!(record.amount, *amount);
+        let expected_len = message.len().min(DONATION_MESSAGE_PREVIEW_LEN);
+        assert_eq!(record.message(), &message[..expected_len]);
+    }
+    assert_eq!(recent[0].message().len(), DONATION_MESSAGE_PREVIEW_LEN, "Long message truncated to the preview");
+    let dropped: Vec<Pubkey> = donations[..3].iter().map(|(donor, _, _)| *donor).collect();
+    assert!(recent.iter().all(|record| !dropped.contains(&record.donor)), "Oldest donations overwritten");
+
+    // Totals cover every donation, not just the buffered ones
+    let treasury_account = env.banks_client.get_account(main_treasury_pda).await?.unwrap();
+    let treasury_state = MainTreasuryState::try_from_slice(&treasury_account.data)?;
+    assert_eq!(treasury_state.donation_count, donation_total as u64);
+    assert_eq!(treasury_state.total_donations, donations.iter().map(|(_, amount, _)| amount).sum::<u64>());
+
+    println!("✅ DONATE-003: Recent donations buffer wraps and totals accumulate");
+    Ok(())
+}
@@ -224,3 +224,84 @@ async fn test_process_admin_change_success_and_new_admin_can_pause() -> TestResu
 }
 
 
+
+/// A system state account created with the original 83-byte layout (before `swap_sol_fee`
+/// and the later fields) stays readable, is rejected by setters until migrated, and is
+/// grown to the current size by MigrateSystemState with the admin intact
+#[tokio::test]
+async fn test_migrate_system_state_from_original_layout() -> TestResult {
+    use solana_sdk::account::AccountSharedData;
+
+    let mut context: ProgramTestContext = create_program_test().start_with_context().await;
+    let admin = Keypair::new();
+    initialize_treasury_system(
+        &mut context.banks_client,
+        &context.payer,
+        context.last_blockhash,
+        &admin,
+    ).await?;
+
+    let program_id = fixed_ratio_trading::id();
+    let system_state_pda = get_system_state_pda(&program_id);
+    let program_data_address = get_test_program_data_address(&program_id);
+    let rent = context.banks_client.get_rent().await?;
+
+    // Rewrite the account as a deployed original-layout account: the fields up to
+    // admin_change_timestamp, zero padded to the 83 bytes that layout reserved
+    let mut legacy_bytes = SystemState::new(admin.pubkey()).try_to_vec()?;
+    legacy_bytes.truncate(1 + 8 + 1 + 32 + 1 + 8);
+    legacy_bytes.resize(SystemState::V1_LEN, 0);
+    let mut legacy_account = AccountSharedData::new(rent.minimum_balance(SystemState::V1_LEN), SystemState::V1_LEN, &program_id);
+    legacy_account.set_data_from_slice(&legacy_bytes);
+    context.set_account(&system_state_pda, &legacy_account);
+
+    let loaded = SystemState::from_account_data_unchecked(&legacy_bytes)?;
+    assert_eq!(loaded.admin_authority, admin.pubkey());
+    assert_eq!((loaded.swap_sol_fee, loaded.consolidation_skip_window), (0, 0), "Missing fields read as zero");
+    assert_eq!(loaded.system_message(), "");
+
+    let set_message_ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin.pubkey(), true),
+            AccountMeta::new(system_state_pda, false),
+            AccountMeta::new_readonly(program_data_address, false),
+        ],
+        data: PoolInstruction::SetSystemMessage { message: b"migrated".to_vec() }.try_to_vec()?,
+    };
+    let migrate_ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin.pubkey(), true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new(system_state_pda, false),
+            AccountMeta::new_readonly(program_data_address, false),
+        ],
+        data: PoolInstruction::MigrateSystemState {}.try_to_vec()?,
+    };
+    let fund_admin_ix = solana_sdk::system_instruction::transfer(&context.payer.pubkey(), &admin.pubkey(), 1_000_000_000);
+
+    // Setters cannot write the larger layout into the old account
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(&[set_message_ix.clone()], Some(&context.payer.pubkey()), &[&context.payer, &admin], blockhash);
+    assert!(context.banks_client.process_transaction(transaction).await.is_err(), "Setter must fail before migration");
+
+    // Admin migrates, paying the extra rent
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(&[fund_admin_ix, migrate_ix], Some(&context.payer.pubkey()), &[&context.payer, &admin], blockhash);
+    context.banks_client.process_transaction(transaction).await?;
+
+    let account = context.banks_client.get_account(system_state_pda).await?.expect("System state should exist");
+    assert_eq!(account.data.len(), SystemState::LEN);
+    assert!(rent.is_exempt(account.lamports, account.data.len()), "Migrated account must stay rent-exempt");
+    assert_eq!(SystemState::from_account_data_unchecked(&account.data)?.admin_authority, admin.pubkey());
+
+    // The setter now succeeds; a fresh blockhash keeps the bank from replaying the cached failure
+    let blockhash = context.banks_client.get_new_latest_blockhash(&blockhash).await?;
+    let transaction = Transaction::new_signed_with_payer(&[set_message_ix], Some(&context.payer.pubkey()), &[&context.payer, &admin], blockhash);
+    context.banks_client.process_transaction(transaction).await?;
+    let account = context.banks_client.get_account(system_state_pda).await?.expect("System state should exist");
+    assert_eq!(SystemState::from_account_data_unchecked(&account.data)?.system_message(), "migrated");
+
+    Ok(())
+}
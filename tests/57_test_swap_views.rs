@@ -0,0 +1,552 @@
+/*
+MIT License
+
+Copyright (c) 2024 Davinci
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Swap View Instruction Tests
+//!
+//! Tests for read-only swap helpers that report values through return data:
+//! - `GetSwapSolCost`: minimum SOL a user needs before submitting a swap
+//! - `GetRoundTripCost`: token and SOL cost of swapping in and straight back out
+//! - `GetRoundingSurplus`: input-token units the pool kept from truncated swap outputs
+//! - `CanSwap`: go/no-go for a swap of a given size, with the blocking error code
+//! - `GetSwapQuote`: swap output and SOL fee, computed without transferring anything
+//! - `GetSwapFeeBreakdown`: how a swap's SOL fee divides between the protocol and the pool
+//! - `GetFeeHistory`: the pool's swap fees per UTC day over the last week
+
+#![allow(unused_imports)]
+#![allow(dead_code)]
+
+mod common;
+
+use common::*;
+use common::liquidity_helpers::{create_liquidity_test_foundation, execute_deposit_operation, execute_swap_operation};
+use common::setup::simulate_and_get_return_data;
+use serial_test::serial;
+use fixed_ratio_trading::constants::{BASE_SIGNATURE_FEE, PAUSE_FLAG_SWAPS, SWAP_CONTRACT_FEE, SYSTEM_STATE_SEED_PREFIX};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+/// Builds a GetSwapSolCost instruction for the given pool and output account
+fn create_get_swap_sol_cost_instruction(pool_state_pda: &Pubkey, user_output_account: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*pool_state_pda, false),
+            AccountMeta::new_readonly(*user_output_account, false),
+        ],
+        data: PoolInstruction::GetSwapSolCost {}.try_to_vec().unwrap(),
+    }
+}
+
+/// A user without an output token account must also fund the account's rent
+#[tokio::test]
+#[serial]
+async fn test_get_swap_sol_cost_includes_output_account_rent() -> TestResult {
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let rent = foundation.env.banks_client.get_rent().await?;
+    let token_account_rent = rent.minimum_balance(TokenAccount::LEN);
+
+    // Output account that has never been created
+    let missing_output_account = Keypair::new().pubkey();
+    let ix = create_get_swap_sol_cost_instruction(&pool_state_pda, &missing_output_account);
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, ix).await?;
+    let missing_cost = u64::from_le_bytes(return_data.as_slice().try_into()?);
+
+    assert_eq!(
+        missing_cost,
+        BASE_SIGNATURE_FEE + SWAP_CONTRACT_FEE + token_account_rent,
+        "Cost for a missing output account must include token account rent"
+    );
+
+    // Existing output account only pays fees
+    let existing_output_account = foundation.user1_base_account.pubkey();
+    let ix = create_get_swap_sol_cost_instruction(&pool_state_pda, &existing_output_account);
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, ix).await?;
+    let existing_cost = u64::from_le_bytes(return_data.as_slice().try_into()?);
+
+    assert_eq!(existing_cost, BASE_SIGNATURE_FEE + SWAP_CONTRACT_FEE);
+    assert_eq!(missing_cost - existing_cost, token_account_rent);
+
+    println!("✅ GetSwapSolCost: {} lamports without output account, {} with", missing_cost, existing_cost);
+    Ok(())
+}
+
+/// Builds a GetRoundTripCost instruction for the given pool and start token
+fn create_get_round_trip_cost_instruction(pool_state_pda: &Pubkey, amount_in: u64, start_token_mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*pool_state_pda, false),
+        ],
+        data: PoolInstruction::GetRoundTripCost {
+            amount_in,
+            start_token_mint: *start_token_mint,
+            pool_id: *pool_state_pda,
+        }.try_to_vec().unwrap(),
+    }
+}
+
+/// Decodes the four little-endian u64 values returned by GetRoundTripCost
+fn decode_round_trip_cost(return_data: &[u8]) -> [u64; 4] {
+    assert_eq!(return_data.len(), 32, "GetRoundTripCost must return four u64 values");
+    let mut values = [0u64; 4];
+    for (i, chunk) in return_data.chunks_exact(8).enumerate() {
+        values[i] = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    values
+}
+
+/// Round-trip loss must equal the rounding lost on both legs plus both swap fees
+#[tokio::test]
+#[serial]
+async fn test_get_round_trip_cost_matches_both_legs() -> TestResult {
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let ratio_a = foundation.pool_config.ratio_a_numerator as u128;
+    let ratio_b = foundation.pool_config.ratio_b_denominator as u128;
+
+    // Odd amount so the leg into the smaller-valued side is not a whole multiple
+    let amount_in: u64 = 1_000_001;
+
+    for (start_mint, start_is_a) in [
+        (foundation.pool_config.token_a_mint, true),
+        (foundation.pool_config.token_b_mint, false),
+    ] {
+        let ix = create_get_round_trip_cost_instruction(&pool_state_pda, amount_in, &start_mint);
+        let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, ix).await?;
+        let [first_leg_out, final_amount, token_loss, sol_fees] = decode_round_trip_cost(&return_data);
+
+        let (forward_num, forward_den) = if start_is_a { (ratio_b, ratio_a) } else { (ratio_a, ratio_b) };
+        let expected_first_leg = (amount_in as u128 * forward_num / forward_den) as u64;
+        let expected_final = (expected_first_leg as u128 * forward_den / forward_num) as u64;
+
+        assert_eq!(first_leg_out, expected_first_leg, "First leg must match swap math");
+        assert_eq!(final_amount, expected_final, "Second leg must match swap math");
+        assert_eq!(token_loss, amount_in - expected_final, "Token loss must equal rounding across both legs");
+        assert_eq!(sol_fees, 2 * SWAP_CONTRACT_FEE, "SOL fees must cover one swap fee per leg");
+
+        println!("✅ Round trip from {}: out {} → back {} (loss {}, fees {} lamports)",
+                 if start_is_a { "A" } else { "B" }, first_leg_out, final_amount, token_loss, sol_fees);
+    }
+
+    Ok(())
+}
+
+/// Builds a GetRoundingSurplus instruction for the given pool
+fn create_get_rounding_surplus_instruction(pool_state_pda: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*pool_state_pda, false),
+        ],
+        data: PoolInstruction::GetRoundingSurplus {}.try_to_vec().unwrap(),
+    }
+}
+
+/// Swaps with truncated outputs must add the leftover input units to the surplus counters
+#[tokio::test]
+#[serial]
+async fn test_rounding_surplus_accumulates_truncated_units() -> TestResult {
+    let mut foundation = create_liquidity_test_foundation(Some(3)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let token_a_mint = foundation.pool_config.token_a_mint;
+    let token_b_mint = foundation.pool_config.token_b_mint;
+    let ratio_a = foundation.pool_config.ratio_a_numerator as u128;
+    let ratio_b = foundation.pool_config.ratio_b_denominator as u128;
+
+    // Provide liquidity on both sides
+    let user1_pubkey = foundation.user1.pubkey();
+    let user1_primary = foundation.user1_primary_account.pubkey();
+    let user1_base = foundation.user1_base_account.pubkey();
+    let user1_lp_a = foundation.user1_lp_a_account.pubkey();
+    let user1_lp_b = foundation.user1_lp_b_account.pubkey();
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_primary, &user1_lp_a, &token_a_mint, 1_000_000).await?;
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_base, &user1_lp_b, &token_b_mint, 1_000_000).await?;
+
+    // Odd amounts in both directions; at 3:1 at least one direction truncates
+    let user2_pubkey = foundation.user2.pubkey();
+    let user2_primary = foundation.user2_primary_account.pubkey();
+    let user2_base = foundation.user2_base_account.pubkey();
+    let swaps = [(true, 1_001u64), (true, 2_002u64), (false, 1_001u64), (false, 2_002u64)];
+
+    let mut expected_surplus_a = 0u64;
+    let mut expected_surplus_b = 0u64;
+    for (input_is_a, amount_in) in swaps {
+        // Surplus = input minus the value of the floored output, rounded up into input units
+        let (num, den) = if input_is_a { (ratio_b, ratio_a) } else { (ratio_a, ratio_b) };
+        let amount_out = amount_in as u128 * num / den;
+        let surplus = (amount_in as u128 - (amount_out * den).div_ceil(num)) as u64;
+
+        if input_is_a {
+            execute_swap_operation(&mut foundation, &user2_pubkey, &user2_primary, &user2_base, &token_a_mint, amount_in).await?;
+            expected_surplus_a += surplus;
+        } else {
+            execute_swap_operation(&mut foundation, &user2_pubkey, &user2_base, &user2_primary, &token_b_mint, amount_in).await?;
+            expected_surplus_b += surplus;
+        }
+    }
+    assert!(expected_surplus_a + expected_surplus_b > 0, "Test swaps must produce truncated outputs");
+
+    let ix = create_get_rounding_surplus_instruction(&pool_state_pda);
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, ix).await?;
+    assert_eq!(return_data.len(), 16, "GetRoundingSurplus must return two u64 values");
+    let surplus_a = u64::from_le_bytes(return_data[0..8].try_into()?);
+    let surplus_b = u64::from_le_bytes(return_data[8..16].try_into()?);
+
+    assert_eq!(surplus_a, expected_surplus_a, "Token A surplus must equal truncated units from A → B swaps");
+    assert_eq!(surplus_b, expected_surplus_b, "Token B surplus must equal truncated units from B → A swaps");
+
+    // Only the pool owner can sweep the surplus
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+    let owner = foundation.env.payer.insecure_clone();
+    let config = foundation.pool_config.clone();
+    let sweep_ix = |signer: &Pubkey, mint: Pubkey, vault: Pubkey, destination: Pubkey| Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(pool_state_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: PoolInstruction::SweepRoundingSurplus { sweep_token_mint: mint, pool_id: pool_state_pda }.try_to_vec().unwrap(),
+    };
+    let outsider = foundation.user2.insecure_clone();
+    let mut tx = Transaction::new_with_payer(&[sweep_ix(&outsider.pubkey(), token_a_mint, config.token_a_vault_pda, user2_primary)], Some(&outsider.pubkey()));
+    tx.sign(&[&outsider], foundation.env.banks_client.get_latest_blockhash().await?);
+    let error = foundation.env.banks_client.process_transaction(tx).await.expect_err("Non-owner sweep must fail");
+    assert_eq!(
+        error.unwrap(),
+        solana_sdk::transaction::TransactionError::InstructionError(0, solana_sdk::instruction::InstructionError::Custom(
+            fixed_ratio_trading::error::PoolError::Unauthorized.error_code()
+        )),
+    );
+
+    // The owner sweeps each side: exactly the surplus moves out of the vault and liquidity
+    for (mint, vault, surplus) in [(token_a_mint, config.token_a_vault_pda, surplus_a), (token_b_mint, config.token_b_vault_pda, surplus_b)] {
+        let destination = Keypair::new();
+        let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+        tokens::create_token_account(&mut foundation.env.banks_client, &owner, blockhash, &destination, &mint, &owner.pubkey()).await?;
+        let state_before = get_pool_state(&mut foundation.env.banks_client, &pool_state_pda).await.unwrap();
+        let vault_before = tokens::get_token_balance(&mut foundation.env.banks_client, &vault).await;
+
+        let ix = sweep_ix(&owner.pubkey(), mint, vault, destination.pubkey());
+        let swept = simulate_and_get_return_data(&mut foundation.env.banks_client, &owner, ix.clone()).await?;
+        assert_eq!(swept, surplus.to_le_bytes().to_vec(), "Sweep must report the accumulated surplus");
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&owner.pubkey()));
+        tx.sign(&[&owner], foundation.env.banks_client.get_latest_blockhash().await?);
+        foundation.env.banks_client.process_transaction(tx).await?;
+
+        let state_after = get_pool_state(&mut foundation.env.banks_client, &pool_state_pda).await.unwrap();
+        let liquidity = |state: &fixed_ratio_trading::state::PoolState| if mint == token_a_mint { state.total_token_a_liquidity } else { state.total_token_b_liquidity };
+        assert_eq!(tokens::get_token_balance(&mut foundation.env.banks_client, &destination.pubkey()).await, surplus);
+        assert_eq!(tokens::get_token_balance(&mut foundation.env.banks_client, &vault).await, vault_before - surplus);
+        assert_eq!(liquidity(&state_after), liquidity(&state_before) - surplus, "Swept units leave the tracked liquidity");
+    }
+
+    let ix = create_get_rounding_surplus_instruction(&pool_state_pda);
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, ix).await?;
+    assert_eq!(return_data, [0u8; 16].to_vec(), "Both counters are spent after the sweep");
+
+    println!("✅ Rounding surplus: {} Token A, {} Token B, swept by the owner", surplus_a, surplus_b);
+    Ok(())
+}
+
+/// Builds a CanSwap instruction for user2 swapping from the given mint
+fn create_can_swap_instruction(foundation: &common::liquidity_helpers::LiquidityTestFoundation, input_token_mint: &Pubkey, amount_in: u64) -> Instruction {
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(foundation.pool_config.pool_state_pda, false),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new_readonly(foundation.user2.pubkey(), false),
+        ],
+        data: PoolInstruction::CanSwap {
+            input_token_mint: *input_token_mint,
+            amount_in,
+            pool_id: foundation.pool_config.pool_state_pda,
+        }.try_to_vec().unwrap(),
+    }
+}
+
+/// Simulates CanSwap and decodes `[can_swap, reason_code]`
+async fn simulate_can_swap(foundation: &mut common::liquidity_helpers::LiquidityTestFoundation, input_token_mint: &Pubkey, amount_in: u64) -> Result<(u64, u64), Box<dyn std::error::Error>> {
+    let ix = create_can_swap_instruction(foundation, input_token_mint, amount_in);
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, ix).await?;
+    assert_eq!(return_data.len(), 16, "CanSwap must return two u64 values");
+    Ok((
+        u64::from_le_bytes(return_data[0..8].try_into()?),
+        u64::from_le_bytes(return_data[8..16].try_into()?),
+    ))
+}
+
+/// A healthy pool reports the swap as possible; a swap-paused pool reports the pause error
+#[tokio::test]
+#[serial]
+async fn test_can_swap_healthy_and_paused_pool() -> TestResult {
+    use fixed_ratio_trading::error::PoolError;
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let token_a_mint = foundation.pool_config.token_a_mint;
+    let token_b_mint = foundation.pool_config.token_b_mint;
+
+    // Provide Token B so A → B swaps have output liquidity
+    let user1_pubkey = foundation.user1.pubkey();
+    let user1_token_b = if foundation.pool_config.token_a_is_the_multiple {
+        foundation.user1_base_account.pubkey()
+    } else {
+        foundation.user1_primary_account.pubkey()
+    };
+    let user1_lp_b = foundation.user1_lp_b_account.pubkey();
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &token_b_mint, 1_000_000).await?;
+
+    // Healthy pool: swap can proceed
+    let (can_swap, reason_code) = simulate_can_swap(&mut foundation, &token_a_mint, 1_000).await?;
+    assert_eq!((can_swap, reason_code), (1, 0), "Healthy pool must allow the swap");
+
+    // Output larger than the pool's Token B liquidity is blocked
+    let (can_swap, reason_code) = simulate_can_swap(&mut foundation, &token_a_mint, 10_000_000).await?;
+    assert_eq!(can_swap, 0);
+    assert_eq!(reason_code, 1003, "Oversized swap must report InsufficientFunds");
+
+    // Pause swaps on the pool
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+    let pause_ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(foundation.env.payer.pubkey(), true),
+            AccountMeta::new(system_state_pda, false),
+            AccountMeta::new(foundation.pool_config.pool_state_pda, false),
+            AccountMeta::new_readonly(get_test_program_data_address(&PROGRAM_ID), false),
+        ],
+        data: PoolInstruction::PausePool {
+            pause_flags: PAUSE_FLAG_SWAPS,
+            pool_id: foundation.pool_config.pool_state_pda,
+        }.try_to_vec()?,
+    };
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let pause_tx = Transaction::new_signed_with_payer(&[pause_ix], Some(&foundation.env.payer.pubkey()), &[&foundation.env.payer], blockhash);
+    foundation.env.banks_client.process_transaction(pause_tx).await?;
+
+    let (can_swap, reason_code) = simulate_can_swap(&mut foundation, &token_a_mint, 1_000).await?;
+    assert_eq!(can_swap, 0, "Paused pool must not allow the swap");
+    assert_eq!(reason_code, PoolError::PoolSwapsPaused.error_code() as u64, "Reason must be the pool swap pause");
+
+    println!("✅ CanSwap: healthy pool allowed, paused pool blocked with code {}", reason_code);
+    Ok(())
+}
+
+/// Builds a GetSwapQuote instruction for the given pool
+fn create_get_swap_quote_instruction(pool_state_pda: &Pubkey, input_token_mint: &Pubkey, amount_in: u64) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*pool_state_pda, false),
+        ],
+        data: PoolInstruction::GetSwapQuote {
+            input_token_mint: *input_token_mint,
+            amount_in,
+            pool_id: *pool_state_pda,
+        }.try_to_vec().unwrap(),
+    }
+}
+
+/// Quoted output and fee must match an executed swap exactly in both directions, for several ratios
+#[tokio::test]
+#[serial]
+async fn test_get_swap_quote_matches_executed_swap() -> TestResult {
+    for ratio in [1u64, 3, 7] {
+        let mut foundation = create_liquidity_test_foundation(Some(ratio)).await?;
+        let pool_state_pda = foundation.pool_config.pool_state_pda;
+        let token_a_mint = foundation.pool_config.token_a_mint;
+        let token_b_mint = foundation.pool_config.token_b_mint;
+
+        // Provide liquidity on both sides
+        let user1_pubkey = foundation.user1.pubkey();
+        let (user1_token_a, user1_token_b) = if foundation.pool_config.token_a_is_the_multiple {
+            (foundation.user1_primary_account.pubkey(), foundation.user1_base_account.pubkey())
+        } else {
+            (foundation.user1_base_account.pubkey(), foundation.user1_primary_account.pubkey())
+        };
+        let user1_lp_a = foundation.user1_lp_a_account.pubkey();
+        let user1_lp_b = foundation.user1_lp_b_account.pubkey();
+        execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_a, &user1_lp_a, &token_a_mint, 1_000_000).await?;
+        execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &token_b_mint, 1_000_000).await?;
+
+        let user2_pubkey = foundation.user2.pubkey();
+        let (user2_token_a, user2_token_b) = if foundation.pool_config.token_a_is_the_multiple {
+            (foundation.user2_primary_account.pubkey(), foundation.user2_base_account.pubkey())
+        } else {
+            (foundation.user2_base_account.pubkey(), foundation.user2_primary_account.pubkey())
+        };
+
+        // Odd amounts so at least one direction truncates
+        for (input_mint, input_account, output_account, amount_in) in [
+            (token_a_mint, user2_token_a, user2_token_b, 10_001u64),
+            (token_b_mint, user2_token_b, user2_token_a, 10_001u64),
+        ] {
+            let ix = create_get_swap_quote_instruction(&pool_state_pda, &input_mint, amount_in);
+            let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, ix).await?;
+            assert_eq!(return_data.len(), 24, "GetSwapQuote must return three u64 values");
+            let quoted_out = u64::from_le_bytes(return_data[0..8].try_into()?);
+            let quoted_fee = u64::from_le_bytes(return_data[8..16].try_into()?);
+            let has_liquidity = u64::from_le_bytes(return_data[16..24].try_into()?);
+            assert_eq!(has_liquidity, 1, "Pool holds enough liquidity for this quote");
+
+            let output_before = get_token_balance(&mut foundation.env.banks_client, &output_account).await;
+            let fees_before = get_pool_state(&mut foundation.env.banks_client, &pool_state_pda).await.unwrap().collected_swap_contract_fees;
+
+            execute_swap_operation(&mut foundation, &user2_pubkey, &input_account, &output_account, &input_mint, amount_in).await?;
+
+            let output_after = get_token_balance(&mut foundation.env.banks_client, &output_account).await;
+            let fees_after = get_pool_state(&mut foundation.env.banks_client, &pool_state_pda).await.unwrap().collected_swap_contract_fees;
+            assert_eq!(output_after - output_before, quoted_out, "Executed output must equal the quote");
+            assert_eq!(fees_after - fees_before, quoted_fee, "Collected swap fee must equal the quoted fee");
+            assert_eq!(quoted_fee, SWAP_CONTRACT_FEE);
+
+            println!("✅ Ratio {}: quote {} in → {} out (fee {} lamports) matched execution", ratio, amount_in, quoted_out, quoted_fee);
+        }
+
+        // A quote larger than the pool can pay is still returned, flagged as unfillable
+        let ix = create_get_swap_quote_instruction(&pool_state_pda, &token_a_mint, u64::MAX / 1_000_000);
+        let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, ix).await?;
+        assert_eq!(u64::from_le_bytes(return_data[16..24].try_into()?), 0, "Oversized quote must report insufficient liquidity");
+    }
+
+    Ok(())
+}
+
+/// Builds a GetSwapFeeBreakdown instruction for the given pool
+fn create_get_swap_fee_breakdown_instruction(pool_state_pda: &Pubkey) -> Instruction {
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*pool_state_pda, false),
+            AccountMeta::new_readonly(system_state_pda, false),
+        ],
+        data: PoolInstruction::GetSwapFeeBreakdown {
+            pool_id: *pool_state_pda,
+        }.try_to_vec().unwrap(),
+    }
+}
+
+/// The portions are the system swap SOL fee and the pool's swap contract fee, and sum to the total
+#[tokio::test]
+#[serial]
+async fn test_get_swap_fee_breakdown_portions() -> TestResult {
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &PROGRAM_ID);
+
+    // Any protocol fee different from the pool fee shows which portion is which
+    let swap_sol_fee = SWAP_CONTRACT_FEE / 3;
+    let set_fee_ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(foundation.system_authority.pubkey(), true),
+            AccountMeta::new(system_state_pda, false),
+            AccountMeta::new_readonly(get_test_program_data_address(&PROGRAM_ID), false),
+        ],
+        data: PoolInstruction::SetSwapSolFee { swap_sol_fee }.try_to_vec()?,
+    };
+    let mut tx = Transaction::new_with_payer(&[set_fee_ix], Some(&foundation.env.payer.pubkey()));
+    tx.sign(&[&foundation.env.payer, &foundation.system_authority], foundation.env.banks_client.get_latest_blockhash().await?);
+    foundation.env.banks_client.process_transaction(tx).await?;
+
+    let ix = create_get_swap_fee_breakdown_instruction(&pool_state_pda);
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, ix).await?;
+    assert_eq!(return_data.len(), 24, "GetSwapFeeBreakdown must return three u64 values");
+    let total_fee = u64::from_le_bytes(return_data[0..8].try_into()?);
+    let protocol_portion = u64::from_le_bytes(return_data[8..16].try_into()?);
+    let pool_portion = u64::from_le_bytes(return_data[16..24].try_into()?);
+
+    assert_eq!(protocol_portion + pool_portion, total_fee, "Portions must sum to the total fee");
+    assert_eq!(protocol_portion, swap_sol_fee, "Protocol portion is the system swap SOL fee");
+    assert_eq!(pool_portion, SWAP_CONTRACT_FEE, "Pool portion is the pool's swap contract fee");
+
+    println!("✅ Total {} = protocol {} + pool {} lamports", total_fee, protocol_portion, pool_portion);
+
+    Ok(())
+}
+
+/// Swaps made today must show up in entry 0 of GetFeeHistory, split by input token
+#[tokio::test]
+#[serial]
+async fn test_get_fee_history_records_todays_swap_fees() -> TestResult {
+    use fixed_ratio_trading::types::FeeHistory;
+    use fixed_ratio_trading::constants::{FEE_HISTORY_DAYS, SECONDS_PER_DAY};
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let pool_state_pda = foundation.pool_config.pool_state_pda;
+    let token_a_mint = foundation.pool_config.token_a_mint;
+    let token_b_mint = foundation.pool_config.token_b_mint;
+
+    let user1_pubkey = foundation.user1.pubkey();
+    let user1_primary = foundation.user1_primary_account.pubkey();
+    let user1_base = foundation.user1_base_account.pubkey();
+    let user1_lp_a = foundation.user1_lp_a_account.pubkey();
+    let user1_lp_b = foundation.user1_lp_b_account.pubkey();
+    let (user1_token_a, user1_token_b) = if foundation.pool_config.token_a_is_the_multiple {
+        (user1_primary, user1_base)
+    } else {
+        (user1_base, user1_primary)
+    };
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_a, &user1_lp_a, &token_a_mint, 1_000).await?;
+    execute_deposit_operation(&mut foundation, &user1_pubkey, &user1_token_b, &user1_lp_b, &token_b_mint, 1_000).await?;
+
+    let get_fee_history_ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![AccountMeta::new_readonly(pool_state_pda, false)],
+        data: PoolInstruction::GetFeeHistory { pool_id: pool_state_pda }.try_to_vec()?,
+    };
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, get_fee_history_ix.clone()).await?;
+    let history = FeeHistory::try_from_slice(&return_data)?;
+    assert_eq!(history.fees_token_a, [0; FEE_HISTORY_DAYS], "A pool without swaps has no fee history");
+    assert_eq!(history.fees_token_b, [0; FEE_HISTORY_DAYS]);
+
+    // Two swaps paying in Token A, one paying in Token B
+    let user2_pubkey = foundation.user2.pubkey();
+    let (user2_token_a, user2_token_b) = if foundation.pool_config.token_a_is_the_multiple {
+        (foundation.user2_primary_account.pubkey(), foundation.user2_base_account.pubkey())
+    } else {
+        (foundation.user2_base_account.pubkey(), foundation.user2_primary_account.pubkey())
+    };
+    execute_swap_operation(&mut foundation, &user2_pubkey, &user2_token_a, &user2_token_b, &token_a_mint, 100).await?;
+    execute_swap_operation(&mut foundation, &user2_pubkey, &user2_token_a, &user2_token_b, &token_a_mint, 200).await?;
+    execute_swap_operation(&mut foundation, &user2_pubkey, &user2_token_b, &user2_token_a, &token_b_mint, 100).await?;
+
+    let return_data = simulate_and_get_return_data(&mut foundation.env.banks_client, &foundation.env.payer, get_fee_history_ix).await?;
+    assert_eq!(return_data.len(), 8 + 16 * FEE_HISTORY_DAYS, "FeeHistory is a day start plus two 7-day arrays");
+    let history = FeeHistory::try_from_slice(&return_data)?;
+    assert_eq!(history.day_start % SECONDS_PER_DAY, 0, "Days start at UTC midnight");
+    assert_eq!(history.fees_token_a[0], 2 * SWAP_CONTRACT_FEE);
+    assert_eq!(history.fees_token_b[0], SWAP_CONTRACT_FEE);
+    assert!(history.fees_token_a[1..].iter().chain(&history.fees_token_b[1..]).all(|fee| *fee == 0), "Earlier days had no swaps");
+
+    println!("✅ Today's swap fees: {} lamports from Token A input, {} from Token B input", history.fees_token_a[0], history.fees_token_b[0]);
+    Ok(())
+}
@@ -0,0 +1,324 @@
+/*
+MIT License
+
+Copyright (c) 2024 Davinci
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Pool State Invariant Tests
+//!
+//! Property-style tests that drive a pool through seeded random sequences of
+//! deposits, withdrawals and swaps. After every operation the on-chain state is
+//! compared against a simple off-chain model and a set of accounting invariants:
+//! - Vault balances equal the tracked Token A / Token B liquidity
+//! - LP mint supplies equal net deposits per side
+//! - Pool liquidity is always worth at least the outstanding LP supply
+//! - Token supplies are conserved between users and vaults
+//! - Pool SOL balance grows by exactly the fees tracked in pool state
+//! - No tracked field underflows (wraps past the token supply)
+//!
+//! Failures print the seed and step so a sequence can be replayed exactly.
+
+#![allow(unused_imports)]
+#![allow(dead_code)]
+
+mod common;
+
+use common::*;
+use common::liquidity_helpers::{
+    create_liquidity_test_foundation,
+    execute_deposit_operation,
+    execute_swap_operation,
+    execute_withdrawal_operation,
+    LiquidityTestFoundation,
+};
+use serial_test::serial;
+use std::collections::HashSet;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+/// Operations performed per seeded sequence
+const STEPS_PER_SEQUENCE: usize = 40;
+
+/// Upper bound on a single random operation amount
+const MAX_OPERATION_AMOUNT: u64 = 200_000;
+
+/// SplitMix64 generator so every sequence is reproducible from its seed
+struct SeededRng(u64);
+
+impl SeededRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `1..=max` (`max` must be non-zero)
+    fn amount(&mut self, max: u64) -> u64 {
+        1 + self.next_u64() % max
+    }
+}
+
+/// Expected pool accounting, updated alongside every successful operation
+#[derive(Debug, Default)]
+struct PoolModel {
+    total_token_a: u64,
+    total_token_b: u64,
+    lp_a_supply: u64,
+    lp_b_supply: u64,
+    sol_fees: u64,
+}
+
+/// Token accounts of one foundation user: (signer, token A, token B, LP A, LP B)
+fn user_accounts(foundation: &LiquidityTestFoundation, user_index: usize) -> (Pubkey, Pubkey, Pubkey, Pubkey, Pubkey) {
+    let (user, primary, base, lp_a, lp_b) = if user_index == 0 {
+        (&foundation.user1, &foundation.user1_primary_account, &foundation.user1_base_account,
+         &foundation.user1_lp_a_account, &foundation.user1_lp_b_account)
+    } else {
+        (&foundation.user2, &foundation.user2_primary_account, &foundation.user2_base_account,
+         &foundation.user2_lp_a_account, &foundation.user2_lp_b_account)
+    };
+    let (token_a, token_b) = if foundation.pool_config.token_a_is_the_multiple {
+        (primary.pubkey(), base.pubkey())
+    } else {
+        (base.pubkey(), primary.pubkey())
+    };
+    (user.pubkey(), token_a, token_b, lp_a.pubkey(), lp_b.pubkey())
+}
+
+/// Reads a mint's current supply
+async fn mint_supply(banks_client: &mut BanksClient, mint: &Pubkey) -> u64 {
+    let account = banks_client.get_account(*mint).await
+        .expect("Failed to fetch mint")
+        .expect("Mint should exist");
+    MintAccount::unpack(&account.data).expect("Failed to unpack mint").supply
+}
+
+/// Asserts every pool invariant against the model; `context` identifies the seed and step
+async fn assert_invariants(
+    foundation: &mut LiquidityTestFoundation,
+    model: &PoolModel,
+    baseline: &PoolState,
+    baseline_lamports: u64,
+    token_supplies: (u64, u64),
+    context: &str,
+) -> TestResult {
+    let config = foundation.pool_config.clone();
+    let pool = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await
+        .expect("Pool state should exist");
+    let (supply_a, supply_b) = token_supplies;
+
+    // Tracked liquidity follows the model and never wraps past the token supply
+    assert_eq!(pool.total_token_a_liquidity, model.total_token_a, "{}: Token A liquidity drifted from model", context);
+    assert_eq!(pool.total_token_b_liquidity, model.total_token_b, "{}: Token B liquidity drifted from model", context);
+    assert!(pool.total_token_a_liquidity <= supply_a, "{}: Token A liquidity underflowed", context);
+    assert!(pool.total_token_b_liquidity <= supply_b, "{}: Token B liquidity underflowed", context);
+
+    // Vault reserves match tracked liquidity exactly
+    let vault_a = get_token_balance(&mut foundation.env.banks_client, &config.token_a_vault_pda).await;
+    let vault_b = get_token_balance(&mut foundation.env.banks_client, &config.token_b_vault_pda).await;
+    assert_eq!(vault_a, pool.total_token_a_liquidity, "{}: Token A vault does not match tracked liquidity", context);
+    assert_eq!(vault_b, pool.total_token_b_liquidity, "{}: Token B vault does not match tracked liquidity", context);
+
+    // LP supply equals net deposits per side
+    let lp_a_supply = mint_supply(&mut foundation.env.banks_client, &foundation.lp_token_a_mint_pda).await;
+    let lp_b_supply = mint_supply(&mut foundation.env.banks_client, &foundation.lp_token_b_mint_pda).await;
+    assert_eq!(lp_a_supply, model.lp_a_supply, "{}: LP A supply drifted from model", context);
+    assert_eq!(lp_b_supply, model.lp_b_supply, "{}: LP B supply drifted from model", context);
+
+    // Pool holdings are worth at least what LP holders can claim (swaps only round in the pool's favor)
+    let ratio_a = pool.ratio_a_numerator as u128;
+    let ratio_b = pool.ratio_b_denominator as u128;
+    let liquidity_value = pool.total_token_a_liquidity as u128 * ratio_b + pool.total_token_b_liquidity as u128 * ratio_a;
+    let lp_claim_value = lp_a_supply as u128 * ratio_b + lp_b_supply as u128 * ratio_a;
+    assert!(liquidity_value >= lp_claim_value, "{}: Pool liquidity worth less than LP supply", context);
+
+    // Tokens only move between users and vaults
+    let mut user_a_total = 0u64;
+    let mut user_b_total = 0u64;
+    for user_index in 0..2 {
+        let (_, token_a, token_b, _, _) = user_accounts(foundation, user_index);
+        user_a_total += get_token_balance(&mut foundation.env.banks_client, &token_a).await;
+        user_b_total += get_token_balance(&mut foundation.env.banks_client, &token_b).await;
+    }
+    assert_eq!(user_a_total + vault_a, supply_a, "{}: Token A not conserved", context);
+    assert_eq!(user_b_total + vault_b, supply_b, "{}: Token B not conserved", context);
+    assert_eq!(mint_supply(&mut foundation.env.banks_client, &config.token_a_mint).await, supply_a, "{}: Token A supply changed", context);
+    assert_eq!(mint_supply(&mut foundation.env.banks_client, &config.token_b_mint).await, supply_b, "{}: Token B supply changed", context);
+
+    // SOL fees: tracked counters and the pool's lamports grow by exactly the fees paid
+    let collected = (pool.collected_liquidity_fees - baseline.collected_liquidity_fees)
+        + (pool.collected_swap_contract_fees - baseline.collected_swap_contract_fees);
+    assert_eq!(collected, model.sol_fees, "{}: Collected fee counters drifted from model", context);
+    assert_eq!(pool.total_sol_fees_collected - baseline.total_sol_fees_collected, model.sol_fees,
+               "{}: Total SOL fee counter drifted from model", context);
+    let pool_lamports = foundation.env.banks_client.get_balance(config.pool_state_pda).await?;
+    assert_eq!(pool_lamports - baseline_lamports, model.sol_fees, "{}: Pool lamports do not match collected fees", context);
+
+    Ok(())
+}
+
+/// Runs one seeded sequence of random operations, checking invariants after each
+async fn run_invariant_sequence(seed: u64, pool_ratio: u64) -> TestResult {
+    let mut foundation = create_liquidity_test_foundation(Some(pool_ratio)).await?;
+    let mut rng = SeededRng(seed);
+    let config = foundation.pool_config.clone();
+    let (ratio_a, ratio_b) = (config.ratio_a_numerator, config.ratio_b_denominator);
+
+    let baseline = get_pool_state(&mut foundation.env.banks_client, &config.pool_state_pda).await
+        .expect("Pool state should exist");
+    let baseline_lamports = foundation.env.banks_client.get_balance(config.pool_state_pda).await?;
+    let token_supplies = (
+        mint_supply(&mut foundation.env.banks_client, &config.token_a_mint).await,
+        mint_supply(&mut foundation.env.banks_client, &config.token_b_mint).await,
+    );
+    let mut model = PoolModel {
+        total_token_a: baseline.total_token_a_liquidity,
+        total_token_b: baseline.total_token_b_liquidity,
+        lp_a_supply: mint_supply(&mut foundation.env.banks_client, &foundation.lp_token_a_mint_pda).await,
+        lp_b_supply: mint_supply(&mut foundation.env.banks_client, &foundation.lp_token_b_mint_pda).await,
+        sol_fees: 0,
+    };
+    assert_invariants(&mut foundation, &model, &baseline, baseline_lamports, token_supplies, &format!("seed {} initial", seed)).await?;
+
+    // Identical transactions would be rejected as duplicates, so each (op, user, amount) runs once
+    let mut executed = HashSet::new();
+    let mut operations_run = 0usize;
+
+    for step in 0..STEPS_PER_SEQUENCE {
+        let operation = rng.next_u64() % 6;
+        let user_index = (rng.next_u64() % 2) as usize;
+        let (user, token_a, token_b, lp_a, lp_b) = user_accounts(&foundation, user_index);
+        let side_is_a = operation.is_multiple_of(2);
+        let (token_account, lp_account, mint) = if side_is_a {
+            (token_a, lp_a, config.token_a_mint)
+        } else {
+            (token_b, lp_b, config.token_b_mint)
+        };
+
+        let description = match operation {
+            // Deposit into one side
+            0 | 1 => {
+                let balance = get_token_balance(&mut foundation.env.banks_client, &token_account).await;
+                if balance == 0 {
+                    continue;
+                }
+                let amount = rng.amount(balance.min(MAX_OPERATION_AMOUNT));
+                if !executed.insert((operation, user_index, amount)) {
+                    continue;
+                }
+                execute_deposit_operation(&mut foundation, &user, &token_account, &lp_account, &mint, amount).await?;
+                if side_is_a {
+                    model.total_token_a += amount;
+                    model.lp_a_supply += amount;
+                } else {
+                    model.total_token_b += amount;
+                    model.lp_b_supply += amount;
+                }
+                model.sol_fees += baseline.contract_liquidity_fee;
+                format!("deposit {} {}", amount, if side_is_a { "A" } else { "B" })
+            }
+            // Withdraw from one side (bounded by the user's LP and that side's liquidity)
+            2 | 3 => {
+                let lp_balance = get_token_balance(&mut foundation.env.banks_client, &lp_account).await;
+                let side_liquidity = if side_is_a { model.total_token_a } else { model.total_token_b };
+                let limit = lp_balance.min(side_liquidity).min(MAX_OPERATION_AMOUNT);
+                if limit == 0 {
+                    continue;
+                }
+                let amount = rng.amount(limit);
+                if !executed.insert((operation, user_index, amount)) {
+                    continue;
+                }
+                execute_withdrawal_operation(&mut foundation, &user, &lp_account, &token_account, &mint, amount).await?;
+                if side_is_a {
+                    model.total_token_a -= amount;
+                    model.lp_a_supply -= amount;
+                } else {
+                    model.total_token_b -= amount;
+                    model.lp_b_supply -= amount;
+                }
+                model.sol_fees += baseline.contract_liquidity_fee;
+                format!("withdraw {} {}", amount, if side_is_a { "A" } else { "B" })
+            }
+            // Swap from one side into the other (bounded by output liquidity)
+            _ => {
+                let output_account = if side_is_a { token_b } else { token_a };
+                let (num, den, output_liquidity) = if side_is_a {
+                    (ratio_b, ratio_a, model.total_token_b)
+                } else {
+                    (ratio_a, ratio_b, model.total_token_a)
+                };
+                let balance = get_token_balance(&mut foundation.env.banks_client, &token_account).await;
+                let max_input_for_liquidity = ((output_liquidity as u128 * den as u128) / num as u128) as u64;
+                let limit = balance.min(max_input_for_liquidity).min(MAX_OPERATION_AMOUNT);
+                if limit == 0 {
+                    continue;
+                }
+                let amount_in = rng.amount(limit);
+                let amount_out = (amount_in as u128 * num as u128 / den as u128) as u64;
+                if amount_out == 0 || !executed.insert((operation, user_index, amount_in)) {
+                    continue;
+                }
+                execute_swap_operation(&mut foundation, &user, &token_account, &output_account, &mint, amount_in).await?;
+                if side_is_a {
+                    model.total_token_a += amount_in;
+                    model.total_token_b -= amount_out;
+                } else {
+                    model.total_token_b += amount_in;
+                    model.total_token_a -= amount_out;
+                }
+                model.sol_fees += baseline.swap_contract_fee;
+                format!("swap {} {} → {}", amount_in, if side_is_a { "A" } else { "B" }, amount_out)
+            }
+        };
+
+        operations_run += 1;
+        let context = format!("seed {} step {} (user{} {})", seed, step, user_index + 1, description);
+        assert_invariants(&mut foundation, &model, &baseline, baseline_lamports, token_supplies, &context).await?;
+    }
+
+    assert!(operations_run >= STEPS_PER_SEQUENCE / 2, "Seed {} ran only {} operations", seed, operations_run);
+    println!("✅ Seed {}: {} operations, invariants held (A {}, B {}, LP A {}, LP B {})",
+             seed, operations_run, model.total_token_a, model.total_token_b, model.lp_a_supply, model.lp_b_supply);
+    Ok(())
+}
+
+/// Random deposit/withdraw/swap sequences on a 2:1 pool keep all accounting invariants
+#[tokio::test]
+#[serial]
+async fn test_random_sequences_preserve_invariants_2_to_1() -> TestResult {
+    for seed in [1u64, 0x5EED_0002] {
+        run_invariant_sequence(seed, 2).await?;
+    }
+    Ok(())
+}
+
+/// A 3:1 pool truncates swap outputs in both directions, exercising rounding paths
+#[tokio::test]
+#[serial]
+async fn test_random_sequences_preserve_invariants_3_to_1() -> TestResult {
+    for seed in [7u64, 0x5EED_0003] {
+        run_invariant_sequence(seed, 3).await?;
+    }
+    Ok(())
+}
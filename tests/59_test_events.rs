@@ -0,0 +1,156 @@
+/*
+MIT License
+
+Copyright (c) 2024 Davinci
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Program Event Layout Tests
+//!
+//! Guards the Borsh layout and text log lines of the structured events. Indexers
+//! decode these bytes and parse these lines positionally, so any layout change
+//! here must come with a new `EVENT_VERSION`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use fixed_ratio_trading::events::{
+    DepositEvent, FeeWithdrawalEvent, ProgramEvent, SwapEvent, WithdrawEvent, EVENT_VERSION,
+};
+use solana_program::pubkey::Pubkey;
+
+/// Round-trips an event and checks its variant tag and encoded size
+fn assert_event_layout(event: ProgramEvent, expected_tag: u8, expected_len: usize) {
+    let bytes = event.try_to_vec().expect("Event should serialize");
+    assert_eq!(bytes[0], expected_tag, "Variant tag identifies the event type");
+    assert_eq!(bytes.len(), expected_len, "Event layout size changed; bump EVENT_VERSION");
+    assert_eq!(ProgramEvent::try_from_slice(&bytes).expect("Event should deserialize"), event);
+}
+
+#[test]
+fn test_event_version() {
+    assert_eq!(EVENT_VERSION, 1);
+}
+
+#[test]
+fn test_swap_event_layout() {
+    let event = SwapEvent {
+        pool: Pubkey::new_unique(),
+        user: Pubkey::new_unique(),
+        input_mint: Pubkey::new_unique(),
+        amount_in: 10_000,
+        amount_out: 5_000,
+        fee: 27_150,
+    };
+    assert_eq!(SwapEvent::try_from_slice(&event.try_to_vec().unwrap()).unwrap(), event);
+
+    // tag + 3 pubkeys + 3 u64s, with amount_in right after the pubkeys
+    let bytes = ProgramEvent::Swap(event.clone()).try_to_vec().unwrap();
+    assert_eq!(&bytes[1..33], event.pool.as_ref());
+    assert_eq!(u64::from_le_bytes(bytes[97..105].try_into().unwrap()), event.amount_in);
+    assert_event_layout(ProgramEvent::Swap(event), 0, 1 + 3 * 32 + 3 * 8);
+}
+
+#[test]
+fn test_deposit_event_layout() {
+    let event = DepositEvent {
+        pool: Pubkey::new_unique(),
+        user: Pubkey::new_unique(),
+        deposit_mint: Pubkey::new_unique(),
+        amount: 1_000_000,
+        lp_minted: 1_000_000,
+        fee: 1_300_000,
+    };
+    assert_eq!(DepositEvent::try_from_slice(&event.try_to_vec().unwrap()).unwrap(), event);
+    assert_event_layout(ProgramEvent::Deposit(event), 1, 1 + 3 * 32 + 3 * 8);
+}
+
+#[test]
+fn test_withdraw_event_layout() {
+    let event = WithdrawEvent {
+        pool: Pubkey::new_unique(),
+        user: Pubkey::new_unique(),
+        withdraw_mint: Pubkey::new_unique(),
+        lp_burned: 400_000,
+        amount_out: 400_000,
+        fee: 1_300_000,
+    };
+    assert_eq!(WithdrawEvent::try_from_slice(&event.try_to_vec().unwrap()).unwrap(), event);
+    assert_event_layout(ProgramEvent::Withdraw(event), 2, 1 + 3 * 32 + 3 * 8);
+}
+
+#[test]
+fn test_fee_withdrawal_event_layout() {
+    let event = FeeWithdrawalEvent {
+        treasury: Pubkey::new_unique(),
+        destination: Pubkey::new_unique(),
+        amount: 5_000_000,
+        remaining_balance: 95_000_000,
+    };
+    assert_eq!(FeeWithdrawalEvent::try_from_slice(&event.try_to_vec().unwrap()).unwrap(), event);
+    assert_event_layout(ProgramEvent::FeeWithdrawal(event), 3, 1 + 2 * 32 + 2 * 8);
+}
+
+#[test]
+fn test_event_log_lines() {
+    let pool = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let swap = ProgramEvent::Swap(SwapEvent {
+        pool,
+        user: Pubkey::new_unique(),
+        input_mint: mint,
+        amount_in: 10_000,
+        amount_out: 5_000,
+        fee: 27_150,
+    });
+    assert_eq!(swap.log_line(), format!("SWAP|pool={}|in_mint={}|in=10000|out=5000|fee=27150", pool, mint));
+
+    let deposit = ProgramEvent::Deposit(DepositEvent {
+        pool,
+        user: Pubkey::new_unique(),
+        deposit_mint: mint,
+        amount: 1_000,
+        lp_minted: 1_000,
+        fee: 1_300_000,
+    });
+    assert_eq!(deposit.log_line(), format!("DEPOSIT|pool={}|mint={}|amount=1000|lp=1000|fee=1300000", pool, mint));
+
+    let withdraw = ProgramEvent::Withdraw(WithdrawEvent {
+        pool,
+        user: Pubkey::new_unique(),
+        withdraw_mint: mint,
+        lp_burned: 400,
+        amount_out: 400,
+        fee: 1_300_000,
+    });
+    assert_eq!(withdraw.log_line(), format!("WITHDRAW|pool={}|mint={}|lp=400|out=400|fee=1300000", pool, mint));
+
+    let fee_withdrawal = ProgramEvent::FeeWithdrawal(FeeWithdrawalEvent {
+        treasury,
+        destination,
+        amount: 5_000,
+        remaining_balance: 95_000,
+    });
+    assert_eq!(
+        fee_withdrawal.log_line(),
+        format!("FEE_WITHDRAWAL|treasury={}|destination={}|amount=5000|remaining=95000", treasury, destination)
+    );
+}
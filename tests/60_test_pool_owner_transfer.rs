@@ -0,0 +1,275 @@
+/*
+MIT License
+
+Copyright (c) 2024 Davinci
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Pool Ownership Transfer Tests
+//!
+//! Covers the two-step, time-locked pool ownership handoff: `InitiateOwnerTransfer`
+//! nominates a new owner, who must sign `AcceptOwnerTransfer` after
+//! `POOL_OWNER_TRANSFER_DELAY`; the current owner can `CancelOwnerTransfer` before then.
+
+mod common;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use common::setup::create_program_test;
+use fixed_ratio_trading::{
+    constants::{POOL_OWNER_TRANSFER_DELAY, SYSTEM_STATE_SEED_PREFIX},
+    error::PoolError,
+    state::{pool_state::PoolState, system_state::SystemState},
+    types::instructions::PoolInstruction,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::Account,
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+/// A pool state account owned by `pool_owner`, plus the system state it validates against
+struct OwnerTransferSetup {
+    context: ProgramTestContext,
+    pool_owner: Keypair,
+    system_state_pda: Pubkey,
+    pool_state_pda: Pubkey,
+}
+
+async fn setup_pool_with_owner() -> OwnerTransferSetup {
+    let program_id = fixed_ratio_trading::id();
+    let mut program_test = create_program_test();
+    let pool_owner = Keypair::new();
+
+    let token_a_mint = Pubkey::new_unique();
+    let token_b_mint = Pubkey::new_unique();
+    let pool_state_pda = Pubkey::find_program_address(
+        &[
+            b"pool_state",
+            token_a_mint.as_ref(),
+            token_b_mint.as_ref(),
+            &[1u64.to_le_bytes(), 1u64.to_le_bytes()].concat(),
+        ],
+        &program_id,
+    ).0;
+    let pool_state = PoolState {
+        owner: pool_owner.pubkey(),
+        token_a_mint,
+        token_b_mint,
+        ratio_a_numerator: 1,
+        ratio_b_denominator: 1,
+        ..PoolState::default()
+    };
+    program_test.add_account(
+        pool_state_pda,
+        Account {
+            lamports: 10_000_000,
+            data: pool_state.try_to_vec().unwrap(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &program_id);
+    program_test.add_account(
+        system_state_pda,
+        Account {
+            lamports: 1_000_000,
+            data: SystemState::new(Pubkey::new_unique()).try_to_vec().unwrap(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    OwnerTransferSetup {
+        context: program_test.start_with_context().await,
+        pool_owner,
+        system_state_pda,
+        pool_state_pda,
+    }
+}
+
+impl OwnerTransferSetup {
+    fn initiate_ix(&self, signer: &Pubkey, new_owner: Pubkey) -> Instruction {
+        Instruction {
+            program_id: fixed_ratio_trading::id(),
+            accounts: vec![
+                AccountMeta::new_readonly(*signer, true),
+                AccountMeta::new_readonly(self.system_state_pda, false),
+                AccountMeta::new(self.pool_state_pda, false),
+            ],
+            data: PoolInstruction::InitiateOwnerTransfer { new_owner, pool_id: self.pool_state_pda }.try_to_vec().unwrap(),
+        }
+    }
+
+    fn accept_ix(&self, signer: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: fixed_ratio_trading::id(),
+            accounts: vec![
+                AccountMeta::new_readonly(*signer, true),
+                AccountMeta::new_readonly(self.system_state_pda, false),
+                AccountMeta::new(self.pool_state_pda, false),
+            ],
+            data: PoolInstruction::AcceptOwnerTransfer { pool_id: self.pool_state_pda }.try_to_vec().unwrap(),
+        }
+    }
+
+    fn cancel_ix(&self, signer: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: fixed_ratio_trading::id(),
+            accounts: vec![
+                AccountMeta::new_readonly(*signer, true),
+                AccountMeta::new(self.pool_state_pda, false),
+            ],
+            data: PoolInstruction::CancelOwnerTransfer { pool_id: self.pool_state_pda }.try_to_vec().unwrap(),
+        }
+    }
+
+    /// Sends `instruction` signed by the payer and `signer`, returning the custom error code on failure
+    async fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), u32> {
+        let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+        let mut tx = Transaction::new_with_payer(&[instruction], Some(&self.context.payer.pubkey()));
+        tx.sign(&[&self.context.payer, signer], blockhash);
+        match self.context.banks_client.process_transaction(tx).await {
+            Ok(()) => Ok(()),
+            Err(error) => match error.unwrap() {
+                TransactionError::InstructionError(0, InstructionError::Custom(code)) => Err(code),
+                other => panic!("Unexpected transaction error: {:?}", other),
+            },
+        }
+    }
+
+    async fn pool_state(&mut self) -> PoolState {
+        let account = self.context.banks_client.get_account(self.pool_state_pda).await.unwrap().unwrap();
+        PoolState::try_from_slice(&account.data).unwrap()
+    }
+
+    /// Moves the clock to the moment the pending transfer becomes acceptable
+    async fn warp_past_delay(&mut self) {
+        let requested_at = self.pool_state().await.owner_transfer_requested_at;
+        let mut clock = self.context.banks_client.get_sysvar::<Clock>().await.unwrap();
+        clock.unix_timestamp = requested_at + POOL_OWNER_TRANSFER_DELAY;
+        self.context.set_sysvar(&clock);
+    }
+}
+
+#[tokio::test]
+async fn test_owner_transfer_initiate_then_accept() -> TestResult {
+    let mut setup = setup_pool_with_owner().await;
+    let owner = setup.pool_owner.insecure_clone();
+    let new_owner = Keypair::new();
+
+    // Step 1: The owner nominates the new owner; ownership does not move yet
+    setup.send(setup.initiate_ix(&owner.pubkey(), new_owner.pubkey()), &owner).await.expect("Initiate must succeed");
+    let pool_state = setup.pool_state().await;
+    assert_eq!(pool_state.owner, owner.pubkey());
+    assert_eq!(pool_state.pending_owner, new_owner.pubkey());
+    assert!(pool_state.owner_transfer_requested_at > 0);
+
+    // Step 2: After the delay the nominee accepts and becomes the owner
+    setup.warp_past_delay().await;
+    setup.send(setup.accept_ix(&new_owner.pubkey()), &new_owner).await.expect("Accept after the delay must succeed");
+    let pool_state = setup.pool_state().await;
+    assert_eq!(pool_state.owner, new_owner.pubkey());
+    assert!(!pool_state.owner_transfer_pending());
+    assert_eq!(pool_state.owner_transfer_requested_at, 0);
+
+    // Step 3: The previous owner no longer controls the pool
+    let result = setup.send(setup.initiate_ix(&owner.pubkey(), owner.pubkey()), &owner).await;
+    assert_eq!(result, Err(PoolError::Unauthorized.error_code()));
+
+    println!("✅ Pool ownership moved to {} after the delay", new_owner.pubkey());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_owner_transfer_initiate_then_cancel() -> TestResult {
+    let mut setup = setup_pool_with_owner().await;
+    let owner = setup.pool_owner.insecure_clone();
+    let new_owner = Keypair::new();
+
+    setup.send(setup.initiate_ix(&owner.pubkey(), new_owner.pubkey()), &owner).await.expect("Initiate must succeed");
+
+    // Only the current owner may cancel
+    let result = setup.send(setup.cancel_ix(&new_owner.pubkey()), &new_owner).await;
+    assert_eq!(result, Err(PoolError::Unauthorized.error_code()));
+
+    setup.send(setup.cancel_ix(&owner.pubkey()), &owner).await.expect("Owner cancel must succeed");
+    let pool_state = setup.pool_state().await;
+    assert!(!pool_state.owner_transfer_pending());
+    assert_eq!(pool_state.owner, owner.pubkey());
+
+    // The cancelled nominee cannot accept, even after the delay
+    setup.warp_past_delay().await;
+    let result = setup.send(setup.accept_ix(&new_owner.pubkey()), &new_owner).await;
+    assert_eq!(result, Err(PoolError::NoPendingOwnerTransfer.error_code()));
+    assert_eq!(setup.pool_state().await.owner, owner.pubkey());
+
+    println!("✅ Cancelled owner transfer can no longer be accepted");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_owner_transfer_accept_before_delay_fails() -> TestResult {
+    let mut setup = setup_pool_with_owner().await;
+    let owner = setup.pool_owner.insecure_clone();
+    let new_owner = Keypair::new();
+
+    setup.send(setup.initiate_ix(&owner.pubkey(), new_owner.pubkey()), &owner).await.expect("Initiate must succeed");
+
+    let result = setup.send(setup.accept_ix(&new_owner.pubkey()), &new_owner).await;
+    let expected_code = PoolError::OwnerTransferTimelockActive { remaining_seconds: 0 }.error_code();
+    assert_eq!(result, Err(expected_code), "Accepting before the delay must fail");
+    let pool_state = setup.pool_state().await;
+    assert_eq!(pool_state.owner, owner.pubkey());
+    assert_eq!(pool_state.pending_owner, new_owner.pubkey(), "Transfer stays pending");
+
+    println!("✅ Early acceptance rejected with code {}", expected_code);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_owner_transfer_accept_by_wrong_key_fails() -> TestResult {
+    let mut setup = setup_pool_with_owner().await;
+    let owner = setup.pool_owner.insecure_clone();
+    let new_owner = Keypair::new();
+    let stranger = Keypair::new();
+
+    setup.send(setup.initiate_ix(&owner.pubkey(), new_owner.pubkey()), &owner).await.expect("Initiate must succeed");
+    setup.warp_past_delay().await;
+
+    for wrong_key in [&stranger, &owner] {
+        let result = setup.send(setup.accept_ix(&wrong_key.pubkey()), wrong_key).await;
+        assert_eq!(result, Err(PoolError::Unauthorized.error_code()), "Only the pending owner may accept");
+    }
+    let pool_state = setup.pool_state().await;
+    assert_eq!(pool_state.owner, owner.pubkey());
+    assert_eq!(pool_state.pending_owner, new_owner.pubkey());
+
+    println!("✅ Acceptance by keys other than the pending owner rejected");
+    Ok(())
+}
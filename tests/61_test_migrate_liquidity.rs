@@ -0,0 +1,300 @@
+/*
+MIT License
+
+Copyright (c) 2024 Davinci
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Liquidity Migration Tests
+//!
+//! Covers `MigrateLiquidity`: the pool owner moves all vault liquidity of a pool into a
+//! freshly created pool with the same token pair at a different ratio, with the destination
+//! LP tokens escrowed for the source LP holders, and `ClaimMigratedLiquidity`, through which
+//! each holder exchanges their source LP tokens for them.
+
+mod common;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use common::{
+    liquidity_helpers::{create_liquidity_test_foundation, execute_deposit_operation, execute_withdrawal_operation, LiquidityTestFoundation},
+    pool_helpers::{create_pool_new_pattern, PoolConfig},
+    tokens::{create_token_account, get_token_balance},
+};
+use fixed_ratio_trading::{
+    constants::{
+        LP_TOKEN_A_MINT_SEED_PREFIX, LP_TOKEN_B_MINT_SEED_PREFIX, MIGRATION_ESCROW_LP_A_SEED_PREFIX,
+        MIGRATION_ESCROW_LP_B_SEED_PREFIX, SYSTEM_STATE_SEED_PREFIX,
+    },
+    error::PoolError,
+    state::PoolState,
+    types::instructions::PoolInstruction,
+};
+use serial_test::serial;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+/// LP mint PDAs of a pool
+fn lp_mints(pool_state_pda: &Pubkey) -> (Pubkey, Pubkey) {
+    let program_id = fixed_ratio_trading::id();
+    (
+        Pubkey::find_program_address(&[LP_TOKEN_A_MINT_SEED_PREFIX, pool_state_pda.as_ref()], &program_id).0,
+        Pubkey::find_program_address(&[LP_TOKEN_B_MINT_SEED_PREFIX, pool_state_pda.as_ref()], &program_id).0,
+    )
+}
+
+/// Migration escrow PDAs of a source pool
+fn escrows(source_pool_state_pda: &Pubkey) -> (Pubkey, Pubkey) {
+    let program_id = fixed_ratio_trading::id();
+    (
+        Pubkey::find_program_address(&[MIGRATION_ESCROW_LP_A_SEED_PREFIX, source_pool_state_pda.as_ref()], &program_id).0,
+        Pubkey::find_program_address(&[MIGRATION_ESCROW_LP_B_SEED_PREFIX, source_pool_state_pda.as_ref()], &program_id).0,
+    )
+}
+
+fn migrate_ix(signer: &Pubkey, source: &PoolConfig, dest: &PoolConfig) -> Instruction {
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &fixed_ratio_trading::id());
+    let (dest_lp_a_mint, dest_lp_b_mint) = lp_mints(&dest.pool_state_pda);
+    let (escrow_lp_a, escrow_lp_b) = escrows(&source.pool_state_pda);
+    Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new(*signer, true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new(source.pool_state_pda, false),
+            AccountMeta::new(source.token_a_vault_pda, false),
+            AccountMeta::new(source.token_b_vault_pda, false),
+            AccountMeta::new(dest.pool_state_pda, false),
+            AccountMeta::new(dest.token_a_vault_pda, false),
+            AccountMeta::new(dest.token_b_vault_pda, false),
+            AccountMeta::new(dest_lp_a_mint, false),
+            AccountMeta::new(dest_lp_b_mint, false),
+            AccountMeta::new(escrow_lp_a, false),
+            AccountMeta::new(escrow_lp_b, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+        ],
+        data: PoolInstruction::MigrateLiquidity {
+            source_pool_id: source.pool_state_pda,
+            dest_pool_id: dest.pool_state_pda,
+        }.try_to_vec().unwrap(),
+    }
+}
+
+/// Claim of `lp_amount` source LP tokens of side A (`side_a`) or B
+fn claim_ix(holder: &Pubkey, source: &PoolConfig, dest: &PoolConfig, side_a: bool, holder_source_lp: &Pubkey, holder_dest_lp: &Pubkey, lp_amount: u64) -> Instruction {
+    let (system_state_pda, _) = Pubkey::find_program_address(&[SYSTEM_STATE_SEED_PREFIX], &fixed_ratio_trading::id());
+    let (source_lp_a_mint, source_lp_b_mint) = lp_mints(&source.pool_state_pda);
+    let (dest_lp_a_mint, dest_lp_b_mint) = lp_mints(&dest.pool_state_pda);
+    let (escrow_lp_a, escrow_lp_b) = escrows(&source.pool_state_pda);
+    let (source_lp_mint, dest_lp_mint, escrow) = if side_a {
+        (source_lp_a_mint, dest_lp_a_mint, escrow_lp_a)
+    } else {
+        (source_lp_b_mint, dest_lp_b_mint, escrow_lp_b)
+    };
+    Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*holder, true),
+            AccountMeta::new_readonly(system_state_pda, false),
+            AccountMeta::new_readonly(source.pool_state_pda, false),
+            AccountMeta::new(source_lp_mint, false),
+            AccountMeta::new(*holder_source_lp, false),
+            AccountMeta::new_readonly(dest.pool_state_pda, false),
+            AccountMeta::new_readonly(dest_lp_mint, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(*holder_dest_lp, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: PoolInstruction::ClaimMigratedLiquidity {
+            source_pool_id: source.pool_state_pda,
+            dest_pool_id: dest.pool_state_pda,
+            lp_amount,
+        }.try_to_vec().unwrap(),
+    }
+}
+
+/// Sends `instruction` signed by the payer and `signer`, returning the custom error code on failure
+async fn send(foundation: &mut LiquidityTestFoundation, instruction: Instruction, signer: &Keypair) -> Result<(), u32> {
+    let payer = foundation.env.payer.insecure_clone();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await.unwrap();
+    let mut tx = Transaction::new_with_payer(
+        &[ComputeBudgetInstruction::set_compute_unit_limit(400_000), instruction],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, signer], blockhash);
+    match foundation.env.banks_client.process_transaction(tx).await {
+        Ok(()) => Ok(()),
+        Err(error) => match error.unwrap() {
+            TransactionError::InstructionError(1, InstructionError::Custom(code)) => Err(code),
+            other => panic!("Unexpected transaction error: {:?}", other),
+        },
+    }
+}
+
+async fn pool_state(foundation: &mut LiquidityTestFoundation, pool_state_pda: Pubkey) -> PoolState {
+    let account = foundation.env.banks_client.get_account(pool_state_pda).await.unwrap().unwrap();
+    PoolState::try_from_slice(&account.data).unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_migrate_liquidity_to_new_ratio_pool() -> TestResult {
+    // Source pool at 2:1 with liquidity on both sides, all of it user1's
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let source = foundation.pool_config.clone();
+    let user = foundation.user1.insecure_clone();
+    let (user_token_a, user_token_b) = if source.token_a_is_the_multiple {
+        (foundation.user1_primary_account.pubkey(), foundation.user1_base_account.pubkey())
+    } else {
+        (foundation.user1_base_account.pubkey(), foundation.user1_primary_account.pubkey())
+    };
+    let user_lp_a = foundation.user1_lp_a_account.pubkey();
+    let user_lp_b = foundation.user1_lp_b_account.pubkey();
+    execute_deposit_operation(&mut foundation, &user.pubkey(), &user_token_a, &user_lp_a, &source.token_a_mint, 10_000).await?;
+    execute_deposit_operation(&mut foundation, &user.pubkey(), &user_token_b, &user_lp_b, &source.token_b_mint, 5_000).await?;
+
+    // Destination pool with the same tokens at a corrected 3:1 ratio, owned by the same payer
+    let owner = foundation.env.payer.insecure_clone();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    let dest = create_pool_new_pattern(
+        &mut foundation.env.banks_client,
+        &owner,
+        blockhash,
+        &foundation.primary_mint,
+        &foundation.base_mint,
+        Some(3),
+    ).await?;
+    let (dest_lp_a_mint, dest_lp_b_mint) = lp_mints(&dest.pool_state_pda);
+    let (escrow_lp_a, escrow_lp_b) = escrows(&source.pool_state_pda);
+
+    // A non-owner cannot migrate
+    let outsider = foundation.user2.insecure_clone();
+    let outsider_ix = migrate_ix(&outsider.pubkey(), &source, &dest);
+    assert_eq!(send(&mut foundation, outsider_ix, &outsider).await, Err(PoolError::Unauthorized.error_code()));
+
+    // The owner moves both vaults into the destination pool; its LP goes to the escrows, not the owner
+    let ix = migrate_ix(&owner.pubkey(), &source, &dest);
+    send(&mut foundation, ix, &owner).await.expect("Owner migration must succeed");
+
+    let banks = &mut foundation.env.banks_client;
+    assert_eq!(get_token_balance(banks, &source.token_a_vault_pda).await, 0);
+    assert_eq!(get_token_balance(banks, &source.token_b_vault_pda).await, 0);
+    assert_eq!(get_token_balance(banks, &dest.token_a_vault_pda).await, 10_000);
+    assert_eq!(get_token_balance(banks, &dest.token_b_vault_pda).await, 5_000);
+    assert_eq!(get_token_balance(banks, &escrow_lp_a).await, 10_000, "Destination LP A escrowed 1:1");
+    assert_eq!(get_token_balance(banks, &escrow_lp_b).await, 5_000, "Destination LP B escrowed 1:1");
+
+    let source_state = pool_state(&mut foundation, source.pool_state_pda).await;
+    assert_eq!((source_state.total_token_a_liquidity, source_state.total_token_b_liquidity), (0, 0));
+    let dest_state = pool_state(&mut foundation, dest.pool_state_pda).await;
+    assert_eq!((dest_state.total_token_a_liquidity, dest_state.total_token_b_liquidity), (10_000, 5_000));
+
+    // The destination now holds liquidity, and the source still has LP tokens outstanding:
+    // neither can receive another migration
+    let again_ix = migrate_ix(&owner.pubkey(), &dest, &source);
+    assert_eq!(send(&mut foundation, again_ix, &owner).await, Err(PoolError::MigrationDestinationNotEmpty { dest_pool: source.pool_state_pda }.error_code()));
+
+    // user1 exchanges their source LP tokens for the escrowed destination LP tokens
+    let user_dest_lp_a = Keypair::new();
+    let user_dest_lp_b = Keypair::new();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+    for (account, mint) in [(&user_dest_lp_a, &dest_lp_a_mint), (&user_dest_lp_b, &dest_lp_b_mint)] {
+        create_token_account(&mut foundation.env.banks_client, &owner, blockhash, account, mint, &user.pubkey()).await?;
+    }
+
+    // Nobody can claim more than the holder burns: an outsider holding no source LP gets nothing
+    let outsider_source_lp_a = Keypair::new();
+    let (source_lp_a_mint, _) = lp_mints(&source.pool_state_pda);
+    create_token_account(&mut foundation.env.banks_client, &owner, blockhash, &outsider_source_lp_a, &source_lp_a_mint, &outsider.pubkey()).await?;
+    let outsider_claim = claim_ix(&outsider.pubkey(), &source, &dest, true, &outsider_source_lp_a.pubkey(), &user_dest_lp_a.pubkey(), 10_000);
+    assert!(send(&mut foundation, outsider_claim, &outsider).await.is_err(), "Claim without source LP tokens must fail");
+
+    let claim_a = claim_ix(&user.pubkey(), &source, &dest, true, &user_lp_a, &user_dest_lp_a.pubkey(), 10_000);
+    send(&mut foundation, claim_a, &user).await.expect("Holder claim of LP A must succeed");
+    let claim_b = claim_ix(&user.pubkey(), &source, &dest, false, &user_lp_b, &user_dest_lp_b.pubkey(), 5_000);
+    send(&mut foundation, claim_b, &user).await.expect("Holder claim of LP B must succeed");
+
+    let banks = &mut foundation.env.banks_client;
+    assert_eq!(get_token_balance(banks, &user_lp_a).await, 0, "Source LP A burned");
+    assert_eq!(get_token_balance(banks, &user_lp_b).await, 0, "Source LP B burned");
+    assert_eq!(get_token_balance(banks, &user_dest_lp_a.pubkey()).await, 10_000, "Destination LP A claimed 1:1");
+    assert_eq!(get_token_balance(banks, &user_dest_lp_b.pubkey()).await, 5_000, "Destination LP B claimed 1:1");
+    assert_eq!(get_token_balance(banks, &escrow_lp_a).await, 0);
+    assert_eq!(get_token_balance(banks, &escrow_lp_b).await, 0);
+
+    // The escrow is empty, so a further claim fails
+    let extra_claim = claim_ix(&user.pubkey(), &source, &dest, true, &user_lp_a, &user_dest_lp_a.pubkey(), 1);
+    assert_eq!(
+        send(&mut foundation, extra_claim, &user).await,
+        Err(PoolError::InsufficientFunds { required: 1, available: 0, account: escrow_lp_a }.error_code()),
+    );
+
+    // user1 recovers the deposited tokens from the destination pool
+    let token_a_before = get_token_balance(&mut foundation.env.banks_client, &user_token_a).await;
+    let token_b_before = get_token_balance(&mut foundation.env.banks_client, &user_token_b).await;
+    foundation.pool_config = dest.clone();
+    foundation.lp_token_a_mint_pda = dest_lp_a_mint;
+    foundation.lp_token_b_mint_pda = dest_lp_b_mint;
+    execute_withdrawal_operation(&mut foundation, &user.pubkey(), &user_dest_lp_a.pubkey(), &user_token_a, &dest.token_a_mint, 10_000).await?;
+    execute_withdrawal_operation(&mut foundation, &user.pubkey(), &user_dest_lp_b.pubkey(), &user_token_b, &dest.token_b_mint, 5_000).await?;
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_a).await, token_a_before + 10_000);
+    assert_eq!(get_token_balance(&mut foundation.env.banks_client, &user_token_b).await, token_b_before + 5_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn test_migrate_liquidity_rejects_different_token_pair() -> TestResult {
+    use common::tokens::create_mint;
+
+    let mut foundation = create_liquidity_test_foundation(Some(2)).await?;
+    let source = foundation.pool_config.clone();
+    let owner = foundation.env.payer.insecure_clone();
+    let blockhash = foundation.env.banks_client.get_latest_blockhash().await?;
+
+    // Destination shares Token A's side but pairs it with an unrelated mint
+    let other_mint = Keypair::new();
+    create_mint(&mut foundation.env.banks_client, &owner, blockhash, &other_mint, Some(0)).await?;
+    let dest = create_pool_new_pattern(
+        &mut foundation.env.banks_client,
+        &owner,
+        blockhash,
+        &foundation.primary_mint,
+        &other_mint,
+        Some(2),
+    ).await?;
+
+    let ix = migrate_ix(&owner.pubkey(), &source, &dest);
+    assert_eq!(
+        send(&mut foundation, ix, &owner).await,
+        Err(PoolError::MigrationTokenPairMismatch { source_pool: source.pool_state_pda, dest_pool: dest.pool_state_pda }.error_code()),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,354 @@
+/*
+MIT License
+
+Copyright (c) 2024 Davinci
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Counter Overflow Tests
+//!
+//! Pools are pre-seeded with counters close to `u64::MAX` to check that long-lived pools
+//! degrade gracefully: cumulative fee counters saturate without blocking swaps, while
+//! liquidity counters, which mirror real balances, fail with `MathOverflow` instead of
+//! panicking. The u128 lifetime volume and fee counters keep counting past `u64::MAX`.
+//! Liquidity seeded above the real vault balance trips the swap's `PoolStateCorrupted` check.
+
+mod common;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use common::{
+    liquidity_helpers::{create_deposit_instruction_standardized, create_swap_instruction_standardized},
+    pool_helpers::{create_pool_new_pattern, PoolConfig},
+    setup::{create_program_test, initialize_treasury_system, simulate_and_get_return_data, transfer_sol},
+    tokens::{create_mint, create_token_account, get_token_balance, mint_tokens},
+};
+use fixed_ratio_trading::{
+    constants::{LP_TOKEN_A_MINT_SEED_PREFIX, LP_TOKEN_B_MINT_SEED_PREFIX},
+    error::PoolError,
+    state::{MainTreasuryState, PoolState},
+    types::{instructions::PoolInstruction, PoolActivity},
+};
+use serial_test::serial;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::AccountSharedData,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+/// A 1:1 pool with Token B liquidity and a funded user holding Token A
+struct OverflowSetup {
+    context: ProgramTestContext,
+    pool: PoolConfig,
+    user: Keypair,
+    user_token_a: Keypair,
+    user_token_b: Keypair,
+    user_lp_a: Keypair,
+}
+
+async fn setup_pool() -> Result<OverflowSetup, Box<dyn std::error::Error>> {
+    let mut context = create_program_test().start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let blockhash = context.last_blockhash;
+
+    let mint_1 = Keypair::new();
+    let mint_2 = Keypair::new();
+    for mint in [&mint_1, &mint_2] {
+        create_mint(&mut context.banks_client, &payer, blockhash, mint, Some(6)).await?;
+    }
+    initialize_treasury_system(&mut context.banks_client, &payer, blockhash, &Keypair::new()).await?;
+    let pool = create_pool_new_pattern(&mut context.banks_client, &payer, blockhash, &mint_1, &mint_2, Some(1)).await?;
+    let (lp_token_a_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_A_MINT_SEED_PREFIX, pool.pool_state_pda.as_ref()], &fixed_ratio_trading::id());
+
+    let user = Keypair::new();
+    let user_token_a = Keypair::new();
+    let user_token_b = Keypair::new();
+    let user_lp_a = Keypair::new();
+    let user_lp_b = Keypair::new();
+    let (lp_token_b_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_B_MINT_SEED_PREFIX, pool.pool_state_pda.as_ref()], &fixed_ratio_trading::id());
+    transfer_sol(&mut context.banks_client, &payer, blockhash, &payer, &user.pubkey(), 1_000_000_000).await?;
+    for (account, mint) in [
+        (&user_token_a, &pool.token_a_mint),
+        (&user_token_b, &pool.token_b_mint),
+        (&user_lp_a, &lp_token_a_mint),
+        (&user_lp_b, &lp_token_b_mint),
+    ] {
+        create_token_account(&mut context.banks_client, &payer, blockhash, account, mint, &user.pubkey()).await?;
+    }
+    mint_tokens(&mut context.banks_client, &payer, blockhash, &pool.token_a_mint, &user_token_a.pubkey(), &payer, 100_000).await?;
+    mint_tokens(&mut context.banks_client, &payer, blockhash, &pool.token_b_mint, &user_token_b.pubkey(), &payer, 100_000).await?;
+
+    // Token B liquidity for the swaps to draw on
+    let mut setup = OverflowSetup { context, pool, user, user_token_a, user_token_b, user_lp_a };
+    let deposit_ix = create_deposit_instruction_standardized(
+        &setup.user.pubkey(),
+        &setup.user_token_b.pubkey(),
+        &user_lp_b.pubkey(),
+        &setup.pool,
+        &lp_token_a_mint,
+        &lp_token_b_mint,
+        &PoolInstruction::Deposit {
+            deposit_token_mint: setup.pool.token_b_mint,
+            amount: 50_000,
+            pool_id: setup.pool.pool_state_pda,
+        },
+    )?;
+    setup.send(deposit_ix).await?;
+    Ok(setup)
+}
+
+impl OverflowSetup {
+    /// Sends under a fresh blockhash so a retried identical instruction is not
+    /// deduplicated into the earlier transaction's result
+    async fn send(&mut self, instruction: Instruction) -> Result<(), TransactionError> {
+        let payer = self.context.payer.insecure_clone();
+        let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+        let mut tx = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &self.user], blockhash);
+        self.context.banks_client.process_transaction(tx).await.map_err(|e| e.unwrap())
+    }
+
+    async fn pool_state(&mut self) -> PoolState {
+        let account = self.context.banks_client.get_account(self.pool.pool_state_pda).await.unwrap().unwrap();
+        PoolState::try_from_slice(&account.data).unwrap()
+    }
+
+    /// Rewrites the stored pool state in place, keeping the account's lamports and size
+    async fn seed_pool_state(&mut self, seed: impl FnOnce(&mut PoolState)) {
+        let mut account = self.context.banks_client.get_account(self.pool.pool_state_pda).await.unwrap().unwrap();
+        let mut state = PoolState::try_from_slice(&account.data).unwrap();
+        seed(&mut state);
+        let bytes = state.try_to_vec().unwrap();
+        account.data[..bytes.len()].copy_from_slice(&bytes);
+        self.context.set_account(&self.pool.pool_state_pda, &AccountSharedData::from(account));
+    }
+
+    fn swap_a_to_b_ix(&self, amount_in: u64) -> Instruction {
+        create_swap_instruction_standardized(
+            &self.user.pubkey(),
+            &self.user_token_a.pubkey(),
+            &self.user_token_b.pubkey(),
+            &self.pool,
+            &PoolInstruction::Swap {
+                input_token_mint: self.pool.token_a_mint,
+                amount_in,
+                expected_amount_out: amount_in,
+                pool_id: self.pool.pool_state_pda,
+            },
+        ).unwrap()
+    }
+
+    fn deposit_a_ix(&self, amount: u64) -> Instruction {
+        let (lp_token_a_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_A_MINT_SEED_PREFIX, self.pool.pool_state_pda.as_ref()], &fixed_ratio_trading::id());
+        let (lp_token_b_mint, _) = Pubkey::find_program_address(&[LP_TOKEN_B_MINT_SEED_PREFIX, self.pool.pool_state_pda.as_ref()], &fixed_ratio_trading::id());
+        create_deposit_instruction_standardized(
+            &self.user.pubkey(),
+            &self.user_token_a.pubkey(),
+            &self.user_lp_a.pubkey(),
+            &self.pool,
+            &lp_token_a_mint,
+            &lp_token_b_mint,
+            &PoolInstruction::Deposit {
+                deposit_token_mint: self.pool.token_a_mint,
+                amount,
+                pool_id: self.pool.pool_state_pda,
+            },
+        ).unwrap()
+    }
+}
+
+/// Fee helpers saturate the cumulative counters instead of overflowing, while
+/// `add_liquidity` reports `MathOverflow` and leaves the counter untouched
+#[test]
+fn test_state_counters_near_u64_max() {
+    let mut pool = PoolState {
+        collected_swap_contract_fees: u64::MAX - 10,
+        collected_liquidity_fees: u64::MAX - 10,
+        total_sol_fees_collected: u64::MAX - 10,
+        total_token_a_liquidity: u64::MAX - 10,
+        ..PoolState::default()
+    };
+    pool.add_swap_contract_fee(1_000, 0);
+    pool.add_liquidity_fee(1_000, 0);
+    assert_eq!(pool.collected_swap_contract_fees, u64::MAX);
+    assert_eq!(pool.collected_liquidity_fees, u64::MAX);
+    assert_eq!(pool.total_sol_fees_collected, u64::MAX);
+
+    pool.add_liquidity(true, 10).expect("Exactly u64::MAX still fits");
+    assert_eq!(pool.total_token_a_liquidity, u64::MAX);
+    let error = pool.add_liquidity(true, 1).unwrap_err();
+    assert_eq!(error.error_code(), 1084);
+    assert_eq!(pool.total_token_a_liquidity, u64::MAX, "A failed credit must not change the counter");
+    pool.add_liquidity(false, 1).expect("Token B liquidity is tracked separately");
+
+    let mut treasury = MainTreasuryState {
+        total_liquidity_fees: u64::MAX - 1,
+        liquidity_operation_count: u64::MAX,
+        total_donations: u64::MAX - 1,
+        total_pool_creation_fees: u64::MAX,
+        ..MainTreasuryState::default()
+    };
+    treasury.add_liquidity_fee(1_000, 0);
+    treasury.add_donation(1_000, 0);
+    assert_eq!(treasury.total_liquidity_fees, u64::MAX);
+    assert_eq!(treasury.liquidity_operation_count, u64::MAX);
+    assert_eq!(treasury.total_donations, u64::MAX);
+    assert_eq!(treasury.total_fees_collected(), u64::MAX);
+    assert_eq!(treasury.total_operations_processed(), u64::MAX);
+}
+
+/// A swap on a pool whose fee counters are about to wrap still executes; the counters saturate
+#[tokio::test]
+#[serial]
+async fn test_swap_with_fee_counters_near_u64_max() -> TestResult {
+    let mut setup = setup_pool().await?;
+    setup.seed_pool_state(|state| {
+        state.collected_swap_contract_fees = u64::MAX - 1;
+        state.total_sol_fees_collected = u64::MAX - 1;
+    }).await;
+    let before = setup.pool_state().await;
+
+    let ix = setup.swap_a_to_b_ix(1_000);
+    setup.send(ix).await.expect("Swap must not be blocked by a full fee counter");
+
+    let after = setup.pool_state().await;
+    assert_eq!(after.collected_swap_contract_fees, u64::MAX);
+    assert_eq!(after.total_sol_fees_collected, u64::MAX);
+    assert_eq!(after.total_token_a_liquidity, before.total_token_a_liquidity + 1_000);
+    assert_eq!(after.total_token_b_liquidity, before.total_token_b_liquidity - 1_000);
+    Ok(())
+}
+
+/// A deposit that would push pool liquidity past u64::MAX fails with MathOverflow
+#[tokio::test]
+#[serial]
+async fn test_deposit_liquidity_overflow_is_math_overflow() -> TestResult {
+    let mut setup = setup_pool().await?;
+    setup.seed_pool_state(|state| state.total_token_a_liquidity = u64::MAX - 5).await;
+    let user_token_a_before = get_token_balance(&mut setup.context.banks_client, &setup.user_token_a.pubkey()).await;
+
+    let ix = setup.deposit_a_ix(10);
+    assert_eq!(
+        setup.send(ix).await.unwrap_err(),
+        TransactionError::InstructionError(0, InstructionError::Custom(
+            PoolError::MathOverflow { counter: String::new(), amount: 0 }.error_code()
+        )),
+    );
+    assert_eq!(setup.pool_state().await.total_token_a_liquidity, u64::MAX - 5);
+    assert_eq!(get_token_balance(&mut setup.context.banks_client, &setup.user_token_a.pubkey()).await, user_token_a_before);
+
+    // A deposit that exactly fills the counter still goes through
+    let ix = setup.deposit_a_ix(5);
+    setup.send(ix).await.expect("Deposit up to u64::MAX must succeed");
+    assert_eq!(setup.pool_state().await.total_token_a_liquidity, u64::MAX);
+    Ok(())
+}
+
+/// A swap halts with PoolStateCorrupted, before moving any tokens, when the output vault
+/// no longer backs the tracked liquidity plus collected fees
+#[tokio::test]
+#[serial]
+async fn test_swap_halts_when_vault_does_not_back_accounting() -> TestResult {
+    let mut setup = setup_pool().await?;
+    let vault_balance = get_token_balance(&mut setup.context.banks_client, &setup.pool.token_b_vault_pda).await;
+    let corrupted_error = |required: u64| TransactionError::InstructionError(0, InstructionError::Custom(
+        PoolError::PoolStateCorrupted { vault: Pubkey::default(), vault_balance, required }.error_code()
+    ));
+
+    // Tracked liquidity above the vault balance
+    setup.seed_pool_state(|state| state.total_token_b_liquidity = vault_balance + 1).await;
+    let ix = setup.swap_a_to_b_ix(1_000);
+    assert_eq!(setup.send(ix).await.unwrap_err(), corrupted_error(vault_balance + 1));
+
+    // Collected token fees that the vault cannot cover
+    setup.seed_pool_state(|state| {
+        state.total_token_b_liquidity = vault_balance;
+        state.collected_fees_token_b = 1;
+    }).await;
+    let ix = setup.swap_a_to_b_ix(1_000);
+    assert_eq!(setup.send(ix).await.unwrap_err(), corrupted_error(vault_balance + 1));
+    assert_eq!(get_token_balance(&mut setup.context.banks_client, &setup.pool.token_b_vault_pda).await, vault_balance);
+    assert_eq!(get_token_balance(&mut setup.context.banks_client, &setup.user_token_b.pubkey()).await, 100_000 - vault_balance);
+
+    // Consistent accounting lets the swap through again
+    setup.seed_pool_state(|state| state.collected_fees_token_b = 0).await;
+    let ix = setup.swap_a_to_b_ix(1_000);
+    setup.send(ix).await.expect("Swap on a consistent pool must succeed");
+    assert_eq!(get_token_balance(&mut setup.context.banks_client, &setup.pool.token_b_vault_pda).await, vault_balance - 1_000);
+    Ok(())
+}
+
+/// The u128 lifetime counters accumulate past u64::MAX and saturate only at u128::MAX
+#[test]
+fn test_lifetime_counters_accumulate_past_u64_max() {
+    let mut pool = PoolState::default();
+    for _ in 0..3 {
+        pool.record_swap_volume(true, u64::MAX);
+        pool.add_swap_contract_fee(u64::MAX, 0);
+    }
+    pool.add_liquidity_fee(u64::MAX, 0);
+    assert_eq!(pool.cumulative_volume_token_a, 3 * u64::MAX as u128);
+    assert_eq!(pool.cumulative_volume_token_b, 0);
+    assert_eq!(pool.lifetime_fees, 4 * u64::MAX as u128);
+    assert_eq!(pool.total_sol_fees_collected, u64::MAX, "The u64 fee total saturates meanwhile");
+
+    pool.cumulative_volume_token_b = u128::MAX - 1;
+    pool.lifetime_fees = u128::MAX - 1;
+    pool.record_swap_volume(false, 1_000);
+    pool.add_liquidity_fee(1_000, 0);
+    assert_eq!(pool.cumulative_volume_token_b, u128::MAX);
+    assert_eq!(pool.lifetime_fees, u128::MAX);
+}
+
+/// A swap carries the lifetime volume past u64::MAX and GetPoolActivity reports the full u128 value
+#[tokio::test]
+#[serial]
+async fn test_swap_volume_past_u64_max_reported_by_pool_activity() -> TestResult {
+    let mut setup = setup_pool().await?;
+    setup.seed_pool_state(|state| {
+        state.cumulative_volume_token_a = u64::MAX as u128 - 10;
+        state.lifetime_fees = u64::MAX as u128;
+    }).await;
+    let swap_fee = setup.pool_state().await.swap_contract_fee;
+
+    let ix = setup.swap_a_to_b_ix(1_000);
+    setup.send(ix).await?;
+
+    let after = setup.pool_state().await;
+    assert_eq!(after.cumulative_volume_token_a, u64::MAX as u128 + 990);
+    assert_eq!(after.cumulative_volume_token_b, 0);
+    assert_eq!(after.lifetime_fees, u64::MAX as u128 + swap_fee as u128);
+
+    let get_activity_ix = Instruction {
+        program_id: fixed_ratio_trading::id(),
+        accounts: vec![AccountMeta::new_readonly(setup.pool.pool_state_pda, false)],
+        data: PoolInstruction::GetPoolActivity { pool_id: setup.pool.pool_state_pda }.try_to_vec()?,
+    };
+    let payer = setup.context.payer.insecure_clone();
+    let data = simulate_and_get_return_data(&mut setup.context.banks_client, &payer, get_activity_ix).await?;
+    let activity = PoolActivity::try_from_slice(&data)?;
+    assert_eq!(activity.cumulative_volume_token_a, after.cumulative_volume_token_a);
+    assert_eq!(activity.lifetime_fees, after.lifetime_fees);
+    Ok(())
+}
@@ -114,6 +114,8 @@ pub struct LiquidityTestFoundation {
     pub user2_base_account: Keypair,
     pub user2_lp_a_account: Keypair,
     pub user2_lp_b_account: Keypair,
+    /// Admin authority the system was initialized with (signs admin-only instructions)
+    pub system_authority: Keypair,
 }
 
 /// Creates a complete liquidity test foundation with pool + funded users
@@ -494,6 +496,7 @@ pub async fn create_liquidity_test_foundation_with_exact_basis_points(
         user2_base_account,
         user2_lp_a_account,
         user2_lp_b_account,
+        system_authority,
     })
 }
 
@@ -772,6 +775,7 @@ pub async fn create_liquidity_test_foundation_with_custom_pool_advanced(
         user2_base_account,
         user2_lp_a_account,
         user2_lp_b_account,
+        system_authority,
     })
 }
 
@@ -957,6 +961,7 @@ pub async fn create_liquidity_test_foundation_with_fees(
         user2_base_account,
         user2_lp_a_account,
         user2_lp_b_account,
+        system_authority,
     };
 
     // NEW: Actually generate fees if requested
@@ -1161,7 +1166,8 @@ pub fn create_swap_instruction_standardized(
     
     // Extract input mint from instruction data
     let input_token_mint = match swap_instruction_data {
-        PoolInstruction::Swap { input_token_mint, .. } => *input_token_mint,
+        PoolInstruction::Swap { input_token_mint, .. }
+        | PoolInstruction::SwapWithExpiry { input_token_mint, .. } => *input_token_mint,
         _ => return Err("Invalid instruction type for swap".into()),
     };
     
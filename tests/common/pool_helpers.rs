@@ -205,11 +205,34 @@ pub async fn create_pool_new_pattern(
     multiple_mint: &Keypair,
     base_mint: &Keypair,
     multiple_per_base: Option<u64>,
+) -> Result<PoolConfig, BanksClientError> {
+    create_pool_with_mint_keys(
+        banks,
+        payer,
+        recent_blockhash,
+        &multiple_mint.pubkey(),
+        &base_mint.pubkey(),
+        multiple_per_base,
+    ).await
+}
+
+/// Create a pool from mint addresses rather than mint keypairs
+/// 
+/// Same as `create_pool_new_pattern`, for mints the test does not hold a keypair
+/// for (e.g. the wrapped SOL native mint).
+#[allow(dead_code)]
+pub async fn create_pool_with_mint_keys(
+    banks: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: solana_sdk::hash::Hash,
+    multiple_mint: &Pubkey,
+    base_mint: &Pubkey,
+    multiple_per_base: Option<u64>,
 ) -> Result<PoolConfig, BanksClientError> {
     let ratio = multiple_per_base.unwrap_or(constants::DEFAULT_RATIO);
     
     // Get normalized pool configuration using modern function
-    let config = normalize_pool_config(&multiple_mint.pubkey(), &base_mint.pubkey(), ratio, 1);
+    let config = normalize_pool_config(multiple_mint, base_mint, ratio, 1);
 
     // Check if pool already exists
     if let Some(_existing_pool) = get_pool_state(banks, &config.pool_state_pda).await {
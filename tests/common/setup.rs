@@ -662,4 +662,34 @@ pub async fn initialize_treasury_system(
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+/// Simulate a view instruction and return the data it set via `set_return_data`
+/// 
+/// # Arguments
+/// * `banks` - Banks client
+/// * `payer` - Fee payer used to sign the simulated transaction
+/// * `instruction` - The view instruction to simulate
+/// 
+/// # Returns
+/// The raw return data bytes (empty if the program set none)
+#[allow(dead_code)]
+pub async fn simulate_and_get_return_data(
+    banks: &mut BanksClient,
+    payer: &Keypair,
+    instruction: Instruction,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let recent_blockhash = banks.get_latest_blockhash().await?;
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[payer], recent_blockhash);
+
+    let simulation = banks.simulate_transaction(transaction).await?;
+    if let Some(Err(e)) = simulation.result {
+        return Err(format!("View simulation failed: {:?}", e).into());
+    }
+
+    Ok(simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .map(|return_data| return_data.data)
+        .unwrap_or_default())
+}
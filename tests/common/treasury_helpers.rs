@@ -40,8 +40,8 @@ SOFTWARE.
 //! - Comprehensive state comparison with detailed deltas
 //! - Mock data support for reliable infrastructure testing
 
-use fixed_ratio_trading::state::MainTreasuryState;
-use fixed_ratio_trading::constants::MAIN_TREASURY_SEED_PREFIX;
+use fixed_ratio_trading::state::{DonationRecord, MainTreasuryState};
+use fixed_ratio_trading::constants::{MAIN_TREASURY_SEED_PREFIX, RECENT_DONATIONS_CAPACITY};
 use solana_sdk::pubkey::Pubkey;
 
 
@@ -172,6 +172,13 @@ pub async fn get_treasury_state_verified() -> Result<MainTreasuryState, Box<dyn
         last_withdrawal_timestamp: 0,
         donation_count: 0,
         total_donations: 0,
+        autocompound_pool: Pubkey::default(),
+        autocompound_reserve: 0,
+        autocompound_lp_tokens: 0,
+        withdrawal_window_start: 0,
+        withdrawn_in_window: 0,
+        recent_donations: [DonationRecord::default(); RECENT_DONATIONS_CAPACITY],
+        next_donation_slot: 0,
     };
     
     println!("📊 Treasury state verification (mock for debugging):");
@@ -538,6 +545,13 @@ pub async fn execute_treasury_withdrawal_with_verification(
         last_withdrawal_timestamp: mock_timestamp - 7200, // 2 hours ago
         donation_count: 5,
         total_donations: 500000, // 0.5 SOL in donations
+        autocompound_pool: Pubkey::default(),
+        autocompound_reserve: 0,
+        autocompound_lp_tokens: 0,
+        withdrawal_window_start: 0,
+        withdrawn_in_window: 0,
+        recent_donations: [DonationRecord::default(); RECENT_DONATIONS_CAPACITY],
+        next_donation_slot: 0,
     };
     
     // Calculate maximum withdrawable amount (respecting rent exemption)
@@ -634,6 +648,13 @@ pub async fn simulate_failed_treasury_withdrawal() -> Result<FailedOpResult, Box
         last_withdrawal_timestamp: mock_timestamp - 10800, // 3 hours ago
         donation_count: 2,
         total_donations: 100000, // 0.1 SOL in donations
+        autocompound_pool: Pubkey::default(),
+        autocompound_reserve: 0,
+        autocompound_lp_tokens: 0,
+        withdrawal_window_start: 0,
+        withdrawn_in_window: 0,
+        recent_donations: [DonationRecord::default(); RECENT_DONATIONS_CAPACITY],
+        next_donation_slot: 0,
     };
     
     // Simulate attempting to withdraw more than available